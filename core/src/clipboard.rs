@@ -1,6 +1,9 @@
 //! Access the clipboard.
 
-use std::{any::Any, sync::Arc};
+use std::{
+    any::{Any, TypeId},
+    sync::Arc,
+};
 
 use dnd::{DndAction, DndDestinationRectangle, DndSurface};
 use mime::{self, AllowedMimeTypes, AsMimeTypes, ClipboardStoreData};
@@ -57,6 +60,22 @@ pub trait Clipboard {
     ) {
     }
 
+    /// Returns the MIME types the current content of the [`Clipboard`] is
+    /// available in.
+    ///
+    /// The list is empty if the [`Clipboard`] cannot list its offers.
+    fn available_types(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Returns the MIME types the current content of the primary
+    /// [`Clipboard`] is available in.
+    ///
+    /// The list is empty if the [`Clipboard`] cannot list its offers.
+    fn primary_available_types(&self) -> Vec<String> {
+        Vec::new()
+    }
+
     /// Starts a DnD operation.
     fn register_dnd_destination(
         &self,
@@ -89,6 +108,18 @@ pub trait Clipboard {
     fn peek_dnd(&self, _mime: String) -> Option<(Vec<u8>, String)> {
         None
     }
+
+    /// Consider using [`start_dnd_with_payload`] instead
+    /// Stores a value to be moved in-process to a destination of the next
+    /// DnD operation, replacing the previous one.
+    fn set_dnd_payload(&mut self, _payload: Option<Box<dyn Any>>) {}
+
+    /// Consider using [`take_dnd_payload`] instead
+    /// Takes the in-process value of the ongoing DnD operation, if it is of
+    /// the type with the given [`TypeId`].
+    fn take_dnd_payload(&mut self, _type_id: TypeId) -> Option<Box<dyn Any>> {
+        None
+    }
 }
 
 /// Starts a DnD operation.
@@ -101,6 +132,7 @@ pub fn start_dnd<T: 'static, R: 'static, M: 'static>(
     content: Box<dyn AsMimeTypes + Send + 'static>,
     actions: DndAction,
 ) {
+    clipboard.set_dnd_payload(None);
     clipboard.start_dnd(
         internal,
         source_surface,
@@ -113,6 +145,50 @@ pub fn start_dnd<T: 'static, R: 'static, M: 'static>(
     );
 }
 
+/// Starts a DnD operation that moves the given `payload` directly to a
+/// destination in the same application, even in another window.
+///
+/// The `content` is still offered to other applications, so the payload is
+/// discarded if the drop lands outside of the application.
+pub fn start_dnd_with_payload<
+    T: 'static,
+    R: 'static,
+    M: 'static,
+    P: 'static,
+>(
+    clipboard: &mut dyn Clipboard,
+    internal: bool,
+    source_surface: Option<DndSource>,
+    icon_surface: Option<(Element<'static, M, T, R>, State)>,
+    content: Box<dyn AsMimeTypes + Send + 'static>,
+    actions: DndAction,
+    payload: P,
+) {
+    start_dnd(
+        clipboard,
+        internal,
+        source_surface,
+        icon_surface,
+        content,
+        actions,
+    );
+    clipboard.set_dnd_payload(Some(Box::new(payload)));
+}
+
+/// Takes the payload of type `P` of the ongoing DnD operation, started with
+/// [`start_dnd_with_payload`] in the same application.
+///
+/// Returns `None` if the DnD operation was started by another application,
+/// without a payload, or with a payload of a different type.
+pub fn take_dnd_payload<P: 'static>(
+    clipboard: &mut dyn Clipboard,
+) -> Option<P> {
+    clipboard
+        .take_dnd_payload(TypeId::of::<P>())
+        .and_then(|payload| payload.downcast().ok())
+        .map(|payload| *payload)
+}
+
 /// A null implementation of the [`Clipboard`] trait.
 #[derive(Debug, Clone, Copy)]
 pub struct Null;
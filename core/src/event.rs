@@ -4,8 +4,11 @@ use dnd::DndSurface;
 
 use crate::keyboard;
 use crate::mouse;
+use crate::theme;
 use crate::touch;
+use crate::touchpad;
 use crate::window;
+use crate::Font;
 #[cfg(feature = "wayland")]
 /// A platform specific event for wayland
 pub mod wayland;
@@ -29,6 +32,9 @@ pub enum Event {
     /// A touch event
     Touch(touch::Event),
 
+    /// A touchpad gesture event
+    Touchpad(touchpad::Event),
+
     #[cfg(feature = "a11y")]
     /// An Accesskit event for a specific Accesskit Node in an accessible widget
     A11y(
@@ -39,6 +45,25 @@ pub enum Event {
     /// A DnD event.
     Dnd(DndEvent<DndSurface>),
 
+    /// A global keyboard shortcut registered by the application was
+    /// triggered, even if none of its windows had focus.
+    GlobalShortcut(keyboard::shortcut::Id),
+
+    /// A font loaded in the background became available.
+    ///
+    /// Every user interface is laid out again when fonts are loaded, so that
+    /// text drawn with a fallback font is reshaped with the new one. The
+    /// fonts loaded during the same frame share a single layout pass.
+    FontLoaded(Font),
+
+    /// The [`Palette`] of the theme of the application changed.
+    ///
+    /// It is produced at most once per frame, whether the theme changed
+    /// because of the system appearance or because of the application.
+    ///
+    /// [`Palette`]: crate::theme::Palette
+    ThemePaletteChanged(theme::Palette),
+
     /// A platform specific event
     PlatformSpecific(PlatformSpecific),
 }
@@ -6,7 +6,7 @@ mod seat;
 mod session_lock;
 mod window;
 
-use crate::{time::Instant, window::Id};
+use crate::{mouse, time::Instant, window::Id};
 use sctk::reexports::client::protocol::{
     wl_output::WlOutput, wl_seat::WlSeat, wl_surface::WlSurface,
 };
@@ -32,6 +32,12 @@ pub enum Event {
     Window(WindowEvent, WlSurface, Id),
     /// Seat Event
     Seat(SeatEvent, WlSeat),
+    /// Pointer event of a seat other than the primary one
+    ///
+    /// The pointer of the primary seat produces regular mouse events, while
+    /// the pointers of the other seats of multi-seat setups produce this
+    /// event, tagged with the seat they belong to.
+    Pointer(mouse::Event, WlSeat),
     /// Data Device event
     DataSource(DataSourceEvent),
     /// Dnd Offer events
@@ -56,6 +62,9 @@ impl Event {
                 *x += vector.x as f64;
                 *y += vector.y as f64;
             }
+            Event::Pointer(mouse::Event::CursorMoved { position }, _) => {
+                *position = *position + vector;
+            }
             _ => {}
         }
     }
@@ -22,6 +22,10 @@ pub enum Event {
 
         /// The text produced by the key press, if any.
         text: Option<SmolStr>,
+
+        /// Whether the key press was synthesized by the system because the
+        /// key is being held down.
+        repeat: bool,
     },
 
     /// A keyboard key was released.
@@ -37,5 +41,8 @@ pub enum Event {
     },
 
     /// The keyboard modifiers have changed.
+    ///
+    /// It is produced even when only a modifier key is pressed or released,
+    /// and it carries the full state of the modifiers.
     ModifiersChanged(Modifiers),
 }
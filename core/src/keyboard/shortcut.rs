@@ -0,0 +1,39 @@
+//! Register keyboard shortcuts with the system.
+use crate::keyboard::{Key, Modifiers};
+
+use std::sync::atomic::{self, AtomicU32};
+
+/// A [`Key`] pressed together with some [`Modifiers`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Shortcut {
+    /// The [`Modifiers`] that must be held.
+    pub modifiers: Modifiers,
+
+    /// The [`Key`] that triggers the [`Shortcut`].
+    pub key: Key,
+}
+
+impl Shortcut {
+    /// Creates a new [`Shortcut`] with the given [`Modifiers`] and [`Key`].
+    pub fn new(modifiers: Modifiers, key: Key) -> Self {
+        Self { modifiers, key }
+    }
+}
+
+/// The identifier of a [`Shortcut`] registered with the system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Id(u32);
+
+static COUNT: AtomicU32 = AtomicU32::new(1);
+
+impl Id {
+    /// Creates a new unique shortcut [`Id`].
+    pub fn unique() -> Id {
+        Id(COUNT.fetch_add(1, atomic::Ordering::Relaxed))
+    }
+
+    /// Returns the raw value of the [`Id`].
+    pub fn as_u32(self) -> u32 {
+        self.0
+    }
+}
@@ -31,8 +31,10 @@ pub mod overlay;
 pub mod renderer;
 pub mod svg;
 pub mod text;
+pub mod theme;
 pub mod time;
 pub mod touch;
+pub mod touchpad;
 pub mod widget;
 pub mod window;
 
@@ -1,5 +1,6 @@
 //! Handle mouse events.
 pub mod click;
+pub mod custom_cursor;
 
 mod button;
 mod cursor;
@@ -9,5 +10,6 @@ mod interaction;
 pub use button::Button;
 pub use click::Click;
 pub use cursor::Cursor;
-pub use event::{Event, ScrollDelta};
+pub use custom_cursor::CustomCursor;
+pub use event::{Event, ScrollDelta, ScrollPhase};
 pub use interaction::Interaction;
@@ -0,0 +1,158 @@
+//! Use custom images as the mouse cursor.
+use crate::{Point, Size};
+
+use std::mem;
+
+/// Builds a [`CustomCursor`] from its RGBA pixels in the `sRGB` color space
+/// and the `hotspot` of the image that points at the cursor position.
+///
+/// A `hotspot` outside of the bounds of the image is clamped to its closest
+/// pixel, and a warning is logged.
+pub fn from_rgba(
+    rgba: Vec<u8>,
+    width: u32,
+    height: u32,
+    hotspot: Point<u32>,
+) -> Result<CustomCursor, Error> {
+    const PIXEL_SIZE: usize = mem::size_of::<u8>() * 4;
+
+    if rgba.len() % PIXEL_SIZE != 0 {
+        return Err(Error::ByteCountNotDivisibleBy4 {
+            byte_count: rgba.len(),
+        });
+    }
+
+    let pixel_count = rgba.len() / PIXEL_SIZE;
+
+    if pixel_count != (width * height) as usize {
+        return Err(Error::DimensionsVsPixelCount {
+            width,
+            height,
+            width_x_height: (width * height) as usize,
+            pixel_count,
+        });
+    }
+
+    if width == 0 || height == 0 {
+        return Err(Error::Empty);
+    }
+
+    let clamped =
+        Point::new(hotspot.x.min(width - 1), hotspot.y.min(height - 1));
+
+    if clamped != hotspot {
+        log::warn!(
+            "The hotspot ({}, {}) of the custom cursor is outside of its \
+            {width}x{height} image, clamping it to ({}, {})",
+            hotspot.x,
+            hotspot.y,
+            clamped.x,
+            clamped.y,
+        );
+    }
+
+    Ok(CustomCursor {
+        rgba,
+        size: Size::new(width, height),
+        hotspot: clamped,
+    })
+}
+
+/// An image used as the mouse cursor, in place of the icon of a
+/// [`mouse::Interaction`](super::Interaction).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomCursor {
+    rgba: Vec<u8>,
+    size: Size<u32>,
+    hotspot: Point<u32>,
+}
+
+impl CustomCursor {
+    /// Returns the RGBA pixels of the [`CustomCursor`].
+    pub fn rgba(&self) -> &[u8] {
+        &self.rgba
+    }
+
+    /// Returns the size of the image of the [`CustomCursor`].
+    pub fn size(&self) -> Size<u32> {
+        self.size
+    }
+
+    /// Returns the pixel of the image of the [`CustomCursor`] that points at
+    /// the cursor position.
+    ///
+    /// It is always within the bounds of the image.
+    pub fn hotspot(&self) -> Point<u32> {
+        self.hotspot
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+/// An error produced when using [`from_rgba`] with invalid arguments.
+pub enum Error {
+    /// Produced when the length of the `rgba` argument isn't divisible by 4, thus `rgba` can't be
+    /// safely interpreted as 32bpp RGBA pixels.
+    #[error(
+        "The provided RGBA data (with length {byte_count}) isn't divisible \
+        by 4. Therefore, it cannot be safely interpreted as 32bpp RGBA pixels"
+    )]
+    ByteCountNotDivisibleBy4 {
+        /// The length of the provided RGBA data.
+        byte_count: usize,
+    },
+    /// Produced when the number of pixels (`rgba.len() / 4`) isn't equal to `width * height`.
+    /// At least one of your arguments is incorrect.
+    #[error(
+        "The number of RGBA pixels ({pixel_count}) does not match the \
+        provided dimensions ({width}x{height})."
+    )]
+    DimensionsVsPixelCount {
+        /// The provided width.
+        width: u32,
+        /// The provided height.
+        height: u32,
+        /// The product of `width` and `height`.
+        width_x_height: usize,
+        /// The amount of pixels of the provided RGBA data.
+        pixel_count: usize,
+    },
+    /// Produced when the image has no pixels, thus there is no pixel for the
+    /// hotspot to point at.
+    #[error("The image of a custom cursor cannot be empty.")]
+    Empty,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crosshair_hotspot_is_its_center() {
+        let cursor =
+            from_rgba(vec![0; 15 * 15 * 4], 15, 15, Point::new(7, 7)).unwrap();
+
+        assert_eq!(cursor.hotspot(), Point::new(7, 7));
+        assert_eq!(cursor.size(), Size::new(15, 15));
+    }
+
+    #[test]
+    fn hotspot_outside_of_the_image_is_clamped() {
+        let cursor =
+            from_rgba(vec![0; 16 * 8 * 4], 16, 8, Point::new(20, 3)).unwrap();
+
+        assert_eq!(cursor.hotspot(), Point::new(15, 3));
+
+        let cursor =
+            from_rgba(vec![0; 16 * 8 * 4], 16, 8, Point::new(16, 8)).unwrap();
+
+        assert_eq!(cursor.hotspot(), Point::new(15, 7));
+    }
+
+    #[test]
+    fn empty_image_is_rejected() {
+        assert!(matches!(
+            from_rgba(Vec::new(), 0, 0, Point::new(0, 0)),
+            Err(Error::Empty)
+        ));
+    }
+}
@@ -1,4 +1,4 @@
-use crate::Point;
+use crate::{Point, Vector};
 
 use super::Button;
 
@@ -32,6 +32,28 @@ pub enum Event {
     WheelScrolled {
         /// The scroll movement.
         delta: ScrollDelta,
+
+        /// The phase of the scroll gesture the movement belongs to.
+        phase: ScrollPhase,
+    },
+
+    /// The mouse was moved by the given raw amount, independently of the
+    /// position of the cursor.
+    ///
+    /// The delta comes straight from the device, without acceleration nor
+    /// scaling, and it keeps being produced while the cursor is grabbed,
+    /// hidden, or stuck at the edge of the screen. This makes it suitable to
+    /// control a camera.
+    ///
+    /// It is only produced for windows whose cursor is grabbed with
+    /// `window::set_cursor_grab`, and it does not redraw them by itself.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland (SCTK):** Unsupported.
+    RawMotion {
+        /// The raw movement of the mouse.
+        delta: Vector,
     },
 }
 
@@ -54,3 +76,31 @@ pub enum ScrollDelta {
         y: f32,
     },
 }
+
+/// The phase of a scroll gesture.
+///
+/// Precise input devices, like touchpads, report the beginning and the end
+/// of a scroll gesture. This can be used to implement momentum scrolling
+/// and rubber-banding.
+///
+/// Platforms and devices without phases, like most mouse wheels, always
+/// report [`ScrollPhase::Moved`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ScrollPhase {
+    /// The user started a scroll gesture.
+    Started,
+    /// The user scrolled as part of an ongoing gesture, or without any
+    /// gesture at all.
+    #[default]
+    Moved,
+    /// The user ended the scroll gesture.
+    Ended,
+    /// The scroll movement was produced by the momentum of a gesture that
+    /// already ended.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Winit:** Momentum is reported as [`ScrollPhase::Moved`], since
+    ///   `winit` does not tell it apart from direct scrolling.
+    Inertia,
+}
@@ -199,6 +199,19 @@ where
                 });
             }
 
+            fn button(
+                &mut self,
+                id: Option<&widget::Id>,
+                bounds: Rectangle,
+                operate_on_children: &mut dyn FnMut(
+                    &mut dyn widget::Operation<T>,
+                ),
+            ) {
+                self.operation.button(id, bounds, &mut |operation| {
+                    operate_on_children(&mut MapOperation { operation });
+                });
+            }
+
             fn focusable(
                 &mut self,
                 state: &mut dyn widget::operation::Focusable,
@@ -1,9 +1,13 @@
 //! Write your own renderer.
+mod backend;
 #[cfg(debug_assertions)]
 mod null;
+mod render_stats;
 
+pub use backend::Backend;
 #[cfg(debug_assertions)]
 pub use null::Null;
+pub use render_stats::RenderStats;
 
 use crate::{Background, Border, Color, Rectangle, Shadow, Size, Vector};
 
@@ -0,0 +1,9 @@
+/// The kind of graphics backend used to render the windows of the
+/// application.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backend {
+    /// A GPU-accelerated backend powered by `wgpu`.
+    Wgpu,
+    /// A software backend powered by `tiny-skia`.
+    TinySkia,
+}
@@ -0,0 +1,13 @@
+/// Contains the amount of primitives and draw calls submitted by a renderer
+/// for a single frame.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RenderStats {
+    /// The amount of quads drawn.
+    pub quads: usize,
+    /// The amount of triangle meshes drawn.
+    pub meshes: usize,
+    /// The amount of text runs drawn.
+    pub text_runs: usize,
+    /// The amount of draw calls issued.
+    pub draw_calls: usize,
+}
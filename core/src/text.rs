@@ -198,6 +198,34 @@ pub trait Renderer: crate::Renderer {
     /// Loads a [`Self::Font`] from its bytes.
     fn load_font(&mut self, font: Cow<'static, [u8]>);
 
+    /// Measures the size of the given `content` when laid out with the given
+    /// `font`, `size`, and [`Shaping`] strategy inside the given `bounds`, in
+    /// logical pixels.
+    ///
+    /// The default [`LineHeight`] is used, so the result matches the layout of
+    /// a text widget with the same attributes, regardless of the scale factor
+    /// it is drawn with. Text widgets use [`Shaping::Advanced`] by default.
+    fn measure_text(
+        &self,
+        content: &str,
+        font: Self::Font,
+        size: Pixels,
+        bounds: Size,
+        shaping: Shaping,
+    ) -> Size {
+        Self::Paragraph::with_text(Text {
+            content,
+            bounds,
+            size,
+            line_height: LineHeight::default(),
+            font,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            shaping,
+        })
+        .min_bounds()
+    }
+
     /// Draws the given [`Paragraph`] at the given position and with the given
     /// [`Color`].
     fn fill_paragraph(
@@ -0,0 +1,14 @@
+//! Describe the appearance preferred by the user.
+pub mod palette;
+
+pub use palette::Palette;
+
+/// The appearance of the system, which may be light or dark.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// A light appearance, with dark text on bright backgrounds.
+    Light,
+
+    /// A dark appearance, with bright text on dark backgrounds.
+    Dark,
+}
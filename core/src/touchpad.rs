@@ -0,0 +1,47 @@
+//! Handle touchpad gestures.
+
+/// A touchpad gesture.
+///
+/// Every gesture reports both the change since its last event and the
+/// total change since it started.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Event {
+    /// A pinch gesture, usually used to zoom.
+    ///
+    /// Positive values indicate magnification (i.e. zooming in), while
+    /// negative values indicate shrinking (i.e. zooming out).
+    Pinched {
+        /// The [`Phase`] of the gesture.
+        phase: Phase,
+        /// The change in magnification since the last event.
+        delta: f32,
+        /// The change in magnification since the gesture started.
+        total: f32,
+    },
+
+    /// A rotation gesture, in degrees.
+    ///
+    /// Positive values indicate counterclockwise rotation, while negative
+    /// values indicate clockwise rotation.
+    Rotated {
+        /// The [`Phase`] of the gesture.
+        phase: Phase,
+        /// The rotation since the last event.
+        delta: f32,
+        /// The rotation since the gesture started.
+        total: f32,
+    },
+}
+
+/// The phase of a touchpad gesture.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    /// The gesture started.
+    Started,
+    /// The gesture is in progress.
+    Updated,
+    /// The gesture ended.
+    Ended,
+    /// The gesture was cancelled.
+    Cancelled,
+}
@@ -1,10 +1,12 @@
 //! Query or update internal widget state.
 pub mod focusable;
+pub mod layout_dump;
 pub mod scrollable;
 pub mod search_id;
 pub mod text_input;
 
 pub use focusable::Focusable;
+pub use layout_dump::LayoutDump;
 pub use scrollable::Scrollable;
 pub use text_input::TextInput;
 
@@ -59,6 +61,31 @@ impl<M: 'static> Operation<OperationOutputWrapper<M>> for OperationWrapper<M> {
         }
     }
 
+    fn button(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(
+            &mut dyn Operation<OperationOutputWrapper<M>>,
+        ),
+    ) {
+        match self {
+            OperationWrapper::Message(operation) => {
+                operation.button(id, bounds, &mut |operation| {
+                    operate_on_children(&mut MapOperation { operation });
+                });
+            }
+            OperationWrapper::Id(operation) => {
+                operation.button(id, bounds, &mut |operation| {
+                    operate_on_children(&mut MapOperation { operation });
+                });
+            }
+            OperationWrapper::Wrapper(operation) => {
+                operation.button(id, bounds, operate_on_children);
+            }
+        }
+    }
+
     fn focusable(&mut self, state: &mut dyn Focusable, id: Option<&Id>) {
         match self {
             OperationWrapper::Message(operation) => {
@@ -172,6 +199,17 @@ impl<'a, T, B> Operation<T> for MapOperation<'a, B> {
         });
     }
 
+    fn button(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        self.operation.button(id, bounds, &mut |operation| {
+            operate_on_children(&mut MapOperation { operation });
+        });
+    }
+
     fn focusable(&mut self, state: &mut dyn Focusable, id: Option<&Id>) {
         self.operation.focusable(state, id);
     }
@@ -209,6 +247,18 @@ pub trait Operation<T> {
         operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
     );
 
+    /// Operates on a button, which contains other widgets.
+    ///
+    /// By default, the button is operated on as a container.
+    fn button(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        self.container(id, bounds, operate_on_children);
+    }
+
     /// Operates on a widget that can be focused.
     fn focusable(&mut self, _state: &mut dyn Focusable, _id: Option<&Id>) {}
 
@@ -249,6 +299,60 @@ where
         f: Rc<dyn Fn(A) -> B>,
     }
 
+    struct MapRef<'a, A> {
+        operation: &'a mut dyn Operation<A>,
+    }
+
+    impl<'a, A, B> Operation<B> for MapRef<'a, A> {
+        fn container(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<B>),
+        ) {
+            let Self { operation, .. } = self;
+
+            operation.container(id, bounds, &mut |operation| {
+                operate_on_children(&mut MapRef { operation });
+            });
+        }
+
+        fn button(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<B>),
+        ) {
+            let Self { operation, .. } = self;
+
+            operation.button(id, bounds, &mut |operation| {
+                operate_on_children(&mut MapRef { operation });
+            });
+        }
+
+        fn scrollable(
+            &mut self,
+            state: &mut dyn Scrollable,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            translation: Vector,
+        ) {
+            self.operation.scrollable(state, id, bounds, translation);
+        }
+
+        fn focusable(&mut self, state: &mut dyn Focusable, id: Option<&Id>) {
+            self.operation.focusable(state, id);
+        }
+
+        fn text_input(&mut self, state: &mut dyn TextInput, id: Option<&Id>) {
+            self.operation.text_input(state, id);
+        }
+
+        fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
+            self.operation.custom(state, id);
+        }
+    }
+
     impl<A, B> Operation<B> for Map<A, B>
     where
         A: 'static,
@@ -260,61 +364,26 @@ where
             bounds: Rectangle,
             operate_on_children: &mut dyn FnMut(&mut dyn Operation<B>),
         ) {
-            struct MapRef<'a, A> {
-                operation: &'a mut dyn Operation<A>,
-            }
-
-            impl<'a, A, B> Operation<B> for MapRef<'a, A> {
-                fn container(
-                    &mut self,
-                    id: Option<&Id>,
-                    bounds: Rectangle,
-                    operate_on_children: &mut dyn FnMut(&mut dyn Operation<B>),
-                ) {
-                    let Self { operation, .. } = self;
-
-                    operation.container(id, bounds, &mut |operation| {
-                        operate_on_children(&mut MapRef { operation });
-                    });
-                }
-
-                fn scrollable(
-                    &mut self,
-                    state: &mut dyn Scrollable,
-                    id: Option<&Id>,
-                    bounds: Rectangle,
-                    translation: Vector,
-                ) {
-                    self.operation.scrollable(state, id, bounds, translation);
-                }
-
-                fn focusable(
-                    &mut self,
-                    state: &mut dyn Focusable,
-                    id: Option<&Id>,
-                ) {
-                    self.operation.focusable(state, id);
-                }
-
-                fn text_input(
-                    &mut self,
-                    state: &mut dyn TextInput,
-                    id: Option<&Id>,
-                ) {
-                    self.operation.text_input(state, id);
-                }
+            let Self { operation, .. } = self;
 
-                fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
-                    self.operation.custom(state, id);
-                }
+            MapRef {
+                operation: operation.as_mut(),
             }
+            .container(id, bounds, operate_on_children);
+        }
 
+        fn button(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<B>),
+        ) {
             let Self { operation, .. } = self;
 
             MapRef {
                 operation: operation.as_mut(),
             }
-            .container(id, bounds, operate_on_children);
+            .button(id, bounds, operate_on_children);
         }
 
         fn focusable(&mut self, state: &mut dyn Focusable, id: Option<&Id>) {
@@ -0,0 +1,251 @@
+//! Dump the layout of a widget tree.
+use crate::id::Internal;
+use crate::widget::operation::{Focusable, Operation, Scrollable, TextInput};
+use crate::widget::Id;
+use crate::{Rectangle, Vector};
+
+use std::any::Any;
+use std::fmt::Write;
+
+/// An [`Operation`] that records the kind, [`Id`], and bounds of every widget
+/// in a widget tree, so they can be serialized as JSON.
+///
+/// Only containers, buttons, and scrollables are recorded by default, since
+/// they are the only widgets that report their bounds. When the `debug` feature is enabled,
+/// focusable widgets, text inputs, and custom widgets are recorded as well,
+/// together with their focus and scroll state.
+#[derive(Debug, Default)]
+pub struct LayoutDump {
+    roots: Vec<Node>,
+    stack: Vec<Node>,
+}
+
+#[derive(Debug)]
+struct Node {
+    kind: &'static str,
+    id: Option<Id>,
+    bounds: Option<Rectangle>,
+    #[cfg(feature = "debug")]
+    metadata: Vec<(&'static str, String)>,
+    children: Vec<Node>,
+}
+
+impl Node {
+    fn new(
+        kind: &'static str,
+        id: Option<&Id>,
+        bounds: Option<Rectangle>,
+    ) -> Self {
+        Self {
+            kind,
+            id: id.cloned(),
+            bounds,
+            #[cfg(feature = "debug")]
+            metadata: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+}
+
+impl LayoutDump {
+    /// Creates a new, empty [`LayoutDump`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Serializes the recorded widget tree as a JSON array of nodes.
+    ///
+    /// Every node is an object with a `kind`, an `id` (a string for custom
+    /// identifiers, a number for unique ones, or `null`), its `bounds`, and
+    /// its `children`.
+    pub fn to_json(&self) -> String {
+        let mut json = String::new();
+
+        write_nodes(&mut json, &self.roots);
+
+        json
+    }
+
+    fn push(&mut self, node: Node) {
+        match self.stack.last_mut() {
+            Some(parent) => parent.children.push(node),
+            None => self.roots.push(node),
+        }
+    }
+}
+
+impl<T> Operation<T> for LayoutDump {
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        self.stack.push(Node::new("container", id, Some(bounds)));
+
+        operate_on_children(self);
+
+        if let Some(node) = self.stack.pop() {
+            self.push(node);
+        }
+    }
+
+    fn button(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        self.stack.push(Node::new("button", id, Some(bounds)));
+
+        operate_on_children(self);
+
+        if let Some(node) = self.stack.pop() {
+            self.push(node);
+        }
+    }
+
+    fn scrollable(
+        &mut self,
+        _state: &mut dyn Scrollable,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        _translation: Vector,
+    ) {
+        #[allow(unused_mut)]
+        let mut node = Node::new("scrollable", id, Some(bounds));
+
+        #[cfg(feature = "debug")]
+        node.metadata.push((
+            "translation",
+            format!(
+                "{{\"x\":{},\"y\":{}}}",
+                number(_translation.x),
+                number(_translation.y)
+            ),
+        ));
+
+        self.push(node);
+    }
+
+    fn focusable(&mut self, _state: &mut dyn Focusable, _id: Option<&Id>) {
+        #[cfg(feature = "debug")]
+        {
+            let mut node = Node::new("focusable", _id, None);
+
+            node.metadata
+                .push(("focused", _state.is_focused().to_string()));
+
+            self.push(node);
+        }
+    }
+
+    fn text_input(&mut self, _state: &mut dyn TextInput, _id: Option<&Id>) {
+        #[cfg(feature = "debug")]
+        self.push(Node::new("text_input", _id, None));
+    }
+
+    fn custom(&mut self, _state: &mut dyn Any, _id: Option<&Id>) {
+        #[cfg(feature = "debug")]
+        self.push(Node::new("custom", _id, None));
+    }
+}
+
+fn write_nodes(json: &mut String, nodes: &[Node]) {
+    json.push('[');
+
+    for (i, node) in nodes.iter().enumerate() {
+        if i > 0 {
+            json.push(',');
+        }
+
+        write_node(json, node);
+    }
+
+    json.push(']');
+}
+
+fn write_node(json: &mut String, node: &Node) {
+    let _ = write!(json, "{{\"kind\":\"{}\",\"id\":", node.kind);
+
+    match &node.id {
+        Some(id) => write_id(json, &id.0),
+        None => json.push_str("null"),
+    }
+
+    json.push_str(",\"bounds\":");
+
+    match node.bounds {
+        Some(bounds) => {
+            let _ = write!(
+                json,
+                "{{\"x\":{},\"y\":{},\"width\":{},\"height\":{}}}",
+                number(bounds.x),
+                number(bounds.y),
+                number(bounds.width),
+                number(bounds.height)
+            );
+        }
+        None => json.push_str("null"),
+    }
+
+    #[cfg(feature = "debug")]
+    for (key, value) in &node.metadata {
+        let _ = write!(json, ",\"{key}\":{value}");
+    }
+
+    json.push_str(",\"children\":");
+    write_nodes(json, &node.children);
+    json.push('}');
+}
+
+fn write_id(json: &mut String, id: &Internal) {
+    match id {
+        Internal::Unique(id) => {
+            let _ = write!(json, "{id}");
+        }
+        Internal::Custom(_, name) => write_string(json, name),
+        Internal::Set(ids) => {
+            json.push('[');
+
+            for (i, id) in ids.iter().enumerate() {
+                if i > 0 {
+                    json.push(',');
+                }
+
+                write_id(json, id);
+            }
+
+            json.push(']');
+        }
+    }
+}
+
+fn write_string(json: &mut String, value: &str) {
+    json.push('"');
+
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(json, "\\u{:04x}", c as u32);
+            }
+            c => json.push(c),
+        }
+    }
+
+    json.push('"');
+}
+
+/// Formats a number as JSON, which has no representation for infinities.
+fn number(value: f32) -> String {
+    if value.is_finite() {
+        value.to_string()
+    } else {
+        String::from("null")
+    }
+}
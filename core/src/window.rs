@@ -2,20 +2,44 @@
 pub mod icon;
 pub mod settings;
 
+mod aspect_ratio_mode;
+mod backdrop_material;
+mod cursor_grab_mode;
+mod decoration_mode;
 mod event;
 mod id;
+mod ime;
 mod level;
 mod mode;
+mod monitor;
 mod position;
+mod redraw_reason;
 mod redraw_request;
+mod render_priority;
+mod resize_directions;
+mod snap_position;
+mod surface_format;
 mod user_attention;
+mod video_mode;
 
+pub use aspect_ratio_mode::AspectRatioMode;
+pub use backdrop_material::BackdropMaterial;
+pub use cursor_grab_mode::CursorGrabMode;
+pub use decoration_mode::DecorationMode;
 pub use event::Event;
 pub use icon::Icon;
 pub use id::Id;
+pub use ime::Ime;
 pub use level::Level;
 pub use mode::Mode;
+pub use monitor::Monitor;
 pub use position::Position;
+pub use redraw_reason::RedrawReason;
 pub use redraw_request::RedrawRequest;
+pub use render_priority::RenderPriority;
+pub use resize_directions::ResizeDirections;
 pub use settings::Settings;
+pub use snap_position::SnapPosition;
+pub use surface_format::{PixelFormat, SurfaceFormat};
 pub use user_attention::UserAttention;
+pub use video_mode::VideoMode;
@@ -0,0 +1,15 @@
+/// The way the aspect ratio of a window is maintained.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum AspectRatioMode {
+    /// The size of the window itself is constrained to the aspect ratio.
+    ///
+    /// Whenever the window is resized, its height is adjusted to match its
+    /// width.
+    #[default]
+    ConstrainWindow,
+
+    /// The window can have any size, but its contents are laid out with the
+    /// aspect ratio and centered, leaving bars filled with the background
+    /// color of the application around them; like a letterboxed video.
+    LetterboxContent,
+}
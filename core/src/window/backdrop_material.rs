@@ -0,0 +1,19 @@
+/// A material drawn by the system behind the contents of a window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BackdropMaterial {
+    /// No backdrop material.
+    #[default]
+    None,
+
+    /// A material tinted with the wallpaper of the desktop, meant for
+    /// long-lived windows.
+    Mica,
+
+    /// A translucent and blurred material, meant for transient windows like
+    /// popups and dialogs.
+    Acrylic,
+
+    /// A variant of [`Mica`](Self::Mica) with a stronger tint, meant for
+    /// windows with tabs in their title bar.
+    Tabbed,
+}
@@ -0,0 +1,24 @@
+/// The behavior of the cursor grab of a window.
+///
+/// It can be used to confine or lock the cursor, like games and drawing
+/// applications do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CursorGrabMode {
+    /// The cursor is not grabbed.
+    #[default]
+    None,
+
+    /// The cursor is confined to the area of the window.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **macOS:** Unsupported.
+    Confined,
+
+    /// The cursor is locked to its current position inside the window.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **X11 / Windows:** Unsupported.
+    Locked,
+}
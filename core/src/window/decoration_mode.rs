@@ -0,0 +1,11 @@
+/// The party responsible for drawing the decorations of a window (i.e. the
+/// title bar and borders).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DecorationMode {
+    /// The application draws its own decorations inside the window.
+    ClientSide,
+
+    /// The window manager or compositor draws the decorations around the
+    /// window.
+    ServerSide,
+}
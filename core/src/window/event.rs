@@ -1,4 +1,5 @@
 use crate::time::Instant;
+use crate::window::{Ime, RedrawReason, VideoMode};
 use crate::{Point, Size};
 
 use std::path::PathBuf;
@@ -23,6 +24,9 @@ pub enum Event {
     Closed,
 
     /// A window was moved.
+    ///
+    /// Consecutive moves within the same event loop iteration are coalesced
+    /// into a single event carrying the latest position.
     Moved {
         /// The new logical x location of the window
         x: i32,
@@ -38,11 +42,50 @@ pub enum Event {
         height: u32,
     },
 
+    /// The surface of a window needs to be reconfigured to a new physical
+    /// size.
+    ///
+    /// This event is only emitted for windows that do not reconfigure their
+    /// surface automatically.
+    SurfaceNeedsReconfigure {
+        /// The new physical width of the surface
+        width: u32,
+        /// The new physical height of the surface
+        height: u32,
+    },
+
+    /// The scale factor of a window changed.
+    ///
+    /// Both factors are provided so cached geometry can be rescaled
+    /// proportionally by `new / old`.
+    ScaleFactorChanged {
+        /// The previous scale factor of the window
+        old: f64,
+        /// The new scale factor of the window
+        new: f64,
+    },
     /// A window redraw was requested.
     ///
     /// The [`Instant`] contains the current time.
     RedrawRequested(Instant),
 
+    /// It is a good time to draw the next frame of an animation of the
+    /// window, as requested with a frame callback.
+    ///
+    /// The [`Instant`] contains the time of the frame, as reported by the
+    /// Wayland compositor. On other platforms, it is the time the callback
+    /// was received.
+    FrameCallback(Instant),
+
+    /// The reason of the [`RedrawRequested`] event of the window that comes
+    /// right after it.
+    ///
+    /// This is only broadcast to subscriptions, and only when the `debug`
+    /// feature is enabled.
+    ///
+    /// [`RedrawRequested`]: Self::RedrawRequested
+    RedrawReason(RedrawReason),
+
     /// The user has requested for the window to close.
     CloseRequested,
 
@@ -52,6 +95,11 @@ pub enum Event {
     /// A window was unfocused.
     Unfocused,
 
+    /// An input method editor (IME) event was received by a window.
+    ///
+    /// It is only received while the window allows IME input.
+    Ime(Ime),
+
     /// A file is being hovered over the window.
     ///
     /// When the user hovers multiple files at once, this event will be emitted
@@ -69,4 +117,8 @@ pub enum Event {
     /// There will be a single `FilesHoveredLeft` event triggered even if
     /// multiple files were hovered.
     FilesHoveredLeft,
+
+    /// The [`VideoMode`] requested for exclusive fullscreen was rejected, and
+    /// the window fell back to borderless fullscreen.
+    ExclusiveFullscreenRejected(VideoMode),
 }
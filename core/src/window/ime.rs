@@ -0,0 +1,32 @@
+/// An input method editor (IME) event.
+///
+/// The events of a composition arrive in the following order: [`Enabled`],
+/// any amount of [`Preedit`] and [`Commit`], and finally [`Disabled`].
+///
+/// [`Enabled`]: Ime::Enabled
+/// [`Preedit`]: Ime::Preedit
+/// [`Commit`]: Ime::Commit
+/// [`Disabled`]: Ime::Disabled
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Ime {
+    /// The IME was enabled, and composition events may follow.
+    Enabled,
+
+    /// The text being composed changed.
+    ///
+    /// An empty `content` means the composition was cleared.
+    Preedit {
+        /// The text being composed.
+        content: String,
+
+        /// The byte range of the cursor in the `content`, if it should be
+        /// displayed.
+        cursor: Option<(usize, usize)>,
+    },
+
+    /// The composition finished with the given text.
+    Commit(String),
+
+    /// The IME was disabled, and any ongoing composition was discarded.
+    Disabled,
+}
@@ -0,0 +1,36 @@
+/// A descriptor of a monitor.
+///
+/// It can be stored and compared against the monitors reported later on to
+/// target a specific monitor.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Monitor {
+    /// The human-readable name of the monitor, if available.
+    pub name: Option<String>,
+
+    /// The position of the top-left corner of the monitor relative to the
+    /// desktop, in physical pixels.
+    pub position: (i32, i32),
+
+    /// The resolution of the monitor, in physical pixels.
+    pub size: (u32, u32),
+
+    /// The scale factor of the monitor.
+    pub scale_factor: f64,
+
+    /// The refresh rate of the monitor in millihertz, if available.
+    pub refresh_rate_millihertz: Option<u32>,
+}
+
+impl Monitor {
+    /// Returns whether the [`Monitor`] describes the same physical monitor as
+    /// the given one.
+    ///
+    /// Unlike equality, the scale factor and the refresh rate are ignored,
+    /// since they can change while the monitor stays connected.
+    pub fn is_same(&self, other: &Monitor) -> bool {
+        self.name == other.name
+            && self.position == other.position
+            && self.size == other.size
+    }
+}
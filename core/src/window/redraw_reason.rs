@@ -0,0 +1,14 @@
+/// The reason why a window was redrawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RedrawReason {
+    /// Events, like user input, were processed by the window.
+    Input,
+    /// Messages were processed by the application.
+    Message,
+    /// The layout of the window was invalidated, e.g. by a resize.
+    Layout,
+    /// A widget requested a redraw on the next frame or at a given time.
+    Timer,
+    /// The windowing system requested a redraw unconditionally.
+    Unconditional,
+}
@@ -0,0 +1,19 @@
+/// The priority of the rendering of a window with respect to the other
+/// windows of an application.
+///
+/// Windows with a higher priority are rendered first. Under load, the
+/// rendering of [`RenderPriority::Low`] windows may be deferred for a few
+/// frames, but never indefinitely.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum RenderPriority {
+    /// The window may lag behind when rendering is too slow, like a settings
+    /// window.
+    Low,
+
+    /// The default behavior.
+    #[default]
+    Normal,
+
+    /// The window is latency-critical, like a live preview.
+    High,
+}
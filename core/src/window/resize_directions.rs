@@ -0,0 +1,30 @@
+use bitflags::bitflags;
+
+bitflags! {
+    /// The edges of a window that can initiate a drag resize.
+    ///
+    /// A corner can only initiate a diagonal drag resize if both of its
+    /// edges can.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct ResizeDirections: u8 {
+        /// The top edge.
+        const NORTH = 0b0001;
+        /// The bottom edge.
+        const SOUTH = 0b0010;
+        /// The right edge.
+        const EAST = 0b0100;
+        /// The left edge.
+        const WEST = 0b1000;
+        /// Every edge.
+        const ALL = Self::NORTH.bits()
+            | Self::SOUTH.bits()
+            | Self::EAST.bits()
+            | Self::WEST.bits();
+    }
+}
+
+impl Default for ResizeDirections {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
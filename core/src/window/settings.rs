@@ -76,6 +76,40 @@ pub struct Settings {
     ///
     /// By default this is enabled.
     pub exit_on_close_request: bool,
+
+    /// Whether the widget state of the window should be kept while the window
+    /// is hidden.
+    ///
+    /// Keeping it allows a hidden window to be shown again instantly, without
+    /// rebuilding its layout. Disabling it frees the memory used by the widget
+    /// state of a window as long as it stays hidden.
+    ///
+    /// By default this is enabled.
+    pub keep_cache_when_hidden: bool,
+
+    /// Whether the surface of the window should be reconfigured automatically
+    /// when the window is resized.
+    ///
+    /// If disabled, a [`SurfaceNeedsReconfigure`] event is emitted instead
+    /// and the surface keeps its size until it is reconfigured explicitly.
+    ///
+    /// By default this is enabled.
+    ///
+    /// [`SurfaceNeedsReconfigure`]: crate::window::Event::SurfaceNeedsReconfigure
+    pub auto_reconfigure_surface: bool,
+
+    /// Whether the window should stay hidden until the fonts loading in the
+    /// background when it is created are available.
+    ///
+    /// This avoids showing text drawn with a fallback font that is reshaped
+    /// once the font is loaded. The window is shown anyway if the fonts take
+    /// more than a few seconds to load. It has no effect on windows that are
+    /// not [`visible`].
+    ///
+    /// By default this is disabled.
+    ///
+    /// [`visible`]: Self::visible
+    pub wait_for_fonts: bool,
 }
 
 impl Default for Settings {
@@ -93,6 +127,9 @@ impl Default for Settings {
             level: Level::default(),
             icon: None,
             exit_on_close_request: true,
+            keep_cache_when_hidden: true,
+            auto_reconfigure_surface: true,
+            wait_for_fonts: false,
             platform_specific: PlatformSpecific::default(),
         }
     }
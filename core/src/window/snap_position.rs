@@ -0,0 +1,95 @@
+use crate::Rectangle;
+
+/// A region of the screen a window can be snapped to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnapPosition {
+    /// The left half of the screen.
+    Left,
+    /// The right half of the screen.
+    Right,
+    /// The top half of the screen.
+    Top,
+    /// The bottom half of the screen.
+    Bottom,
+    /// The top-left quarter of the screen.
+    TopLeft,
+    /// The top-right quarter of the screen.
+    TopRight,
+    /// The bottom-left quarter of the screen.
+    BottomLeft,
+    /// The bottom-right quarter of the screen.
+    BottomRight,
+}
+
+impl SnapPosition {
+    /// Returns the bounds of the [`SnapPosition`] inside of the given
+    /// `area` of the screen.
+    ///
+    /// The halves are rounded down, so that the bounds of opposite positions
+    /// never overlap and always cover the whole `area`.
+    pub fn bounds(self, area: Rectangle) -> Rectangle {
+        let left_width = (area.width / 2.0).floor();
+        let top_height = (area.height / 2.0).floor();
+
+        let left = (area.x, left_width);
+        let right = (area.x + left_width, area.width - left_width);
+        let full_width = (area.x, area.width);
+
+        let top = (area.y, top_height);
+        let bottom = (area.y + top_height, area.height - top_height);
+        let full_height = (area.y, area.height);
+
+        let ((x, width), (y, height)) = match self {
+            Self::Left => (left, full_height),
+            Self::Right => (right, full_height),
+            Self::Top => (full_width, top),
+            Self::Bottom => (full_width, bottom),
+            Self::TopLeft => (left, top),
+            Self::TopRight => (right, top),
+            Self::BottomLeft => (left, bottom),
+            Self::BottomRight => (right, bottom),
+        };
+
+        Rectangle {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounds_cover_the_area() {
+        let area = Rectangle {
+            x: -1281.0,
+            y: 30.0,
+            width: 1281.0,
+            height: 995.0,
+        };
+
+        assert_eq!(
+            SnapPosition::Left.bounds(area),
+            Rectangle {
+                x: -1281.0,
+                y: 30.0,
+                width: 640.0,
+                height: 995.0,
+            }
+        );
+
+        assert_eq!(
+            SnapPosition::BottomRight.bounds(area),
+            Rectangle {
+                x: -641.0,
+                y: 527.0,
+                width: 641.0,
+                height: 498.0,
+            }
+        );
+    }
+}
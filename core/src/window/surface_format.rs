@@ -0,0 +1,31 @@
+/// The format of the pixels presented to the surface of a window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SurfaceFormat {
+    /// The layout of the channels of every pixel.
+    pub pixel_format: PixelFormat,
+
+    /// Whether the color channels are encoded with the sRGB transfer
+    /// function, instead of being linear.
+    pub srgb: bool,
+}
+
+/// The layout of the channels of a pixel, in memory order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PixelFormat {
+    /// 8 bits per channel, ordered as red, green, blue, and alpha.
+    Rgba8,
+
+    /// 8 bits per channel, ordered as blue, green, red, and alpha.
+    Bgra8,
+
+    /// 10 bits for each color channel and 2 bits for alpha, packed in a
+    /// little-endian 32-bit integer.
+    Rgb10a2,
+
+    /// 16-bit floating point per channel, ordered as red, green, blue, and
+    /// alpha.
+    Rgba16Float,
+
+    /// A format that has no portable description.
+    Other,
+}
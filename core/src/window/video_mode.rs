@@ -0,0 +1,16 @@
+/// A video mode supported by a monitor.
+///
+/// It can be used to put a window in exclusive fullscreen at a specific
+/// resolution and refresh rate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VideoMode {
+    /// The resolution of the video mode, in physical pixels.
+    pub size: (u32, u32),
+
+    /// The bit depth of the video mode.
+    pub bit_depth: u16,
+
+    /// The refresh rate of the video mode in millihertz.
+    pub refresh_rate_millihertz: u32,
+}
@@ -0,0 +1,353 @@
+//! Apply an output color profile to the rendered frames.
+use thiserror::Error;
+
+/// The linear sRGB to XYZ matrix, chromatically adapted to the D50 white
+/// point of the ICC profile connection space.
+const SRGB_TO_XYZ_D50: [[f32; 3]; 3] = [
+    [0.436_074_7, 0.385_064_9, 0.143_080_4],
+    [0.222_504_5, 0.716_878_6, 0.060_616_9],
+    [0.013_932_2, 0.097_104_5, 0.714_173_3],
+];
+
+/// An output color profile, describing how linear sRGB colors are converted
+/// to the color space of a display.
+///
+/// Only matrix/TRC RGB profiles are supported. Tone response curves are
+/// approximated with a single gamma exponent per channel.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColorProfile {
+    /// The matrix converting linear sRGB colors to linear device colors, in
+    /// row-major order.
+    pub matrix: [[f32; 3]; 3],
+
+    /// The gamma exponent of the tone response curve of each channel of the
+    /// device.
+    pub gamma: [f32; 3],
+}
+
+/// An error produced when parsing the ICC data of a [`ColorProfile`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum Error {
+    /// The data is not a valid ICC profile.
+    #[error("the data is not a valid ICC profile")]
+    Invalid,
+
+    /// The profile does not describe an RGB color space.
+    #[error("the profile does not describe an RGB color space")]
+    NotRgb,
+
+    /// The tag with the given signature is missing or malformed.
+    #[error("the `{0}` tag is missing or malformed")]
+    MissingTag(&'static str),
+
+    /// The colorants of the profile cannot be inverted.
+    #[error("the colorants of the profile cannot be inverted")]
+    Singular,
+}
+
+impl ColorProfile {
+    /// Parses a [`ColorProfile`] from the given ICC data.
+    pub fn from_icc(data: &[u8]) -> Result<Self, Error> {
+        let size = read_u32(data, 0).ok_or(Error::Invalid)? as usize;
+
+        if size < 132
+            || size > data.len()
+            || data.get(36..40) != Some(b"acsp".as_slice())
+        {
+            return Err(Error::Invalid);
+        }
+
+        let data = &data[..size];
+
+        if data.get(16..20) != Some(b"RGB ".as_slice()) {
+            return Err(Error::NotRgb);
+        }
+
+        let colorant = |signature| {
+            let tag = find_tag(data, signature)?;
+
+            if tag.get(0..4) != Some(b"XYZ ".as_slice()) {
+                return None;
+            }
+
+            Some([
+                read_s15_fixed16(tag, 8)?,
+                read_s15_fixed16(tag, 12)?,
+                read_s15_fixed16(tag, 16)?,
+            ])
+        };
+
+        let [red, green, blue] = ["rXYZ", "gXYZ", "bXYZ"].map(|signature| {
+            colorant(signature).ok_or(Error::MissingTag(signature))
+        });
+
+        let (red, green, blue) = (red?, green?, blue?);
+
+        // The colorants are the columns of the device to XYZ matrix
+        let device_to_xyz = [
+            [red[0], green[0], blue[0]],
+            [red[1], green[1], blue[1]],
+            [red[2], green[2], blue[2]],
+        ];
+
+        let xyz_to_device = invert(device_to_xyz).ok_or(Error::Singular)?;
+
+        let [red, green, blue] = ["rTRC", "gTRC", "bTRC"].map(|signature| {
+            find_tag(data, signature)
+                .and_then(curve_gamma)
+                .ok_or(Error::MissingTag(signature))
+        });
+
+        Ok(Self {
+            matrix: multiply(xyz_to_device, SRGB_TO_XYZ_D50),
+            gamma: [red?, green?, blue?],
+        })
+    }
+}
+
+/// Returns the data of the tag with the given signature.
+fn find_tag<'a>(data: &'a [u8], signature: &str) -> Option<&'a [u8]> {
+    let count = read_u32(data, 128)? as usize;
+
+    data.get(132..)?
+        .chunks_exact(12)
+        .take(count)
+        .find(|entry| &entry[0..4] == signature.as_bytes())
+        .and_then(|entry| {
+            let offset = read_u32(entry, 4)? as usize;
+            let size = read_u32(entry, 8)? as usize;
+
+            data.get(offset..offset.checked_add(size)?)
+        })
+}
+
+/// Approximates the given `curv` or `para` tag with a gamma exponent.
+///
+/// Sampled curves and parametric functions are evaluated in the middle of
+/// their domain, where the exponent of a pure power curve is estimated from.
+fn curve_gamma(tag: &[u8]) -> Option<f32> {
+    let gamma = match tag.get(0..4)? {
+        b"curv" => match read_u32(tag, 8)? {
+            0 => 1.0,
+            1 => f32::from(read_u16(tag, 12)?) / 256.0,
+            count => {
+                // Estimate the exponent from the middle of the table
+                let middle = (count - 1) / 2;
+                let x = middle as f32 / (count - 1) as f32;
+                let y = f32::from(read_u16(tag, 12 + middle as usize * 2)?)
+                    / f32::from(u16::MAX);
+
+                y.ln() / x.ln()
+            }
+        },
+        b"para" => {
+            let function = read_u16(tag, 8)?;
+
+            let count = match function {
+                0 => 1,
+                1 => 3,
+                2 => 4,
+                3 => 5,
+                4 => 7,
+                _ => return None,
+            };
+
+            let mut parameters = [0.0; 7];
+
+            for (i, parameter) in parameters.iter_mut().take(count).enumerate()
+            {
+                *parameter = read_s15_fixed16(tag, 12 + i * 4)?;
+            }
+
+            let [g, a, b, c, d, e, f] = parameters;
+
+            if function == 0 {
+                g
+            } else {
+                let x = 0.5_f32;
+
+                let y = match function {
+                    1 if x >= -b / a => (a * x + b).powf(g),
+                    1 => 0.0,
+                    2 if x >= -b / a => (a * x + b).powf(g) + c,
+                    2 => c,
+                    3 if x >= d => (a * x + b).powf(g),
+                    3 => c * x,
+                    _ if x >= d => (a * x + b).powf(g) + e,
+                    _ => c * x + f,
+                };
+
+                y.ln() / x.ln()
+            }
+        }
+        _ => return None,
+    };
+
+    (gamma.is_finite() && gamma > 0.0).then_some(gamma)
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_be_bytes(
+        data.get(offset..offset + 2)?.try_into().ok()?,
+    ))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_be_bytes(
+        data.get(offset..offset + 4)?.try_into().ok()?,
+    ))
+}
+
+fn read_s15_fixed16(data: &[u8], offset: usize) -> Option<f32> {
+    let value = read_u32(data, offset)? as i32;
+
+    Some(value as f32 / 65536.0)
+}
+
+fn multiply(a: [[f32; 3]; 3], b: [[f32; 3]; 3]) -> [[f32; 3]; 3] {
+    let mut result = [[0.0; 3]; 3];
+
+    for (i, row) in result.iter_mut().enumerate() {
+        for (j, value) in row.iter_mut().enumerate() {
+            *value = (0..3).map(|k| a[i][k] * b[k][j]).sum();
+        }
+    }
+
+    result
+}
+
+fn invert(m: [[f32; 3]; 3]) -> Option<[[f32; 3]; 3]> {
+    let cofactor = |i: usize, j: usize| {
+        let (r0, r1) = ((i + 1) % 3, (i + 2) % 3);
+        let (c0, c1) = ((j + 1) % 3, (j + 2) % 3);
+
+        m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0]
+    };
+
+    let determinant: f32 = (0..3).map(|j| m[0][j] * cofactor(0, j)).sum();
+
+    if determinant.abs() < f32::EPSILON {
+        return None;
+    }
+
+    let mut inverse = [[0.0; 3]; 3];
+
+    for (i, row) in inverse.iter_mut().enumerate() {
+        for (j, value) in row.iter_mut().enumerate() {
+            *value = cofactor(j, i) / determinant;
+        }
+    }
+
+    Some(inverse)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn para(function: u16, parameters: &[u32]) -> Vec<u8> {
+        let mut tag = b"para".to_vec();
+        tag.extend([0; 4]);
+        tag.extend(function.to_be_bytes());
+        tag.extend([0; 2]);
+
+        for parameter in parameters {
+            tag.extend(parameter.to_be_bytes());
+        }
+
+        tag
+    }
+
+    fn curv(entries: &[u16]) -> Vec<u8> {
+        let mut tag = b"curv".to_vec();
+        tag.extend([0; 4]);
+        tag.extend((entries.len() as u32).to_be_bytes());
+
+        for entry in entries {
+            tag.extend(entry.to_be_bytes());
+        }
+
+        tag
+    }
+
+    fn assert_gamma(tag: &[u8], expected: f32) {
+        let gamma = curve_gamma(tag).expect("Approximate curve");
+
+        assert!(
+            (gamma - expected).abs() < 0.01,
+            "expected a gamma of {expected}, got {gamma}"
+        );
+    }
+
+    #[test]
+    fn curv_identity() {
+        assert_gamma(&curv(&[]), 1.0);
+    }
+
+    #[test]
+    fn curv_gamma() {
+        // The `u8Fixed8Number` 2.2 of common display profiles
+        assert_gamma(&curv(&[0x0233]), 2.2);
+    }
+
+    #[test]
+    fn curv_srgb_table() {
+        let entries: Vec<u16> = (0..1024)
+            .map(|i| {
+                let x = i as f32 / 1023.0;
+
+                let y = if x <= 0.040_45 {
+                    x / 12.92
+                } else {
+                    ((x + 0.055) / 1.055).powf(2.4)
+                };
+
+                (y * f32::from(u16::MAX)).round() as u16
+            })
+            .collect();
+
+        assert_gamma(&curv(&entries), 2.224);
+    }
+
+    #[test]
+    fn para_gamma() {
+        // Y = X^1.8
+        assert_gamma(&para(0, &[0x0001_CCCD]), 1.8);
+    }
+
+    #[test]
+    fn para_srgb() {
+        // The `rTRC` tag of the ICC v4 sRGB profiles
+        let tag = para(
+            3,
+            &[
+                0x0002_6666,
+                0x0000_F2A7,
+                0x0000_0D59,
+                0x0000_13D0,
+                0x0000_0A5B,
+            ],
+        );
+
+        assert_gamma(&tag, 2.224);
+    }
+
+    #[test]
+    fn para_offsets() {
+        // Y = (0.5X + 0.5)^2 + 0.1, shifted away from a pure power curve
+        let tag =
+            para(2, &[0x0002_0000, 0x0000_8000, 0x0000_8000, 0x0000_1999]);
+
+        assert_gamma(&tag, (0.6625_f32).ln() / 0.5_f32.ln());
+    }
+
+    #[test]
+    fn para_truncated() {
+        assert_eq!(curve_gamma(&para(3, &[0x0002_6666, 0x0000_F2A7])), None);
+    }
+
+    #[test]
+    fn para_unknown_function() {
+        assert_eq!(curve_gamma(&para(5, &[0x0001_0000; 7])), None);
+    }
+}
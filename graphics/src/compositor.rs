@@ -1,8 +1,11 @@
 //! A compositor is responsible for initializing a renderer and managing window
 //! surfaces.
-use crate::{Error, Viewport};
+use crate::{ColorProfile, Error, Viewport};
 
+use crate::core::window::SurfaceFormat;
 use crate::core::Color;
+
+pub use crate::core::renderer::{Backend, RenderStats};
 use crate::futures::{MaybeSend, MaybeSync};
 
 use raw_window_handle::{HasDisplayHandle, HasWindowHandle};
@@ -51,6 +54,68 @@ pub trait Compositor: Sized {
     /// Returns [`Information`] used by this [`Compositor`].
     fn fetch_information(&self) -> Information;
 
+    /// Returns the [`Backend`] used by this [`Compositor`], if it is one of
+    /// the built-in backends.
+    fn backend(&self) -> Option<Backend> {
+        None
+    }
+
+    /// Replaces this [`Compositor`] with a software fallback compatible with
+    /// the given window, returning whether it was replaced.
+    ///
+    /// Every renderer and surface created by the previous [`Compositor`] must
+    /// be created again.
+    fn fall_back<W: Window + Clone>(&mut self, _compatible_window: W) -> bool {
+        false
+    }
+
+    /// Returns the [`RenderStats`] of the last frame presented with the given
+    /// [`Renderer`], if the backend keeps track of them.
+    ///
+    /// [`Renderer`]: Self::Renderer
+    fn fetch_render_stats(
+        &self,
+        _renderer: &Self::Renderer,
+    ) -> Option<RenderStats> {
+        None
+    }
+
+    /// Returns the [`SurfaceFormat`] of the frames presented to the given
+    /// [`Surface`], if it can be described.
+    ///
+    /// [`Surface`]: Self::Surface
+    fn fetch_surface_format(
+        &self,
+        _surface: &Self::Surface,
+    ) -> Option<SurfaceFormat> {
+        None
+    }
+
+    /// Sets the output [`ColorProfile`] applied to the frames presented with
+    /// the given [`Renderer`], or resets it to sRGB when `None`.
+    ///
+    /// [`Renderer`]: Self::Renderer
+    fn set_color_profile(
+        &mut self,
+        _renderer: &mut Self::Renderer,
+        _profile: Option<ColorProfile>,
+    ) {
+    }
+
+    /// Sets whether the given [`Renderer`] may retain its frames between
+    /// presents and only draw their damaged regions again.
+    ///
+    /// Damage tracking must be disabled when the frames presented to a
+    /// surface are drawn by more than one [`Renderer`].
+    ///
+    /// [`Renderer`]: Self::Renderer
+    fn set_damage_tracking(
+        &mut self,
+        _renderer: &mut Self::Renderer,
+        _enabled: bool,
+    ) {
+    }
+
     /// Presents the [`Renderer`] primitives to the next frame of the given [`Surface`].
     ///
     /// [`Renderer`]: Self::Renderer
@@ -76,6 +141,24 @@ pub trait Compositor: Sized {
         background_color: Color,
         overlay: &[T],
     ) -> Vec<u8>;
+
+    /// Draws the current [`Renderer`] primitives to an offscreen buffer of
+    /// the size of the given [`Viewport`], without any [`Surface`], and
+    /// returns its bytes ordered as `RGBA` in the `sRGB` color space.
+    ///
+    /// Returns `None` if the [`Compositor`] cannot render offscreen.
+    ///
+    /// [`Renderer`]: Self::Renderer
+    /// [`Surface`]: Self::Surface
+    fn render_offscreen<T: AsRef<str>>(
+        &mut self,
+        _renderer: &mut Self::Renderer,
+        _viewport: &Viewport,
+        _background_color: Color,
+        _overlay: &[T],
+    ) -> Option<Vec<u8>> {
+        None
+    }
 }
 
 /// A window that can be used in a [`Compositor`].
@@ -123,10 +206,32 @@ pub enum SurfaceError {
 }
 
 /// Contains information about the graphics (e.g. graphics adapter, graphics backend).
+///
+/// New fields may be added over time, so an [`Information`] can only be
+/// built with [`Information::new`] outside of this crate.
 #[derive(Debug)]
+#[non_exhaustive]
 pub struct Information {
     /// Contains the graphics adapter.
     pub adapter: String,
     /// Contains the graphics backend.
     pub backend: String,
+    /// Whether the graphics adapter is hardware-accelerated.
+    ///
+    /// Only discrete and integrated GPUs are considered hardware-accelerated.
+    /// Virtualized GPUs are treated conservatively and, like software
+    /// rasterizers (e.g. llvmpipe) and unknown adapters, are not.
+    pub hardware_accelerated: bool,
+}
+
+impl Information {
+    /// Creates some new [`Information`] for the given graphics adapter and
+    /// backend, which is not considered hardware-accelerated.
+    pub fn new(adapter: impl Into<String>, backend: impl Into<String>) -> Self {
+        Self {
+            adapter: adapter.into(),
+            backend: backend.into(),
+            hardware_accelerated: false,
+        }
+    }
 }
@@ -24,6 +24,7 @@ mod viewport;
 
 pub mod backend;
 pub mod color;
+pub mod color_profile;
 pub mod compositor;
 pub mod damage;
 pub mod gradient;
@@ -39,6 +40,7 @@ pub mod image;
 
 pub use antialiasing::Antialiasing;
 pub use backend::Backend;
+pub use color_profile::ColorProfile;
 pub use compositor::Compositor;
 pub use damage::Damage;
 pub use error::Error;
@@ -20,8 +20,31 @@ pub struct Renderer<B: Backend> {
     default_font: Font,
     default_text_size: Pixels,
     primitives: Vec<Primitive<B::Primitive>>,
+    previous: Vec<Primitive<B::Primitive>>,
+    frame: u64,
 }
 
+/// The primitives recorded by a [`Renderer`] in its last two frames.
+#[derive(Debug)]
+pub struct Frames<'a, T> {
+    /// The number of the current frame.
+    ///
+    /// It increases every time the [`Renderer`] is cleared.
+    pub number: u64,
+    /// The primitives of the current frame.
+    pub current: &'a [Primitive<T>],
+    /// The primitives of the frame before the current one.
+    pub previous: &'a [Primitive<T>],
+}
+
+impl<'a, T> Clone for Frames<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for Frames<'a, T> {}
+
 impl<B: Backend> Renderer<B> {
     /// Creates a new [`Renderer`] from the given [`Backend`].
     pub fn new(
@@ -34,6 +57,8 @@ impl<B: Backend> Renderer<B> {
             default_font,
             default_text_size,
             primitives: Vec::new(),
+            previous: Vec::new(),
+            frame: 0,
         }
     }
 
@@ -56,6 +81,22 @@ impl<B: Backend> Renderer<B> {
         f(&mut self.backend, &self.primitives)
     }
 
+    /// Runs the given closure with the [`Backend`] and the [`Frames`] recorded
+    /// by the [`Renderer`].
+    pub fn with_frames<O>(
+        &mut self,
+        f: impl FnOnce(&mut B, Frames<'_, B::Primitive>) -> O,
+    ) -> O {
+        f(
+            &mut self.backend,
+            Frames {
+                number: self.frame,
+                current: &self.primitives,
+                previous: &self.previous,
+            },
+        )
+    }
+
     /// Starts recording a new layer.
     pub fn start_layer(&mut self) -> Vec<Primitive<B::Primitive>> {
         std::mem::take(&mut self.primitives)
@@ -125,7 +166,11 @@ impl<B: Backend> iced_core::Renderer for Renderer<B> {
     }
 
     fn clear(&mut self) {
+        // Keep the primitives of the last frame, so backends can compute the
+        // damage of the next one without cloning them
+        std::mem::swap(&mut self.primitives, &mut self.previous);
         self.primitives.clear();
+        self.frame = self.frame.wrapping_add(1);
     }
 }
 
@@ -257,3 +302,75 @@ where
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::layout;
+    use crate::core::text::{Renderer as _, Shaping};
+    use crate::core::widget::{self, Tree, Widget};
+
+    struct Null;
+
+    impl Backend for Null {
+        type Primitive = ();
+    }
+
+    impl backend::Text for Null {
+        fn load_font(&mut self, _font: Cow<'static, [u8]>) {}
+    }
+
+    struct Theme;
+
+    impl widget::text::StyleSheet for Theme {
+        type Style = ();
+
+        fn appearance(&self, _style: ()) -> widget::text::Appearance {
+            widget::text::Appearance::default()
+        }
+    }
+
+    #[test]
+    fn measure_text_matches_text_layout() {
+        let renderer = Renderer::new(Null, Font::DEFAULT, Pixels(16.0));
+        let bounds = Size::new(200.0, f32::INFINITY);
+
+        for content in [
+            "Hello, world!",
+            "The quick brown fox jumps over the lazy dog",
+            "مرحبا بالعالم",
+            "ﬁne offices",
+        ] {
+            for size in [12.0, 16.0, 23.5] {
+                let measured = renderer.measure_text(
+                    content,
+                    Font::DEFAULT,
+                    Pixels(size),
+                    bounds,
+                    Shaping::Advanced,
+                );
+
+                let text = widget::Text::<Theme, Renderer<Null>>::new(content)
+                    .size(size);
+
+                let mut tree =
+                    Tree::new(&text as &dyn Widget<(), Theme, Renderer<Null>>);
+
+                let laid_out = Widget::<(), Theme, Renderer<Null>>::layout(
+                    &text,
+                    &mut tree,
+                    &renderer,
+                    &layout::Limits::new(Size::ZERO, bounds),
+                )
+                .size();
+
+                assert!(
+                    (measured.width - laid_out.width).abs() < 0.5
+                        && (measured.height - laid_out.height).abs() < 0.5,
+                    "{content:?} at {size}: measured {measured:?}, \
+                    laid out {laid_out:?}"
+                );
+            }
+        }
+    }
+}
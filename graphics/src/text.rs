@@ -15,7 +15,7 @@ use crate::core::{Color, Point, Rectangle, Size};
 
 use once_cell::sync::OnceCell;
 use std::borrow::Cow;
-use std::sync::{Arc, RwLock, Weak};
+use std::sync::{Arc, Mutex, RwLock, Weak};
 
 /// Returns the global [`FontSystem`].
 pub fn font_system() -> &'static RwLock<FontSystem> {
@@ -33,6 +33,45 @@ pub fn font_system() -> &'static RwLock<FontSystem> {
     })
 }
 
+/// Returns the family names of the fonts of the global [`FontSystem`],
+/// deduplicated and sorted alphabetically.
+///
+/// Enumerating the fonts is slow, so the result is cached until a new font
+/// is loaded.
+pub fn font_families() -> Vec<String> {
+    static CACHE: Mutex<Option<(Version, Vec<String>)>> = Mutex::new(None);
+
+    let font_system = font_system().read().expect("Read font system");
+    let version = font_system.version();
+
+    let mut cache = CACHE.lock().expect("Lock font families cache");
+
+    if let Some((cached_version, families)) = cache.as_ref() {
+        if *cached_version == version {
+            return families.clone();
+        }
+    }
+
+    let mut families: Vec<String> = font_system
+        .raw
+        .db()
+        .faces()
+        .filter_map(|face| face.families.first())
+        .map(|(family, _language)| family.clone())
+        .collect();
+
+    families.sort_by(|a, b| {
+        a.to_lowercase()
+            .cmp(&b.to_lowercase())
+            .then_with(|| a.cmp(b))
+    });
+    families.dedup();
+
+    *cache = Some((version, families.clone()));
+
+    families
+}
+
 /// A set of system fonts.
 #[allow(missing_debug_implementations)]
 pub struct FontSystem {
@@ -1,6 +1,9 @@
+use crate::core::window::SurfaceFormat;
 use crate::core::Color;
-use crate::graphics::compositor::{Information, SurfaceError, Window};
-use crate::graphics::{Error, Viewport};
+use crate::graphics::compositor::{
+    Backend, Information, RenderStats, SurfaceError, Window,
+};
+use crate::graphics::{ColorProfile, Error, Viewport};
 use crate::{Renderer, Settings};
 
 use std::env;
@@ -26,8 +29,12 @@ impl crate::graphics::Compositor for Compositor {
         settings: Self::Settings,
         compatible_window: W,
     ) -> Result<Self, Error> {
-        let candidates =
-            Candidate::list_from_env().unwrap_or(Candidate::default_list());
+        let candidates = match settings.backend {
+            Some(backend) => vec![Candidate::from(backend)],
+            None => {
+                Candidate::list_from_env().unwrap_or(Candidate::default_list())
+            }
+        };
 
         let mut error = Error::GraphicsAdapterNotFound;
 
@@ -101,6 +108,122 @@ impl crate::graphics::Compositor for Compositor {
         }
     }
 
+    fn backend(&self) -> Option<Backend> {
+        Some(match self {
+            Self::TinySkia(_) => Backend::TinySkia,
+            #[cfg(feature = "wgpu")]
+            Self::Wgpu(_) => Backend::Wgpu,
+        })
+    }
+
+    fn fall_back<W: Window + Clone>(&mut self, compatible_window: W) -> bool {
+        match self {
+            Self::TinySkia(_) => false,
+            #[cfg(feature = "wgpu")]
+            Self::Wgpu(compositor) => {
+                let settings = compositor.settings();
+
+                match Candidate::TinySkia.build(
+                    Settings {
+                        default_font: settings.default_font,
+                        default_text_size: settings.default_text_size,
+                        antialiasing: settings.antialiasing,
+                        color_profile: settings.color_profile,
+                        ..Settings::default()
+                    },
+                    compatible_window,
+                ) {
+                    Ok(fallback) => {
+                        log::warn!("Falling back to the tiny-skia backend");
+
+                        *self = fallback;
+                        true
+                    }
+                    Err(error) => {
+                        log::error!(
+                            "Failed to fall back to tiny-skia: {error}"
+                        );
+
+                        false
+                    }
+                }
+            }
+        }
+    }
+
+    fn fetch_render_stats(
+        &self,
+        renderer: &Self::Renderer,
+    ) -> Option<RenderStats> {
+        match (self, renderer) {
+            (
+                Self::TinySkia(compositor),
+                crate::Renderer::TinySkia(renderer),
+            ) => compositor.fetch_render_stats(renderer),
+            #[cfg(feature = "wgpu")]
+            (Self::Wgpu(compositor), crate::Renderer::Wgpu(renderer)) => {
+                compositor.fetch_render_stats(renderer)
+            }
+            #[allow(unreachable_patterns)]
+            _ => None,
+        }
+    }
+
+    fn fetch_surface_format(
+        &self,
+        surface: &Self::Surface,
+    ) -> Option<SurfaceFormat> {
+        match (self, surface) {
+            (Self::TinySkia(compositor), Surface::TinySkia(surface)) => {
+                compositor.fetch_surface_format(surface)
+            }
+            #[cfg(feature = "wgpu")]
+            (Self::Wgpu(compositor), Surface::Wgpu(surface)) => {
+                compositor.fetch_surface_format(surface)
+            }
+            #[allow(unreachable_patterns)]
+            _ => None,
+        }
+    }
+
+    fn set_color_profile(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        profile: Option<ColorProfile>,
+    ) {
+        match (self, renderer) {
+            (
+                Self::TinySkia(compositor),
+                crate::Renderer::TinySkia(renderer),
+            ) => compositor.set_color_profile(renderer, profile),
+            #[cfg(feature = "wgpu")]
+            (Self::Wgpu(compositor), crate::Renderer::Wgpu(renderer)) => {
+                compositor.set_color_profile(renderer, profile);
+            }
+            #[allow(unreachable_patterns)]
+            _ => {}
+        }
+    }
+
+    fn set_damage_tracking(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        enabled: bool,
+    ) {
+        match (self, renderer) {
+            (
+                Self::TinySkia(compositor),
+                crate::Renderer::TinySkia(renderer),
+            ) => compositor.set_damage_tracking(renderer, enabled),
+            #[cfg(feature = "wgpu")]
+            (Self::Wgpu(compositor), crate::Renderer::Wgpu(renderer)) => {
+                compositor.set_damage_tracking(renderer, enabled);
+            }
+            #[allow(unreachable_patterns)]
+            _ => {}
+        }
+    }
+
     fn present<T: AsRef<str>>(
         &mut self,
         renderer: &mut Self::Renderer,
@@ -129,12 +252,12 @@ impl crate::graphics::Compositor for Compositor {
                 Self::Wgpu(compositor),
                 crate::Renderer::Wgpu(renderer),
                 Surface::Wgpu(surface),
-            ) => renderer.with_primitives(|backend, primitives| {
+            ) => renderer.with_frames(|backend, frames| {
                 iced_wgpu::window::compositor::present(
                     compositor,
                     backend,
                     surface,
-                    primitives,
+                    frames,
                     viewport,
                     background_color,
                     overlay,
@@ -193,6 +316,37 @@ impl crate::graphics::Compositor for Compositor {
             ),
         }
     }
+
+    fn render_offscreen<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    ) -> Option<Vec<u8>> {
+        match (self, renderer) {
+            (Self::TinySkia(compositor), Renderer::TinySkia(renderer)) => {
+                compositor.render_offscreen(
+                    renderer,
+                    viewport,
+                    background_color,
+                    overlay,
+                )
+            }
+            #[cfg(feature = "wgpu")]
+            (Self::Wgpu(compositor), Renderer::Wgpu(renderer)) => compositor
+                .render_offscreen(
+                    renderer,
+                    viewport,
+                    background_color,
+                    overlay,
+                ),
+            #[allow(unreachable_patterns)]
+            _ => panic!(
+                "The provided renderer is not compatible with the compositor."
+            ),
+        }
+    }
 }
 
 enum Candidate {
@@ -232,6 +386,13 @@ impl Candidate {
     ) -> Result<Compositor, Error> {
         match self {
             Self::TinySkia => {
+                if settings.color_profile.is_some() {
+                    log::warn!(
+                        "Color profiles are not supported by tiny-skia; \
+                        frames will be presented as sRGB"
+                    );
+                }
+
                 let compositor = iced_tiny_skia::window::compositor::new(
                     iced_tiny_skia::Settings {
                         default_font: settings.default_font,
@@ -244,12 +405,21 @@ impl Candidate {
             }
             #[cfg(feature = "wgpu")]
             Self::Wgpu => {
+                let from_env = iced_wgpu::Settings::from_env();
+
                 let compositor = iced_wgpu::window::compositor::new(
                     iced_wgpu::Settings {
+                        present_mode: settings
+                            .present_mode
+                            .unwrap_or(from_env.present_mode),
+                        internal_backend: settings
+                            .internal_backend
+                            .unwrap_or(from_env.internal_backend),
                         default_font: settings.default_font,
                         default_text_size: settings.default_text_size,
                         antialiasing: settings.antialiasing,
-                        ..iced_wgpu::Settings::from_env()
+                        color_profile: settings.color_profile,
+                        share_device: from_env.share_device,
                     },
                     _compatible_window,
                 )?;
@@ -263,3 +433,12 @@ impl Candidate {
         }
     }
 }
+
+impl From<Backend> for Candidate {
+    fn from(backend: Backend) -> Self {
+        match backend {
+            Backend::Wgpu => Self::Wgpu,
+            Backend::TinySkia => Self::TinySkia,
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use crate::core::{Font, Pixels};
-use crate::graphics::Antialiasing;
+use crate::graphics::compositor::Backend;
+use crate::graphics::{Antialiasing, ColorProfile};
 
 /// The settings of a Backend.
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -16,6 +17,33 @@ pub struct Settings {
     ///
     /// By default, it is `None`.
     pub antialiasing: Option<Antialiasing>,
+
+    /// The output [`ColorProfile`] applied to the presented frames.
+    ///
+    /// It is only supported by the `wgpu` backend. By default, it is `None`
+    /// and frames are presented as sRGB.
+    pub color_profile: Option<ColorProfile>,
+
+    /// The [`Backend`] that must be used by the compositor.
+    ///
+    /// By default, it is `None` and the backends listed in the `ICED_BACKEND`
+    /// environment variable are tried in order, falling back to `wgpu` and
+    /// then `tiny-skia`.
+    pub backend: Option<Backend>,
+
+    /// The present mode of the `wgpu` backend.
+    ///
+    /// By default, it is `None` and `AutoVsync` is used.
+    #[cfg(feature = "wgpu")]
+    pub present_mode: Option<crate::wgpu::wgpu::PresentMode>,
+
+    /// The internal graphics backends that the `wgpu` backend can use, like
+    /// Vulkan or Metal.
+    ///
+    /// By default, it is `None` and they are configured with the
+    /// `WGPU_BACKEND` environment variable.
+    #[cfg(feature = "wgpu")]
+    pub internal_backend: Option<crate::wgpu::wgpu::Backends>,
 }
 
 impl Default for Settings {
@@ -24,6 +52,12 @@ impl Default for Settings {
             default_font: Font::default(),
             default_text_size: Pixels(14.0),
             antialiasing: None,
+            color_profile: None,
+            backend: None,
+            #[cfg(feature = "wgpu")]
+            present_mode: None,
+            #[cfg(feature = "wgpu")]
+            internal_backend: None,
         }
     }
 }
@@ -2,9 +2,13 @@
 use window_clipboard::mime::{AllowedMimeTypes, AsMimeTypes};
 
 use crate::command::{self, Command};
+use crate::core;
 use crate::futures::MaybeSend;
 
+use std::borrow::Cow;
 use std::fmt;
+use std::path::PathBuf;
+use std::time::Duration;
 
 /// A clipboard action to be performed by some [`Command`].
 ///
@@ -23,6 +27,25 @@ pub enum Action<T> {
     /// Read the clipboard and produce `T` with the result.
     ReadData(Vec<String>, Box<dyn Fn(Option<(Vec<u8>, String)>) -> T>),
 
+    /// Read the `text/uri-list` contents of the clipboard and produce `T`
+    /// with the paths of its `file://` URIs.
+    ReadUris(Box<dyn Fn(Vec<PathBuf>) -> T>),
+
+    /// Write the given HTML to the clipboard, offering `plain` as a
+    /// `text/plain` fallback for receivers that do not accept `text/html`.
+    WriteHtml {
+        /// The HTML contents.
+        html: String,
+        /// The plain text fallback.
+        plain: String,
+    },
+
+    /// Read the MIME types the contents of the [`Target`] clipboard are
+    /// available in and produce `T` with them.
+    ///
+    /// The list is empty if the clipboard is empty.
+    ReadAvailableTypes(Target, Box<dyn Fn(Vec<String>) -> T>),
+
     /// Read the clipboard and produce `T` with the result.
     ReadPrimary(Box<dyn Fn(Option<String>) -> T>),
 
@@ -35,6 +58,76 @@ pub enum Action<T> {
     #[allow(clippy::type_complexity)]
     /// Read the clipboard and produce `T` with the result.
     ReadPrimaryData(Vec<String>, Box<dyn Fn(Option<(Vec<u8>, String)>) -> T>),
+
+    /// Write the given contents to the [`Target`] clipboard and clear it
+    /// once `ttl` elapses, unless it holds different contents by then.
+    WriteWithExpiry {
+        /// The clipboard to write to.
+        target: Target,
+        /// The contents to write.
+        contents: String,
+        /// The time after which the contents are cleared.
+        ttl: Duration,
+    },
+}
+
+/// A clipboard of the system.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Target {
+    /// The standard clipboard.
+    #[default]
+    Standard,
+    /// The primary selection.
+    Primary,
+}
+
+impl Target {
+    /// Reads the text contents of the [`Target`] in the given clipboard.
+    pub fn read(self, clipboard: &impl core::Clipboard) -> Option<String> {
+        match self {
+            Self::Standard => clipboard.read(),
+            Self::Primary => clipboard.read_primary(),
+        }
+    }
+
+    /// Writes the given text contents to the [`Target`] in the given
+    /// clipboard.
+    pub fn write(self, clipboard: &mut impl core::Clipboard, contents: String) {
+        match self {
+            Self::Standard => clipboard.write(contents),
+            Self::Primary => clipboard.write_primary(contents),
+        }
+    }
+
+    /// Returns the MIME types the contents of the [`Target`] in the given
+    /// clipboard are available in.
+    pub fn available_types(
+        self,
+        clipboard: &impl core::Clipboard,
+    ) -> Vec<String> {
+        match self {
+            Self::Standard => clipboard.available_types(),
+            Self::Primary => clipboard.primary_available_types(),
+        }
+    }
+
+    /// Clears the [`Target`] in the given clipboard if it still holds the
+    /// given contents.
+    ///
+    /// Returns whether the [`Target`] was cleared.
+    pub fn clear_if_unchanged(
+        self,
+        clipboard: &mut impl core::Clipboard,
+        contents: &str,
+    ) -> bool {
+        if self.read(clipboard).as_deref() != Some(contents) {
+            return false;
+        }
+
+        self.write(clipboard, String::new());
+
+        true
+    }
 }
 
 impl<T> Action<T> {
@@ -53,6 +146,13 @@ impl<T> Action<T> {
             Self::ReadData(a, o) => {
                 Action::ReadData(a, Box::new(move |s| f(o(s))))
             }
+            Self::ReadUris(o) => Action::ReadUris(Box::new(move |s| f(o(s)))),
+            Self::WriteHtml { html, plain } => {
+                Action::WriteHtml { html, plain }
+            }
+            Self::ReadAvailableTypes(target, o) => {
+                Action::ReadAvailableTypes(target, Box::new(move |s| f(o(s))))
+            }
             Self::ReadPrimary(o) => {
                 Action::ReadPrimary(Box::new(move |s| f(o(s))))
             }
@@ -63,6 +163,15 @@ impl<T> Action<T> {
             Self::ReadPrimaryData(a, o) => {
                 Action::ReadPrimaryData(a, Box::new(move |s| f(o(s))))
             }
+            Self::WriteWithExpiry {
+                target,
+                contents,
+                ttl,
+            } => Action::WriteWithExpiry {
+                target,
+                contents,
+                ttl,
+            },
         }
     }
 }
@@ -74,10 +183,19 @@ impl<T> fmt::Debug for Action<T> {
             Self::Write(_) => write!(f, "Action::Write"),
             Self::WriteData(_) => write!(f, "Action::WriteData"),
             Self::ReadData(_, _) => write!(f, "Action::ReadData"),
+            Self::ReadUris(_) => write!(f, "Action::ReadUris"),
+            Self::WriteHtml { .. } => write!(f, "Action::WriteHtml"),
+            Self::ReadAvailableTypes(target, _) => {
+                write!(f, "Action::ReadAvailableTypes({target:?})")
+            }
             Self::ReadPrimary(_) => write!(f, "Action::ReadPrimary"),
             Self::WritePrimary(_) => write!(f, "Action::WritePrimary"),
             Self::WritePrimaryData(_) => write!(f, "Action::WritePrimaryData"),
             Self::ReadPrimaryData(_, _) => write!(f, "Action::ReadPrimaryData"),
+            Self::WriteWithExpiry { target, ttl, .. } => write!(
+                f,
+                "Action::WriteWithExpiry {{ target: {target:?}, ttl: {ttl:?} }}"
+            ),
         }
     }
 }
@@ -94,6 +212,43 @@ pub fn write<Message>(contents: String) -> Command<Message> {
     Command::single(command::Action::Clipboard(Action::Write(contents)))
 }
 
+/// Read the paths of the files in the clipboard.
+///
+/// Only the `file://` URIs of the `text/uri-list` contents are returned.
+pub fn read_uris<Message>(
+    f: impl Fn(Vec<PathBuf>) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Clipboard(Action::ReadUris(Box::new(f))))
+}
+
+/// Write the given HTML to the clipboard, together with a plain text
+/// fallback.
+pub fn write_html<Message>(html: String, plain: String) -> Command<Message> {
+    Command::single(command::Action::Clipboard(Action::WriteHtml {
+        html,
+        plain,
+    }))
+}
+
+/// Read the MIME types the current contents of the [`Target`] clipboard are
+/// available in.
+///
+/// This can be used to let the user choose the format to paste.
+///
+/// ## Platform-specific
+///
+/// - **System clipboards:** Unsupported, since their offers cannot be listed.
+///   An empty list is produced.
+pub fn read_available_types<Message>(
+    target: Target,
+    f: impl Fn(Vec<String>) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Clipboard(Action::ReadAvailableTypes(
+        target,
+        Box::new(f),
+    )))
+}
+
 /// Read the current contents of primary.
 pub fn read_primary<Message>(
     f: impl Fn(Option<String>) -> Message + 'static,
@@ -108,6 +263,23 @@ pub fn write_primary<Message>(contents: String) -> Command<Message> {
     Command::single(command::Action::Clipboard(Action::WritePrimary(contents)))
 }
 
+/// Write the given contents to the [`Target`] clipboard and clear it once
+/// `ttl` elapses.
+///
+/// The clipboard is left untouched if it holds different contents by then;
+/// for instance, if the user copied something else in the meantime.
+pub fn write_with_expiry<Message>(
+    target: Target,
+    contents: String,
+    ttl: Duration,
+) -> Command<Message> {
+    Command::single(command::Action::Clipboard(Action::WriteWithExpiry {
+        target,
+        contents,
+        ttl,
+    }))
+}
+
 /// Read the current contents of the clipboard.
 pub fn read_data<T: AllowedMimeTypes + Send + Sync + 'static, Message>(
     f: impl Fn(Option<T>) -> Message + 'static,
@@ -148,3 +320,137 @@ pub fn write_primary_data<Message>(
         Box::new(contents),
     )))
 }
+
+/// The MIME type of a list of URIs, as defined by RFC 2483.
+pub const URI_LIST_MIME_TYPE: &str = "text/uri-list";
+
+/// Parses the contents of a `text/uri-list` into the paths of its `file://`
+/// URIs.
+///
+/// Comments, URIs with other schemes and `file://` URIs pointing to a remote
+/// host are skipped.
+pub fn file_paths(uri_list: &[u8]) -> Vec<PathBuf> {
+    String::from_utf8_lossy(uri_list)
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|uri| url::Url::parse(uri).ok())
+        .filter(|url| url.scheme() == "file")
+        .filter_map(|url| url.to_file_path().ok())
+        .collect()
+}
+
+/// HTML contents with a plain text fallback, offered to the clipboard under
+/// both `text/html` and `text/plain` MIME types.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Html {
+    /// The HTML contents.
+    pub html: String,
+    /// The plain text fallback.
+    pub plain: String,
+}
+
+impl Html {
+    const HTML_MIME_TYPES: &'static [&'static str] =
+        &["text/html", "text/html;charset=utf-8"];
+
+    const PLAIN_MIME_TYPES: &'static [&'static str] = &[
+        "text/plain;charset=utf-8",
+        "text/plain",
+        "UTF8_STRING",
+        "STRING",
+        "TEXT",
+    ];
+}
+
+impl AsMimeTypes for Html {
+    fn available(&self) -> Cow<'static, [String]> {
+        Self::HTML_MIME_TYPES
+            .iter()
+            .chain(Self::PLAIN_MIME_TYPES)
+            .map(|mime_type| mime_type.to_string())
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    fn as_bytes(&self, mime_type: &str) -> Option<Cow<'static, [u8]>> {
+        if Self::HTML_MIME_TYPES.contains(&mime_type) {
+            Some(Cow::Owned(self.html.clone().into_bytes()))
+        } else if Self::PLAIN_MIME_TYPES.contains(&mime_type) {
+            Some(Cow::Owned(self.plain.clone().into_bytes()))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(unix)]
+    #[test]
+    fn file_paths_from_uri_list() {
+        let uri_list = b"# comment\r\n\
+            file:///home/user/My%20File.txt\r\n\
+            https://iced.rs\r\n\
+            file://localhost/tmp/a%25b\n\
+            file://remote/tmp/c\n";
+
+        assert_eq!(
+            file_paths(uri_list),
+            vec![
+                PathBuf::from("/home/user/My File.txt"),
+                PathBuf::from("/tmp/a%b"),
+            ]
+        );
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn file_paths_with_drive_letters() {
+        assert_eq!(
+            file_paths(b"file:///C:/Users/user/My%20File.txt\r\n"),
+            vec![PathBuf::from(r"C:\Users\user\My File.txt")]
+        );
+    }
+
+    #[derive(Default)]
+    struct Memory {
+        standard: Option<String>,
+        primary: Option<String>,
+    }
+
+    impl core::Clipboard for Memory {
+        fn read(&self) -> Option<String> {
+            self.standard.clone()
+        }
+
+        fn write(&mut self, contents: String) {
+            self.standard = Some(contents);
+        }
+
+        fn read_primary(&self) -> Option<String> {
+            self.primary.clone()
+        }
+
+        fn write_primary(&mut self, contents: String) {
+            self.primary = Some(contents);
+        }
+    }
+
+    #[test]
+    fn expired_contents_are_cleared_only_if_unchanged() {
+        let mut clipboard = Memory::default();
+
+        Target::Standard.write(&mut clipboard, String::from("hunter2"));
+        Target::Primary.write(&mut clipboard, String::from("hunter2"));
+        Target::Primary.write(&mut clipboard, String::from("copied later"));
+
+        assert!(Target::Standard.clear_if_unchanged(&mut clipboard, "hunter2"));
+        assert!(!Target::Primary.clear_if_unchanged(&mut clipboard, "hunter2"));
+
+        assert_eq!(clipboard.standard.as_deref(), Some(""));
+        assert_eq!(clipboard.primary.as_deref(), Some("copied later"));
+    }
+}
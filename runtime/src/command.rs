@@ -6,6 +6,7 @@ pub mod platform_specific;
 pub use action::Action;
 
 use crate::core::widget;
+use crate::core::window;
 use crate::futures::futures;
 use crate::futures::MaybeSend;
 
@@ -43,6 +44,15 @@ impl<T> Command<T> {
         Self::single(Action::Widget(Box::new(operation)))
     }
 
+    /// Creates a [`Command`] that performs a [`widget::Operation`] on the
+    /// user interface of the given window only.
+    pub fn widget_for(
+        id: window::Id,
+        operation: impl widget::Operation<T> + 'static,
+    ) -> Self {
+        Self::single(Action::WidgetFor(id, Box::new(operation)))
+    }
+
     /// Creates a [`Command`] that performs the action of the given future.
     pub fn perform<A>(
         future: impl Future<Output = A> + 'static + MaybeSend,
@@ -36,6 +36,12 @@ pub enum Action<T> {
     /// Run a widget action.
     Widget(Box<dyn widget::Operation<T>>),
 
+    /// Run a widget action on the user interface of the given window only.
+    ///
+    /// Unlike [`Action::Widget`], the user interfaces of other windows are
+    /// not traversed.
+    WidgetFor(window::Id, Box<dyn widget::Operation<T>>),
+
     /// Run a Dnd action.
     Dnd(crate::dnd::DndAction<T>),
 
@@ -48,11 +54,39 @@ pub enum Action<T> {
         tagger: Box<dyn Fn(Result<(), font::Error>) -> T>,
     },
 
+    /// Load a font from the bytes produced by a [`Future`], without
+    /// blocking the application.
+    ///
+    /// An [`Event::FontLoaded`] is produced once the font is available.
+    ///
+    /// [`Future`]: iced_futures::BoxFuture
+    /// [`Event::FontLoaded`]: crate::core::Event::FontLoaded
+    LoadFontAsync {
+        /// The [`Font`] provided by the bytes.
+        ///
+        /// [`Font`]: font::Font
+        font: font::Font,
+
+        /// The future producing the bytes of the font to load.
+        bytes: iced_futures::BoxFuture<Cow<'static, [u8]>>,
+    },
+
     /// Run a platform specific action
     PlatformSpecific(crate::command::platform_specific::Action<T>),
 }
 
 impl<T> Action<T> {
+    /// Expands the batched window actions of the [`Action`] into their
+    /// sub-actions, in order.
+    pub fn flatten(self) -> Vec<Self> {
+        match self {
+            Self::Window(action) => {
+                action.flatten().into_iter().map(Self::Window).collect()
+            }
+            action => vec![action],
+        }
+    }
+
     /// Applies a transformation to the result of a [`Command`].
     ///
     /// [`Command`]: crate::Command
@@ -75,10 +109,17 @@ impl<T> Action<T> {
             Self::Widget(operation) => {
                 Action::Widget(Box::new(widget::operation::map(operation, f)))
             }
+            Self::WidgetFor(id, operation) => Action::WidgetFor(
+                id,
+                Box::new(widget::operation::map(operation, f)),
+            ),
             Self::LoadFont { bytes, tagger } => Action::LoadFont {
                 bytes,
                 tagger: Box::new(move |result| f(tagger(result))),
             },
+            Self::LoadFontAsync { font, bytes } => {
+                Action::LoadFontAsync { font, bytes }
+            }
             Self::PlatformSpecific(action) => {
                 Action::PlatformSpecific(action.map(f))
             }
@@ -102,7 +143,13 @@ impl<T> fmt::Debug for Action<T> {
             }
             Self::System(action) => write!(f, "Action::System({action:?})"),
             Self::Widget(_action) => write!(f, "Action::Widget"),
+            Self::WidgetFor(id, _action) => {
+                write!(f, "Action::WidgetFor({id:?})")
+            }
             Self::LoadFont { .. } => write!(f, "Action::LoadFont"),
+            Self::LoadFontAsync { font, .. } => {
+                write!(f, "Action::LoadFontAsync({font:?})")
+            }
             Self::PlatformSpecific(action) => {
                 write!(f, "Action::PlatformSpecific({:?})", action)
             }
@@ -2,8 +2,9 @@ use std::fmt;
 use std::marker::PhantomData;
 
 use iced_core::layout::Limits;
+use iced_core::mouse::CustomCursor;
 use iced_core::window::Mode;
-use iced_core::Size;
+use iced_core::{Rectangle, Size};
 use iced_futures::MaybeSend;
 use sctk::reexports::protocols::xdg::shell::client::xdg_toplevel::ResizeEdge;
 
@@ -173,13 +174,56 @@ pub enum Action<T> {
         /// app id of the window
         app_id: String,
     },
+    /// Set the cursor theme of the window
+    CursorTheme {
+        /// id of the window
+        id: Id,
+        /// name of the cursor theme, or `None` for the environment theme
+        theme: Option<String>,
+        /// size of the cursor, or `None` for the environment size
+        size: Option<u32>,
+    },
+    /// Set the custom cursor of the window
+    CustomCursor {
+        /// id of the window
+        id: Id,
+        /// image of the cursor, or `None` for the cursor icons
+        cursor: Option<CustomCursor>,
+    },
+    /// Request a frame callback for the window
+    RequestFrameCallback {
+        /// id of the window
+        id: Id,
+    },
+    /// Set the area of the text caret, where the IME candidate window is
+    /// placed
+    ImeArea {
+        /// id of the window
+        id: Id,
+        /// logical area of the caret
+        area: Rectangle,
+    },
+    /// Set whether the window accepts IME input
+    ImeAllowed {
+        /// id of the window
+        id: Id,
+        /// whether IME input is allowed
+        allowed: bool,
+    },
+    /// Get the name of the output the window is on
+    OutputName {
+        /// id of the window
+        id: Id,
+        /// message generation
+        message: Box<dyn FnOnce(Option<String>) -> T + 'static>,
+    },
 }
 
 impl<T> Action<T> {
     /// Maps the output of a window [`Action`] using the provided closure.
     pub fn map<A>(
         self,
-        _: impl Fn(T) -> A + 'static + MaybeSend + Sync,
+        mapper: impl Fn(T) -> A + 'static + MaybeSend + Sync,
     ) -> Action<A>
     where
         T: 'static,
@@ -212,6 +256,23 @@ impl<T> Action<T> {
             Action::ToggleMaximized { id } => Action::ToggleMaximized { id },
             Action::ToggleFullscreen { id } => Action::ToggleFullscreen { id },
             Action::AppId { id, app_id } => Action::AppId { id, app_id },
+            Action::CursorTheme { id, theme, size } => {
+                Action::CursorTheme { id, theme, size }
+            }
+            Action::CustomCursor { id, cursor } => {
+                Action::CustomCursor { id, cursor }
+            }
+            Action::RequestFrameCallback { id } => {
+                Action::RequestFrameCallback { id }
+            }
+            Action::ImeArea { id, area } => Action::ImeArea { id, area },
+            Action::ImeAllowed { id, allowed } => {
+                Action::ImeAllowed { id, allowed }
+            }
+            Action::OutputName { id, message } => Action::OutputName {
+                id,
+                message: Box::new(move |name| mapper(message(name))),
+            },
         }
     }
 }
@@ -309,6 +370,37 @@ impl<T> fmt::Debug for Action<T> {
                 "Action::Window::Mode {{ id: {:?}, app_id: {:?} }}",
                 id, app_id
             ),
+            Action::CursorTheme { id, theme, size } => write!(
+                f,
+                "Action::Window::CursorTheme {{ id: {:?}, theme: {:?}, size: {:?} }}",
+                id, theme, size
+            ),
+            Action::CustomCursor { id, cursor } => write!(
+                f,
+                "Action::Window::CustomCursor {{ id: {:?}, hotspot: {:?} }}",
+                id,
+                cursor.as_ref().map(CustomCursor::hotspot)
+            ),
+            Action::RequestFrameCallback { id } => write!(
+                f,
+                "Action::Window::RequestFrameCallback {{ id: {:?} }}",
+                id
+            ),
+            Action::ImeArea { id, area } => write!(
+                f,
+                "Action::Window::ImeArea {{ id: {:?}, area: {:?} }}",
+                id, area
+            ),
+            Action::ImeAllowed { id, allowed } => write!(
+                f,
+                "Action::Window::ImeAllowed {{ id: {:?}, allowed: {:?} }}",
+                id, allowed
+            ),
+            Action::OutputName { id, .. } => write!(
+                f,
+                "Action::Window::OutputName {{ id: {:?} }}",
+                id
+            ),
         }
     }
 }
@@ -364,18 +456,70 @@ impl<T> TryFrom<window::Action<T>> for Action<T> {
             }),
             window::Action::Drag(id) => Ok(Action::InteractiveMove { id }),
             window::Action::FetchSize(_, _)
+            | window::Action::FetchFrameInsets(_, _)
             | window::Action::FetchMaximized(_, _)
             | window::Action::Move(_, _)
             | window::Action::FetchMode(_, _)
             | window::Action::ToggleMaximize(_)
             | window::Action::ToggleDecorations(_)
+            | window::Action::GetDecorationMode(_, _)
+            | window::Action::FetchTitle(_, _)
+            | window::Action::FetchSurfaceScale(_, _)
+            | window::Action::GetSurfaceFormat(_, _)
             | window::Action::RequestUserAttention(_, _)
+            | window::Action::SetUrgent(_, _)
             | window::Action::GainFocus(_)
             | window::Action::ChangeLevel(_, _)
+            | window::Action::SetKeepAbove(_, _)
+            | window::Action::Batch(_)
+            | window::Action::SetKeepBelow(_, _)
+            | window::Action::SetPreferredTheme(_, _)
+            | window::Action::SetResizeIncrements(_, _)
+            | window::Action::SetBaseSize(_, _)
             | window::Action::ShowWindowMenu(_)
+            | window::Action::SetTrafficLightOffset(_, _)
+            | window::Action::SetDocumentEdited(_, _)
+            | window::Action::SetMinimizeAnimation(_, _)
+            | window::Action::SetDocumentPath(_, _)
+            | window::Action::PlayAttentionSound(_)
+            | window::Action::SetAttentionSoundEnabled(_, _)
+            | window::Action::SetResizeBorderHighlight(_, _)
+            | window::Action::SetResizeHitThickness(_, _)
+            | window::Action::SetResizeCornerSize(_, _)
+            | window::Action::SetResizeDirections(_, _)
+            | window::Action::SetAspectRatio(_, _, _)
+            | window::Action::ShowResizeGrip(_, _)
+            | window::Action::SetTitleBarRegion(_, _)
+            | window::Action::SyncResizeBorderWithTheme(_, _)
+            | window::Action::SetBackdrop(_, _)
+            | window::Action::Snap(_, _)
+            | window::Action::SetFrameExtents(_, _)
+            | window::Action::SetFrameInterval(_, _)
+            | window::Action::SetInputPassthrough(_, _, _)
+            | window::Action::SetCursorGrab(_, _, _)
+            | window::Action::SetPreciseMouseMotion(_, _)
+            | window::Action::SetLiveResize(_, _)
+            | window::Action::SetGlobalScaleFactor(_)
+            | window::Action::FollowGlobalScaleFactor(_, _)
+            | window::Action::SetWindowRole(_, _)
+            | window::Action::SetColorProfile(_, _)
+            | window::Action::FetchVideoModes(_, _)
+            | window::Action::SetExclusiveFullscreen(_, _)
+            | window::Action::SetInputCapture(_, _)
             | window::Action::FetchId(_, _)
+            | window::Action::FetchAvailableMonitors(_, _)
             | window::Action::ChangeIcon(_, _)
             | window::Action::Screenshot(_, _)
+            | window::Action::RenderElement(_, _, _, _)
+            | window::Action::DropCache(_)
+            | window::Action::FitToContent(_)
+            | window::Action::SetRenderPriority(_, _)
+            | window::Action::SetCursorIcon(_, _)
+            | window::Action::SetDimOverlay(_, _)
+            | window::Action::DumpLayout(_, _)
+            | window::Action::ReconfigureSurface(_)
+            | window::Action::MinimizeAll
+            | window::Action::RestoreAll
             | window::Action::FetchMinimized(_, _) => Err(Error::NotSupported),
             window::Action::Maximize(id, maximized) => {
                 if maximized {
@@ -394,6 +538,27 @@ impl<T> TryFrom<window::Action<T>> for Action<T> {
             window::Action::ChangeMode(id, mode) => {
                 Ok(Action::Mode(id, mode.into()))
             }
+            window::Action::SetTitle(id, title) => {
+                Ok(Action::Title { id, title })
+            }
+            window::Action::SetCursorTheme(id, theme, size) => {
+                Ok(Action::CursorTheme { id, theme, size })
+            }
+            window::Action::SetCustomCursor(id, cursor) => {
+                Ok(Action::CustomCursor { id, cursor })
+            }
+            window::Action::RequestFrameCallback(id) => {
+                Ok(Action::RequestFrameCallback { id })
+            }
+            window::Action::SetImeArea(id, area) => {
+                Ok(Action::ImeArea { id, area })
+            }
+            window::Action::SetImeAllowed(id, allowed) => {
+                Ok(Action::ImeAllowed { id, allowed })
+            }
+            window::Action::GetCurrentOutputName(id, message) => {
+                Ok(Action::OutputName { id, message })
+            }
         }
     }
 }
@@ -1,5 +1,6 @@
 #![allow(missing_docs)]
 use crate::core::time;
+use crate::core::window::RedrawReason;
 
 use std::collections::VecDeque;
 
@@ -31,6 +32,7 @@ pub struct Debug {
 
     message_count: usize,
     last_messages: VecDeque<String>,
+    last_redraw_reason: Option<RedrawReason>,
 }
 
 impl Debug {
@@ -63,6 +65,7 @@ impl Debug {
 
             message_count: 0,
             last_messages: VecDeque::new(),
+            last_redraw_reason: None,
         }
     }
 
@@ -136,6 +139,10 @@ impl Debug {
         self.message_count += 1;
     }
 
+    pub fn log_redraw(&mut self, reason: RedrawReason) {
+        self.last_redraw_reason = Some(reason);
+    }
+
     pub fn overlay(&self) -> Vec<String> {
         if !self.is_enabled {
             return Vec::new();
@@ -166,6 +173,7 @@ impl Debug {
             self.draw_durations.average(),
         ));
         lines.push(key_value("Render:", self.render_durations.average()));
+        lines.push(key_value("Redraw reason:", self.last_redraw_reason));
         lines.push(key_value("Message count:", self.message_count));
         lines.push(String::from("Last messages:"));
         lines.extend(self.last_messages.iter().map(|msg| {
@@ -1,4 +1,6 @@
 #![allow(missing_docs)]
+use crate::core::window::RedrawReason;
+
 #[derive(Debug, Default)]
 pub struct Debug;
 
@@ -41,6 +43,8 @@ impl Debug {
     ) {
     }
 
+    pub fn log_redraw(&mut self, _reason: RedrawReason) {}
+
     pub fn overlay(&self) -> Vec<String> {
         Vec::new()
     }
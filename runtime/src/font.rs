@@ -2,7 +2,10 @@
 pub use iced_core::font::*;
 
 use crate::command::{self, Command};
+use crate::futures::MaybeSend;
+
 use std::borrow::Cow;
+use std::future::Future;
 
 /// An error while loading a font.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -17,3 +20,26 @@ pub fn load(
         tagger: Box::new(std::convert::identity),
     })
 }
+
+/// Load the given [`Font`] from the bytes produced by a [`Future`], without
+/// blocking the application.
+///
+/// Text using the [`Font`] is drawn with a fallback font until it becomes
+/// available. Then, an [`Event::FontLoaded`] is produced and the text is
+/// laid out again with the new font.
+///
+/// [`Event::FontLoaded`]: crate::core::Event::FontLoaded
+pub fn load_async<Message, B>(
+    font: Font,
+    bytes: impl Future<Output = B> + MaybeSend + 'static,
+) -> Command<Message>
+where
+    B: Into<Cow<'static, [u8]>>,
+{
+    use crate::futures::futures::FutureExt;
+
+    Command::single(command::Action::LoadFontAsync {
+        font,
+        bytes: Box::pin(bytes.map(Into::into)),
+    })
+}
@@ -24,6 +24,7 @@ pub mod keyboard;
 pub mod overlay;
 pub mod program;
 pub mod system;
+pub mod theme;
 pub mod user_interface;
 pub mod window;
 
@@ -1,6 +1,99 @@
 //! Access the native system.
 mod action;
+mod cancellation;
+mod disk_info;
+mod global_shortcut;
 mod information;
+mod key_repeat;
+mod monitor_layout;
+mod process_info;
+
+#[cfg(all(feature = "portal", unix, not(target_os = "macos")))]
+pub mod portal;
+#[cfg(feature = "system")]
+pub mod query;
 
 pub use action::Action;
+pub use cancellation::CancellationToken;
+pub use disk_info::{DiskInfo, DiskInfoError};
+pub use global_shortcut::GlobalShortcutError;
 pub use information::Information;
+pub use key_repeat::KeyRepeat;
+pub use monitor_layout::MonitorLayout;
+pub use process_info::ProcessInfo;
+
+pub use crate::core::renderer::{Backend, RenderStats};
+
+use crate::command::{self, Command};
+use crate::core::time::Duration;
+
+use std::path::PathBuf;
+
+/// Query for available system information, returning a [`CancellationToken`]
+/// that can be used to discard the result before it is produced.
+pub fn fetch_information_cancellable<Message>(
+    f: impl Fn(Information) -> Message + Send + 'static,
+) -> (Command<Message>, CancellationToken) {
+    cancellable(|token| Action::QueryInformationCancellable(token, Box::new(f)))
+}
+
+/// Query for the double click interval of the system, returning a
+/// [`CancellationToken`] that can be used to discard the result before it is
+/// produced.
+pub fn fetch_double_click_interval_cancellable<Message>(
+    f: impl FnOnce(Duration) -> Message + Send + 'static,
+) -> (Command<Message>, CancellationToken) {
+    cancellable(|token| {
+        Action::QueryDoubleClickIntervalCancellable(token, Box::new(f))
+    })
+}
+
+/// Query for the name of the window manager or compositor the application is
+/// running under, returning a [`CancellationToken`] that can be used to
+/// discard the result before it is produced.
+pub fn fetch_window_manager_name_cancellable<Message>(
+    f: impl FnOnce(Option<String>) -> Message + Send + 'static,
+) -> (Command<Message>, CancellationToken) {
+    cancellable(|token| Action::GetWindowManagerName(token, Box::new(f)))
+}
+
+/// Query for the key repeat settings of the system, returning a
+/// [`CancellationToken`] that can be used to discard the result before it is
+/// produced.
+pub fn fetch_key_repeat_cancellable<Message>(
+    f: impl FnOnce(KeyRepeat) -> Message + Send + 'static,
+) -> (Command<Message>, CancellationToken) {
+    cancellable(|token| Action::GetKeyRepeat(token, Box::new(f)))
+}
+
+/// Query for the [`ProcessInfo`] of the current process, returning a
+/// [`CancellationToken`] that can be used to discard the result before it is
+/// produced.
+pub fn fetch_process_info_cancellable<Message>(
+    f: impl FnOnce(ProcessInfo) -> Message + Send + 'static,
+) -> (Command<Message>, CancellationToken) {
+    cancellable(|token| Action::GetProcessInfo(token, Box::new(f)))
+}
+
+/// Query for the [`DiskInfo`] of the filesystem containing the given `path`,
+/// returning a [`CancellationToken`] that can be used to discard the result
+/// before it is produced.
+pub fn fetch_disk_info_cancellable<Message>(
+    path: impl Into<PathBuf>,
+    f: impl FnOnce(Result<DiskInfo, DiskInfoError>) -> Message + Send + 'static,
+) -> (Command<Message>, CancellationToken) {
+    cancellable(|token| Action::GetDiskInfo(token, path.into(), Box::new(f)))
+}
+
+/// Creates a [`Command`] out of the [`Action`] built with a new
+/// [`CancellationToken`], returning both.
+fn cancellable<Message>(
+    action: impl FnOnce(CancellationToken) -> Action<Message>,
+) -> (Command<Message>, CancellationToken) {
+    let token = CancellationToken::new();
+
+    let command =
+        Command::single(command::Action::System(action(token.clone())));
+
+    (command, token)
+}
@@ -1,12 +1,250 @@
+use crate::core::keyboard::shortcut::{self, Shortcut};
+use crate::core::time::Duration;
+use crate::core::window;
 use crate::system;
 
 use iced_futures::MaybeSend;
+use std::collections::HashMap;
 use std::fmt;
+use std::path::PathBuf;
 
 /// An operation to be performed on the system.
 pub enum Action<T> {
     /// Query system information and produce `T` with the result.
     QueryInformation(Box<dyn Closure<T>>),
+    /// Query system information and produce `T` with the result, unless the
+    /// [`CancellationToken`] is cancelled before the query finishes.
+    ///
+    /// [`CancellationToken`]: system::CancellationToken
+    QueryInformationCancellable(system::CancellationToken, Box<dyn Closure<T>>),
+    /// Query the double click interval of the system and produce `T` with the
+    /// result.
+    ///
+    /// If the system does not provide one, a default of 500 milliseconds is
+    /// used.
+    QueryDoubleClickInterval(Box<dyn FnOnce(Duration) -> T + MaybeSend>),
+    /// Query the double click interval of the system and produce `T` with the
+    /// result, unless the [`CancellationToken`] is cancelled before the query
+    /// finishes.
+    ///
+    /// If the system does not provide one, a default of 500 milliseconds is
+    /// used.
+    ///
+    /// [`CancellationToken`]: system::CancellationToken
+    QueryDoubleClickIntervalCancellable(
+        system::CancellationToken,
+        Box<dyn FnOnce(Duration) -> T + MaybeSend>,
+    ),
+    /// Query the [`RenderStats`] of the last frame drawn in the window with
+    /// the given [`window::Id`] and produce `T` with the result.
+    ///
+    /// The result is `None` if the renderer does not keep track of them.
+    ///
+    /// [`RenderStats`]: system::RenderStats
+    GetRenderStats(
+        window::Id,
+        Box<dyn FnOnce(Option<system::RenderStats>) -> T + MaybeSend>,
+    ),
+    /// Produce `T` as soon as the next genuine user interaction (i.e.
+    /// keyboard, mouse, or touch input) is received by any window.
+    ///
+    /// Combined with a timer, this can be used to detect idleness.
+    AwaitInteraction(Box<dyn FnOnce() -> T + MaybeSend>),
+    /// Query the graphics [`Backend`] currently used to render the windows
+    /// of the application and produce `T` with the result.
+    ///
+    /// The backend may change at runtime if the GPU-accelerated backend fails
+    /// and the application falls back to software rendering. The result is
+    /// `None` if the backend cannot be determined.
+    ///
+    /// [`Backend`]: system::Backend
+    GetActiveBackend(Box<dyn FnOnce(Option<system::Backend>) -> T + MaybeSend>),
+    /// Query whether the windows of the application are rendered with a
+    /// hardware-accelerated graphics adapter and produce `T` with the result.
+    ///
+    /// Only discrete and integrated GPUs are considered hardware-accelerated.
+    /// Virtualized GPUs, software rasterizers like llvmpipe, and the
+    /// `tiny-skia` backend are not.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland (SCTK):** Always `false`.
+    IsHardwareAccelerated(Box<dyn FnOnce(bool) -> T + MaybeSend>),
+    /// Capture a [`Screenshot`] of every window of the application and
+    /// produce `T` with the results, keyed by [`window::Id`].
+    ///
+    /// Minimized and hidden windows are skipped, since they have nothing to
+    /// present.
+    ///
+    /// Every window is rendered again and read back from the GPU
+    /// synchronously, so the cost grows with the number and the size of the
+    /// windows. Avoid capturing them on every frame.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland (SCTK):** Unsupported. An empty map is produced.
+    ///
+    /// [`Screenshot`]: crate::window::Screenshot
+    CaptureAllWindows(
+        Box<
+            dyn FnOnce(HashMap<window::Id, crate::window::Screenshot>) -> T
+                + MaybeSend,
+        >,
+    ),
+    /// Query the name of the window manager or compositor the application is
+    /// running under and produce `T` with the result.
+    ///
+    /// The result is `None` if it cannot be detected, which is always the
+    /// case outside of Linux.
+    ///
+    /// No result is produced if the [`CancellationToken`] is cancelled
+    /// before the query finishes.
+    ///
+    /// [`CancellationToken`]: system::CancellationToken
+    GetWindowManagerName(
+        system::CancellationToken,
+        Box<dyn FnOnce(Option<String>) -> T + MaybeSend>,
+    ),
+    /// Query the [`KeyRepeat`] settings of the system and produce `T` with
+    /// the result.
+    ///
+    /// If the system does not provide them, a default delay of 500
+    /// milliseconds and a default interval of 30 milliseconds are used.
+    ///
+    /// No result is produced if the [`CancellationToken`] is cancelled
+    /// before the query finishes.
+    ///
+    /// [`KeyRepeat`]: system::KeyRepeat
+    /// [`CancellationToken`]: system::CancellationToken
+    GetKeyRepeat(
+        system::CancellationToken,
+        Box<dyn FnOnce(system::KeyRepeat) -> T + MaybeSend>,
+    ),
+    /// Register a [`Shortcut`] that is triggered even when none of the
+    /// windows of the application have focus, and produce `T` with the
+    /// [`shortcut::Id`] of the registration.
+    ///
+    /// Every time the [`Shortcut`] is pressed, an
+    /// [`Event::GlobalShortcut`] with the [`shortcut::Id`] is produced. All
+    /// the registered shortcuts are unregistered when the application exits.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Windows:** Registered with `RegisterHotKey`.
+    /// - **Linux:** Registered with the global shortcuts portal of
+    ///   `xdg-desktop-portal`, if the `portal` feature is enabled. The portal
+    ///   may ask the user to confirm or change the shortcut.
+    /// - **Other platforms:** Always fails with
+    ///   [`GlobalShortcutError::Unsupported`].
+    ///
+    /// [`Event::GlobalShortcut`]: crate::core::Event::GlobalShortcut
+    /// [`GlobalShortcutError::Unsupported`]: system::GlobalShortcutError::Unsupported
+    RegisterGlobalShortcut(
+        Shortcut,
+        Box<
+            dyn FnOnce(Result<shortcut::Id, system::GlobalShortcutError>) -> T
+                + MaybeSend,
+        >,
+    ),
+    /// Unregister the global [`Shortcut`] with the given [`shortcut::Id`].
+    UnregisterGlobalShortcut(shortcut::Id),
+    /// Query the [`MonitorLayout`] of the virtual desktop and produce `T`
+    /// with the result.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland (SCTK):** The monitors are placed at the logical position
+    ///   of their output in the compositor space, scaled by their scale
+    ///   factor. There is no primary monitor.
+    ///
+    /// [`MonitorLayout`]: system::MonitorLayout
+    GetMonitorLayout(Box<dyn FnOnce(system::MonitorLayout) -> T + MaybeSend>),
+    /// Query the scale factor of the given [`window::Monitor`] and produce
+    /// `T` with the result.
+    ///
+    /// The result is `None` if the monitor is not connected anymore.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland (SCTK):** The monitor is matched with the output of the
+    ///   compositor it was produced from.
+    GetMonitorScaleFactor(
+        window::Monitor,
+        Box<dyn FnOnce(Option<f64>) -> T + MaybeSend>,
+    ),
+    /// Query every [`window::Monitor`] of the desktop with its scale factor
+    /// and produce `T` with the result.
+    ///
+    /// The query does not depend on any window, so it also succeeds when the
+    /// application has none. Fractional scale factors, like `1.25` or `1.5`,
+    /// are reported as is.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland (SCTK):** The scale factor of an output is the ratio
+    ///   between its current mode and its logical size, so it is fractional
+    ///   when the compositor scales it by a fractional amount.
+    GetDesktopScaling(Box<dyn FnOnce(Vec<window::Monitor>) -> T + MaybeSend>),
+    /// Query the family names of the fonts installed in the system, and of
+    /// the fonts loaded by the application, and produce `T` with the result.
+    ///
+    /// The families are deduplicated and sorted alphabetically. The fonts
+    /// are enumerated in a separate thread and the result is cached until a
+    /// new font is loaded.
+    GetSystemFonts(Box<dyn FnOnce(Vec<String>) -> T + MaybeSend>),
+    /// Query the [`ProcessInfo`] of the current process and produce `T` with
+    /// the result.
+    ///
+    /// Only the process identifier is known if the `system` feature of the
+    /// shell is disabled.
+    ///
+    /// No result is produced if the [`CancellationToken`] is cancelled
+    /// before the query finishes.
+    ///
+    /// [`ProcessInfo`]: system::ProcessInfo
+    /// [`CancellationToken`]: system::CancellationToken
+    GetProcessInfo(
+        system::CancellationToken,
+        Box<dyn FnOnce(system::ProcessInfo) -> T + MaybeSend>,
+    ),
+    /// Query the [`DiskInfo`] of the filesystem containing the given path
+    /// and produce `T` with the result.
+    ///
+    /// If the path does not exist, its nearest existing ancestor is used.
+    /// The query always fails with [`DiskInfoError::Unsupported`] if the
+    /// `system` feature of the shell is disabled.
+    ///
+    /// No result is produced if the [`CancellationToken`] is cancelled
+    /// before the query finishes.
+    ///
+    /// [`DiskInfo`]: system::DiskInfo
+    /// [`DiskInfoError::Unsupported`]: system::DiskInfoError::Unsupported
+    /// [`CancellationToken`]: system::CancellationToken
+    GetDiskInfo(
+        system::CancellationToken,
+        PathBuf,
+        Box<
+            dyn FnOnce(Result<system::DiskInfo, system::DiskInfoError>) -> T
+                + MaybeSend,
+        >,
+    ),
+    /// Inject the given [`Event`] into the window with the given
+    /// [`window::Id`], as if it had been received from the system.
+    ///
+    /// The event goes through the user interface and the subscriptions like
+    /// any other event. A [`mouse::Event::CursorMoved`] also moves the cursor
+    /// of the window, so that the following mouse events are delivered at its
+    /// position.
+    ///
+    /// This is meant for accessibility tools and automated tests.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland (SCTK):** Unsupported.
+    ///
+    /// [`Event`]: crate::core::Event
+    /// [`mouse::Event::CursorMoved`]: crate::core::mouse::Event::CursorMoved
+    InjectEvent(window::Id, crate::core::Event),
 }
 
 pub trait Closure<T>: Fn(system::Information) -> T + MaybeSend {}
@@ -26,6 +264,73 @@ impl<T> Action<T> {
             Self::QueryInformation(o) => {
                 Action::QueryInformation(Box::new(move |s| f(o(s))))
             }
+            Self::QueryInformationCancellable(token, o) => {
+                Action::QueryInformationCancellable(
+                    token,
+                    Box::new(move |s| f(o(s))),
+                )
+            }
+            Self::QueryDoubleClickInterval(o) => {
+                Action::QueryDoubleClickInterval(Box::new(move |s| f(o(s))))
+            }
+            Self::QueryDoubleClickIntervalCancellable(token, o) => {
+                Action::QueryDoubleClickIntervalCancellable(
+                    token,
+                    Box::new(move |s| f(o(s))),
+                )
+            }
+            Self::GetRenderStats(id, o) => {
+                Action::GetRenderStats(id, Box::new(move |s| f(o(s))))
+            }
+            Self::GetWindowManagerName(token, o) => {
+                Action::GetWindowManagerName(token, Box::new(move |s| f(o(s))))
+            }
+            Self::AwaitInteraction(o) => {
+                Action::AwaitInteraction(Box::new(move || f(o())))
+            }
+            Self::GetActiveBackend(o) => {
+                Action::GetActiveBackend(Box::new(move |s| f(o(s))))
+            }
+            Self::IsHardwareAccelerated(o) => {
+                Action::IsHardwareAccelerated(Box::new(move |s| f(o(s))))
+            }
+            Self::CaptureAllWindows(o) => {
+                Action::CaptureAllWindows(Box::new(move |s| f(o(s))))
+            }
+            Self::GetKeyRepeat(token, o) => {
+                Action::GetKeyRepeat(token, Box::new(move |s| f(o(s))))
+            }
+            Self::RegisterGlobalShortcut(shortcut, o) => {
+                Action::RegisterGlobalShortcut(
+                    shortcut,
+                    Box::new(move |s| f(o(s))),
+                )
+            }
+            Self::UnregisterGlobalShortcut(id) => {
+                Action::UnregisterGlobalShortcut(id)
+            }
+            Self::GetMonitorLayout(o) => {
+                Action::GetMonitorLayout(Box::new(move |s| f(o(s))))
+            }
+            Self::GetMonitorScaleFactor(monitor, o) => {
+                Action::GetMonitorScaleFactor(
+                    monitor,
+                    Box::new(move |s| f(o(s))),
+                )
+            }
+            Self::GetDesktopScaling(o) => {
+                Action::GetDesktopScaling(Box::new(move |s| f(o(s))))
+            }
+            Self::GetSystemFonts(o) => {
+                Action::GetSystemFonts(Box::new(move |s| f(o(s))))
+            }
+            Self::GetProcessInfo(token, o) => {
+                Action::GetProcessInfo(token, Box::new(move |s| f(o(s))))
+            }
+            Self::GetDiskInfo(token, path, o) => {
+                Action::GetDiskInfo(token, path, Box::new(move |s| f(o(s))))
+            }
+            Self::InjectEvent(id, event) => Action::InjectEvent(id, event),
         }
     }
 }
@@ -34,6 +339,59 @@ impl<T> fmt::Debug for Action<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::QueryInformation(_) => write!(f, "Action::QueryInformation"),
+            Self::QueryInformationCancellable(_, _) => {
+                write!(f, "Action::QueryInformationCancellable")
+            }
+            Self::QueryDoubleClickInterval(_) => {
+                write!(f, "Action::QueryDoubleClickInterval")
+            }
+            Self::QueryDoubleClickIntervalCancellable(_, _) => {
+                write!(f, "Action::QueryDoubleClickIntervalCancellable")
+            }
+            Self::GetRenderStats(id, _) => {
+                write!(f, "Action::GetRenderStats({id:?})")
+            }
+            Self::GetWindowManagerName(_, _) => {
+                write!(f, "Action::GetWindowManagerName")
+            }
+            Self::AwaitInteraction(_) => write!(f, "Action::AwaitInteraction"),
+            Self::GetActiveBackend(_) => {
+                write!(f, "Action::GetActiveBackend")
+            }
+            Self::IsHardwareAccelerated(_) => {
+                write!(f, "Action::IsHardwareAccelerated")
+            }
+            Self::CaptureAllWindows(_) => {
+                write!(f, "Action::CaptureAllWindows")
+            }
+            Self::GetKeyRepeat(_, _) => {
+                write!(f, "Action::GetKeyRepeat")
+            }
+            Self::RegisterGlobalShortcut(shortcut, _) => {
+                write!(f, "Action::RegisterGlobalShortcut({shortcut:?})")
+            }
+            Self::UnregisterGlobalShortcut(id) => {
+                write!(f, "Action::UnregisterGlobalShortcut({id:?})")
+            }
+            Self::GetMonitorLayout(_) => {
+                write!(f, "Action::GetMonitorLayout")
+            }
+            Self::GetMonitorScaleFactor(monitor, _) => {
+                write!(f, "Action::GetMonitorScaleFactor({monitor:?})")
+            }
+            Self::GetDesktopScaling(_) => {
+                write!(f, "Action::GetDesktopScaling")
+            }
+            Self::GetSystemFonts(_) => write!(f, "Action::GetSystemFonts"),
+            Self::GetProcessInfo(_, _) => {
+                write!(f, "Action::GetProcessInfo")
+            }
+            Self::GetDiskInfo(_, path, _) => {
+                write!(f, "Action::GetDiskInfo({path:?})")
+            }
+            Self::InjectEvent(id, event) => {
+                write!(f, "Action::InjectEvent({id:?}, {event:?})")
+            }
         }
     }
 }
@@ -0,0 +1,65 @@
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// A token that can be used to cancel a system [`Action`] that performs
+/// work in the background.
+///
+/// Once cancelled, the result of the [`Action`] is discarded instead of
+/// being produced as a message.
+///
+/// [`Action`]: super::Action
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<Mutex<bool>>);
+
+impl CancellationToken {
+    /// Creates a new [`CancellationToken`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels the [`Action`] associated with the [`CancellationToken`].
+    ///
+    /// Once this returns, the [`Action`] is guaranteed not to produce its
+    /// result anymore.
+    ///
+    /// [`Action`]: super::Action
+    pub fn cancel(&self) {
+        *self.0.lock().unwrap_or_else(PoisonError::into_inner) = true;
+    }
+
+    /// Returns whether the [`CancellationToken`] has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        *self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Runs `f` and returns its output, unless the [`CancellationToken`] has
+    /// been cancelled.
+    ///
+    /// The [`CancellationToken`] cannot be cancelled while `f` runs, which
+    /// makes it possible to send the result of an [`Action`] without racing
+    /// against [`cancel`].
+    ///
+    /// [`Action`]: super::Action
+    /// [`cancel`]: Self::cancel
+    pub fn unless_cancelled<T>(&self, f: impl FnOnce() -> T) -> Option<T> {
+        let cancelled = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+
+        (!*cancelled).then(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unless_cancelled_runs_until_cancelled() {
+        let token = CancellationToken::new();
+
+        assert_eq!(token.unless_cancelled(|| 42), Some(42));
+
+        token.clone().cancel();
+
+        assert!(token.is_cancelled());
+        assert_eq!(token.unless_cancelled(|| 42), None);
+    }
+}
@@ -0,0 +1,22 @@
+use thiserror::Error;
+
+/// The space of the filesystem containing a path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiskInfo {
+    /// The total space of the filesystem, in bytes.
+    pub total: u64,
+    /// The space of the filesystem available for writing, in bytes.
+    pub available: u64,
+}
+
+/// An error produced when querying the [`DiskInfo`] of a path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum DiskInfoError {
+    /// The platform does not support querying disk information.
+    #[error("disk information is not supported on this platform")]
+    Unsupported,
+
+    /// Neither the path nor any of its ancestors exist in a known filesystem.
+    #[error("the path is not contained in any known filesystem")]
+    NotFound,
+}
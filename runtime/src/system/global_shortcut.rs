@@ -0,0 +1,17 @@
+use thiserror::Error;
+
+/// An error produced when registering a global keyboard shortcut.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum GlobalShortcutError {
+    /// The platform or compositor does not support global shortcuts.
+    #[error("global shortcuts are not supported on this platform")]
+    Unsupported,
+
+    /// The key of the shortcut cannot be registered globally.
+    #[error("the key of the shortcut cannot be registered globally")]
+    InvalidKey,
+
+    /// The shortcut is already registered, possibly by another application.
+    #[error("the shortcut is already registered")]
+    AlreadyRegistered,
+}
@@ -0,0 +1,10 @@
+use crate::core::time::Duration;
+
+/// The key repeat settings of the system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyRepeat {
+    /// The time a key must be held before it starts repeating.
+    pub delay: Duration,
+    /// The time between two consecutive repeats of a held key.
+    pub interval: Duration,
+}
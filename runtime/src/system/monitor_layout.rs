@@ -0,0 +1,118 @@
+use crate::core::window::Monitor;
+use crate::core::Point;
+
+/// The layout of the monitors of the virtual desktop.
+///
+/// Every [`Monitor`] is positioned in the coordinate space of the virtual
+/// desktop, in physical pixels. Monitors placed to the left of or above the
+/// primary monitor have negative coordinates.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MonitorLayout {
+    /// The monitors of the virtual desktop.
+    pub monitors: Vec<Monitor>,
+    /// The index of the primary monitor in [`monitors`], if known.
+    ///
+    /// [`monitors`]: Self::monitors
+    pub primary: Option<usize>,
+}
+
+impl MonitorLayout {
+    /// Returns the primary [`Monitor`] of the layout, if known.
+    pub fn primary(&self) -> Option<&Monitor> {
+        self.monitors.get(self.primary?)
+    }
+
+    /// Returns the position and size of the smallest rectangle containing
+    /// every [`Monitor`] of the layout, in physical pixels.
+    pub fn bounds(&self) -> Option<((i32, i32), (u32, u32))> {
+        let mut monitors = self.monitors.iter().map(|monitor| {
+            let (x, y) = monitor.position;
+            let (width, height) = monitor.size;
+
+            (
+                (i64::from(x), i64::from(y)),
+                (
+                    i64::from(x) + i64::from(width),
+                    i64::from(y) + i64::from(height),
+                ),
+            )
+        });
+
+        let first = monitors.next()?;
+
+        let ((left, top), (right, bottom)) =
+            monitors.fold(first, |(min, max), (start, end)| {
+                (
+                    (min.0.min(start.0), min.1.min(start.1)),
+                    (max.0.max(end.0), max.1.max(end.1)),
+                )
+            });
+
+        Some((
+            (left as i32, top as i32),
+            ((right - left) as u32, (bottom - top) as u32),
+        ))
+    }
+
+    /// Returns the [`Monitor`] containing the given physical position of the
+    /// virtual desktop, if any.
+    pub fn monitor_at(&self, x: i32, y: i32) -> Option<&Monitor> {
+        self.monitors.iter().find(|monitor| {
+            let (left, top) = monitor.position;
+            let (width, height) = monitor.size;
+
+            let (x, y) = (i64::from(x), i64::from(y));
+
+            x >= i64::from(left)
+                && x < i64::from(left) + i64::from(width)
+                && y >= i64::from(top)
+                && y < i64::from(top) + i64::from(height)
+        })
+    }
+
+    /// Converts the given physical position of the virtual desktop into
+    /// the logical position expected by [`window::move_to`] for a window with
+    /// the given scale factor.
+    ///
+    /// [`window::move_to`]: crate::window::move_to
+    pub fn to_logical(position: (i32, i32), scale_factor: f64) -> Point {
+        Point::new(
+            (f64::from(position.0) / scale_factor) as f32,
+            (f64::from(position.1) / scale_factor) as f32,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn monitor(position: (i32, i32), size: (u32, u32)) -> Monitor {
+        Monitor {
+            name: None,
+            position,
+            size,
+            scale_factor: 1.0,
+            refresh_rate_millihertz: None,
+        }
+    }
+
+    #[test]
+    fn monitors_left_of_and_above_the_primary_one() {
+        let layout = MonitorLayout {
+            monitors: vec![
+                monitor((0, 0), (1920, 1080)),
+                monitor((-1280, 0), (1280, 1024)),
+                monitor((0, -1440), (2560, 1440)),
+            ],
+            primary: Some(0),
+        };
+
+        assert_eq!(layout.primary(), Some(&layout.monitors[0]));
+        assert_eq!(layout.bounds(), Some(((-1280, -1440), (3840, 2520))));
+
+        assert_eq!(layout.monitor_at(-1, 10), Some(&layout.monitors[1]));
+        assert_eq!(layout.monitor_at(100, -1), Some(&layout.monitors[2]));
+        assert_eq!(layout.monitor_at(-1, -1), None);
+    }
+}
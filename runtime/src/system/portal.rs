@@ -0,0 +1,322 @@
+//! Register global keyboard shortcuts with the global shortcuts portal of
+//! `xdg-desktop-portal`.
+//!
+//! The portal may ask the user to confirm or change the triggers of the
+//! shortcuts, so registering a shortcut completes asynchronously.
+use crate::core::keyboard::key::{self, Key};
+use crate::core::keyboard::shortcut::{self, Shortcut};
+use crate::core::keyboard::Modifiers;
+use crate::futures::futures::channel::mpsc;
+use crate::futures::futures::{self, StreamExt};
+use crate::system::GlobalShortcutError;
+
+use ashpd::desktop::global_shortcuts::{GlobalShortcuts, NewShortcut};
+use ashpd::desktop::Session;
+use ashpd::WindowIdentifier;
+
+use std::collections::BTreeMap;
+use std::sync::{Mutex, PoisonError};
+
+type Trigger = Box<dyn Fn(shortcut::Id) + Send>;
+type Reply = Box<dyn FnOnce(Result<shortcut::Id, GlobalShortcutError>) + Send>;
+
+enum Request {
+    Register {
+        id: shortcut::Id,
+        trigger: String,
+        on_trigger: Trigger,
+        on_result: Reply,
+    },
+    Unregister(shortcut::Id),
+    UnregisterAll,
+}
+
+/// The sender of the requests to the thread talking to the portal.
+static SENDER: Mutex<Option<mpsc::UnboundedSender<Request>>> = Mutex::new(None);
+
+/// Registers the given [`Shortcut`] with the portal, calling `on_trigger`
+/// with its [`shortcut::Id`] every time it is pressed.
+///
+/// `on_result` is called once the portal answers.
+pub fn register(
+    shortcut: &Shortcut,
+    on_trigger: impl Fn(shortcut::Id) + Send + 'static,
+    on_result: impl FnOnce(Result<shortcut::Id, GlobalShortcutError>)
+        + Send
+        + 'static,
+) {
+    let Some(trigger) = trigger(shortcut) else {
+        on_result(Err(GlobalShortcutError::InvalidKey));
+        return;
+    };
+
+    let request = Request::Register {
+        id: shortcut::Id::unique(),
+        trigger,
+        on_trigger: Box::new(on_trigger),
+        on_result: Box::new(on_result),
+    };
+
+    if let Err(Request::Register { on_result, .. }) = send(request) {
+        on_result(Err(GlobalShortcutError::Unsupported));
+    }
+}
+
+/// Unregisters the [`Shortcut`] with the given [`shortcut::Id`].
+pub fn unregister(id: shortcut::Id) {
+    let _ = send(Request::Unregister(id));
+}
+
+/// Unregisters every [`Shortcut`] registered by the application, closing
+/// the session with the portal.
+pub fn unregister_all() {
+    let sender = SENDER.lock().unwrap_or_else(PoisonError::into_inner).take();
+
+    // Avoid spawning the thread if nothing was ever registered
+    if let Some(sender) = sender {
+        let _ = sender.unbounded_send(Request::UnregisterAll);
+    }
+}
+
+fn send(request: Request) -> Result<(), Request> {
+    let mut sender = SENDER.lock().unwrap_or_else(PoisonError::into_inner);
+
+    if sender.is_none() {
+        *sender = spawn();
+    }
+
+    match sender.as_ref() {
+        Some(sender) => sender
+            .unbounded_send(request)
+            .map_err(mpsc::TrySendError::into_inner),
+        None => Err(request),
+    }
+}
+
+fn spawn() -> Option<mpsc::UnboundedSender<Request>> {
+    let (sender, requests) = mpsc::unbounded();
+
+    let _ = std::thread::Builder::new()
+        .name(String::from("iced global shortcuts"))
+        .spawn(move || futures::executor::block_on(run(requests)))
+        .ok()?;
+
+    Some(sender)
+}
+
+async fn run(mut requests: mpsc::UnboundedReceiver<Request>) {
+    let Ok(portal) = GlobalShortcuts::new().await else {
+        return reject(requests).await;
+    };
+
+    let Ok(session) = portal.create_session().await else {
+        return reject(requests).await;
+    };
+
+    let Ok(activations) = portal.receive_activated().await else {
+        let _ = session.close().await;
+
+        return reject(requests).await;
+    };
+
+    let mut activations = Box::pin(activations.fuse());
+    let mut shortcuts: BTreeMap<shortcut::Id, (String, Trigger)> =
+        BTreeMap::new();
+
+    loop {
+        futures::select! {
+            request = requests.next() => match request {
+                Some(Request::Register {
+                    id,
+                    trigger,
+                    on_trigger,
+                    on_result,
+                }) => {
+                    let _ = shortcuts.insert(id, (trigger, on_trigger));
+
+                    let result =
+                        match bind(&portal, &session, &shortcuts).await {
+                            Ok(bound) if bound.contains(&name(id)) => Ok(id),
+                            Ok(_) => {
+                                Err(GlobalShortcutError::AlreadyRegistered)
+                            }
+                            Err(_) => Err(GlobalShortcutError::Unsupported),
+                        };
+
+                    if result.is_err() {
+                        let _ = shortcuts.remove(&id);
+                    }
+
+                    on_result(result);
+                }
+                Some(Request::Unregister(id)) => {
+                    // The portal replaces the shortcuts of the session with
+                    // the ones bound last
+                    if shortcuts.remove(&id).is_some() {
+                        let _ = bind(&portal, &session, &shortcuts).await;
+                    }
+                }
+                Some(Request::UnregisterAll) | None => break,
+            },
+            activation = activations.next() => {
+                let Some(activation) = activation else {
+                    continue;
+                };
+
+                if let Some((id, (_, on_trigger))) = shortcuts
+                    .iter()
+                    .find(|(id, _)| name(**id) == activation.shortcut_id())
+                {
+                    on_trigger(*id);
+                }
+            }
+        }
+    }
+
+    let _ = session.close().await;
+}
+
+/// Answers every registration with [`GlobalShortcutError::Unsupported`],
+/// since the portal is not available.
+async fn reject(mut requests: mpsc::UnboundedReceiver<Request>) {
+    while let Some(request) = requests.next().await {
+        match request {
+            Request::Register { on_result, .. } => {
+                on_result(Err(GlobalShortcutError::Unsupported));
+            }
+            Request::Unregister(_) => {}
+            Request::UnregisterAll => break,
+        }
+    }
+}
+
+/// Binds the given shortcuts to the session, returning the names of the
+/// shortcuts bound by the portal.
+async fn bind(
+    portal: &GlobalShortcuts<'_>,
+    session: &Session<'_>,
+    shortcuts: &BTreeMap<shortcut::Id, (String, Trigger)>,
+) -> ashpd::Result<Vec<String>> {
+    let shortcuts: Vec<_> = shortcuts
+        .iter()
+        .map(|(id, (trigger, _))| {
+            NewShortcut::new(name(*id), trigger.as_str())
+                .preferred_trigger(trigger.as_str())
+        })
+        .collect();
+
+    let response = portal
+        .bind_shortcuts(session, &shortcuts, &WindowIdentifier::default())
+        .await?
+        .response()?;
+
+    Ok(response
+        .shortcuts()
+        .iter()
+        .map(|shortcut| shortcut.id().to_owned())
+        .collect())
+}
+
+/// Returns the name of the shortcut with the given [`shortcut::Id`] in the
+/// session.
+fn name(id: shortcut::Id) -> String {
+    id.as_u32().to_string()
+}
+
+/// Returns the trigger of the given [`Shortcut`], as described by the
+/// shortcuts specification of `xdg-desktop-portal`.
+fn trigger(shortcut: &Shortcut) -> Option<String> {
+    let key = key_name(&shortcut.key)?;
+
+    let modifiers = [
+        (Modifiers::CTRL, "CTRL"),
+        (Modifiers::ALT, "ALT"),
+        (Modifiers::SHIFT, "SHIFT"),
+        (Modifiers::LOGO, "LOGO"),
+    ];
+
+    let mut trigger = String::new();
+
+    for (modifier, name) in modifiers {
+        if shortcut.modifiers.contains(modifier) {
+            trigger.push_str(name);
+            trigger.push('+');
+        }
+    }
+
+    trigger.push_str(&key);
+
+    Some(trigger)
+}
+
+/// Returns the XKB keysym name of the given [`Key`].
+fn key_name(key: &Key) -> Option<String> {
+    use key::Named;
+
+    let name = match key {
+        Key::Character(c) => {
+            let mut chars = c.chars();
+            let c = chars.next()?.to_ascii_lowercase();
+
+            if chars.next().is_some() || !c.is_ascii_alphanumeric() {
+                return None;
+            }
+
+            return Some(c.to_string());
+        }
+        Key::Named(named) => match named {
+            Named::Space => "space",
+            Named::Enter => "Return",
+            Named::Escape => "Escape",
+            Named::Tab => "Tab",
+            Named::Backspace => "BackSpace",
+            Named::Delete => "Delete",
+            Named::Insert => "Insert",
+            Named::Home => "Home",
+            Named::End => "End",
+            Named::PageUp => "Page_Up",
+            Named::PageDown => "Page_Down",
+            Named::ArrowLeft => "Left",
+            Named::ArrowRight => "Right",
+            Named::ArrowUp => "Up",
+            Named::ArrowDown => "Down",
+            Named::PrintScreen => "Print",
+            Named::Pause => "Pause",
+            Named::MediaPlayPause => "XF86AudioPlay",
+            Named::MediaStop => "XF86AudioStop",
+            Named::MediaTrackNext => "XF86AudioNext",
+            Named::MediaTrackPrevious => "XF86AudioPrev",
+            Named::AudioVolumeUp => "XF86AudioRaiseVolume",
+            Named::AudioVolumeDown => "XF86AudioLowerVolume",
+            Named::AudioVolumeMute => "XF86AudioMute",
+            Named::F1 => "F1",
+            Named::F2 => "F2",
+            Named::F3 => "F3",
+            Named::F4 => "F4",
+            Named::F5 => "F5",
+            Named::F6 => "F6",
+            Named::F7 => "F7",
+            Named::F8 => "F8",
+            Named::F9 => "F9",
+            Named::F10 => "F10",
+            Named::F11 => "F11",
+            Named::F12 => "F12",
+            Named::F13 => "F13",
+            Named::F14 => "F14",
+            Named::F15 => "F15",
+            Named::F16 => "F16",
+            Named::F17 => "F17",
+            Named::F18 => "F18",
+            Named::F19 => "F19",
+            Named::F20 => "F20",
+            Named::F21 => "F21",
+            Named::F22 => "F22",
+            Named::F23 => "F23",
+            Named::F24 => "F24",
+            _ => return None,
+        },
+        Key::Unidentified => return None,
+    };
+
+    Some(name.to_owned())
+}
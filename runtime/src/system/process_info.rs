@@ -0,0 +1,29 @@
+use crate::core::time::Duration;
+
+use std::time::SystemTime;
+
+/// Information about the process of the application.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProcessInfo {
+    /// The identifier of the process.
+    pub pid: u32,
+    /// The time at which the process was started, if known.
+    pub start_time: Option<SystemTime>,
+    /// How long the process has been running for, if known.
+    pub uptime: Option<Duration>,
+    /// The memory used by the process in bytes, if known.
+    pub memory: Option<u64>,
+}
+
+impl ProcessInfo {
+    /// Returns the [`ProcessInfo`] of the current process that is known
+    /// without querying the system; that is, only its identifier.
+    pub fn current() -> Self {
+        Self {
+            pid: std::process::id(),
+            start_time: None,
+            uptime: None,
+            memory: None,
+        }
+    }
+}
@@ -0,0 +1,161 @@
+//! Query the native system, independently of the windowing shell.
+use crate::core::time::Duration;
+use crate::system::{
+    DiskInfo, DiskInfoError, Information, KeyRepeat, ProcessInfo,
+};
+
+use std::path::Path;
+
+/// The double click interval used when the system does not provide one.
+pub const DEFAULT_DOUBLE_CLICK_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The key repeat settings used when the system does not provide them.
+pub const DEFAULT_KEY_REPEAT: KeyRepeat = KeyRepeat {
+    delay: Duration::from_millis(500),
+    interval: Duration::from_millis(30),
+};
+
+/// Queries the [`Information`] of the system, given the name of the graphics
+/// adapter and backend used to render the application.
+pub fn information(
+    graphics_adapter: String,
+    graphics_backend: String,
+) -> Information {
+    use sysinfo::{CpuExt, ProcessExt, System, SystemExt};
+    let mut system = System::new_all();
+    system.refresh_all();
+
+    let cpu = system.global_cpu_info();
+
+    let memory_used = sysinfo::get_current_pid()
+        .and_then(|pid| system.process(pid).ok_or("Process not found"))
+        .map(ProcessExt::memory)
+        .ok();
+
+    Information {
+        system_name: system.name(),
+        system_kernel: system.kernel_version(),
+        system_version: system.long_os_version(),
+        system_short_version: system.os_version(),
+        cpu_brand: cpu.brand().into(),
+        cpu_cores: system.physical_core_count(),
+        memory_total: system.total_memory(),
+        memory_used,
+        graphics_adapter,
+        graphics_backend,
+    }
+}
+
+/// Queries the [`ProcessInfo`] of the current process.
+///
+/// Only its identifier is known if the process cannot be queried.
+pub fn process_info() -> ProcessInfo {
+    use sysinfo::{ProcessExt, System, SystemExt};
+
+    let current = ProcessInfo::current();
+
+    let Ok(pid) = sysinfo::get_current_pid() else {
+        return current;
+    };
+
+    let mut system = System::new();
+
+    if !system.refresh_process(pid) {
+        return current;
+    }
+
+    let Some(process) = system.process(pid) else {
+        return current;
+    };
+
+    ProcessInfo {
+        start_time: Some(
+            std::time::UNIX_EPOCH + Duration::from_secs(process.start_time()),
+        ),
+        uptime: Some(Duration::from_secs(process.run_time())),
+        memory: Some(process.memory()),
+        ..current
+    }
+}
+
+/// Queries the [`DiskInfo`] of the filesystem containing the given `path`,
+/// or its nearest existing ancestor.
+pub fn disk_info(path: &Path) -> Result<DiskInfo, DiskInfoError> {
+    use sysinfo::{DiskExt, System, SystemExt};
+
+    // Paths that do not exist yet are resolved to their nearest existing
+    // ancestor, where an empty ancestor stands for the current directory
+    let path = path
+        .ancestors()
+        .find_map(|ancestor| {
+            if ancestor.as_os_str().is_empty() {
+                Path::new(".").canonicalize().ok()
+            } else {
+                ancestor.canonicalize().ok()
+            }
+        })
+        .ok_or(DiskInfoError::NotFound)?;
+
+    let mut system = System::new();
+    system.refresh_disks_list();
+
+    // The innermost mount point containing the path is its filesystem
+    system
+        .disks()
+        .iter()
+        .filter(|disk| path.starts_with(disk.mount_point()))
+        .max_by_key(|disk| disk.mount_point().components().count())
+        .map(|disk| DiskInfo {
+            total: disk.total_space(),
+            available: disk.available_space(),
+        })
+        .ok_or(DiskInfoError::NotFound)
+}
+
+/// Reads the `gtk-double-click-time` setting of the GTK configuration files
+/// of the user.
+#[cfg(target_os = "linux")]
+pub fn gtk_double_click_interval() -> Option<Duration> {
+    let config = std::env::var_os("XDG_CONFIG_HOME")
+        .map(std::path::PathBuf::from)
+        .or_else(|| {
+            std::env::var_os("HOME")
+                .map(|home| std::path::PathBuf::from(home).join(".config"))
+        })?;
+
+    ["gtk-4.0", "gtk-3.0"].into_iter().find_map(|version| {
+        let settings =
+            std::fs::read_to_string(config.join(version).join("settings.ini"))
+                .ok()?;
+
+        settings.lines().find_map(|line| {
+            let (key, value) = line.split_once('=')?;
+
+            if key.trim() == "gtk-double-click-time" {
+                value.trim().parse().ok().map(Duration::from_millis)
+            } else {
+                None
+            }
+        })
+    })
+}
+
+/// Reads the name of the current desktop from the environment variables set
+/// by the session.
+#[cfg(target_os = "linux")]
+pub fn desktop_name() -> Option<String> {
+    [
+        "XDG_CURRENT_DESKTOP",
+        "XDG_SESSION_DESKTOP",
+        "DESKTOP_SESSION",
+    ]
+    .into_iter()
+    .find_map(|variable| {
+        let value = std::env::var(variable).ok()?;
+
+        // `XDG_CURRENT_DESKTOP` may contain a colon-separated list
+        let name = value.split(':').next()?.trim();
+
+        (!name.is_empty()).then(|| name.to_owned())
+    })
+}
@@ -0,0 +1,21 @@
+//! Follow the theme of the application.
+use crate::core::theme::Palette;
+use crate::core::Event;
+use crate::futures::event;
+use crate::futures::Subscription;
+
+/// Subscribes to the changes of the [`Palette`] of the theme.
+///
+/// The resulting [`Subscription`] produces an item whenever the effective
+/// [`Palette`] changes, whether the application changed its theme or the
+/// theme followed the appearance of the system. Several changes within the
+/// same frame produce a single item.
+///
+/// This is useful to update resources that are not drawn by widgets, like the
+/// icon of a system tray.
+pub fn palette_changes() -> Subscription<Palette> {
+    event::listen_raw(|event, _status| match event {
+        Event::ThemePaletteChanged(palette) => Some(palette),
+        _ => None,
+    })
+}
@@ -9,9 +9,15 @@ pub use action::Action;
 pub use screenshot::Screenshot;
 
 use crate::command::{self, Command};
-use crate::core::time::Instant;
-use crate::core::window::{Event, Icon, Level, Mode, Settings, UserAttention};
-use crate::core::{Point, Size};
+use crate::core::time::{Duration, Instant};
+use crate::core::window::{
+    AspectRatioMode, BackdropMaterial, CursorGrabMode, DecorationMode, Event,
+    Icon, Level, Mode, Monitor, RenderPriority, ResizeDirections, Settings,
+    SnapPosition, SurfaceFormat, UserAttention, VideoMode,
+};
+use crate::core::{
+    mouse, theme, Color, Element, Padding, Point, Rectangle, Size,
+};
 use crate::futures::event;
 use crate::futures::Subscription;
 
@@ -32,6 +38,21 @@ pub fn frames() -> Subscription<(Id, Instant)> {
     })
 }
 
+/// Subscribes to the frame callbacks requested with
+/// [`request_frame_callback`].
+///
+/// Unlike [`frames`], this [`Subscription`] only produces items when a
+/// callback was explicitly requested, so an animation can stop requesting
+/// frames once it has settled.
+pub fn frame_callbacks() -> Subscription<(Id, Instant)> {
+    event::listen_raw(|event, _status| match event {
+        iced_core::Event::Window(id, Event::FrameCallback(at)) => {
+            Some((id, at))
+        }
+        _ => None,
+    })
+}
+
 #[cfg(feature = "wayland")]
 /// Subscribes to the frames of the window of the running application.
 ///
@@ -53,6 +74,26 @@ pub fn wayland_frames() -> Subscription<(Id, Instant)> {
     })
 }
 
+/// Subscribes to the position changes of the windows of the running
+/// application.
+///
+/// The resulting [`Subscription`] produces the new logical position of the
+/// top-left corner of a window every time it is moved. Consecutive moves
+/// happening within the same event loop iteration are coalesced, so only
+/// the latest position is reported while a window is being dragged.
+///
+/// Moves requested through [`move_to`] are reported as well; compare the
+/// produced position with the requested one to tell them apart from moves
+/// performed by the user.
+pub fn moves() -> Subscription<(Id, Point)> {
+    event::listen_raw(|event, _status| match event {
+        iced_core::Event::Window(id, Event::Moved { x, y }) => {
+            Some((id, Point::new(x as f32, y as f32)))
+        }
+        _ => None,
+    })
+}
+
 /// Spawns a new window with the given `settings`.
 ///
 /// Returns the new window [`Id`] alongside the [`Command`].
@@ -88,6 +129,21 @@ pub fn fetch_size<Message>(
     Command::single(command::Action::Window(Action::FetchSize(id, Box::new(f))))
 }
 
+/// Fetches the logical insets of the decorations of the window (i.e. the
+/// title bar height and border widths).
+///
+/// The insets are always zero on Wayland, since client-side decorations are
+/// drawn inside the window.
+pub fn fetch_frame_insets<Message>(
+    id: Id,
+    f: impl FnOnce(Padding) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::FetchFrameInsets(
+        id,
+        Box::new(f),
+    )))
+}
+
 /// Fetches if the window is maximized.
 pub fn fetch_maximized<Message>(
     id: Id,
@@ -120,6 +176,19 @@ pub fn minimize<Message>(id: Id, minimized: bool) -> Command<Message> {
     Command::single(command::Action::Window(Action::Minimize(id, minimized)))
 }
 
+/// Minimizes all the windows of the application that are not minimized
+/// already.
+pub fn minimize_all<Message>() -> Command<Message> {
+    Command::single(command::Action::Window(Action::MinimizeAll))
+}
+
+/// Restores all the windows minimized by [`minimize_all`].
+///
+/// Windows that were minimized before are left untouched.
+pub fn restore_all<Message>() -> Command<Message> {
+    Command::single(command::Action::Window(Action::RestoreAll))
+}
+
 /// Moves the window to the given logical coordinates.
 pub fn move_to<Message>(id: Id, position: Point) -> Command<Message> {
     Command::single(command::Action::Window(Action::Move(id, position)))
@@ -143,6 +212,49 @@ pub fn toggle_maximize<Message>(id: Id) -> Command<Message> {
     Command::single(command::Action::Window(Action::ToggleMaximize(id)))
 }
 
+/// Fetches the [`DecorationMode`] of the window.
+///
+/// The result is only known on Wayland, where the compositor may choose to
+/// draw the decorations itself. It is `None` everywhere else.
+pub fn fetch_decoration_mode<Message>(
+    id: Id,
+    f: impl FnOnce(Option<DecorationMode>) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::GetDecorationMode(
+        id,
+        Box::new(f),
+    )))
+}
+
+/// Fetches the name of the output, or monitor, the window with the given
+/// [`Id`] is currently on.
+///
+/// The result is `None` if the output cannot be determined, like when the
+/// window spans several outputs on Wayland.
+pub fn fetch_current_output_name<Message>(
+    id: Id,
+    f: impl FnOnce(Option<String>) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::GetCurrentOutputName(
+        id,
+        Box::new(f),
+    )))
+}
+
+/// Fetches the [`SurfaceFormat`] of the frames presented to the window.
+///
+/// This describes the pixel format and color encoding of the window, which is
+/// necessary to interpret its contents when blitting them elsewhere.
+pub fn fetch_surface_format<Message>(
+    id: Id,
+    f: impl FnOnce(Option<SurfaceFormat>) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::GetSurfaceFormat(
+        id,
+        Box::new(f),
+    )))
+}
+
 /// Toggles the window decorations.
 pub fn toggle_decorations<Message>(id: Id) -> Command<Message> {
     Command::single(command::Action::Window(Action::ToggleDecorations(id)))
@@ -164,6 +276,13 @@ pub fn request_user_attention<Message>(
     )))
 }
 
+/// Sets or unsets the urgency hint of the window.
+///
+/// The hint is cleared automatically once the window gains focus.
+pub fn set_urgent<Message>(id: Id, urgent: bool) -> Command<Message> {
+    Command::single(command::Action::Window(Action::SetUrgent(id, urgent)))
+}
+
 /// Brings the window to the front and sets input focus. Has no effect if the window is
 /// already in focus, minimized, or not visible.
 ///
@@ -174,11 +293,448 @@ pub fn gain_focus<Message>(id: Id) -> Command<Message> {
     Command::single(command::Action::Window(Action::GainFocus(id)))
 }
 
+/// Runs the given window actions in order, before the next frame of any
+/// window is presented.
+pub fn batch<Message>(
+    actions: impl IntoIterator<Item = Action<Message>>,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::Batch(
+        actions.into_iter().collect(),
+    )))
+}
+
 /// Changes the window [`Level`].
 pub fn change_level<Message>(id: Id, level: Level) -> Command<Message> {
     Command::single(command::Action::Window(Action::ChangeLevel(id, level)))
 }
 
+/// Keeps the window with the given [`Id`] above all the other windows, or
+/// stops doing so.
+pub fn set_keep_above<Message>(id: Id, above: bool) -> Command<Message> {
+    Command::single(command::Action::Window(Action::SetKeepAbove(id, above)))
+}
+
+/// Keeps the window with the given [`Id`] below all the other windows, or
+/// stops doing so.
+pub fn set_keep_below<Message>(id: Id, below: bool) -> Command<Message> {
+    Command::single(command::Action::Window(Action::SetKeepBelow(id, below)))
+}
+
+/// Sets the theme [`Mode`] of the decorations drawn by the system for the
+/// window.
+///
+/// `None` makes the window follow the theme of the system again.
+///
+/// [`Mode`]: theme::Mode
+pub fn set_preferred_theme<Message>(
+    id: Id,
+    theme: Option<theme::Mode>,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::SetPreferredTheme(
+        id, theme,
+    )))
+}
+
+/// Sets the resize increments of the window, in logical pixels.
+///
+/// `None` removes them.
+pub fn set_resize_increments<Message>(
+    id: Id,
+    increments: Option<Size>,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::SetResizeIncrements(
+        id, increments,
+    )))
+}
+
+/// Sets the base size of the window, in logical pixels, which the resize
+/// increments are added to.
+///
+/// The base size replaces the minimum size of the window.
+pub fn set_base_size<Message>(id: Id, size: Size) -> Command<Message> {
+    Command::single(command::Action::Window(Action::SetBaseSize(id, size)))
+}
+
+/// Sets the area of the window, in logical coordinates, where the text caret
+/// is located.
+///
+/// The IME candidate window will be placed next to this area.
+pub fn set_ime_area<Message>(id: Id, area: Rectangle) -> Command<Message> {
+    Command::single(command::Action::Window(Action::SetImeArea(id, area)))
+}
+
+/// Sets whether the window with the given [`Id`] accepts input method editor
+/// (IME) input.
+///
+/// The window only receives [`Ime`] events while it is allowed.
+///
+/// [`Ime`]: crate::core::window::Event::Ime
+pub fn set_ime_allowed<Message>(id: Id, allowed: bool) -> Command<Message> {
+    Command::single(command::Action::Window(Action::SetImeAllowed(id, allowed)))
+}
+
+/// Routes all keyboard and mouse events of the application to the window with
+/// the given [`Id`] while `capture` is enabled, regardless of which window
+/// has focus.
+pub fn set_input_capture<Message>(id: Id, capture: bool) -> Command<Message> {
+    Command::single(command::Action::Window(Action::SetInputCapture(
+        id, capture,
+    )))
+}
+
+/// Makes the window with the given [`Id`] transparent to mouse input while
+/// `passthrough` is enabled.
+///
+/// If a [`Duration`] is given, the passthrough is undone once it elapses; e.g.
+/// a tooltip can let clicks through for a moment before becoming interactive.
+pub fn set_input_passthrough<Message>(
+    id: Id,
+    passthrough: bool,
+    duration: Option<Duration>,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::SetInputPassthrough(
+        id,
+        passthrough,
+        duration,
+    )))
+}
+
+/// Sets the [`CursorGrabMode`] of the window with the given [`Id`].
+///
+/// The window receives [`mouse::Event::RawMotion`] while its cursor is
+/// grabbed.
+///
+/// [`mouse::Event::RawMotion`]: crate::core::mouse::Event::RawMotion
+pub fn set_cursor_grab<Message>(
+    id: Id,
+    mode: CursorGrabMode,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::SetCursorGrab(
+        id, mode, None,
+    )))
+}
+
+/// Sets the [`CursorGrabMode`] of the window with the given [`Id`], and
+/// produces a message with whether the grab succeeded.
+///
+/// Grabbing the cursor can fail depending on the platform; e.g. locking the
+/// cursor is not supported on Windows.
+pub fn try_set_cursor_grab<Message>(
+    id: Id,
+    mode: CursorGrabMode,
+    f: impl FnOnce(bool) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::SetCursorGrab(
+        id,
+        mode,
+        Some(Box::new(f)),
+    )))
+}
+
+/// Sets whether the window with the given [`Id`] renders continuously while it
+/// is interactively resized, or only once the resize ends.
+///
+/// This only has an effect on Windows, where it is disabled by default.
+pub fn set_live_resize<Message>(id: Id, live: bool) -> Command<Message> {
+    Command::single(command::Action::Window(Action::SetLiveResize(id, live)))
+}
+
+/// Sets a scale factor applied to every window of the application on top of
+/// its own, like a global zoom.
+///
+/// Windows can stop following it with [`follow_global_scale_factor`].
+pub fn set_global_scale_factor<Message>(scale_factor: f64) -> Command<Message> {
+    Command::single(command::Action::Window(Action::SetGlobalScaleFactor(
+        scale_factor,
+    )))
+}
+
+/// Sets whether the window with the given [`Id`] follows the scale factor set
+/// with [`set_global_scale_factor`].
+pub fn follow_global_scale_factor<Message>(
+    id: Id,
+    follows: bool,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::FollowGlobalScaleFactor(
+        id, follows,
+    )))
+}
+
+/// Sets whether the window with the given [`Id`] receives every mouse motion
+/// sample, even if the application coalesces mouse motion.
+///
+/// This is meant for precise input, like the canvas of a drawing application.
+pub fn set_precise_mouse_motion<Message>(
+    id: Id,
+    precise: bool,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::SetPreciseMouseMotion(
+        id, precise,
+    )))
+}
+
+/// Moves the standard window buttons (i.e. the traffic lights) to the given
+/// logical offset from the top-left corner of the title bar.
+///
+/// This is only supported on macOS.
+pub fn set_traffic_light_offset<Message>(
+    id: Id,
+    offset: Point,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::SetTrafficLightOffset(
+        id, offset,
+    )))
+}
+
+/// Marks the document of the window with the given [`Id`] as having unsaved
+/// changes.
+///
+/// This is only supported on macOS.
+pub fn set_document_edited<Message>(id: Id, edited: bool) -> Command<Message> {
+    Command::single(command::Action::Window(Action::SetDocumentEdited(
+        id, edited,
+    )))
+}
+
+/// Sets the path of the file represented by the window with the given [`Id`].
+///
+/// This is only supported on macOS.
+pub fn set_document_path<Message>(
+    id: Id,
+    path: impl Into<PathBuf>,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::SetDocumentPath(
+        id,
+        path.into(),
+    )))
+}
+
+/// Enables or disables the animation shown by the system when the window with
+/// the given [`Id`] is minimized.
+///
+/// This is only supported on Windows and macOS.
+pub fn set_minimize_animation<Message>(
+    id: Id,
+    enabled: bool,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::SetMinimizeAnimation(
+        id, enabled,
+    )))
+}
+
+/// Sets the cursor theme and size used while the pointer is over the window
+/// with the given [`Id`].
+///
+/// The theme and size of the environment are used when `None`. This is only
+/// supported on Wayland.
+pub fn set_cursor_theme<Message>(
+    id: Id,
+    theme: Option<String>,
+    size: Option<u32>,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::SetCursorTheme(
+        id, theme, size,
+    )))
+}
+
+/// Requests a [`FrameCallback`] event for the window with the given [`Id`],
+/// to time the next frame of an animation.
+///
+/// Multiple requests before the next frame are coalesced into a single event.
+///
+/// [`FrameCallback`]: crate::core::window::Event::FrameCallback
+pub fn request_frame_callback<Message>(id: Id) -> Command<Message> {
+    Command::single(command::Action::Window(Action::RequestFrameCallback(id)))
+}
+
+/// Plays the alert sound of the operating system to draw attention to the
+/// window with the given [`Id`].
+///
+/// This is a no-op if attention sounds are disabled for the window or no
+/// sound service is available.
+pub fn play_attention_sound<Message>(id: Id) -> Command<Message> {
+    Command::single(command::Action::Window(Action::PlayAttentionSound(id)))
+}
+
+/// Enables or disables the attention sounds of the window with the given
+/// [`Id`].
+pub fn set_attention_sound_enabled<Message>(
+    id: Id,
+    enabled: bool,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::SetAttentionSoundEnabled(
+        id, enabled,
+    )))
+}
+
+/// Highlights the hovered edge of the resize border of the window with the
+/// given [`Id`] using the provided [`Color`], or disables the highlight when
+/// `None`.
+///
+/// This only has an effect on windows without decorations.
+pub fn set_resize_border_highlight<Message>(
+    id: Id,
+    color: Option<Color>,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::SetResizeBorderHighlight(
+        id, color,
+    )))
+}
+
+/// Sets the logical thickness of the area that initiates a drag resize of the
+/// window with the given [`Id`], independently of its resize border.
+///
+/// This only has an effect on windows without decorations.
+pub fn set_resize_hit_thickness<Message>(
+    id: Id,
+    thickness: u32,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::SetResizeHitThickness(
+        id, thickness,
+    )))
+}
+
+/// Sets the logical size of the corners that initiate a diagonal drag resize
+/// of the window with the given [`Id`], independently of its edges.
+///
+/// This only has an effect on windows without decorations.
+pub fn set_resize_corner_size<Message>(id: Id, size: u32) -> Command<Message> {
+    Command::single(command::Action::Window(Action::SetResizeCornerSize(
+        id, size,
+    )))
+}
+
+/// Sets the edges that initiate a drag resize of the window with the given
+/// [`Id`].
+///
+/// The edges that are left out do not show a resize cursor when hovered.
+/// This only has an effect on windows without decorations.
+pub fn set_resize_directions<Message>(
+    id: Id,
+    directions: ResizeDirections,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::SetResizeDirections(
+        id, directions,
+    )))
+}
+
+/// Maintains the given aspect ratio, the width divided by the height, in the
+/// window with the given [`Id`], or stops maintaining it with `None`.
+///
+/// With [`AspectRatioMode::LetterboxContent`], the window can have any size
+/// and its contents are centered with bars around them; e.g. a video player
+/// that keeps the aspect ratio of its video.
+pub fn set_aspect_ratio<Message>(
+    id: Id,
+    aspect_ratio: Option<f32>,
+    mode: AspectRatioMode,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::SetAspectRatio(
+        id,
+        aspect_ratio,
+        mode,
+    )))
+}
+
+/// Sets whether a resize grip is drawn at the bottom-right corner of the
+/// window with the given [`Id`].
+///
+/// Pressing the grip resizes the window diagonally. It is hidden while the
+/// window is maximized or not resizable, and when the bottom or right edge of
+/// the window cannot be dragged.
+pub fn show_resize_grip<Message>(id: Id, visible: bool) -> Command<Message> {
+    Command::single(command::Action::Window(Action::ShowResizeGrip(
+        id, visible,
+    )))
+}
+
+/// Sets whether the resize border of the window with the given [`Id`]
+/// follows the width provided by the theme.
+///
+/// An explicit [`set_resize_hit_thickness`] stops the syncing until it is
+/// enabled again. This only has an effect on windows without decorations.
+pub fn sync_resize_border_with_theme<Message>(
+    id: Id,
+    synced: bool,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::SyncResizeBorderWithTheme(
+        id, synced,
+    )))
+}
+
+/// Sets the logical region of the window with the given [`Id`] that behaves
+/// like its title bar, or removes it with `None`.
+///
+/// Pressing this region drags the window, and double-clicking it toggles the
+/// maximization of the window, unless the press is captured by a widget.
+pub fn set_title_bar_region<Message>(
+    id: Id,
+    region: Option<Rectangle>,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::SetTitleBarRegion(
+        id, region,
+    )))
+}
+
+/// Sets the [`BackdropMaterial`] drawn by the system behind the contents of
+/// the window with the given [`Id`].
+///
+/// This is only supported on Windows 11.
+pub fn set_backdrop<Message>(
+    id: Id,
+    material: BackdropMaterial,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::SetBackdrop(id, material)))
+}
+
+/// Snaps the window with the given [`Id`] to the [`SnapPosition`] of the
+/// work area of its current monitor.
+pub fn snap<Message>(id: Id, position: SnapPosition) -> Command<Message> {
+    Command::single(command::Action::Window(Action::Snap(id, position)))
+}
+
+/// Declares the logical extents of the client-side shadows of the window with
+/// the given [`Id`], so that the window manager can lay it out correctly.
+///
+/// This is only supported on X11.
+pub fn set_frame_extents<Message>(
+    id: Id,
+    extents: Padding,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::SetFrameExtents(
+        id, extents,
+    )))
+}
+
+/// Sets the minimum [`Duration`] between two consecutive presents of the
+/// window with the given [`Id`].
+///
+/// A [`Duration::ZERO`] interval presents frames as soon as they are drawn.
+pub fn set_frame_interval<Message>(
+    id: Id,
+    interval: Duration,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::SetFrameInterval(
+        id, interval,
+    )))
+}
+
+/// Sets the role of the window with the given [`Id`], which is used by session
+/// managers to restore it.
+///
+/// This is only supported on X11.
+pub fn set_window_role<Message>(id: Id, role: String) -> Command<Message> {
+    Command::single(command::Action::Window(Action::SetWindowRole(id, role)))
+}
+
+/// Applies the output color profile described by the given ICC data to the
+/// window with the given [`Id`].
+///
+/// Empty or invalid data resets the window to sRGB.
+pub fn set_color_profile<Message>(id: Id, icc: Vec<u8>) -> Command<Message> {
+    Command::single(command::Action::Window(Action::SetColorProfile(id, icc)))
+}
+
 /// Show window menu at cursor position.
 pub fn show_window_menu<Message>(id: Id) -> Command<Message> {
     Command::single(command::Action::Window(Action::ShowWindowMenu(id)))
@@ -193,6 +749,41 @@ pub fn fetch_id<Message>(
     Command::single(command::Action::Window(Action::FetchId(id, Box::new(f))))
 }
 
+/// Fetches the monitors available to the window.
+pub fn fetch_available_monitors<Message>(
+    id: Id,
+    f: impl FnOnce(Vec<Monitor>) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::FetchAvailableMonitors(
+        id,
+        Box::new(f),
+    )))
+}
+
+/// Fetches the video modes supported by the current monitor of the window.
+pub fn fetch_video_modes<Message>(
+    id: Id,
+    f: impl FnOnce(Vec<VideoMode>) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::FetchVideoModes(
+        id,
+        Box::new(f),
+    )))
+}
+
+/// Puts the window in exclusive fullscreen with the given [`VideoMode`].
+///
+/// If the [`VideoMode`] is rejected, the window falls back to borderless
+/// fullscreen and an [`Event::ExclusiveFullscreenRejected`] is emitted.
+pub fn set_exclusive_fullscreen<Message>(
+    id: Id,
+    video_mode: VideoMode,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::SetExclusiveFullscreen(
+        id, video_mode,
+    )))
+}
+
 /// Changes the [`Icon`] of the window.
 pub fn change_icon<Message>(id: Id, icon: Icon) -> Command<Message> {
     Command::single(command::Action::Window(Action::ChangeIcon(id, icon)))
@@ -208,3 +799,166 @@ pub fn screenshot<Message>(
         Box::new(f),
     )))
 }
+
+/// Renders the given [`Element`] off-screen with the given `theme` and
+/// `size`, in logical pixels, and captures a [`Screenshot`] of the result.
+///
+/// The scale factor of the window is used, but its contents are left
+/// untouched. This is useful to generate previews or thumbnails without
+/// showing them.
+///
+/// The `Theme` and `Renderer` must be the ones of the application; otherwise,
+/// nothing is rendered and `None` is produced.
+pub fn render_element<Message, Theme, Renderer>(
+    id: Id,
+    element: impl Into<Element<'static, Message, Theme, Renderer>>,
+    theme: Theme,
+    size: Size,
+    f: impl FnOnce(Option<Screenshot>) -> Message + Send + 'static,
+) -> Command<Message>
+where
+    Message: 'static,
+    Theme: 'static,
+    Renderer: 'static,
+{
+    Command::single(command::Action::Window(Action::RenderElement(
+        id,
+        Box::new((element.into(), theme)),
+        size,
+        Box::new(f),
+    )))
+}
+
+/// Dumps the layout of the widget tree of the window as JSON.
+///
+/// This can be useful to debug layouts or to assert the position of some
+/// widgets in automated tests.
+pub fn dump_layout<Message>(
+    id: Id,
+    f: impl FnOnce(String) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::DumpLayout(
+        id,
+        Box::new(f),
+    )))
+}
+
+/// Drops the cached widget state of the window.
+///
+/// This can be used to free memory of a window that is hidden, when its
+/// [`Settings::keep_cache_when_hidden`] is enabled.
+pub fn drop_cache<Message>(id: Id) -> Command<Message> {
+    Command::single(command::Action::Window(Action::DropCache(id)))
+}
+
+/// Forces the cursor icon of the window with the given [`Id`] to the given
+/// [`mouse::Interaction`], or clears it with `None`.
+///
+/// The cursor icon is kept regardless of the widgets under the cursor until
+/// it is cleared. This is useful to show that the application is busy.
+pub fn set_cursor_icon<Message>(
+    id: Id,
+    interaction: Option<mouse::Interaction>,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::SetCursorIcon(
+        id,
+        interaction,
+    )))
+}
+
+/// Replaces the cursor of the window with the given [`Id`] with the given
+/// [`mouse::CustomCursor`], or goes back to the regular cursor icons with
+/// `None`.
+///
+/// Use [`mouse::custom_cursor::from_rgba`] to build a cursor whose hotspot
+/// is the pixel that clicks, like the center of a crosshair.
+pub fn set_custom_cursor<Message>(
+    id: Id,
+    cursor: Option<mouse::CustomCursor>,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::SetCustomCursor(
+        id, cursor,
+    )))
+}
+
+/// Dims the window with the given [`Id`] with a translucent overlay of the
+/// given [`Color`], or removes it with `None`.
+///
+/// The overlay fades in and out. Combine it with an input capture to also
+/// block the interaction with the dimmed window.
+pub fn set_dim_overlay<Message>(
+    id: Id,
+    color: Option<Color>,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::SetDimOverlay(id, color)))
+}
+
+/// Sets the title of the window with the given [`Id`].
+///
+/// This is useful to show transient status, like the progress of a download,
+/// without coupling it to the title of the application. The title is kept
+/// until the title of the application changes.
+pub fn set_title<Message>(
+    id: Id,
+    title: impl Into<String>,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::SetTitle(id, title.into())))
+}
+
+/// Fetches the current title of the window with the given [`Id`].
+///
+/// The result is `None` if the window does not exist.
+pub fn fetch_title<Message>(
+    id: Id,
+    f: impl FnOnce(Option<String>) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::FetchTitle(
+        id,
+        Box::new(f),
+    )))
+}
+
+/// Fetches the scale factor applied to the surface of the window with the
+/// given [`Id`] by the windowing system.
+///
+/// The scale factor is fractional on displays with fractional scaling, which
+/// can be used to render pixel-perfect contents. The result is `None` if the
+/// window does not exist.
+pub fn fetch_surface_scale<Message>(
+    id: Id,
+    f: impl FnOnce(Option<f64>) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::FetchSurfaceScale(
+        id,
+        Box::new(f),
+    )))
+}
+
+/// Sets the [`RenderPriority`] of the window with the given [`Id`].
+///
+/// This lets a latency-critical window, like a live preview, stay smooth
+/// while secondary windows lag behind under load.
+pub fn set_render_priority<Message>(
+    id: Id,
+    priority: RenderPriority,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::SetRenderPriority(
+        id, priority,
+    )))
+}
+
+/// Resizes the window to the natural size of its contents.
+///
+/// The new size is clamped to the minimum and maximum sizes of the window
+/// and to the work area of its current monitor.
+pub fn fit_to_content<Message>(id: Id) -> Command<Message> {
+    Command::single(command::Action::Window(Action::FitToContent(id)))
+}
+
+/// Reconfigures the surface of the window to the current size of the window.
+///
+/// This is only necessary for windows with
+/// [`Settings::auto_reconfigure_surface`] disabled.
+pub fn reconfigure_surface<Message>(id: Id) -> Command<Message> {
+    Command::single(command::Action::Window(Action::ReconfigureSurface(id)))
+}
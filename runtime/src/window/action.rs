@@ -1,9 +1,16 @@
-use crate::core::window::{Icon, Id, Level, Mode, Settings, UserAttention};
-use crate::core::{Point, Size};
+use crate::core::time::Duration;
+use crate::core::window::{
+    AspectRatioMode, BackdropMaterial, CursorGrabMode, DecorationMode, Icon,
+    Id, Level, Mode, Monitor, RenderPriority, ResizeDirections, Settings,
+    SnapPosition, SurfaceFormat, UserAttention, VideoMode,
+};
+use crate::core::{mouse, theme, Color, Padding, Point, Rectangle, Size};
 use crate::futures::MaybeSend;
 use crate::window::Screenshot;
 
+use std::any::Any;
 use std::fmt;
+use std::path::PathBuf;
 
 /// An operation to be performed on some window.
 pub enum Action<T> {
@@ -21,6 +28,18 @@ pub enum Action<T> {
     Resize(Id, Size),
     /// Fetch the current logical dimensions of the window.
     FetchSize(Id, Box<dyn FnOnce(Size) -> T + 'static>),
+    /// Fetch the logical insets of the decorations of the window (i.e. the
+    /// title bar height and border widths).
+    ///
+    /// The insets are computed from the difference between the outer and
+    /// inner position and size of the window.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland:** Always zero, since client-side decorations are drawn
+    ///   inside the window.
+    /// - **iOS / Android / Web:** Always zero.
+    FetchFrameInsets(Id, Box<dyn FnOnce(Padding) -> T + 'static>),
     /// Fetch if the current window is maximized or not.
     ///
     /// ## Platform-specific
@@ -36,6 +55,16 @@ pub enum Action<T> {
     FetchMinimized(Id, Box<dyn FnOnce(Option<bool>) -> T + 'static>),
     /// Set the window to minimized or back
     Minimize(Id, bool),
+    /// Minimize all the windows of the application that are not minimized
+    /// already.
+    ///
+    /// ## Platform-specific
+    /// - **Wayland:** Windows are always considered not minimized, since
+    ///   their state cannot be queried.
+    MinimizeAll,
+    /// Restore all the windows minimized by [`Action::MinimizeAll`], leaving
+    /// windows that were minimized before untouched.
+    RestoreAll,
     /// Move the window to the given logical coordinates.
     ///
     /// Unsupported on Wayland.
@@ -46,6 +75,39 @@ pub enum Action<T> {
     FetchMode(Id, Box<dyn FnOnce(Mode) -> T + 'static>),
     /// Toggle the window to maximized or back
     ToggleMaximize(Id),
+    /// Fetch the [`DecorationMode`] of the window.
+    ///
+    /// The result is `None` if the decorations are not negotiated with the
+    /// compositor.
+    ///
+    /// ## Platform-specific
+    /// - **Wayland:** Reflects the mode chosen by the compositor through
+    ///   `xdg-decoration`, once the window has been configured.
+    /// - **Other platforms:** Always `None`.
+    GetDecorationMode(
+        Id,
+        Box<dyn FnOnce(Option<DecorationMode>) -> T + 'static>,
+    ),
+    /// Fetch the [`SurfaceFormat`] of the frames presented to the window.
+    ///
+    /// This describes how the bytes of the window are laid out, which is
+    /// necessary to blit its contents elsewhere.
+    ///
+    /// ## Platform-specific
+    /// - **Wayland:** Unsupported.
+    GetSurfaceFormat(Id, Box<dyn FnOnce(Option<SurfaceFormat>) -> T + 'static>),
+    /// Fetch the name of the output, or monitor, the window is currently on.
+    ///
+    /// The result is `None` if the output cannot be determined or has no
+    /// name.
+    ///
+    /// ## Platform-specific
+    /// - **Wayland:** The name advertised by the compositor for the output,
+    ///   like `DP-1`. The result is `None` while the window spans several
+    ///   outputs.
+    /// - **Other platforms:** The name of the monitor with the largest
+    ///   overlap with the window.
+    GetCurrentOutputName(Id, Box<dyn FnOnce(Option<String>) -> T + 'static>),
     /// Toggle whether window has decorations.
     ///
     /// ## Platform-specific
@@ -66,6 +128,18 @@ pub enum Action<T> {
     /// - **X11:** Requests for user attention must be manually cleared.
     /// - **Wayland:** Requires `xdg_activation_v1` protocol, `None` has no effect.
     RequestUserAttention(Id, Option<UserAttention>),
+    /// Set or unset the urgency hint of the window.
+    ///
+    /// Some window managers only honor the urgency hint and ignore regular
+    /// requests for user attention. The hint is cleared automatically once
+    /// the window gains focus.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **X11:** Sets the `UrgencyHint` flag of `WM_HINTS`.
+    /// - **Wayland:** Requires `xdg_activation_v1` protocol.
+    /// - **iOS / Android / Web:** Unsupported.
+    SetUrgent(Id, bool),
     /// Bring the window to the front and sets input focus. Has no effect if the window is
     /// already in focus, minimized, or not visible.
     ///
@@ -79,6 +153,405 @@ pub enum Action<T> {
     GainFocus(Id),
     /// Change the window [`Level`].
     ChangeLevel(Id, Level),
+    /// Keep the window above all the other windows, or stop doing so.
+    ///
+    /// Keeping the window above others stops keeping it below them.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **X11:** Toggles the `_NET_WM_STATE_ABOVE` state of the window.
+    /// - **Web / Wayland:** Unsupported.
+    SetKeepAbove(Id, bool),
+    /// Keep the window below all the other windows, or stop doing so.
+    ///
+    /// Keeping the window below others stops keeping it above them.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **X11:** Toggles the `_NET_WM_STATE_BELOW` state of the window.
+    /// - **Web / Wayland:** Unsupported.
+    SetKeepBelow(Id, bool),
+    /// Set the theme [`Mode`] of the decorations drawn by the system for
+    /// the window, like its title bar.
+    ///
+    /// `None` makes the window follow the theme of the system again.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **macOS:** Also changes the appearance of the rest of the window.
+    /// - **X11:** Sets the `_GTK_THEME_VARIANT` hint of the window, which
+    ///   falls back to [`Mode::Dark`] when `None` is used.
+    /// - **iOS / Android / Web / Wayland:** Unsupported.
+    ///
+    /// [`Mode`]: theme::Mode
+    /// [`Mode::Dark`]: theme::Mode::Dark
+    SetPreferredTheme(Id, Option<theme::Mode>),
+    /// Set the resize increments of the window, in logical pixels.
+    ///
+    /// The window will only be resized by multiples of these increments,
+    /// which is useful for applications laid out in cells, like terminals.
+    /// `None` removes them.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **macOS:** The increments are rounded to whole numbers.
+    /// - **Windows / iOS / Android / Web / Wayland:** Unsupported.
+    SetResizeIncrements(Id, Option<Size>),
+    /// Set the base size of the window, in logical pixels, which the resize
+    /// increments are added to.
+    ///
+    /// The base size is approximated with the minimum size of the window,
+    /// which replaces any minimum size set before. Window managers follow the
+    /// ICCCM and use the minimum size as the base size when the latter is
+    /// missing, so the size of the window is always the base size plus a
+    /// multiple of the resize increments.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **macOS / Windows:** The increments apply to the whole size of the
+    ///   window, so the base size only limits how small it can be.
+    /// - **iOS / Android / Web / Wayland:** Unsupported.
+    SetBaseSize(Id, Size),
+    /// Set the area of the window, in logical coordinates, where the text
+    /// caret is located.
+    ///
+    /// The IME candidate window will be placed next to this area. The area is
+    /// clamped within the bounds of the window.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **iOS / Android / Web / Orbital:** Unsupported.
+    SetImeArea(Id, Rectangle),
+    /// Set whether the window accepts input method editor (IME) input.
+    ///
+    /// The window only receives [`Ime`] events while it is allowed. It is
+    /// disallowed by default.
+    ///
+    /// [`Ime`]: crate::core::window::Event::Ime
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **iOS / Android / Web / Orbital:** Unsupported.
+    SetImeAllowed(Id, bool),
+    /// Route all keyboard and mouse events of the application to the window,
+    /// regardless of which window has focus.
+    ///
+    /// The capture is released when disabled or when the window is closed.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Single window applications:** No-op.
+    SetInputCapture(Id, bool),
+    /// Make the window transparent to mouse input, letting clicks pass
+    /// through it to whatever is below.
+    ///
+    /// If a [`Duration`] is given, the passthrough is undone once it elapses.
+    /// A new passthrough request for the same window cancels any pending
+    /// revert.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **iOS / Android / Web / Wayland:** Unsupported.
+    SetInputPassthrough(Id, bool, Option<Duration>),
+    /// Set the [`CursorGrabMode`] of the window, confining or locking the
+    /// cursor to it.
+    ///
+    /// Grabbing the cursor can fail depending on the platform. If a callback
+    /// is given, it is called with whether the grab succeeded.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **iOS / Android / Web / Wayland:** Unsupported.
+    SetCursorGrab(
+        Id,
+        CursorGrabMode,
+        Option<Box<dyn FnOnce(bool) -> T + 'static>>,
+    ),
+    /// Set whether the window receives every mouse motion sample, even if
+    /// the application coalesces mouse motion.
+    ///
+    /// This is meant for precise input, like the canvas of a drawing
+    /// application.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland (SCTK):** No-op, since mouse motion is never coalesced.
+    SetPreciseMouseMotion(Id, bool),
+    /// Set whether the window renders continuously while it is interactively
+    /// resized, or only once the resize ends.
+    ///
+    /// Rendering continuously is smoother but heavier. It is disabled by
+    /// default.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Windows:** The event loop does not resume during an interactive
+    ///   resize, so an update is emulated after every resize of the window.
+    /// - **Other platforms:** No-op.
+    SetLiveResize(Id, bool),
+    /// Set a scale factor applied to every window of the application on top
+    /// of its own, like a global zoom.
+    ///
+    /// It also applies to the windows opened afterwards, except the ones that
+    /// stopped following it with [`FollowGlobalScaleFactor`]. Scale factors
+    /// that are not strictly positive are ignored.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland (SCTK):** Unsupported.
+    ///
+    /// [`FollowGlobalScaleFactor`]: Self::FollowGlobalScaleFactor
+    SetGlobalScaleFactor(f64),
+    /// Set whether the window follows the scale factor set with
+    /// [`SetGlobalScaleFactor`].
+    ///
+    /// Windows follow it by default.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland (SCTK):** Unsupported.
+    ///
+    /// [`SetGlobalScaleFactor`]: Self::SetGlobalScaleFactor
+    FollowGlobalScaleFactor(Id, bool),
+    /// Move the standard window buttons (i.e. the traffic lights) to the
+    /// given logical offset from the top-left corner of the title bar.
+    ///
+    /// This is useful to align the buttons with a custom title bar.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **macOS:** The offset is reapplied after fullscreen transitions.
+    /// - **Other platforms:** No-op.
+    SetTrafficLightOffset(Id, Point),
+    /// Mark the document of the window as having unsaved changes.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **macOS:** A dot is shown in the close button of the window.
+    /// - **Other platforms:** No-op.
+    SetDocumentEdited(Id, bool),
+    /// Set the path of the file represented by the window.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **macOS:** The icon of the file is shown next to the title, and can
+    ///   be dragged or used to reveal the file.
+    /// - **Other platforms:** No-op.
+    SetDocumentPath(Id, PathBuf),
+    /// Enable or disable the animation shown by the system when the window
+    /// is minimized.
+    ///
+    /// Only the animation of the system is affected; the animations of the
+    /// application itself keep running.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Windows:** Disables the transitions of the DWM for the window,
+    ///   which also covers restoring it.
+    /// - **macOS:** Sets the `animationBehavior` of the window, which turns
+    ///   off the animations of AppKit for it. The minimize effect of the
+    ///   Dock itself is a preference of the user and it is kept.
+    /// - **Other platforms:** Unsupported.
+    SetMinimizeAnimation(Id, bool),
+    /// Set the cursor theme and size used while the pointer is over the
+    /// window, falling back to the ones of the environment when `None`.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland:** A warning is logged if the theme is not installed.
+    /// - **Other platforms:** No-op.
+    SetCursorTheme(Id, Option<String>, Option<u32>),
+    /// Request a [`FrameCallback`] event for the window, emitted when it is
+    /// a good time to draw the next frame of an animation.
+    ///
+    /// Multiple requests before the next frame are coalesced into a single
+    /// event.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland:** Emitted when the compositor sends the `wl_surface`
+    ///   frame callback.
+    /// - **Other platforms:** Emitted on the next redraw of the window, paced
+    ///   by the refresh rate of its monitor.
+    ///
+    /// [`FrameCallback`]: crate::core::window::Event::FrameCallback
+    RequestFrameCallback(Id),
+    /// Play the alert sound of the operating system to draw attention to the
+    /// window, unless attention sounds are disabled for it.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland:** Rings the bell of the compositor through
+    ///   `xdg_system_bell_v1`, falling back to the bell of XWayland.
+    /// - **X11:** Rings the bell of the X server.
+    /// - **Wayland (SCTK) / Web:** Unsupported.
+    PlayAttentionSound(Id),
+    /// Enable or disable the attention sounds of the window.
+    ///
+    /// While disabled, [`PlayAttentionSound`] is a no-op. Attention sounds
+    /// are enabled by default.
+    ///
+    /// [`PlayAttentionSound`]: Self::PlayAttentionSound
+    SetAttentionSoundEnabled(Id, bool),
+    /// Highlight the edge of the resize border of the window under the
+    /// cursor with the given [`Color`], or disable the highlight with `None`.
+    ///
+    /// This gives visual affordance for resizing windows without decorations.
+    /// The highlight is not drawn while a resize is in progress.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Decorated windows:** No-op.
+    SetResizeBorderHighlight(Id, Option<Color>),
+    /// Set the logical thickness of the area along the edges of the window
+    /// that initiates a drag resize, without changing the drawn resize
+    /// border.
+    ///
+    /// This allows a generous grab area with an invisible or thin border. The
+    /// thickness is capped to a quarter of the smallest dimension of the
+    /// window.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Decorated windows:** No-op.
+    SetResizeHitThickness(Id, u32),
+    /// Set the logical size of the square areas at the corners of the window
+    /// that initiate a diagonal drag resize, independently of the thickness
+    /// of its edges.
+    ///
+    /// This makes corners easier to grab on touch devices. The corners take
+    /// priority over the edges wherever they overlap, and they are never
+    /// smaller than the edges.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Decorated windows:** No-op.
+    SetResizeCornerSize(Id, u32),
+    /// Set the edges of the window that initiate a drag resize and show a
+    /// directional cursor when hovered.
+    ///
+    /// Every edge can be used by default.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Decorated windows:** No-op.
+    SetResizeDirections(Id, ResizeDirections),
+    /// Maintain the given aspect ratio, the width divided by the height, in
+    /// the window with the given [`AspectRatioMode`], or stop maintaining it
+    /// with `None`.
+    ///
+    /// The window itself can be constrained, or its contents can be
+    /// letterboxed; i.e. laid out with the aspect ratio and centered, with
+    /// bars of the background color of the application around them.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland:** Unsupported.
+    SetAspectRatio(Id, Option<f32>, AspectRatioMode),
+    /// Set whether a resize grip is drawn at the bottom-right corner of the
+    /// window.
+    ///
+    /// Pressing the grip initiates a diagonal drag resize, which makes the
+    /// window easy to resize on touch devices. The grip is hidden while the
+    /// window is maximized or not resizable, and when [`SetResizeDirections`]
+    /// excludes its bottom or right edge.
+    ///
+    /// [`SetResizeDirections`]: Self::SetResizeDirections
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland (SCTK):** Unsupported.
+    ShowResizeGrip(Id, bool),
+    /// Set whether the resize border of the window follows the width provided
+    /// by the theme, updating it whenever the theme changes.
+    ///
+    /// An explicit [`SetResizeHitThickness`] stops the syncing until it is
+    /// enabled again. The resize border given on creation is used while the
+    /// theme does not provide one.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Decorated windows:** No-op.
+    ///
+    /// [`SetResizeHitThickness`]: Self::SetResizeHitThickness
+    SyncResizeBorderWithTheme(Id, bool),
+    /// Set the logical region of the window that behaves like its title bar,
+    /// or remove it with `None`.
+    ///
+    /// Pressing this region drags the window, and double-clicking it toggles
+    /// the maximization of the window. Presses captured by a widget inside the
+    /// region, like a button, are left to the widget.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland (SCTK) / Web:** Unsupported.
+    SetTitleBarRegion(Id, Option<Rectangle>),
+    /// Set the [`BackdropMaterial`] drawn by the system behind the contents
+    /// of the window.
+    ///
+    /// The material is only visible through the transparent parts of the
+    /// window, so the window should be created with transparency and drawn
+    /// with a translucent background color.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Windows:** Requires Windows 11. A warning is logged if the
+    ///   background color of the window is fully opaque.
+    /// - **Other platforms:** No-op.
+    SetBackdrop(Id, BackdropMaterial),
+    /// Move and resize the window to the [`SnapPosition`] of the work area
+    /// of its current monitor, leaving out panels and taskbars.
+    ///
+    /// The decorations of the window are kept inside of the snapped region,
+    /// while the client-side shadows declared with [`SetFrameExtents`] are
+    /// left outside of it.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **X11:** The work area is read from the `_NET_WORKAREA` property.
+    /// - **macOS:** The whole monitor is used as the work area.
+    /// - **iOS / Android / Web / Wayland:** Unsupported.
+    ///
+    /// [`SetFrameExtents`]: Self::SetFrameExtents
+    Snap(Id, SnapPosition),
+    /// Declare the logical extents of the client-side shadows of the window,
+    /// so that the window manager can exclude them when placing and snapping
+    /// the window.
+    ///
+    /// The extents are not updated automatically; if they depend on the size
+    /// of the window, this action must be issued again on every resize.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **X11:** Sets the `_GTK_FRAME_EXTENTS` property of the window. The
+    ///   extents are reapplied when the scale factor changes.
+    /// - **Other platforms:** No-op.
+    SetFrameExtents(Id, Padding),
+    /// Set the minimum [`Duration`] between two consecutive presents of the
+    /// window, or disable it with [`Duration::ZERO`].
+    ///
+    /// This smooths frame pacing on adaptive-sync displays without forcing
+    /// vsync. The first frame after user input is always presented right
+    /// away, to keep the window responsive.
+    SetFrameInterval(Id, Duration),
+    /// Set the role of the window, which is used by session managers to
+    /// restore each window of an application to its previous state.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **X11:** Sets the `WM_WINDOW_ROLE` property of the window.
+    /// - **Other platforms:** No-op.
+    SetWindowRole(Id, String),
+    /// Apply the output color profile described by the given ICC data to the
+    /// frames of the window, or reset it to sRGB with empty data.
+    ///
+    /// Only matrix/TRC RGB profiles are supported. Invalid data falls back
+    /// to sRGB.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **tiny-skia:** Unsupported; frames are always presented as sRGB.
+    SetColorProfile(Id, Vec<u8>),
     /// Show window menu at cursor position.
     ///
     /// ## Platform-specific
@@ -86,6 +559,26 @@ pub enum Action<T> {
     ShowWindowMenu(Id),
     /// Fetch the raw identifier unique to the window.
     FetchId(Id, Box<dyn FnOnce(u64) -> T + 'static>),
+    /// Fetch the monitors available to the window.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **iOS / Android / Web:** Unsupported.
+    FetchAvailableMonitors(Id, Box<dyn FnOnce(Vec<Monitor>) -> T + 'static>),
+    /// Fetch the video modes supported by the current monitor of the window.
+    FetchVideoModes(Id, Box<dyn FnOnce(Vec<VideoMode>) -> T + 'static>),
+    /// Put the window in exclusive fullscreen with the given [`VideoMode`].
+    ///
+    /// If the [`VideoMode`] is rejected, the window falls back to borderless
+    /// fullscreen and a [`window::Event::ExclusiveFullscreenRejected`] is
+    /// emitted.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland / iOS / Android / Web / Orbital:** Unsupported.
+    ///
+    /// [`window::Event::ExclusiveFullscreenRejected`]: crate::core::window::Event::ExclusiveFullscreenRejected
+    SetExclusiveFullscreen(Id, VideoMode),
     /// Change the window [`Icon`].
     ///
     /// On Windows and X11, this is typically the small icon in the top-left
@@ -103,9 +596,129 @@ pub enum Action<T> {
     ChangeIcon(Id, Icon),
     /// Screenshot the viewport of the window.
     Screenshot(Id, Box<dyn FnOnce(Screenshot) -> T + 'static>),
+    /// Render an element off-screen with the given size, in logical pixels,
+    /// and the scale factor of the window, and produce `T` with a
+    /// [`Screenshot`] of the result.
+    ///
+    /// The element is paired with the theme it is drawn with, and both must
+    /// be of the types of the application. Otherwise, or if the window does
+    /// not exist or the renderer cannot render off-screen, `None` is given.
+    RenderElement(
+        Id,
+        Box<dyn Any>,
+        Size,
+        Box<dyn FnOnce(Option<Screenshot>) -> T + 'static>,
+    ),
+    /// Dump the layout of the widget tree of the window as JSON.
+    ///
+    /// See [`LayoutDump`] for the format of the result.
+    ///
+    /// [`LayoutDump`]: crate::core::widget::operation::LayoutDump
+    DumpLayout(Id, Box<dyn FnOnce(String) -> T + 'static>),
+    /// Drop the cached widget state of the window.
+    ///
+    /// The user interface of the window will be rebuilt from scratch the
+    /// next time it is updated.
+    DropCache(Id),
+    /// Force the cursor icon of the window to the given
+    /// [`mouse::Interaction`], or go back to the interaction of the widgets
+    /// with `None`.
+    ///
+    /// The cursor icon is changed immediately, and it is kept regardless of
+    /// the widgets under the cursor until it is cleared.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland:** Unsupported.
+    SetCursorIcon(Id, Option<mouse::Interaction>),
+    /// Replace the cursor of the window with the given
+    /// [`mouse::CustomCursor`], or go back to the cursor icons of the
+    /// interactions with `None`.
+    ///
+    /// The hotspot of the [`mouse::CustomCursor`] is the pixel of its image
+    /// that points at the cursor position.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Windows / macOS / Web:** Unsupported.
+    /// - **Wayland:** Unsupported by the `winit` shell.
+    SetCustomCursor(Id, Option<mouse::CustomCursor>),
+    /// Draw a translucent overlay of the given [`Color`] over the whole
+    /// window, or remove it with `None`.
+    ///
+    /// This is useful to dim a parent window behind a modal dialog. The
+    /// overlay fades in and out, and it does not affect the interaction with
+    /// the widgets of the window.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland:** Unsupported.
+    SetDimOverlay(Id, Option<Color>),
+    /// Set the title of the window, without querying the title of the
+    /// application.
+    ///
+    /// The title is kept until the title returned by the application
+    /// changes.
+    SetTitle(Id, String),
+    /// Fetch the current title of the window.
+    ///
+    /// This is the title last set on the window, either by the application
+    /// or by [`SetTitle`]. The result is `None` if the window does not exist.
+    ///
+    /// [`SetTitle`]: Self::SetTitle
+    FetchTitle(Id, Box<dyn FnOnce(Option<String>) -> T + 'static>),
+    /// Fetch the scale factor applied to the surface of the window by the
+    /// windowing system.
+    ///
+    /// Unlike the scale factor of the application, this is the exact scale
+    /// preferred by the compositor for the surface, which is fractional on
+    /// displays with fractional scaling, like `1.25`. It is an integer
+    /// otherwise. The result is `None` if the window does not exist.
+    FetchSurfaceScale(Id, Box<dyn FnOnce(Option<f64>) -> T + 'static>),
+    /// Set the [`RenderPriority`] of the window.
+    ///
+    /// Windows with a higher priority are rendered first, and low priority
+    /// windows may lag behind for a few frames when rendering is too slow.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Single-window applications:** No-op.
+    /// - **Wayland:** Unsupported.
+    SetRenderPriority(Id, RenderPriority),
+    /// Resize the window to the natural size of its contents.
+    ///
+    /// The contents are laid out with shrinking limits, and the resulting
+    /// size is clamped to the minimum and maximum sizes of the window and to
+    /// the work area of its current monitor.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland:** Unsupported.
+    FitToContent(Id),
+    /// Reconfigure the surface of the window to the current size of the
+    /// window the next time it is redrawn.
+    ReconfigureSurface(Id),
+    /// Run the given window actions in order, before the next frame of any
+    /// window is presented.
+    ///
+    /// This avoids visible intermediate states when several properties of a
+    /// window change at once, like its size and position. Nested batches are
+    /// flattened, and every sub-action producing a result still does so.
+    Batch(Vec<Action<T>>),
 }
 
 impl<T> Action<T> {
+    /// Expands every [`Action::Batch`] in the [`Action`] into its
+    /// sub-actions, in order.
+    pub fn flatten(self) -> Vec<Self> {
+        match self {
+            Self::Batch(actions) => {
+                actions.into_iter().flat_map(Self::flatten).collect()
+            }
+            action => vec![action],
+        }
+    }
+
     /// Maps the output of a window [`Action`] using the provided closure.
     pub fn map<A>(
         self,
@@ -115,6 +728,20 @@ impl<T> Action<T> {
         T: 'static,
     {
         match self {
+            Self::Batch(actions) => {
+                let f = std::sync::Arc::new(f);
+
+                Action::Batch(
+                    actions
+                        .into_iter()
+                        .map(|action| {
+                            let f = f.clone();
+
+                            action.map(move |output| f(output))
+                        })
+                        .collect(),
+                )
+            }
             Self::Spawn(id, settings) => Action::Spawn(id, settings),
             Self::Close(id) => Action::Close(id),
             Self::Drag(id) => Action::Drag(id),
@@ -122,6 +749,9 @@ impl<T> Action<T> {
             Self::FetchSize(id, o) => {
                 Action::FetchSize(id, Box::new(move |s| f(o(s))))
             }
+            Self::FetchFrameInsets(id, o) => {
+                Action::FetchFrameInsets(id, Box::new(move |s| f(o(s))))
+            }
             Self::FetchMaximized(id, o) => {
                 Action::FetchMaximized(id, Box::new(move |s| f(o(s))))
             }
@@ -130,27 +760,171 @@ impl<T> Action<T> {
                 Action::FetchMinimized(id, Box::new(move |s| f(o(s))))
             }
             Self::Minimize(id, minimized) => Action::Minimize(id, minimized),
+            Self::MinimizeAll => Action::MinimizeAll,
+            Self::RestoreAll => Action::RestoreAll,
             Self::Move(id, position) => Action::Move(id, position),
             Self::ChangeMode(id, mode) => Action::ChangeMode(id, mode),
             Self::FetchMode(id, o) => {
                 Action::FetchMode(id, Box::new(move |s| f(o(s))))
             }
             Self::ToggleMaximize(id) => Action::ToggleMaximize(id),
+            Self::GetDecorationMode(id, o) => {
+                Action::GetDecorationMode(id, Box::new(move |s| f(o(s))))
+            }
+            Self::GetCurrentOutputName(id, o) => {
+                Action::GetCurrentOutputName(id, Box::new(move |s| f(o(s))))
+            }
+            Self::GetSurfaceFormat(id, o) => {
+                Action::GetSurfaceFormat(id, Box::new(move |s| f(o(s))))
+            }
             Self::ToggleDecorations(id) => Action::ToggleDecorations(id),
             Self::RequestUserAttention(id, attention_type) => {
                 Action::RequestUserAttention(id, attention_type)
             }
+            Self::SetUrgent(id, urgent) => Action::SetUrgent(id, urgent),
             Self::GainFocus(id) => Action::GainFocus(id),
             Self::ChangeLevel(id, level) => Action::ChangeLevel(id, level),
+            Self::SetKeepAbove(id, above) => Action::SetKeepAbove(id, above),
+            Self::SetKeepBelow(id, below) => Action::SetKeepBelow(id, below),
+            Self::SetPreferredTheme(id, theme) => {
+                Action::SetPreferredTheme(id, theme)
+            }
+            Self::SetResizeIncrements(id, increments) => {
+                Action::SetResizeIncrements(id, increments)
+            }
+            Self::SetBaseSize(id, size) => Action::SetBaseSize(id, size),
+            Self::SetImeArea(id, area) => Action::SetImeArea(id, area),
+            Self::SetImeAllowed(id, allowed) => {
+                Action::SetImeAllowed(id, allowed)
+            }
+            Self::SetInputCapture(id, capture) => {
+                Action::SetInputCapture(id, capture)
+            }
+            Self::SetInputPassthrough(id, passthrough, duration) => {
+                Action::SetInputPassthrough(id, passthrough, duration)
+            }
+            Self::SetCursorGrab(id, mode, o) => Action::SetCursorGrab(
+                id,
+                mode,
+                o.map(|o| -> Box<dyn FnOnce(bool) -> A> {
+                    Box::new(move |s| f(o(s)))
+                }),
+            ),
+            Self::SetPreciseMouseMotion(id, precise) => {
+                Action::SetPreciseMouseMotion(id, precise)
+            }
+            Self::SetLiveResize(id, live) => Action::SetLiveResize(id, live),
+            Self::SetGlobalScaleFactor(scale_factor) => {
+                Action::SetGlobalScaleFactor(scale_factor)
+            }
+            Self::FollowGlobalScaleFactor(id, follows) => {
+                Action::FollowGlobalScaleFactor(id, follows)
+            }
+            Self::SetTrafficLightOffset(id, offset) => {
+                Action::SetTrafficLightOffset(id, offset)
+            }
+            Self::SetDocumentEdited(id, edited) => {
+                Action::SetDocumentEdited(id, edited)
+            }
+            Self::SetMinimizeAnimation(id, enabled) => {
+                Action::SetMinimizeAnimation(id, enabled)
+            }
+            Self::SetDocumentPath(id, path) => {
+                Action::SetDocumentPath(id, path)
+            }
+            Self::SetCursorTheme(id, theme, size) => {
+                Action::SetCursorTheme(id, theme, size)
+            }
+            Self::RequestFrameCallback(id) => Action::RequestFrameCallback(id),
+            Self::PlayAttentionSound(id) => Action::PlayAttentionSound(id),
+            Self::SetAttentionSoundEnabled(id, enabled) => {
+                Action::SetAttentionSoundEnabled(id, enabled)
+            }
+            Self::SetResizeBorderHighlight(id, color) => {
+                Action::SetResizeBorderHighlight(id, color)
+            }
+            Self::SetResizeHitThickness(id, thickness) => {
+                Action::SetResizeHitThickness(id, thickness)
+            }
+            Self::SetResizeCornerSize(id, size) => {
+                Action::SetResizeCornerSize(id, size)
+            }
+            Self::SetResizeDirections(id, directions) => {
+                Action::SetResizeDirections(id, directions)
+            }
+            Self::SetAspectRatio(id, aspect_ratio, mode) => {
+                Action::SetAspectRatio(id, aspect_ratio, mode)
+            }
+            Self::ShowResizeGrip(id, visible) => {
+                Action::ShowResizeGrip(id, visible)
+            }
+            Self::SyncResizeBorderWithTheme(id, synced) => {
+                Action::SyncResizeBorderWithTheme(id, synced)
+            }
+            Self::SetTitleBarRegion(id, region) => {
+                Action::SetTitleBarRegion(id, region)
+            }
+            Self::SetBackdrop(id, material) => {
+                Action::SetBackdrop(id, material)
+            }
+            Self::Snap(id, position) => Action::Snap(id, position),
+            Self::SetFrameExtents(id, extents) => {
+                Action::SetFrameExtents(id, extents)
+            }
+            Self::SetFrameInterval(id, interval) => {
+                Action::SetFrameInterval(id, interval)
+            }
+            Self::SetWindowRole(id, role) => Action::SetWindowRole(id, role),
+            Self::SetColorProfile(id, icc) => Action::SetColorProfile(id, icc),
             Self::ShowWindowMenu(id) => Action::ShowWindowMenu(id),
             Self::FetchId(id, o) => {
                 Action::FetchId(id, Box::new(move |s| f(o(s))))
             }
+            Self::FetchAvailableMonitors(id, o) => {
+                Action::FetchAvailableMonitors(id, Box::new(move |s| f(o(s))))
+            }
+            Self::FetchVideoModes(id, o) => {
+                Action::FetchVideoModes(id, Box::new(move |s| f(o(s))))
+            }
+            Self::SetExclusiveFullscreen(id, video_mode) => {
+                Action::SetExclusiveFullscreen(id, video_mode)
+            }
             Self::ChangeIcon(id, icon) => Action::ChangeIcon(id, icon),
             Self::Screenshot(id, tag) => Action::Screenshot(
                 id,
                 Box::new(move |screenshot| f(tag(screenshot))),
             ),
+            Self::RenderElement(id, element, size, tag) => {
+                Action::RenderElement(
+                    id,
+                    element,
+                    size,
+                    Box::new(move |screenshot| f(tag(screenshot))),
+                )
+            }
+            Self::DumpLayout(id, o) => {
+                Action::DumpLayout(id, Box::new(move |s| f(o(s))))
+            }
+            Self::DropCache(id) => Action::DropCache(id),
+            Self::SetCursorIcon(id, interaction) => {
+                Action::SetCursorIcon(id, interaction)
+            }
+            Self::SetCustomCursor(id, cursor) => {
+                Action::SetCustomCursor(id, cursor)
+            }
+            Self::SetDimOverlay(id, color) => Action::SetDimOverlay(id, color),
+            Self::SetTitle(id, title) => Action::SetTitle(id, title),
+            Self::FetchTitle(id, o) => {
+                Action::FetchTitle(id, Box::new(move |s| f(o(s))))
+            }
+            Self::FetchSurfaceScale(id, o) => {
+                Action::FetchSurfaceScale(id, Box::new(move |s| f(o(s))))
+            }
+            Self::SetRenderPriority(id, priority) => {
+                Action::SetRenderPriority(id, priority)
+            }
+            Self::FitToContent(id) => Action::FitToContent(id),
+            Self::ReconfigureSurface(id) => Action::ReconfigureSurface(id),
         }
     }
 }
@@ -158,6 +932,9 @@ impl<T> Action<T> {
 impl<T> fmt::Debug for Action<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            Self::Batch(actions) => {
+                write!(f, "Action::Batch({actions:?})")
+            }
             Self::Spawn(id, settings) => {
                 write!(f, "Action::Spawn({id:?}, {settings:?})")
             }
@@ -167,6 +944,9 @@ impl<T> fmt::Debug for Action<T> {
                 write!(f, "Action::Resize({id:?}, {size:?})")
             }
             Self::FetchSize(id, _) => write!(f, "Action::FetchSize({id:?})"),
+            Self::FetchFrameInsets(id, _) => {
+                write!(f, "Action::FetchFrameInsets({id:?})")
+            }
             Self::FetchMaximized(id, _) => {
                 write!(f, "Action::FetchMaximized({id:?})")
             }
@@ -179,6 +959,8 @@ impl<T> fmt::Debug for Action<T> {
             Self::Minimize(id, minimized) => {
                 write!(f, "Action::Minimize({id:?}, {minimized}")
             }
+            Self::MinimizeAll => write!(f, "Action::MinimizeAll"),
+            Self::RestoreAll => write!(f, "Action::RestoreAll"),
             Self::Move(id, position) => {
                 write!(f, "Action::Move({id:?}, {position})")
             }
@@ -189,24 +971,218 @@ impl<T> fmt::Debug for Action<T> {
             Self::ToggleMaximize(id) => {
                 write!(f, "Action::ToggleMaximize({id:?})")
             }
+            Self::GetDecorationMode(id, _) => {
+                write!(f, "Action::GetDecorationMode({id:?})")
+            }
+            Self::GetCurrentOutputName(id, _) => {
+                write!(f, "Action::GetCurrentOutputName({id:?})")
+            }
+            Self::GetSurfaceFormat(id, _) => {
+                write!(f, "Action::GetSurfaceFormat({id:?})")
+            }
             Self::ToggleDecorations(id) => {
                 write!(f, "Action::ToggleDecorations({id:?})")
             }
             Self::RequestUserAttention(id, _) => {
                 write!(f, "Action::RequestUserAttention({id:?})")
             }
+            Self::SetUrgent(id, urgent) => {
+                write!(f, "Action::SetUrgent({id:?}, {urgent})")
+            }
             Self::GainFocus(id) => write!(f, "Action::GainFocus({id:?})"),
             Self::ChangeLevel(id, level) => {
                 write!(f, "Action::ChangeLevel({id:?}, {level:?})")
             }
+            Self::SetKeepAbove(id, above) => {
+                write!(f, "Action::SetKeepAbove({id:?}, {above})")
+            }
+            Self::SetKeepBelow(id, below) => {
+                write!(f, "Action::SetKeepBelow({id:?}, {below})")
+            }
+            Self::SetPreferredTheme(id, theme) => {
+                write!(f, "Action::SetPreferredTheme({id:?}, {theme:?})")
+            }
+            Self::SetResizeIncrements(id, increments) => {
+                write!(f, "Action::SetResizeIncrements({id:?}, {increments:?})")
+            }
+            Self::SetBaseSize(id, size) => {
+                write!(f, "Action::SetBaseSize({id:?}, {size:?})")
+            }
+            Self::SetImeArea(id, area) => {
+                write!(f, "Action::SetImeArea({id:?}, {area:?})")
+            }
+            Self::SetImeAllowed(id, allowed) => {
+                write!(f, "Action::SetImeAllowed({id:?}, {allowed})")
+            }
+            Self::SetInputCapture(id, capture) => {
+                write!(f, "Action::SetInputCapture({id:?}, {capture})")
+            }
+            Self::SetInputPassthrough(id, passthrough, duration) => write!(
+                f,
+                "Action::SetInputPassthrough({id:?}, {passthrough}, {:?})",
+                duration
+            ),
+            Self::SetCursorGrab(id, mode, _) => {
+                write!(f, "Action::SetCursorGrab({id:?}, {mode:?})")
+            }
+            Self::SetPreciseMouseMotion(id, precise) => {
+                write!(f, "Action::SetPreciseMouseMotion({id:?}, {precise})")
+            }
+            Self::SetLiveResize(id, live) => {
+                write!(f, "Action::SetLiveResize({id:?}, {live})")
+            }
+            Self::SetGlobalScaleFactor(scale_factor) => {
+                write!(f, "Action::SetGlobalScaleFactor({scale_factor})")
+            }
+            Self::FollowGlobalScaleFactor(id, follows) => {
+                write!(f, "Action::FollowGlobalScaleFactor({id:?}, {follows})")
+            }
+            Self::SetTrafficLightOffset(id, offset) => {
+                write!(f, "Action::SetTrafficLightOffset({id:?}, {offset})")
+            }
+            Self::SetDocumentEdited(id, edited) => {
+                write!(f, "Action::SetDocumentEdited({id:?}, {edited})")
+            }
+            Self::SetMinimizeAnimation(id, enabled) => {
+                write!(f, "Action::SetMinimizeAnimation({id:?}, {enabled})")
+            }
+            Self::SetDocumentPath(id, path) => {
+                write!(f, "Action::SetDocumentPath({id:?}, {path:?})")
+            }
+            Self::SetCursorTheme(id, theme, size) => {
+                write!(f, "Action::SetCursorTheme({id:?}, {theme:?}, {size:?})")
+            }
+            Self::RequestFrameCallback(id) => {
+                write!(f, "Action::RequestFrameCallback({id:?})")
+            }
+            Self::PlayAttentionSound(id) => {
+                write!(f, "Action::PlayAttentionSound({id:?})")
+            }
+            Self::SetAttentionSoundEnabled(id, enabled) => {
+                write!(f, "Action::SetAttentionSoundEnabled({id:?}, {enabled})")
+            }
+            Self::SetResizeBorderHighlight(id, color) => {
+                write!(f, "Action::SetResizeBorderHighlight({id:?}, {color:?})")
+            }
+            Self::SetResizeHitThickness(id, thickness) => {
+                write!(f, "Action::SetResizeHitThickness({id:?}, {thickness})")
+            }
+            Self::SetResizeCornerSize(id, size) => {
+                write!(f, "Action::SetResizeCornerSize({id:?}, {size})")
+            }
+            Self::SetResizeDirections(id, directions) => {
+                write!(f, "Action::SetResizeDirections({id:?}, {directions:?})")
+            }
+            Self::SetAspectRatio(id, aspect_ratio, mode) => write!(
+                f,
+                "Action::SetAspectRatio({id:?}, {aspect_ratio:?}, {mode:?})"
+            ),
+            Self::ShowResizeGrip(id, visible) => {
+                write!(f, "Action::ShowResizeGrip({id:?}, {visible})")
+            }
+            Self::SyncResizeBorderWithTheme(id, synced) => {
+                write!(f, "Action::SyncResizeBorderWithTheme({id:?}, {synced})")
+            }
+            Self::SetTitleBarRegion(id, region) => {
+                write!(f, "Action::SetTitleBarRegion({id:?}, {region:?})")
+            }
+            Self::SetBackdrop(id, material) => {
+                write!(f, "Action::SetBackdrop({id:?}, {material:?})")
+            }
+            Self::Snap(id, position) => {
+                write!(f, "Action::Snap({id:?}, {position:?})")
+            }
+            Self::SetFrameExtents(id, extents) => {
+                write!(f, "Action::SetFrameExtents({id:?}, {extents:?})")
+            }
+            Self::SetFrameInterval(id, interval) => {
+                write!(f, "Action::SetFrameInterval({id:?}, {interval:?})")
+            }
+            Self::SetWindowRole(id, role) => {
+                write!(f, "Action::SetWindowRole({id:?}, {role:?})")
+            }
+            Self::SetColorProfile(id, icc) => write!(
+                f,
+                "Action::SetColorProfile({id:?}, {} bytes)",
+                icc.len()
+            ),
             Self::ShowWindowMenu(id) => {
                 write!(f, "Action::ShowWindowMenu({id:?})")
             }
             Self::FetchId(id, _) => write!(f, "Action::FetchId({id:?})"),
+            Self::FetchAvailableMonitors(id, _) => {
+                write!(f, "Action::FetchAvailableMonitors({id:?})")
+            }
+            Self::FetchVideoModes(id, _) => {
+                write!(f, "Action::FetchVideoModes({id:?})")
+            }
+            Self::SetExclusiveFullscreen(id, video_mode) => {
+                write!(
+                    f,
+                    "Action::SetExclusiveFullscreen({id:?}, {video_mode:?})"
+                )
+            }
             Self::ChangeIcon(id, _icon) => {
                 write!(f, "Action::ChangeIcon({id:?})")
             }
             Self::Screenshot(id, _) => write!(f, "Action::Screenshot({id:?})"),
+            Self::RenderElement(id, _, size, _) => {
+                write!(f, "Action::RenderElement({id:?}, {size:?})")
+            }
+            Self::DumpLayout(id, _) => write!(f, "Action::DumpLayout({id:?})"),
+            Self::DropCache(id) => write!(f, "Action::DropCache({id:?})"),
+            Self::SetCursorIcon(id, interaction) => {
+                write!(f, "Action::SetCursorIcon({id:?}, {interaction:?})")
+            }
+            Self::SetCustomCursor(id, cursor) => write!(
+                f,
+                "Action::SetCustomCursor({id:?}, {:?})",
+                cursor.as_ref().map(mouse::CustomCursor::hotspot)
+            ),
+            Self::SetDimOverlay(id, color) => {
+                write!(f, "Action::SetDimOverlay({id:?}, {color:?})")
+            }
+            Self::SetTitle(id, title) => {
+                write!(f, "Action::SetTitle({id:?}, {title:?})")
+            }
+            Self::FetchTitle(id, _) => write!(f, "Action::FetchTitle({id:?})"),
+            Self::FetchSurfaceScale(id, _) => {
+                write!(f, "Action::FetchSurfaceScale({id:?})")
+            }
+            Self::SetRenderPriority(id, priority) => {
+                write!(f, "Action::SetRenderPriority({id:?}, {priority:?})")
+            }
+            Self::FitToContent(id) => {
+                write!(f, "Action::FitToContent({id:?})")
+            }
+            Self::ReconfigureSurface(id) => {
+                write!(f, "Action::ReconfigureSurface({id:?})")
+            }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nested_batches_are_flattened_in_order() {
+        let id = Id::MAIN;
+
+        let actions = Action::<()>::Batch(vec![
+            Action::Resize(id, Size::new(800.0, 600.0)),
+            Action::Batch(vec![
+                Action::Move(id, Point::new(10.0, 20.0)),
+                Action::Batch(vec![]),
+            ]),
+            Action::Close(id),
+        ])
+        .flatten();
+
+        assert!(matches!(
+            actions.as_slice(),
+            [Action::Resize(..), Action::Move(..), Action::Close(..)]
+        ));
+    }
+}
@@ -3,6 +3,7 @@ use crate::sctk_event::ActionRequestEvent;
 use crate::{
     clipboard::Clipboard,
     commands::{layer_surface::get_layer_surface, window::get_window},
+    conversion,
     dpi::{LogicalPosition, PhysicalPosition},
     error::{self, Error},
     event_loop::{
@@ -34,7 +35,7 @@ use iced_futures::{
         },
         Widget,
     },
-    Executor, Runtime, Subscription,
+    Executor, MaybeSend, Runtime, Subscription,
 };
 use tracing::error;
 
@@ -49,7 +50,13 @@ use iced_runtime::{
             wayland::{data_device::DndIcon, popup, window},
         },
     },
-    core::{mouse::Interaction, touch, Color, Point, Size},
+    core::{
+        mouse::Interaction,
+        theme::Palette,
+        touch,
+        window::{DecorationMode, RedrawReason},
+        Color, Font, Point, Size,
+    },
     multi_window::Program,
     system, user_interface,
     window::Id as SurfaceId,
@@ -63,13 +70,21 @@ use raw_window_handle::{
     WaylandWindowHandle, WindowHandle,
 };
 use sctk::{
-    reexports::client::{protocol::wl_surface::WlSurface, Proxy, QueueHandle},
+    output::OutputInfo,
+    reexports::client::{
+        protocol::{wl_output::WlOutput, wl_surface::WlSurface},
+        Proxy, QueueHandle,
+    },
     seat::{keyboard::Modifiers, pointer::PointerEventKind},
 };
 use std::mem::ManuallyDrop;
 use std::{
-    collections::HashMap, hash::Hash, marker::PhantomData, os::raw::c_void,
-    ptr::NonNull, time::Duration,
+    collections::{HashMap, HashSet},
+    hash::Hash,
+    marker::PhantomData,
+    os::raw::c_void,
+    ptr::NonNull,
+    time::Duration,
 };
 use wayland_backend::client::ObjectId;
 use wayland_protocols::wp::viewporter::client::wp_viewport::WpViewport;
@@ -367,6 +382,9 @@ where
 
     let mut subsurface_state = None::<SubsurfaceState<A::Message>>;
 
+    // The actions waiting for the update of a specific surface
+    let mut pending_actions: Vec<command::Action<A::Message>> = Vec::new();
+
     {
         run_command(
             &application,
@@ -379,7 +397,7 @@ where
             &mut debug,
             || compositor.fetch_information(),
             &mut auto_size_surfaces,
-            &mut Vec::new(),
+            &mut pending_actions,
             &mut simple_clipboard,
         );
     }
@@ -404,9 +422,20 @@ where
     > = HashMap::new();
 
     let mut messages: Vec<A::Message> = Vec::new();
+    let mut loaded_fonts: Vec<Font> = Vec::new();
+    // The outputs of the compositor, in the order they were announced
+    let mut outputs: Vec<(WlOutput, OutputInfo)> = Vec::new();
+    let mut dnd_ended = false;
+    // The callbacks waiting for the next user interaction
+    let mut interaction_waiters: Vec<
+        Box<dyn FnOnce() -> A::Message + MaybeSend>,
+    > = Vec::new();
     #[cfg(feature = "a11y")]
     let mut commands: Vec<Command<A::Message>> = Vec::new();
     let mut redraw_pending = false;
+    // Every surface synchronizes its own theme, but the palette changes are
+    // shared by the whole application.
+    let mut last_palette = None;
 
     debug.startup_finished();
 
@@ -414,6 +443,7 @@ where
 
     let mut kbd_surface_id: Option<ObjectId> = None;
     let mut mods: Modifiers = Modifiers::default();
+    let mut scrolling = HashSet::new();
     let mut destroyed_surface_ids: HashMap<ObjectId, SurfaceIdWrapper> =
         Default::default();
 
@@ -430,12 +460,19 @@ where
             IcedSctkEvent::UserEvent(message) => {
                 messages.push(message);
             }
+            IcedSctkEvent::FontLoaded(font, bytes) => {
+                use iced_runtime::core::text::Renderer;
+
+                renderer.load_font(bytes);
+                loaded_fonts.push(font);
+            }
             IcedSctkEvent::SctkEvent(event) => {
                 sctk_events.push(event.clone());
                 match event {
                     SctkEvent::SeatEvent { .. } => {} // TODO Ashley: handle later possibly if multiseat support is wanted
                     SctkEvent::PointerEvent {
                         variant,
+                        primary,
                         ..
                     } => {
                         let mut offset = (0., 0.);
@@ -453,7 +490,10 @@ where
                                 }
                             },
                         };
+                        // The cursor of the window follows the pointer of
+                        // the primary seat
                         match variant.kind {
+                            _ if !primary => {}
                             PointerEventKind::Enter { .. } => {
                                 state.set_cursor_position(Some(LogicalPosition { x: variant.position.0 + offset.0, y: variant.position.1 + offset.1 }));
                             }
@@ -467,6 +507,16 @@ where
                             | PointerEventKind::Release { .. }
                             | PointerEventKind::Axis { .. } => {}
                         }
+
+                        if !matches!(
+                            variant.kind,
+                            PointerEventKind::Enter { .. }
+                                | PointerEventKind::Leave { .. }
+                        ) {
+                            messages.extend(
+                                interaction_waiters.drain(..).map(|f| f()),
+                            );
+                        }
                     }
                     SctkEvent::KeyboardEvent { variant, .. } => match variant {
                         KeyboardEventVariant::Leave(_) => {
@@ -477,7 +527,11 @@ where
                         }
                         KeyboardEventVariant::Press(_)
                         | KeyboardEventVariant::Release(_)
-                        | KeyboardEventVariant::Repeat(_) => {}
+                        | KeyboardEventVariant::Repeat(_) => {
+                            messages.extend(
+                                interaction_waiters.drain(..).map(|f| f()),
+                            );
+                        }
                         KeyboardEventVariant::Modifiers(mods) => {
                             if let Some(state) = kbd_surface_id
                                 .as_ref()
@@ -511,6 +565,10 @@ where
                             touch::Event::FingerLost { position, .. } => position,
                         };
                         state.set_cursor_position(Some(LogicalPosition { x: position.x as f64 + offset.0, y: position.y as f64 + offset.1 }));
+
+                        messages.extend(
+                            interaction_waiters.drain(..).map(|f| f()),
+                        );
                     },
                     SctkEvent::WindowEvent { variant, id: wl_surface } => match variant {
                         crate::sctk_event::WindowEventVariant::Created(id, native_id) => {
@@ -557,6 +615,9 @@ where
                                     compositor.configure_surface(&mut c_surface, configure.new_size.0.unwrap().get(), configure.new_size.1.unwrap().get());
                                     state.surface = Some(c_surface);
                                 }
+                                state.decoration_mode = Some(
+                                    conversion::decoration_mode(configure.decoration_mode),
+                                );
                                 if let Some((w, h, _, is_dirty)) = auto_size_surfaces.get_mut(id) {
                                     *is_dirty = first || *w != configure.new_size.0.map(|w| w.get()).unwrap_or_default() || *h != configure.new_size.1.map(|h| h.get()).unwrap_or_default();
                                     state.set_logical_size(*w as f32, *h as f32);
@@ -588,8 +649,18 @@ where
                                 state.set_scale_factor(sf);
                             }
                         },
+                        crate::sctk_event::WindowEventVariant::Title(title) => {
+                            if let Some(state) = surface_ids
+                                .get(&wl_surface.id())
+                                .and_then(|id| states.get_mut(&id.inner()))
+                            {
+                                state.title = title;
+                            }
+                        },
                         // handled by the application
-                        crate::sctk_event::WindowEventVariant::StateChanged(_) => {},
+                        crate::sctk_event::WindowEventVariant::StateChanged(_)
+                        | crate::sctk_event::WindowEventVariant::FrameCallback(_)
+                        | crate::sctk_event::WindowEventVariant::Ime(_) => {},
                     },
                     SctkEvent::LayerSurfaceEvent { variant, id: wl_surface } => match variant {
                         LayerSurfaceEventVariant::Created(id, native_id) => {
@@ -754,14 +825,23 @@ where
                         },
                     },
                     // TODO forward these events to an application which requests them?
-                    SctkEvent::NewOutput { .. } => {
+                    SctkEvent::NewOutput { id, info } => {
+                        if let Some(info) = info {
+                            outputs.push((id, info));
+                        }
                     }
-                    SctkEvent::UpdateOutput { .. } => {
+                    SctkEvent::UpdateOutput { id, info } => {
+                        match outputs.iter_mut().find(|(output, _)| *output == id) {
+                            Some((_, current)) => *current = info,
+                            None => outputs.push((id, info)),
+                        }
                     }
-                    SctkEvent::RemovedOutput( ..) => {
+                    SctkEvent::RemovedOutput(id) => {
+                        outputs.retain(|(output, _)| *output != id);
                     }
                     SctkEvent::ScaleFactorChanged { .. } => {}
                     SctkEvent::DataSource(DataSourceEvent::DndFinished) | SctkEvent::DataSource(DataSourceEvent::DndCancelled)=> {
+                        dnd_ended = true;
                         surface_ids.retain(|id, surface_id| {
                             match surface_id {
                                 SurfaceIdWrapper::Dnd(inner) => {
@@ -968,6 +1048,7 @@ where
                 if !redraw_pending
                     && sctk_events.is_empty()
                     && messages.is_empty()
+                    && loaded_fonts.is_empty()
                 {
                     continue;
                 }
@@ -990,7 +1071,7 @@ where
                         &mut ev_proxy,
                         &mut debug,
                         &mut messages,
-                        &mut Vec::new(),
+                        &mut pending_actions,
                         || compositor.fetch_information(),
                         &mut auto_size_surfaces,
                         &mut simple_clipboard,
@@ -1042,12 +1123,12 @@ where
                         }
                         let has_events = !sctk_events.is_empty();
                         debug.event_processing_started();
-                        #[allow(unused_mut)]
                         let mut native_events: Vec<_> = filtered_sctk
                             .into_iter()
                             .flat_map(|e| {
                                 e.to_native(
                                     &mut mods,
+                                    &mut scrolling,
                                     &surface_ids,
                                     &destroyed_surface_ids,
                                     &subsurface_ids,
@@ -1078,6 +1159,12 @@ where
                                 }),
                             );
                         }
+                        native_events.extend(
+                            loaded_fonts
+                                .iter()
+                                .copied()
+                                .map(CoreEvent::FontLoaded),
+                        );
                         let has_events =
                             has_events || !native_events.is_empty();
 
@@ -1112,13 +1199,43 @@ where
                             || has_events
                             || state.viewport_changed;
                         if redraw_pending || needs_update {
+                            let reason = if state.viewport_changed {
+                                RedrawReason::Layout
+                            } else if !messages.is_empty()
+                                || matches!(
+                                    interface_state,
+                                    user_interface::State::Outdated
+                                )
+                            {
+                                RedrawReason::Message
+                            } else if has_events {
+                                RedrawReason::Input
+                            } else if redraw_pending {
+                                RedrawReason::Timer
+                            } else {
+                                RedrawReason::Unconditional
+                            };
+
+                            let _ = state.redraw_reason.get_or_insert(reason);
+
                             state.set_needs_redraw(
                                 state.frame_pending || needs_update,
                             );
                             state.set_first(false);
                         }
                     }
+                    loaded_fonts.clear();
+
+                    // Discard the in-process payload of a DnD operation once
+                    // it ends, in case it was dropped outside of the
+                    // application
+                    if std::mem::take(&mut dnd_ended) {
+                        simple_clipboard.set_dnd_payload(None);
+                    }
+
                     if needs_update {
+                        actions.append(&mut pending_actions);
+
                         let mut pure_states: HashMap<_, _> =
                             ManuallyDrop::into_inner(interfaces)
                                 .drain()
@@ -1166,6 +1283,129 @@ where
                             // Update state
                             state.synchronize(&application);
                         }
+
+                        // Answer the queries for surfaces that were not
+                        // updated in this iteration, or that do not exist
+                        for action in actions.drain(..) {
+                            match action {
+                                command::Action::Window(
+                                    iced_runtime::window::Action::FetchTitle(
+                                        id,
+                                        tag,
+                                    ),
+                                ) => {
+                                    let title = states
+                                        .get(&id)
+                                        .map(|state| state.title.clone());
+
+                                    ev_proxy
+                                        .send_event(Event::Message(tag(title)));
+                                }
+                                command::Action::Window(
+                                    iced_runtime::window::Action::FetchSurfaceScale(
+                                        id,
+                                        tag,
+                                    ),
+                                ) => {
+                                    let scale = states
+                                        .get(&id)
+                                        .map(State::surface_scale_factor);
+
+                                    ev_proxy
+                                        .send_event(Event::Message(tag(scale)));
+                                }
+                                command::Action::Window(
+                                    iced_runtime::window::Action::GetDecorationMode(
+                                        id,
+                                        tag,
+                                    ),
+                                ) => {
+                                    let mode = states
+                                        .get(&id)
+                                        .and_then(|state| state.decoration_mode);
+
+                                    ev_proxy
+                                        .send_event(Event::Message(tag(mode)));
+                                }
+                                command::Action::System(
+                                    system::Action::AwaitInteraction(f),
+                                ) => {
+                                    interaction_waiters.push(f);
+                                }
+                                command::Action::System(
+                                    system::Action::GetRenderStats(id, tag),
+                                ) => {
+                                    // The renderer is shared by every
+                                    // surface, so its stats are the ones of
+                                    // the last frame drawn
+                                    let stats = states.contains_key(&id).then(|| {
+                                        compositor.fetch_render_stats(&renderer)
+                                    }).flatten();
+
+                                    ev_proxy
+                                        .send_event(Event::Message(tag(stats)));
+                                }
+                                command::Action::System(
+                                    system::Action::GetMonitorLayout(tag),
+                                ) => {
+                                    let layout =
+                                        crate::monitor::layout(&outputs);
+
+                                    ev_proxy.send_event(Event::Message(tag(
+                                        layout,
+                                    )));
+                                }
+                                command::Action::System(
+                                    system::Action::GetDesktopScaling(tag),
+                                ) => {
+                                    let monitors = outputs
+                                        .iter()
+                                        .map(|(_, info)| {
+                                            crate::conversion::monitor(info)
+                                        })
+                                        .collect();
+
+                                    ev_proxy.send_event(Event::Message(tag(
+                                        monitors,
+                                    )));
+                                }
+                                command::Action::System(
+                                    system::Action::GetMonitorScaleFactor(
+                                        monitor,
+                                        tag,
+                                    ),
+                                ) => {
+                                    let scale_factor =
+                                        crate::monitor::scale_factor(
+                                            &outputs, &monitor,
+                                        );
+
+                                    ev_proxy.send_event(Event::Message(tag(
+                                        scale_factor,
+                                    )));
+                                }
+                                command::Action::System(
+                                    system::Action::GetActiveBackend(tag),
+                                ) => {
+                                    let backend = compositor.backend();
+
+                                    ev_proxy.send_event(Event::Message(tag(
+                                        backend,
+                                    )));
+                                }
+                                // Operations wait for the next update of their
+                                // surface, unless it was closed
+                                command::Action::WidgetFor(id, action)
+                                    if states.contains_key(&id) =>
+                                {
+                                    pending_actions.push(
+                                        command::Action::WidgetFor(id, action),
+                                    );
+                                }
+                                _ => {}
+                            }
+                        }
+
                         interfaces = ManuallyDrop::new(build_user_interfaces(
                             &application,
                             &mut renderer,
@@ -1205,6 +1445,13 @@ where
                             None => continue,
                         };
 
+                        let reason = state
+                            .redraw_reason
+                            .take()
+                            .unwrap_or(RedrawReason::Unconditional);
+
+                        debug.log_redraw(reason);
+
                         let redraw_event = CoreEvent::Window(
                             surface_id.inner(),
                             crate::core::window::Event::RedrawRequested(
@@ -1224,8 +1471,31 @@ where
                             &mut messages,
                         );
 
+                        #[cfg(feature = "debug")]
+                        runtime.broadcast(
+                            CoreEvent::Window(
+                                surface_id.inner(),
+                                crate::core::window::Event::RedrawReason(
+                                    reason,
+                                ),
+                            ),
+                            Status::Ignored,
+                        );
+
                         runtime.broadcast(redraw_event, Status::Ignored);
 
+                        if let Some(palette) = state
+                            .take_palette_change()
+                            .filter(|palette| Some(*palette) != last_palette)
+                        {
+                            last_palette = Some(palette);
+
+                            runtime.broadcast(
+                                CoreEvent::ThemePaletteChanged(palette),
+                                Status::Ignored,
+                            );
+                        }
+
                         ev_proxy.send_event(Event::SctkEvent(
                             IcedSctkEvent::RedrawRequested(object_id.clone()),
                         ));
@@ -1282,6 +1552,7 @@ where
                         let event = sctk_events.remove(i);
                         for native_event in event.to_native(
                             &mut mods,
+                            &mut scrolling,
                             &surface_ids,
                             &destroyed_surface_ids,
                             &subsurface_ids,
@@ -1547,12 +1818,40 @@ where
             IcedSctkEvent::Subcompositor(state) => {
                 subsurface_state = Some(state);
             }
+            IcedSctkEvent::GlobalShortcut(id) => {
+                runtime
+                    .broadcast(CoreEvent::GlobalShortcut(id), Status::Ignored);
+            }
+            IcedSctkEvent::ClipboardExpired(target, contents) => {
+                let _ =
+                    target.clear_if_unchanged(&mut simple_clipboard, &contents);
+            }
         }
     }
 
+    // Avoid leaking the registrations of global shortcuts
+    #[cfg(feature = "portal")]
+    system::portal::unregister_all();
+
     Ok(())
 }
 
+/// Returns a future that completes once the given duration elapses.
+///
+/// The executor of the application may not provide timers, so the duration
+/// elapses in a separate thread.
+fn expire(duration: Duration) -> impl Future<Output = ()> {
+    let (sender, receiver) = futures::channel::oneshot::channel();
+
+    let _ = std::thread::spawn(move || {
+        std::thread::sleep(duration);
+
+        let _ = sender.send(());
+    });
+
+    receiver.map(|_| ())
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum SurfaceIdWrapper {
     LayerSurface(SurfaceId),
@@ -1670,6 +1969,7 @@ where
 {
     pub(crate) id: SurfaceIdWrapper,
     title: String,
+    application_title: String,
     application_scale_factor: f64,
     surface_scale_factor: f64,
     viewport: Viewport,
@@ -1678,6 +1978,8 @@ where
     modifiers: Modifiers,
     theme: <A as Program>::Theme,
     appearance: application::Appearance,
+    palette: Option<Palette>,
+    palette_changed: bool,
     application: PhantomData<A>,
     // Time of last frame event, or 0
     frame_pending: bool,
@@ -1689,6 +1991,8 @@ where
     surface: Option<C::Surface>,
     wrapper: SurfaceDisplayWrapper,
     subsurfaces: Vec<SubsurfaceInstance>,
+    decoration_mode: Option<DecorationMode>,
+    redraw_reason: Option<RedrawReason>,
 }
 
 impl<A: Application, C: Compositor> State<A, C>
@@ -1705,10 +2009,12 @@ where
         let scale_factor = application.scale_factor(id.inner());
         let theme = application.theme(id.inner());
         let appearance = theme.appearance(&application.style());
+        let palette = theme.palette();
         let viewport = Viewport::with_physical_size(Size::new(1, 1), 1.0);
 
         Self {
             id,
+            application_title: title.clone(),
             title,
             application_scale_factor: scale_factor,
             surface_scale_factor: 1.0, // assumed to be 1.0 at first
@@ -1719,6 +2025,8 @@ where
             modifiers: Modifiers::default(),
             theme,
             appearance,
+            palette,
+            palette_changed: false,
             application: PhantomData,
             frame_pending: false,
             last_frame_time: 0,
@@ -1729,6 +2037,8 @@ where
             surface: None,
             wrapper,
             subsurfaces: Vec::new(),
+            decoration_mode: None,
+            redraw_reason: None,
         }
     }
 
@@ -1743,7 +2053,8 @@ where
     fn set_frame(&mut self, time: u32) {
         // If we get frame events from mulitple subsurface, should have same time. So ignore if
         // time isn't newer.
-        if time == 0 || time > self.last_frame_time {
+        // Timestamps wrap around, so they are compared by their difference
+        if time == 0 || (time.wrapping_sub(self.last_frame_time) as i32) > 0 {
             self.frame_pending = true;
             self.last_frame_time = time;
         }
@@ -1806,6 +2117,14 @@ where
         self.viewport.scale_factor()
     }
 
+    /// Returns the scale factor of the surface of the [`State`], as preferred
+    /// by the compositor.
+    ///
+    /// It is fractional when the compositor supports fractional scaling.
+    pub fn surface_scale_factor(&self) -> f64 {
+        self.surface_scale_factor
+    }
+
     pub fn set_scale_factor(&mut self, scale_factor: f64) {
         if !approx_eq!(
             f64,
@@ -1881,9 +2200,34 @@ where
     }
 
     fn synchronize(&mut self, application: &A) {
+        // Update title, unless only a `Title` action changed it since
+        let title = application.title(self.id.inner());
+
+        if title != self.application_title {
+            self.title = title.clone();
+            self.application_title = title;
+        }
+
         // Update theme and appearance
         self.theme = application.theme(self.id.inner());
         self.appearance = self.theme.appearance(&application.style());
+
+        let palette = self.theme.palette();
+
+        if palette != self.palette {
+            self.palette = palette;
+            self.palette_changed = true;
+        }
+    }
+
+    /// Returns the new [`Palette`] of the theme of the [`State`], if it
+    /// changed since the last time it was taken.
+    pub fn take_palette_change(&mut self) -> Option<Palette> {
+        if std::mem::take(&mut self.palette_changed) {
+            self.palette
+        } else {
+            None
+        }
     }
 }
 
@@ -1986,7 +2330,11 @@ fn run_command<A, C, E>(
     <A as Program>::Theme: StyleSheet,
     C: Compositor,
 {
-    for action in command.actions() {
+    for action in command
+        .actions()
+        .into_iter()
+        .flat_map(command::Action::flatten)
+    {
         if let Some(a) = handle_actions(
             application,
             cache,
@@ -2039,6 +2387,11 @@ where
                     stream.map(|e| Event::SctkEvent(IcedSctkEvent::UserEvent(e))),
                 ));
             }
+            command::Action::LoadFontAsync { font, bytes } => {
+                runtime.spawn(Box::pin(bytes.map(move |bytes| {
+                    Event::SctkEvent(IcedSctkEvent::FontLoaded(font, bytes))
+                })));
+            }
             command::Action::Clipboard(action) => match action {
                 clipboard::Action::Read(s_to_msg) => {
                     let contents = clipboard.read();
@@ -2056,6 +2409,26 @@ where
                     let message = to_msg(contents);
                     proxy.send_event(Event::Message(message));
                 },
+                clipboard::Action::ReadUris(to_msg) => {
+                    let paths = clipboard
+                        .read_data(vec![clipboard::URI_LIST_MIME_TYPE.into()])
+                        .map(|(bytes, _)| clipboard::file_paths(&bytes))
+                        .unwrap_or_default();
+                    let message = to_msg(paths);
+                    proxy.send_event(Event::Message(message));
+                },
+                clipboard::Action::WriteHtml { html, plain } => {
+                    clipboard.write_data(ClipboardStoreData(Box::new(
+                        clipboard::Html { html, plain },
+                    )))
+                },
+                clipboard::Action::ReadAvailableTypes(target, to_msg) => {
+                    // Without a selection offer, nothing can be read and
+                    // the list is empty.
+                    let types = target.available_types(clipboard);
+                    let message = to_msg(types);
+                    proxy.send_event(Event::Message(message));
+                },
                 clipboard::Action::ReadPrimary(s_to_msg) => {
                     let contents = clipboard.read_primary();
                     let message = s_to_msg(contents);
@@ -2072,7 +2445,75 @@ where
                     let message = to_msg(contents);
                     proxy.send_event(Event::Message(message));
                 },
+                clipboard::Action::WriteWithExpiry { target, contents, ttl } => {
+                    target.write(clipboard, contents.clone());
+
+                    runtime.spawn(Box::pin(expire(ttl).map(move |_| {
+                        Event::SctkEvent(IcedSctkEvent::ClipboardExpired(
+                            target, contents,
+                        ))
+                    })));
+                },
             },
+            command::Action::Window(iced_runtime::window::Action::GetDecorationMode(id, tag)) => {
+                match state {
+                    Some(state) if state.id.inner() == id => {
+                        proxy.send_event(Event::Message(tag(state.decoration_mode)));
+                    }
+                    // The decoration mode is kept by the state of the window,
+                    // so the run loop answers it once every state was updated
+                    Some(_) => {
+                        return Some(command::Action::Window(
+                            iced_runtime::window::Action::GetDecorationMode(id, tag),
+                        ));
+                    }
+                    None => {
+                        proxy.send_event(Event::Message(tag(None)));
+                    }
+                }
+            }
+            command::Action::Window(iced_runtime::window::Action::FetchSurfaceScale(id, tag)) => {
+                match state {
+                    Some(state) if state.id.inner() == id => {
+                        proxy.send_event(Event::Message(tag(Some(state.surface_scale_factor()))));
+                    }
+                    // The scale is kept by the state of the window, so the
+                    // run loop answers it once every state was updated
+                    Some(_) => {
+                        return Some(command::Action::Window(
+                            iced_runtime::window::Action::FetchSurfaceScale(id, tag),
+                        ));
+                    }
+                    None => {
+                        proxy.send_event(Event::Message(tag(None)));
+                    }
+                }
+            }
+            command::Action::Window(iced_runtime::window::Action::SetCursorGrab(_id, _mode, tag)) => {
+                // Grabbing the cursor is not supported yet
+                if let Some(tag) = tag {
+                    proxy.send_event(Event::Message(tag(false)));
+                }
+            }
+            command::Action::Window(iced_runtime::window::Action::FetchTitle(id, tag)) => {
+                match state {
+                    Some(state) if state.id.inner() == id => {
+                        proxy.send_event(Event::Message(tag(Some(
+                            state.title.clone(),
+                        ))));
+                    }
+                    // The title is kept by the state of the window, so the
+                    // run loop answers it once every state was updated
+                    Some(_) => {
+                        return Some(command::Action::Window(
+                            iced_runtime::window::Action::FetchTitle(id, tag),
+                        ));
+                    }
+                    None => {
+                        proxy.send_event(Event::Message(tag(None)));
+                    }
+                }
+            }
             command::Action::Window(action)  => {
                 if let Ok(a) = action.try_into() {
                     return handle_actions(application, cache, state, renderer, command::Action::PlatformSpecific(platform_specific::Action::Wayland(command::platform_specific::wayland::Action::Window(a))), runtime, proxy, debug, _graphics_info, auto_size_surfaces, clipboard);
@@ -2097,7 +2538,285 @@ where
                         });
                     }
                 }
+                system::Action::QueryInformationCancellable(_token, _tag) => {
+                    #[cfg(feature = "system")]
+                    {
+                        let graphics_info = _graphics_info();
+                        let proxy = proxy.clone();
+
+                        let _ = std::thread::spawn(move || {
+                            let information =
+                                crate::system::information(graphics_info);
+
+                            // Sending under the lock of the token cannot race
+                            // against its cancellation
+                            let _ = _token.unless_cancelled(|| {
+                                let message = _tag(information);
+
+                                proxy
+                                    .send_event(Event::Message(message));
+                            });
+                        });
+                    }
+                }
+                system::Action::QueryDoubleClickInterval(_tag) => {
+                    #[cfg(feature = "system")]
+                    {
+                        let proxy = proxy.clone();
+
+                        let _ = std::thread::spawn(move || {
+                            let interval =
+                                crate::system::double_click_interval();
+
+                            let message = _tag(interval);
+
+                            proxy
+                                .send_event(Event::Message(message));
+                        });
+                    }
+                }
+                system::Action::QueryDoubleClickIntervalCancellable(
+                    _token,
+                    _tag,
+                ) => {
+                    #[cfg(feature = "system")]
+                    {
+                        let proxy = proxy.clone();
+
+                        let _ = std::thread::spawn(move || {
+                            let interval =
+                                crate::system::double_click_interval();
+
+                            // Sending under the lock of the token cannot race
+                            // against its cancellation
+                            let _ = _token.unless_cancelled(|| {
+                                let message = _tag(interval);
+
+                                proxy
+                                    .send_event(Event::Message(message));
+                            });
+                        });
+                    }
+                }
+                system::Action::GetRenderStats(id, tag) => {
+                    // The compositor is owned by the run loop, which answers
+                    // the query
+                    return Some(command::Action::System(
+                        system::Action::GetRenderStats(id, tag),
+                    ));
+                }
+                system::Action::AwaitInteraction(f) => {
+                    // The event loop keeps the callback until the next
+                    // user interaction
+                    return Some(command::Action::System(
+                        system::Action::AwaitInteraction(f),
+                    ));
+                }
+                system::Action::GetActiveBackend(tag) => {
+                    // The compositor is owned by the run loop, which answers
+                    // the query
+                    return Some(command::Action::System(
+                        system::Action::GetActiveBackend(tag),
+                    ));
+                }
+                system::Action::IsHardwareAccelerated(tag) => {
+                    let hardware_accelerated =
+                        _graphics_info().hardware_accelerated;
+
+                    proxy.send_event(Event::Message(tag(hardware_accelerated)));
+                }
+                system::Action::CaptureAllWindows(tag) => {
+                    tracing::warn!(
+                        "Capturing all windows is not supported on Wayland"
+                    );
+                    proxy.send_event(Event::Message(tag(HashMap::new())));
+                }
+                system::Action::GetWindowManagerName(_token, _tag) => {
+                    #[cfg(feature = "system")]
+                    {
+                        let proxy = proxy.clone();
+
+                        let _ = std::thread::spawn(move || {
+                            let name = crate::system::window_manager_name();
+
+                            // Sending under the lock of the token cannot race
+                            // against its cancellation
+                            let _ = _token.unless_cancelled(|| {
+                                let message = _tag(name);
+
+                                proxy
+                                    .send_event(Event::Message(message));
+                            });
+                        });
+                    }
+                }
+                system::Action::GetKeyRepeat(_token, _tag) => {
+                    #[cfg(feature = "system")]
+                    {
+                        let proxy = proxy.clone();
+
+                        let _ = std::thread::spawn(move || {
+                            let key_repeat = crate::system::key_repeat();
+
+                            // Sending under the lock of the token cannot race
+                            // against its cancellation
+                            let _ = _token.unless_cancelled(|| {
+                                let message = _tag(key_repeat);
+
+                                proxy
+                                    .send_event(Event::Message(message));
+                            });
+                        });
+                    }
+                }
+                system::Action::RegisterGlobalShortcut(_shortcut, tag) => {
+                    #[cfg(feature = "portal")]
+                    {
+                        let (trigger, triggers) = mpsc::unbounded();
+                        let (reply, result) =
+                            futures::channel::oneshot::channel();
+
+                        system::portal::register(
+                            &_shortcut,
+                            move |id| {
+                                let _ = trigger.unbounded_send(id);
+                            },
+                            move |result| {
+                                let _ = reply.send(result);
+                            },
+                        );
+
+                        runtime.run(Box::pin(triggers.map(|id| {
+                            Event::SctkEvent(IcedSctkEvent::GlobalShortcut(id))
+                        })));
+
+                        runtime.spawn(Box::pin(result.map(|result| {
+                            Event::Message(tag(result.unwrap_or(Err(
+                                system::GlobalShortcutError::Unsupported,
+                            ))))
+                        })));
+                    }
+
+                    #[cfg(not(feature = "portal"))]
+                    proxy.send_event(Event::Message(tag(Err(
+                        system::GlobalShortcutError::Unsupported,
+                    ))));
+                }
+                system::Action::UnregisterGlobalShortcut(_id) => {
+                    #[cfg(feature = "portal")]
+                    system::portal::unregister(_id);
+                }
+                system::Action::InjectEvent(_, _) => {
+                    tracing::warn!(
+                        "Injecting events is not supported by the Wayland \
+                        shell; the event is discarded."
+                    );
+                }
+                system::Action::GetMonitorScaleFactor(monitor, tag) => {
+                    // The outputs are tracked by the run loop, which answers
+                    // the query
+                    return Some(command::Action::System(
+                        system::Action::GetMonitorScaleFactor(monitor, tag),
+                    ));
+                }
+                system::Action::GetDesktopScaling(tag) => {
+                    // The outputs are tracked by the run loop, which answers
+                    // the query
+                    return Some(command::Action::System(
+                        system::Action::GetDesktopScaling(tag),
+                    ));
+                }
+                system::Action::GetSystemFonts(tag) => {
+                    let proxy = proxy.clone();
+
+                    let _ = std::thread::spawn(move || {
+                        let families = iced_graphics::text::font_families();
+
+                        proxy.send_event(Event::Message(tag(families)));
+                    });
+                }
+                system::Action::GetProcessInfo(token, tag) => {
+                    #[cfg(feature = "system")]
+                    {
+                        let proxy = proxy.clone();
+
+                        let _ = std::thread::spawn(move || {
+                            let info = crate::system::process_info();
+
+                            // Sending under the lock of the token cannot race
+                            // against its cancellation
+                            let _ = token.unless_cancelled(|| {
+                                let message = tag(info);
+
+                                proxy
+                                    .send_event(Event::Message(message));
+                            });
+                        });
+                    }
+
+                    #[cfg(not(feature = "system"))]
+                    let _ = token.unless_cancelled(|| {
+                        proxy
+                            .send_event(Event::Message(tag(
+                                system::ProcessInfo::current(),
+                            )));
+                    });
+                }
+                system::Action::GetDiskInfo(token, path, tag) => {
+                    #[cfg(feature = "system")]
+                    {
+                        let proxy = proxy.clone();
+
+                        let _ = std::thread::spawn(move || {
+                            let info = crate::system::disk_info(&path);
+
+                            // Sending under the lock of the token cannot race
+                            // against its cancellation
+                            let _ = token.unless_cancelled(|| {
+                                let message = tag(info);
+
+                                proxy
+                                    .send_event(Event::Message(message));
+                            });
+                        });
+                    }
+
+                    #[cfg(not(feature = "system"))]
+                    {
+                        let _ = path;
+
+                        let _ = token.unless_cancelled(|| {
+                            proxy
+                                .send_event(Event::Message(tag(Err(
+                                    system::DiskInfoError::Unsupported,
+                                ))));
+                        });
+                    }
+                }
+                system::Action::GetMonitorLayout(tag) => {
+                    // The outputs are tracked by the run loop, which answers
+                    // the query
+                    return Some(command::Action::System(
+                        system::Action::GetMonitorLayout(tag),
+                    ));
+                }
             },
+            command::Action::WidgetFor(id, action) => {
+                match state {
+                    Some(state) if state.id.inner() == id => {
+                        // The operation is not carried over to the other
+                        // surfaces
+                        let _ = handle_actions(application, cache, Some(state), renderer, command::Action::Widget(action), runtime, proxy, debug, _graphics_info, auto_size_surfaces, clipboard);
+                    }
+                    // The user interface of the window is built with its
+                    // own state, so the run loop keeps the operation until
+                    // the window is updated
+                    Some(_) => {
+                        return Some(command::Action::WidgetFor(id, action));
+                    }
+                    None => {}
+                }
+            }
             command::Action::Widget(action) => {
                 let state = match state {
                     Some(s) => s,
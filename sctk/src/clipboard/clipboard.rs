@@ -3,6 +3,7 @@ pub use iced_runtime::clipboard::Action;
 
 use iced_runtime::command::{self, Command};
 use raw_window_handle::HasDisplayHandle;
+use std::any::{Any, TypeId};
 use window_clipboard::mime::{self, ClipboardStoreData};
 
 /// A buffer for short-term storage and transfer within and between
@@ -10,6 +11,7 @@ use window_clipboard::mime::{self, ClipboardStoreData};
 #[allow(missing_debug_implementations)]
 pub struct Clipboard {
     pub(crate) state: State,
+    dnd_payload: Option<Box<dyn Any>>,
 }
 
 pub(crate) enum State {
@@ -23,6 +25,7 @@ impl Clipboard {
 
         Clipboard {
             state: context.map(State::Connected).unwrap_or(State::Unavailable),
+            dnd_payload: None,
         }
     }
 
@@ -35,6 +38,7 @@ impl Clipboard {
     pub fn unconnected() -> Clipboard {
         Clipboard {
             state: State::Unavailable,
+            dnd_payload: None,
         }
     }
 }
@@ -118,6 +122,18 @@ impl iced_runtime::core::clipboard::Clipboard for Clipboard {
             State::Unavailable => {}
         }
     }
+
+    fn set_dnd_payload(&mut self, payload: Option<Box<dyn Any>>) {
+        self.dnd_payload = payload;
+    }
+
+    fn take_dnd_payload(&mut self, type_id: TypeId) -> Option<Box<dyn Any>> {
+        if (**self.dnd_payload.as_ref()?).type_id() != type_id {
+            return None;
+        }
+
+        self.dnd_payload.take()
+    }
 }
 
 /// Read the current contents of the clipboard.
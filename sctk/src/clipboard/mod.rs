@@ -3,11 +3,14 @@ mod clipboard;
 
 #[cfg(not(feature = "clipboard"))]
 mod clipboard {
+    use std::any::{Any, TypeId};
     use std::ffi::c_void;
     /// A buffer for short-term storage and transfer within and between
     /// applications.
     #[allow(missing_debug_implementations)]
-    pub struct Clipboard;
+    pub struct Clipboard {
+        dnd_payload: Option<Box<dyn Any>>,
+    }
 
     pub(crate) enum State {
         Connected(()),
@@ -18,7 +21,7 @@ mod clipboard {
         pub unsafe fn connect(
             _display: &impl raw_window_handle::HasDisplayHandle,
         ) -> Clipboard {
-            Clipboard
+            Clipboard { dnd_payload: None }
         }
 
         pub(crate) fn state(&self) -> &State {
@@ -27,7 +30,7 @@ mod clipboard {
 
         /// Creates a new [`Clipboard`]
         pub fn unconnected() -> Clipboard {
-            Clipboard
+            Clipboard { dnd_payload: None }
         }
     }
 
@@ -37,6 +40,21 @@ mod clipboard {
         }
 
         fn write(&mut self, _contents: String) {}
+
+        fn set_dnd_payload(&mut self, payload: Option<Box<dyn Any>>) {
+            self.dnd_payload = payload;
+        }
+
+        fn take_dnd_payload(
+            &mut self,
+            type_id: TypeId,
+        ) -> Option<Box<dyn Any>> {
+            if (**self.dnd_payload.as_ref()?).type_id() != type_id {
+                return None;
+            }
+
+            self.dnd_payload.take()
+        }
     }
 }
 
@@ -1,10 +1,14 @@
 use iced_futures::core::mouse::Interaction;
 use iced_runtime::core::{
     keyboard,
-    mouse::{self, ScrollDelta},
+    mouse::{self, ScrollDelta, ScrollPhase},
+    window, Rectangle, Size,
 };
 use sctk::{
-    reexports::client::protocol::wl_pointer::AxisSource,
+    output::OutputInfo,
+    reexports::client::protocol::{
+        wl_output::Transform, wl_pointer::AxisSource,
+    },
     seat::{
         keyboard::Modifiers,
         pointer::{
@@ -12,6 +16,7 @@ use sctk::{
             BTN_SIDE,
         },
     },
+    shell::xdg::window::DecorationMode,
 };
 
 /// An error that occurred while running an application.
@@ -52,6 +57,47 @@ pub fn pointer_axis_to_native(
     })
 }
 
+/// Returns the [`ScrollPhase`] of an axis frame, keeping track of whether a
+/// scroll gesture is in progress in `scrolling`.
+///
+/// Only touchpads report the end of a gesture, so any other source always
+/// produces [`ScrollPhase::Moved`].
+pub fn pointer_axis_phase(
+    source: Option<AxisSource>,
+    horizontal: AxisScroll,
+    vertical: AxisScroll,
+    scrolling: &mut bool,
+) -> ScrollPhase {
+    if horizontal.stop || vertical.stop {
+        *scrolling = false;
+
+        ScrollPhase::Ended
+    } else if source == Some(AxisSource::Finger)
+        && !std::mem::replace(scrolling, true)
+    {
+        ScrollPhase::Started
+    } else {
+        ScrollPhase::Moved
+    }
+}
+
+/// Converts a logical IME cursor area into the surface local rectangle
+/// expected by `zwp_text_input_v3`, clamping it within the given logical
+/// `bounds` of the surface.
+pub fn ime_area(area: Rectangle, bounds: Size) -> Rectangle<i32> {
+    let x = area.x.clamp(0.0, bounds.width);
+    let y = area.y.clamp(0.0, bounds.height);
+    let width = area.width.min(bounds.width - x).max(0.0);
+    let height = area.height.min(bounds.height - y).max(0.0);
+
+    Rectangle {
+        x: x.round() as i32,
+        y: y.round() as i32,
+        width: width.round() as i32,
+        height: height.round() as i32,
+    }
+}
+
 pub fn modifiers_to_native(mods: Modifiers) -> keyboard::Modifiers {
     let mut native_mods = keyboard::Modifiers::empty();
     if mods.alt {
@@ -94,3 +140,61 @@ pub(crate) fn cursor_icon(cursor: Interaction) -> CursorIcon {
         Interaction::NotAllowed => CursorIcon::NotAllowed,
     }
 }
+
+pub(crate) fn decoration_mode(mode: DecorationMode) -> window::DecorationMode {
+    match mode {
+        DecorationMode::Client => window::DecorationMode::ClientSide,
+        DecorationMode::Server => window::DecorationMode::ServerSide,
+    }
+}
+
+/// Converts the [`OutputInfo`] of a Wayland output into a
+/// [`window::Monitor`].
+///
+/// The scale factor is the ratio between the current mode of the output and
+/// its logical size, which is fractional when the compositor scales the
+/// output by a fractional amount. The logical position of the output in the
+/// compositor space is scaled by it.
+pub fn monitor(info: &OutputInfo) -> window::Monitor {
+    let mode = info.modes.iter().find(|mode| mode.current);
+
+    // The logical size is transformed, unlike the mode
+    let mode_size = mode.map(|mode| match info.transform {
+        Transform::_90
+        | Transform::_270
+        | Transform::Flipped90
+        | Transform::Flipped270 => (mode.dimensions.1, mode.dimensions.0),
+        _ => mode.dimensions,
+    });
+
+    let scale_factor = match (mode_size, info.logical_size) {
+        (Some((width, _)), Some((logical_width, _))) if logical_width > 0 => {
+            f64::from(width) / f64::from(logical_width)
+        }
+        _ => f64::from(info.scale_factor),
+    };
+
+    let (width, height) = mode_size.unwrap_or_else(|| {
+        let (width, height) = info.logical_size.unwrap_or_default();
+
+        (
+            (f64::from(width) * scale_factor).round() as i32,
+            (f64::from(height) * scale_factor).round() as i32,
+        )
+    });
+
+    let (x, y) = info.logical_position.unwrap_or(info.location);
+
+    window::Monitor {
+        name: info.name.clone(),
+        position: (
+            (f64::from(x) * scale_factor).round() as i32,
+            (f64::from(y) * scale_factor).round() as i32,
+        ),
+        size: (width.max(0) as u32, height.max(0) as u32),
+        scale_factor,
+        refresh_rate_millihertz: mode
+            .and_then(|mode| u32::try_from(mode.refresh_rate).ok())
+            .filter(|refresh_rate| *refresh_rate > 0),
+    }
+}
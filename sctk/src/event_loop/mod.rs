@@ -13,7 +13,7 @@ use crate::{
     handlers::{
         activation::IcedRequestData,
         wp_fractional_scaling::FractionalScalingManager,
-        wp_viewporter::ViewporterState,
+        wp_text_input::TextInputManager, wp_viewporter::ViewporterState,
     },
     sctk_event::{
         DataSourceEvent, DndOfferEvent, IcedSctkEvent,
@@ -33,7 +33,7 @@ use iced_runtime::command::platform_specific::{
 };
 use sctk::{
     activation::{ActivationState, RequestData},
-    compositor::CompositorState,
+    compositor::{CompositorState, SurfaceData},
     data_device_manager::DataDeviceManagerState,
     globals::GlobalData,
     output::OutputState,
@@ -45,7 +45,7 @@ use sctk::{
         },
     },
     registry::RegistryState,
-    seat::SeatState,
+    seat::{pointer::CursorIcon, SeatState},
     session_lock::SessionLockState,
     shell::{wlr_layer::LayerShell, xdg::XdgShell, WaylandSurface},
     shm::Shm,
@@ -57,7 +57,7 @@ use sctk::{
 #[cfg(feature = "a11y")]
 use std::sync::{Arc, Mutex};
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     fmt::Debug,
     io::{BufRead, BufReader},
     num::NonZeroU32,
@@ -68,7 +68,7 @@ use wayland_backend::client::WaylandError;
 
 use self::{
     control_flow::ControlFlow,
-    state::{Dnd, LayerSurfaceCreationError, SctkState},
+    state::{CursorTheme, Dnd, LayerSurfaceCreationError, SctkState},
 };
 
 #[derive(Debug, Default, Clone, Copy)]
@@ -189,6 +189,7 @@ where
                 activation_state: ActivationState::bind(&globals, &qh).ok(),
                 session_lock_state: SessionLockState::new(&globals, &qh),
                 session_lock: None,
+                text_input_manager: TextInputManager::new(&globals, &qh).ok(),
 
                 queue_handle: qh,
                 loop_handle,
@@ -197,6 +198,7 @@ where
                 _multipool: None,
                 outputs: Vec::new(),
                 seats: Vec::new(),
+                cursor_icon: CursorIcon::Default,
                 windows: Vec::new(),
                 layer_surfaces: Vec::new(),
                 popups: Vec::new(),
@@ -206,6 +208,8 @@ where
                 touch_points: HashMap::new(),
                 sctk_events: Vec::new(),
                 frame_events: Vec::new(),
+                frame_callback_requests: HashSet::new(),
+                frame_clock: None,
                 pending_user_events: Vec::new(),
                 token_ctr: 0,
                 _accept_counter: 0,
@@ -536,6 +540,28 @@ where
             );
 
             for event in frame_event_back_buffer.drain(..) {
+                let requested = self
+                    .state
+                    .windows
+                    .iter()
+                    .find(|w| w.window.wl_surface() == &event.0)
+                    .map(|w| w.id)
+                    .is_some_and(|id| {
+                        self.state.frame_callback_requests.remove(&id)
+                    });
+                if requested {
+                    let time = self.state.frame_instant(event.1);
+
+                    sticky_exit_callback(
+                        IcedSctkEvent::SctkEvent(SctkEvent::WindowEvent {
+                            variant: WindowEventVariant::FrameCallback(time),
+                            id: event.0.clone(),
+                        }),
+                        &self.state,
+                        &mut control_flow,
+                        &mut callback,
+                    );
+                }
                 sticky_exit_callback(
                     IcedSctkEvent::Frame(event.0, event.1),
                     &self.state,
@@ -808,9 +834,18 @@ where
                         },
                     },
                     Event::SetCursor(iced_icon) => {
-                        if let Some(ptr) = self.state.seats.get(0).and_then(|s| s.ptr.as_ref()) {
-                            let icon = conversion::cursor_icon(iced_icon);
-                            let _ = ptr.set_cursor(self.wayland_dispatcher.as_source_ref().connection(), icon);
+                        let icon = conversion::cursor_icon(iced_icon);
+                        self.state.cursor_icon = icon;
+
+                        if let Some(seat) = self.state.seats.get(0) {
+                            // A custom cursor replaces the icons of the window it belongs to
+                            let has_custom_cursor = seat.ptr_focus.as_ref().is_some_and(|surface| {
+                                self.state.windows.iter().any(|w| w.window.wl_surface() == surface && w.custom_cursor.is_some())
+                            });
+
+                            if let Some(ptr) = seat.ptr.as_ref().filter(|_| !has_custom_cursor) {
+                                let _ = ptr.set_cursor(self.wayland_dispatcher.as_source_ref().connection(), icon);
+                            }
                         }
 
                     }
@@ -878,8 +913,14 @@ where
                         },
                         platform_specific::wayland::window::Action::Title { id, title } => {
                             if let Some(window) = self.state.windows.iter_mut().find(|w| w.id == id) {
-                                window.window.set_title(title);
+                                window.window.set_title(title.clone());
                                 to_commit.insert(id, window.window.wl_surface().clone());
+                                sticky_exit_callback(
+                                    IcedSctkEvent::SctkEvent(SctkEvent::WindowEvent { variant: WindowEventVariant::Title(title), id: window.window.wl_surface().clone()}),
+                                    &self.state,
+                                    &mut control_flow,
+                                    &mut callback,
+                                );
                             }
                         },
                         platform_specific::wayland::window::Action::Minimize { id } => {
@@ -942,6 +983,7 @@ where
                             if let Some(i) = self.state.windows.iter().position(|l| l.id == id) {
                                 let window = self.state.windows.remove(i);
                                 window.window.xdg_toplevel().destroy();
+                                let _ = self.state.frame_callback_requests.remove(&id);
                                 sticky_exit_callback(
                                     IcedSctkEvent::SctkEvent(SctkEvent::WindowEvent {
                                         variant: WindowEventVariant::Close,
@@ -987,6 +1029,101 @@ where
                                 to_commit.insert(id, window.window.wl_surface().clone());
                             }
                         },
+                        platform_specific::wayland::window::Action::CursorTheme { id, theme, size } => {
+                            if let Some(window) = self.state.windows.iter_mut().find(|w| w.id == id) {
+                                window.cursor_theme = CursorTheme { name: theme, size };
+                                let surface = window.window.wl_surface().clone();
+
+                                // Apply the theme right away to the seats hovering the window
+                                for index in 0..self.state.seats.len() {
+                                    if self.state.seats[index].ptr_focus.as_ref() == Some(&surface) {
+                                        self.state.update_cursor_theme(index, &surface);
+                                    }
+                                }
+                            }
+                        },
+                        platform_specific::wayland::window::Action::CustomCursor { id, cursor } => {
+                            if let Some(window) = self.state.windows.iter_mut().find(|w| w.id == id) {
+                                let show_icon = cursor.is_none();
+                                window.custom_cursor = cursor;
+                                let surface = window.window.wl_surface().clone();
+
+                                // Apply the cursor right away to the seats hovering the window
+                                for index in 0..self.state.seats.len() {
+                                    if self.state.seats[index].ptr_focus.as_ref() != Some(&surface) {
+                                        continue;
+                                    }
+
+                                    if show_icon {
+                                        let seat = &mut self.state.seats[index];
+                                        seat.custom_cursor = None;
+
+                                        if let Some(ptr) = seat.ptr.as_ref() {
+                                            let _ = ptr.set_cursor(self.wayland_dispatcher.as_source_ref().connection(), self.state.cursor_icon);
+                                        }
+                                    } else {
+                                        let _ = self.state.apply_custom_cursor(index, &surface);
+                                    }
+                                }
+                            }
+                        },
+                        platform_specific::wayland::window::Action::OutputName { id, message } => {
+                            let name = self.state.windows.iter().find(|w| w.id == id).and_then(|window| {
+                                let mut outputs = window.window.wl_surface().data::<SurfaceData>()?.outputs();
+                                let output = outputs.next()?;
+
+                                // The output is ambiguous while spanning several of them
+                                if outputs.next().is_some() {
+                                    return None;
+                                }
+
+                                self.state.output_state.info(&output)?.name
+                            });
+
+                            sticky_exit_callback(
+                                IcedSctkEvent::UserEvent(message(name)),
+                                &self.state,
+                                &mut control_flow,
+                                &mut callback,
+                            );
+                        },
+                        platform_specific::wayland::window::Action::ImeArea { id, area } => {
+                            if let Some(window) = self.state.windows.iter_mut().find(|w| w.id == id) {
+                                window.ime_area = Some(area);
+                                let surface = window.window.wl_surface();
+
+                                // Move the candidate window of the seats typing into the window
+                                if let (true, Some(area)) = (window.ime_allowed, window.ime_rectangle()) {
+                                    for text_input in self.state.seats.iter().filter(|seat| seat.text_input_focus.as_ref() == Some(surface)).filter_map(|seat| seat.text_input.as_ref()) {
+                                        text_input.set_cursor_rectangle(area.x, area.y, area.width, area.height);
+                                        text_input.commit();
+                                    }
+                                }
+                            }
+                        },
+                        platform_specific::wayland::window::Action::ImeAllowed { id, allowed } => {
+                            if let Some(window) = self.state.windows.iter_mut().find(|w| w.id == id) {
+                                if window.ime_allowed != allowed {
+                                    window.ime_allowed = allowed;
+                                    let surface = window.window.wl_surface().clone();
+
+                                    for index in 0..self.state.seats.len() {
+                                        if self.state.seats[index].text_input_focus.as_ref() == Some(&surface) {
+                                            self.state.set_text_input_enabled(index, &surface, allowed);
+                                        }
+                                    }
+                                }
+                            }
+                        },
+                        platform_specific::wayland::window::Action::RequestFrameCallback { id } => {
+                            if let Some(window) = self.state.windows.iter().find(|w| w.id == id) {
+                                let surface = window.window.wl_surface().clone();
+                                if self.state.frame_callback_requests.insert(id) {
+                                    surface.frame(&self.state.queue_handle, surface.clone());
+                                    to_commit.insert(id, surface);
+                                }
+                            }
+                        },
                     },
                     Event::Popup(action) => match action {
                         platform_specific::wayland::popup::Action::Popup { popup, .. } => {
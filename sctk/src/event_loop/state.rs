@@ -1,15 +1,17 @@
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     fmt::{Debug, Formatter},
     num::NonZeroU32,
+    time::{Duration, Instant},
 };
 
 use crate::{
     application::Event,
+    conversion,
     dpi::LogicalSize,
     handlers::{
         wp_fractional_scaling::FractionalScalingManager,
-        wp_viewporter::ViewporterState,
+        wp_text_input::TextInputManager, wp_viewporter::ViewporterState,
     },
     sctk_event::{
         LayerSurfaceEventVariant, PopupEventVariant, SctkEvent,
@@ -27,7 +29,9 @@ use iced_runtime::{
             window::SctkWindowSettings,
         },
     },
-    core::{touch, Point},
+    core::{
+        mouse::CustomCursor, touch, window::Ime, Point, Rectangle, Size,
+    },
     keyboard::Modifiers,
     window,
 };
@@ -49,6 +53,7 @@ use sctk::{
                 wl_output::WlOutput,
                 wl_region::WlRegion,
                 wl_seat::WlSeat,
+                wl_shm,
                 wl_subsurface::WlSubsurface,
                 wl_surface::{self, WlSurface},
                 wl_touch::WlTouch,
@@ -59,7 +64,7 @@ use sctk::{
     registry::RegistryState,
     seat::{
         keyboard::KeyEvent,
-        pointer::{CursorIcon, ThemedPointer},
+        pointer::{CursorIcon, PointerData, ThemeSpec, ThemedPointer},
         SeatState,
     },
     session_lock::{
@@ -78,8 +83,13 @@ use sctk::{
         },
         WaylandSurface,
     },
-    shm::{multi::MultiPool, Shm},
+    shm::{
+        multi::MultiPool,
+        slot::{Buffer, SlotPool},
+        Shm,
+    },
 };
+use sctk::reexports::protocols::wp::text_input::zv3::client::zwp_text_input_v3::ZwpTextInputV3;
 use wayland_protocols::wp::{
     fractional_scale::v1::client::wp_fractional_scale_v1::WpFractionalScaleV1,
     viewporter::client::wp_viewport::WpViewport,
@@ -99,6 +109,84 @@ pub(crate) struct SctkSeat {
     pub(crate) _modifiers: Modifiers,
     pub(crate) data_device: DataDevice,
     pub(crate) icon: Option<CursorIcon>,
+    pub(crate) cursor_theme: CursorTheme,
+    /// The memory of the custom cursor shown by the pointer, kept alive
+    /// until the compositor releases it.
+    pub(crate) custom_cursor: Option<(SlotPool, Buffer)>,
+    pub(crate) text_input: Option<ZwpTextInputV3>,
+    pub(crate) text_input_focus: Option<WlSurface>,
+    /// The composition and its cursor, applied on the next `done` event of
+    /// the text input
+    pub(crate) ime_preedit: Option<(String, Option<(usize, usize)>)>,
+    /// The committed text, applied on the next `done` event of the text
+    /// input
+    pub(crate) ime_commit: Option<String>,
+}
+
+/// The cursor theme of a pointer, falling back to the theme and size of the
+/// environment when unset.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct CursorTheme {
+    pub(crate) name: Option<String>,
+    pub(crate) size: Option<u32>,
+}
+
+impl CursorTheme {
+    /// Returns the name and size of the [`CursorTheme`], filling the missing
+    /// values from the environment.
+    fn resolve(&self) -> Option<(String, u32)> {
+        if self.name.is_none() && self.size.is_none() {
+            return None;
+        }
+
+        let name = self.name.clone().unwrap_or_else(|| {
+            std::env::var("XCURSOR_THEME")
+                .unwrap_or_else(|_| String::from("default"))
+        });
+
+        let size = self.size.unwrap_or_else(|| {
+            std::env::var("XCURSOR_SIZE")
+                .ok()
+                .and_then(|size| size.parse().ok())
+                .unwrap_or(24)
+        });
+
+        Some((name, size))
+    }
+}
+
+/// Returns whether a cursor theme with the given name is installed in any of
+/// the directories searched by `libXcursor`.
+fn cursor_theme_exists(name: &str) -> bool {
+    let directories: Vec<std::path::PathBuf> = if let Some(path) =
+        std::env::var_os("XCURSOR_PATH")
+    {
+        std::env::split_paths(&path).collect()
+    } else {
+        let home = std::env::var_os("HOME").map(std::path::PathBuf::from);
+        let data_home = std::env::var_os("XDG_DATA_HOME")
+            .map(std::path::PathBuf::from)
+            .or_else(|| home.as_ref().map(|home| home.join(".local/share")));
+        let data_dirs = std::env::var_os("XDG_DATA_DIRS")
+            .unwrap_or_else(|| "/usr/local/share:/usr/share".into());
+
+        data_home
+            .into_iter()
+            .map(|data_home| data_home.join("icons"))
+            .chain(home.map(|home| home.join(".icons")))
+            .chain(
+                std::env::split_paths(&data_dirs)
+                    .map(|data_dir| data_dir.join("icons")),
+            )
+            .chain(std::iter::once("/usr/share/pixmaps".into()))
+            .collect()
+    };
+
+    directories.iter().any(|directory| {
+        let theme = directory.join(name);
+
+        theme.join("cursors").is_dir() || theme.join("index.theme").is_file()
+    })
 }
 
 #[derive(Debug, Clone)]
@@ -115,9 +203,23 @@ pub struct SctkWindow<T> {
         Vec<platform_specific::wayland::window::Action<T>>,
     pub(crate) wp_fractional_scale: Option<WpFractionalScaleV1>,
     pub(crate) wp_viewport: Option<WpViewport>,
+    pub(crate) cursor_theme: CursorTheme,
+    pub(crate) custom_cursor: Option<CustomCursor>,
+    pub(crate) ime_allowed: bool,
+    /// The area of the text caret, where the IME candidate window is placed
+    pub(crate) ime_area: Option<Rectangle>,
 }
 
 impl<T> SctkWindow<T> {
+    /// Returns the area of the text caret, clamped within the window.
+    pub(crate) fn ime_rectangle(&self) -> Option<Rectangle<i32>> {
+        let bounds = self.current_size.map_or(Size::ZERO, |(width, height)| {
+            Size::new(width.get() as f32, height.get() as f32)
+        });
+
+        self.ime_area.map(|area| conversion::ime_area(area, bounds))
+    }
+
     pub(crate) fn set_size(&mut self, logical_size: LogicalSize<NonZeroU32>) {
         self.requested_size =
             Some((logical_size.width.get(), logical_size.height.get()));
@@ -278,6 +380,9 @@ pub struct SctkState<T> {
     // though (for now) only one seat will be active in an iced application at a time, all ought to be tracked
     // Active seat is the first seat in the list
     pub(crate) seats: Vec<SctkSeat>,
+    /// The cursor icon requested by the application, shown when no custom
+    /// cursor replaces it
+    pub(crate) cursor_icon: CursorIcon,
     // Windows / Surfaces
     /// Window list containing all SCTK windows. Since those windows aren't allowed
     /// to be sent to other threads, they live on the event loop's thread
@@ -303,6 +408,11 @@ pub struct SctkState<T> {
     /// event loop and forwarded downstream afterwards.
     pub(crate) sctk_events: Vec<SctkEvent>,
     pub(crate) frame_events: Vec<(WlSurface, u32)>,
+    /// Windows waiting for a requested frame callback
+    pub(crate) frame_callback_requests: HashSet<window::Id>,
+    /// A frame callback timestamp of the compositor and the [`Instant`] it
+    /// corresponds to
+    pub(crate) frame_clock: Option<(u32, Instant)>,
 
     /// pending user events
     pub pending_user_events: Vec<Event<T>>,
@@ -315,6 +425,7 @@ pub struct SctkState<T> {
     /// Viewporter state on the given window.
     pub viewporter_state: Option<ViewporterState<T>>,
     pub(crate) fractional_scaling_manager: Option<FractionalScalingManager<T>>,
+    pub(crate) text_input_manager: Option<TextInputManager<T>>,
     pub(crate) registry_state: RegistryState,
     pub(crate) seat_state: SeatState,
     pub(crate) output_state: OutputState,
@@ -370,6 +481,83 @@ pub enum LayerSurfaceCreationError {
 pub enum DndStartError {}
 
 impl<T> SctkState<T> {
+    /// Converts the timestamp of a frame callback to an [`Instant`].
+    ///
+    /// Timestamps are in milliseconds with an undefined base, so they are
+    /// measured from a timestamp received earlier. The clocks are
+    /// synchronized again if they drift apart.
+    pub(crate) fn frame_instant(&mut self, time: u32) -> Instant {
+        const MAX_DRIFT: Duration = Duration::from_secs(1);
+
+        let now = Instant::now();
+
+        if let Some((origin, origin_instant)) = self.frame_clock {
+            let instant = origin_instant
+                + Duration::from_millis(u64::from(time.wrapping_sub(origin)));
+
+            if instant.saturating_duration_since(now) < MAX_DRIFT
+                && now.saturating_duration_since(instant) < MAX_DRIFT
+            {
+                return instant;
+            }
+        }
+
+        self.frame_clock = Some((time, now));
+
+        now
+    }
+
+    /// Enables or disables the text input of the seat at `index`, which is
+    /// focused on the window with the given `surface`.
+    pub(crate) fn set_text_input_enabled(
+        &mut self,
+        index: usize,
+        surface: &WlSurface,
+        enabled: bool,
+    ) {
+        let Some(seat) = self.seats.get_mut(index) else {
+            return;
+        };
+
+        let Some(text_input) = seat.text_input.clone() else {
+            return;
+        };
+
+        let ime = if enabled {
+            text_input.enable();
+
+            // Enabling resets the state of the text input
+            if let Some(area) = self
+                .windows
+                .iter()
+                .find(|w| w.window.wl_surface() == surface)
+                .and_then(SctkWindow::ime_rectangle)
+            {
+                text_input.set_cursor_rectangle(
+                    area.x,
+                    area.y,
+                    area.width,
+                    area.height,
+                );
+            }
+
+            Ime::Enabled
+        } else {
+            text_input.disable();
+            seat.ime_preedit = None;
+            seat.ime_commit = None;
+
+            Ime::Disabled
+        };
+
+        text_input.commit();
+
+        self.sctk_events.push(SctkEvent::WindowEvent {
+            variant: WindowEventVariant::Ime(ime),
+            id: surface.clone(),
+        });
+    }
+
     pub fn scale_factor_changed(
         &mut self,
         surface: &WlSurface,
@@ -450,6 +638,172 @@ impl<T> SctkState<T>
 where
     T: 'static + Debug,
 {
+    /// Loads the cursor theme requested by the window with the given `surface`
+    /// for the pointer of the seat at `index`, if it differs from the current
+    /// one.
+    ///
+    /// The pointer is created again, since its theme cannot be changed.
+    pub(crate) fn update_cursor_theme(
+        &mut self,
+        index: usize,
+        surface: &WlSurface,
+    ) {
+        let theme = self
+            .windows
+            .iter()
+            .find(|w| w.window.wl_surface() == surface)
+            .map(|w| w.cursor_theme.clone())
+            .unwrap_or_default();
+
+        let Some(seat) = self.seats.get_mut(index) else {
+            return;
+        };
+
+        if seat.ptr.is_none() || seat.cursor_theme == theme {
+            return;
+        }
+
+        let resolved = theme.resolve();
+
+        if let Some((name, _)) = &resolved {
+            if !cursor_theme_exists(name) {
+                tracing::warn!(
+                    "Cursor theme {name:?} not found, falling back to the \
+                    default theme."
+                );
+            }
+        }
+
+        let spec = match &resolved {
+            Some((name, size)) => ThemeSpec::Named { name, size: *size },
+            None => ThemeSpec::System,
+        };
+
+        let cursor_surface =
+            self.compositor_state.create_surface(&self.queue_handle);
+
+        match self.seat_state.get_pointer_with_theme(
+            &self.queue_handle,
+            &seat.seat,
+            self.shm_state.wl_shm(),
+            cursor_surface,
+            spec,
+        ) {
+            Ok(ptr) => {
+                seat.ptr.replace(ptr);
+                seat.cursor_theme = theme;
+                seat.icon = None;
+            }
+            Err(error) => {
+                tracing::warn!("Failed to load cursor theme: {error}");
+            }
+        }
+    }
+
+    /// Shows the custom cursor of the window with the given `surface` on the
+    /// pointer of the seat at the given `index`.
+    ///
+    /// Returns `false` if the window has no custom cursor or it could not be
+    /// shown.
+    pub(crate) fn apply_custom_cursor(
+        &mut self,
+        index: usize,
+        surface: &WlSurface,
+    ) -> bool {
+        let Some(cursor) = self
+            .windows
+            .iter()
+            .find(|w| w.window.wl_surface() == surface)
+            .and_then(|w| w.custom_cursor.as_ref())
+        else {
+            return false;
+        };
+
+        let Some(seat) = self.seats.get_mut(index) else {
+            return false;
+        };
+
+        let Some(ptr) = seat.ptr.as_ref() else {
+            return false;
+        };
+
+        let Some(serial) = ptr
+            .pointer()
+            .data::<PointerData>()
+            .and_then(|data| data.latest_enter_serial())
+        else {
+            return false;
+        };
+
+        let size = cursor.size();
+        let (width, height) = (size.width as i32, size.height as i32);
+
+        let mut pool =
+            match SlotPool::new((width * height * 4) as usize, &self.shm_state)
+            {
+                Ok(pool) => pool,
+                Err(error) => {
+                    tracing::warn!("Failed to create a custom cursor: {error}");
+                    return false;
+                }
+            };
+
+        let (buffer, canvas) = match pool.create_buffer(
+            width,
+            height,
+            width * 4,
+            wl_shm::Format::Argb8888,
+        ) {
+            Ok(buffer) => buffer,
+            Err(error) => {
+                tracing::warn!("Failed to create a custom cursor: {error}");
+                return false;
+            }
+        };
+
+        // `Argb8888` is little-endian and premultiplied
+        for (pixel, rgba) in canvas
+            .chunks_exact_mut(4)
+            .zip(cursor.rgba().chunks_exact(4))
+        {
+            let alpha = u32::from(rgba[3]);
+            let premultiply =
+                |channel: u8| ((u32::from(channel) * alpha + 127) / 255) as u8;
+
+            pixel.copy_from_slice(&[
+                premultiply(rgba[2]),
+                premultiply(rgba[1]),
+                premultiply(rgba[0]),
+                rgba[3],
+            ]);
+        }
+
+        let cursor_surface = ptr.surface();
+
+        if let Err(error) = buffer.attach_to(cursor_surface) {
+            tracing::warn!("Failed to attach a custom cursor: {error}");
+            return false;
+        }
+
+        cursor_surface.set_buffer_scale(1);
+        cursor_surface.damage_buffer(0, 0, width, height);
+        cursor_surface.commit();
+
+        let hotspot = cursor.hotspot();
+
+        ptr.pointer().set_cursor(
+            serial,
+            Some(cursor_surface),
+            hotspot.x as i32,
+            hotspot.y as i32,
+        );
+
+        seat.custom_cursor = Some((pool, buffer));
+        seat.icon = None;
+
+        true
+    }
+
     pub fn get_popup(
         &mut self,
         settings: SctkPopupSettings,
@@ -735,6 +1089,10 @@ where
             resizable,
             wp_viewport,
             wp_fractional_scale,
+            cursor_theme: CursorTheme::default(),
+            custom_cursor: None,
+            ime_allowed: false,
+            ime_area: None,
         });
         (window_id, wl_surface)
     }
@@ -27,10 +27,10 @@ impl<T: Debug> CompositorHandler for SctkState<T> {
         _conn: &Connection,
         _qh: &QueueHandle<Self>,
         surface: &wl_surface::WlSurface,
-        _time: u32,
+        time: u32,
     ) {
-        // TODO time; map subsurface to parent:w
-        self.frame_events.push((surface.clone(), 0));
+        // TODO map subsurface to parent
+        self.frame_events.push((surface.clone(), time));
     }
 
     fn transform_changed(
@@ -8,6 +8,7 @@ pub mod session_lock;
 pub mod shell;
 pub mod subcompositor;
 pub mod wp_fractional_scaling;
+pub mod wp_text_input;
 pub mod wp_viewporter;
 
 use sctk::{
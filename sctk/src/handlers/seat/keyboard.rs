@@ -165,6 +165,17 @@ impl<T: Debug> KeyboardHandler for SctkState<T> {
         }
     }
 
+    fn update_repeat_info(
+        &mut self,
+        _conn: &sctk::reexports::client::Connection,
+        _qh: &sctk::reexports::client::QueueHandle<Self>,
+        _keyboard: &sctk::reexports::client::protocol::wl_keyboard::WlKeyboard,
+        _info: sctk::seat::keyboard::RepeatInfo,
+    ) {
+        #[cfg(feature = "system")]
+        crate::system::set_key_repeat(_info);
+    }
+
     fn update_modifiers(
         &mut self,
         _conn: &sctk::reexports::client::Connection,
@@ -15,7 +15,7 @@ impl<T: Debug> PointerHandler for SctkState<T> {
         pointer: &sctk::reexports::client::protocol::wl_pointer::WlPointer,
         events: &[sctk::seat::pointer::PointerEvent],
     ) {
-        let (is_active, my_seat) =
+        let (seat_index, my_seat) =
             match self.seats.iter_mut().enumerate().find_map(|(i, s)| {
                 if s.ptr.as_ref().map(|p| p.pointer()) == Some(pointer) {
                     Some((i, s))
@@ -23,11 +23,15 @@ impl<T: Debug> PointerHandler for SctkState<T> {
                     None
                 }
             }) {
-                Some((i, s)) => (i == 0, s),
+                Some((i, s)) => (i, s),
                 None => return,
             };
+        let primary = seat_index == 0;
+        let mut entered = None;
+        let mut left_border = None;
 
-        // track events, but only forward for the active seat
+        // track events of every seat, which are told apart by whether they
+        // belong to the primary seat
         for e in events {
             // check if it is over a resizable window's border and handle the event yourself if it is.
             if let Some((resize_edge, window)) = self
@@ -129,18 +133,19 @@ impl<T: Debug> PointerHandler for SctkState<T> {
                     .unwrap()
                     .set_cursor(conn, CursorIcon::Default);
                 my_seat.icon = None;
+                left_border = Some(e.surface.clone());
             }
 
-            if is_active {
-                self.sctk_events.push(SctkEvent::PointerEvent {
-                    variant: e.clone(),
-                    ptr_id: pointer.clone(),
-                    seat_id: my_seat.seat.clone(),
-                });
-            }
+            self.sctk_events.push(SctkEvent::PointerEvent {
+                variant: e.clone(),
+                ptr_id: pointer.clone(),
+                seat_id: my_seat.seat.clone(),
+                primary,
+            });
             match e.kind {
                 PointerEventKind::Enter { .. } => {
                     my_seat.ptr_focus.replace(e.surface.clone());
+                    entered = Some(e.surface.clone());
                 }
                 PointerEventKind::Leave { .. } => {
                     my_seat.ptr_focus.take();
@@ -157,6 +162,14 @@ impl<T: Debug> PointerHandler for SctkState<T> {
                 _ => {}
             }
         }
+
+        if let Some(surface) = &entered {
+            self.update_cursor_theme(seat_index, surface);
+        }
+
+        if let Some(surface) = entered.or(left_border) {
+            let _ = self.apply_custom_cursor(seat_index, &surface);
+        }
     }
 }
 
@@ -1,5 +1,5 @@
 use crate::{
-    event_loop::{state::SctkSeat, state::SctkState},
+    event_loop::{state::CursorTheme, state::SctkSeat, state::SctkState},
     sctk_event::{KeyboardEventVariant, SctkEvent, SeatEventVariant},
 };
 use iced_runtime::keyboard::Modifiers;
@@ -30,6 +30,10 @@ where
         });
         let data_device =
             self.data_device_manager_state.get_data_device(qh, &seat);
+        let text_input = self
+            .text_input_manager
+            .as_ref()
+            .map(|manager| manager.get_text_input(&seat, qh));
         self.seats.push(SctkSeat {
             seat,
             kbd: None,
@@ -43,6 +47,12 @@ where
             last_kbd_press: None,
             last_touch_down: None,
             icon: None,
+            cursor_theme: CursorTheme::default(),
+            custom_cursor: None,
+            text_input,
+            text_input_focus: None,
+            ime_preedit: None,
+            ime_commit: None,
         });
     }
 
@@ -71,6 +81,15 @@ where
                     last_kbd_press: None,
                     last_touch_down: None,
                     icon: None,
+                    cursor_theme: CursorTheme::default(),
+                    custom_cursor: None,
+                    text_input: self
+                        .text_input_manager
+                        .as_ref()
+                        .map(|manager| manager.get_text_input(&seat, qh)),
+                    text_input_focus: None,
+                    ime_preedit: None,
+                    ime_commit: None,
                 });
                 self.seats.last_mut().unwrap()
             }
@@ -110,7 +129,7 @@ where
                     &seat,
                     self.shm_state.wl_shm(),
                     surface,
-                    ThemeSpec::default(),
+                    ThemeSpec::System,
                 ) {
                     self.sctk_events.push(SctkEvent::SeatEvent {
                         variant: SeatEventVariant::NewCapability(
@@ -201,7 +220,10 @@ where
             id: seat.clone(),
         });
         if let Some(i) = self.seats.iter().position(|s| s.seat == seat) {
-            self.seats.remove(i);
+            let seat = self.seats.remove(i);
+            if let Some(text_input) = seat.text_input {
+                text_input.destroy();
+            }
         }
     }
 }
@@ -0,0 +1,159 @@
+//! Handling of the text-input-unstable-v3 protocol.
+
+use std::marker::PhantomData;
+
+use sctk::reexports::client::globals::{BindError, GlobalList};
+use sctk::reexports::client::protocol::wl_seat::WlSeat;
+use sctk::reexports::client::protocol::wl_surface::WlSurface;
+use sctk::reexports::client::Dispatch;
+use sctk::reexports::client::{
+    delegate_dispatch, Connection, Proxy, QueueHandle,
+};
+use sctk::reexports::protocols::wp::text_input::zv3::client::zwp_text_input_manager_v3::ZwpTextInputManagerV3;
+use sctk::reexports::protocols::wp::text_input::zv3::client::zwp_text_input_v3::{
+    Event as TextInputEvent, ZwpTextInputV3,
+};
+
+use sctk::globals::GlobalData;
+
+use crate::event_loop::state::SctkState;
+use crate::sctk_event::{SctkEvent, WindowEventVariant};
+use iced_runtime::core::window::Ime;
+
+/// Text input manager.
+#[derive(Debug)]
+pub struct TextInputManager<T> {
+    manager: ZwpTextInputManagerV3,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: 'static> TextInputManager<T> {
+    /// Create new text input manager.
+    pub fn new(
+        globals: &GlobalList,
+        queue_handle: &QueueHandle<SctkState<T>>,
+    ) -> Result<Self, BindError> {
+        let manager = globals.bind(queue_handle, 1..=1, GlobalData)?;
+        Ok(Self {
+            manager,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Get the text input of the given seat.
+    pub fn get_text_input(
+        &self,
+        seat: &WlSeat,
+        queue_handle: &QueueHandle<SctkState<T>>,
+    ) -> ZwpTextInputV3 {
+        self.manager.get_text_input(seat, queue_handle, GlobalData)
+    }
+}
+
+impl<T: 'static> Dispatch<ZwpTextInputManagerV3, GlobalData, SctkState<T>>
+    for TextInputManager<T>
+{
+    fn event(
+        _: &mut SctkState<T>,
+        _: &ZwpTextInputManagerV3,
+        _: <ZwpTextInputManagerV3 as Proxy>::Event,
+        _: &GlobalData,
+        _: &Connection,
+        _: &QueueHandle<SctkState<T>>,
+    ) {
+        // No events.
+    }
+}
+
+impl<T: 'static> Dispatch<ZwpTextInputV3, GlobalData, SctkState<T>>
+    for TextInputManager<T>
+{
+    fn event(
+        state: &mut SctkState<T>,
+        text_input: &ZwpTextInputV3,
+        event: TextInputEvent,
+        _: &GlobalData,
+        _: &Connection,
+        _: &QueueHandle<SctkState<T>>,
+    ) {
+        let Some(index) = state
+            .seats
+            .iter()
+            .position(|seat| seat.text_input.as_ref() == Some(text_input))
+        else {
+            return;
+        };
+
+        let ime_allowed = |state: &SctkState<T>, surface: &WlSurface| {
+            state
+                .windows
+                .iter()
+                .any(|w| w.ime_allowed && w.window.wl_surface() == surface)
+        };
+
+        let seat = &mut state.seats[index];
+
+        match event {
+            TextInputEvent::Enter { surface } => {
+                seat.text_input_focus = Some(surface.clone());
+
+                if ime_allowed(state, &surface) {
+                    state.set_text_input_enabled(index, &surface, true);
+                }
+            }
+            TextInputEvent::Leave { surface } => {
+                seat.text_input_focus = None;
+
+                if ime_allowed(state, &surface) {
+                    state.set_text_input_enabled(index, &surface, false);
+                }
+            }
+            TextInputEvent::PreeditString {
+                text,
+                cursor_begin,
+                cursor_end,
+            } => {
+                // A negative cursor position hides the cursor
+                let cursor = usize::try_from(cursor_begin)
+                    .ok()
+                    .zip(usize::try_from(cursor_end).ok());
+
+                seat.ime_preedit = Some((text.unwrap_or_default(), cursor));
+            }
+            TextInputEvent::CommitString { text } => {
+                seat.ime_commit = text;
+            }
+            TextInputEvent::Done { .. } => {
+                let Some(surface) = seat.text_input_focus.clone() else {
+                    return;
+                };
+
+                // The composition is cleared before the commit, and replaced
+                // by the pending one afterwards
+                let mut events = vec![Ime::Preedit {
+                    content: String::new(),
+                    cursor: None,
+                }];
+
+                if let Some(text) = seat.ime_commit.take() {
+                    events.push(Ime::Commit(text));
+                }
+
+                if let Some((content, cursor)) = seat.ime_preedit.take() {
+                    events.push(Ime::Preedit { content, cursor });
+                }
+
+                state.sctk_events.extend(events.into_iter().map(|ime| {
+                    SctkEvent::WindowEvent {
+                        variant: WindowEventVariant::Ime(ime),
+                        id: surface.clone(),
+                    }
+                }));
+            }
+            _ => {}
+        }
+    }
+}
+
+delegate_dispatch!(@<T: 'static> SctkState<T>: [ZwpTextInputManagerV3: GlobalData] => TextInputManager<T>);
+delegate_dispatch!(@<T: 'static> SctkState<T>: [ZwpTextInputV3: GlobalData] => TextInputManager<T>);
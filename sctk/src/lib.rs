@@ -7,6 +7,7 @@ pub mod error;
 pub mod event_loop;
 mod handlers;
 pub mod keymap;
+mod monitor;
 pub mod result;
 pub mod sctk_event;
 pub mod settings;
@@ -0,0 +1,32 @@
+//! Query the monitors of the Wayland compositor.
+use crate::core::window::Monitor;
+use crate::runtime::system::MonitorLayout;
+
+use sctk::output::OutputInfo;
+use sctk::reexports::client::protocol::wl_output::WlOutput;
+
+/// Returns the [`MonitorLayout`] of the given outputs of the compositor.
+///
+/// Wayland has no notion of a primary output, so it is never known.
+pub(crate) fn layout(outputs: &[(WlOutput, OutputInfo)]) -> MonitorLayout {
+    MonitorLayout {
+        monitors: outputs
+            .iter()
+            .map(|(_, info)| crate::conversion::monitor(info))
+            .collect(),
+        primary: None,
+    }
+}
+
+/// Returns the scale factor of the output of the given [`Monitor`] among the
+/// given outputs of the compositor, if it is still connected.
+pub(crate) fn scale_factor(
+    outputs: &[(WlOutput, OutputInfo)],
+    monitor: &Monitor,
+) -> Option<f64> {
+    outputs
+        .iter()
+        .map(|(_, info)| crate::conversion::monitor(info))
+        .find(|output| output.is_same(monitor))
+        .map(|output| output.scale_factor)
+}
@@ -1,7 +1,8 @@
 use crate::{
     application::SurfaceIdWrapper,
     conversion::{
-        modifiers_to_native, pointer_axis_to_native, pointer_button_to_native,
+        modifiers_to_native, pointer_axis_phase, pointer_axis_to_native,
+        pointer_button_to_native,
     },
     dpi::PhysicalSize,
     keymap::{self, keysym_to_key},
@@ -13,8 +14,9 @@ use iced_futures::core::event::{
     PlatformSpecific,
 };
 use iced_runtime::{
+    clipboard,
     command::platform_specific::wayland::data_device::DndIcon,
-    core::{event::wayland, keyboard, mouse, touch, window, Point},
+    core::{event::wayland, keyboard, mouse, touch, window, Font, Point},
     keyboard::{key, Key, Location},
     window::Id as SurfaceId,
 };
@@ -41,7 +43,11 @@ use sctk::{
         xdg::{popup::PopupConfigure, window::WindowConfigure},
     },
 };
-use std::{collections::HashMap, time::Instant};
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    time::Instant,
+};
 use wayland_protocols::wp::viewporter::client::wp_viewport::WpViewport;
 use xkeysym::Keysym;
 
@@ -124,6 +130,15 @@ pub enum IcedSctkEvent<T> {
     /// Frame callback event
     Frame(WlSurface, u32),
 
+    /// The bytes of a font loaded asynchronously are available
+    FontLoaded(Font, Cow<'static, [u8]>),
+
+    /// The time to live of some contents written to the clipboard elapsed
+    ClipboardExpired(clipboard::Target, String),
+
+    /// A global keyboard shortcut was triggered
+    GlobalShortcut(keyboard::shortcut::Id),
+
     Subcompositor(SubsurfaceState<T>),
 }
 
@@ -140,6 +155,8 @@ pub enum SctkEvent {
         variant: PointerEvent,
         ptr_id: WlPointer,
         seat_id: WlSeat,
+        /// Whether the pointer belongs to the primary seat
+        primary: bool,
     },
     KeyboardEvent {
         variant: KeyboardEventVariant,
@@ -316,6 +333,12 @@ pub enum WindowEventVariant {
     StateChanged(sctk::reexports::csd_frame::WindowState),
     /// Scale Factor
     ScaleFactorChanged(f64, Option<WpViewport>),
+    /// A requested frame callback was received, with the time of the frame
+    FrameCallback(Instant),
+    /// The title of the window was changed by an action
+    Title(String),
+    /// An input method event of a seat focused on the window
+    Ime(window::Ime),
 }
 
 #[derive(Debug, Clone)]
@@ -400,6 +423,7 @@ impl SctkEvent {
     pub fn to_native(
         self,
         modifiers: &mut Modifiers,
+        scrolling: &mut HashSet<ObjectId>,
         surface_ids: &HashMap<ObjectId, SurfaceIdWrapper>,
         destroyed_surface_ids: &HashMap<ObjectId, SurfaceIdWrapper>,
         subsurface_ids: &HashMap<ObjectId, (i32, i32, SurfaceIdWrapper)>,
@@ -407,72 +431,106 @@ impl SctkEvent {
         match self {
             // TODO Ashley: Platform specific multi-seat events?
             SctkEvent::SeatEvent { .. } => Default::default(),
-            SctkEvent::PointerEvent { variant, .. } => match variant.kind {
-                PointerEventKind::Enter { .. } => {
-                    vec![iced_runtime::core::Event::Mouse(
-                        mouse::Event::CursorEntered,
-                    )]
-                }
-                PointerEventKind::Leave { .. } => {
-                    vec![iced_runtime::core::Event::Mouse(
-                        mouse::Event::CursorLeft,
-                    )]
-                }
-                PointerEventKind::Motion { .. } => {
-                    let offset = if let Some((x_offset, y_offset, _)) =
-                        subsurface_ids.get(&variant.surface.id())
-                    {
-                        (*x_offset, *y_offset)
-                    } else {
-                        (0, 0)
-                    };
-                    vec![iced_runtime::core::Event::Mouse(
-                        mouse::Event::CursorMoved {
+            SctkEvent::PointerEvent {
+                variant,
+                seat_id,
+                primary,
+                ..
+            } => {
+                let events: Vec<mouse::Event> = match variant.kind {
+                    PointerEventKind::Enter { .. } => {
+                        vec![mouse::Event::CursorEntered]
+                    }
+                    PointerEventKind::Leave { .. } => {
+                        vec![mouse::Event::CursorLeft]
+                    }
+                    PointerEventKind::Motion { .. } => {
+                        let offset = if let Some((x_offset, y_offset, _)) =
+                            subsurface_ids.get(&variant.surface.id())
+                        {
+                            (*x_offset, *y_offset)
+                        } else {
+                            (0, 0)
+                        };
+                        vec![mouse::Event::CursorMoved {
                             position: Point::new(
                                 variant.position.0 as f32 + offset.0 as f32,
                                 variant.position.1 as f32 + offset.1 as f32,
                             ),
-                        },
-                    )]
-                }
-                PointerEventKind::Press {
-                    time: _,
-                    button,
-                    serial: _,
-                } => pointer_button_to_native(button)
-                    .map(|b| {
-                        iced_runtime::core::Event::Mouse(
-                            mouse::Event::ButtonPressed(b),
-                        )
-                    })
-                    .into_iter()
-                    .collect(), // TODO Ashley: conversion
-                PointerEventKind::Release {
-                    time: _,
-                    button,
-                    serial: _,
-                } => pointer_button_to_native(button)
-                    .map(|b| {
-                        iced_runtime::core::Event::Mouse(
-                            mouse::Event::ButtonReleased(b),
-                        )
-                    })
+                        }]
+                    }
+                    PointerEventKind::Press {
+                        time: _,
+                        button,
+                        serial: _,
+                    } => pointer_button_to_native(button)
+                        .map(mouse::Event::ButtonPressed)
+                        .into_iter()
+                        .collect(), // TODO Ashley: conversion
+                    PointerEventKind::Release {
+                        time: _,
+                        button,
+                        serial: _,
+                    } => pointer_button_to_native(button)
+                        .map(mouse::Event::ButtonReleased)
+                        .into_iter()
+                        .collect(), // TODO Ashley: conversion
+                    PointerEventKind::Axis {
+                        time: _,
+                        horizontal,
+                        vertical,
+                        source,
+                    } => {
+                        let mut seat_scrolling =
+                            scrolling.contains(&seat_id.id());
+                        let phase = pointer_axis_phase(
+                            source,
+                            horizontal,
+                            vertical,
+                            &mut seat_scrolling,
+                        );
+
+                        if seat_scrolling {
+                            let _ = scrolling.insert(seat_id.id());
+                        } else {
+                            let _ = scrolling.remove(&seat_id.id());
+                        }
+
+                        // The frame that stops a gesture may not carry a
+                        // source, but its phase must still be reported
+                        pointer_axis_to_native(source, horizontal, vertical)
+                            .or((phase == mouse::ScrollPhase::Ended).then_some(
+                                mouse::ScrollDelta::Pixels { x: 0.0, y: 0.0 },
+                            ))
+                            .map(|delta| mouse::Event::WheelScrolled {
+                                delta,
+                                phase,
+                            })
+                            .into_iter()
+                            .collect()
+                    }
+                };
+
+                // Only the primary seat drives the widgets, while the pointers
+                // of other seats are tagged with their seat
+                events
                     .into_iter()
-                    .collect(), // TODO Ashley: conversion
-                PointerEventKind::Axis {
-                    time: _,
-                    horizontal,
-                    vertical,
-                    source,
-                } => pointer_axis_to_native(source, horizontal, vertical)
-                    .map(|a| {
-                        iced_runtime::core::Event::Mouse(
-                            mouse::Event::WheelScrolled { delta: a },
-                        )
+                    .map(|event| {
+                        if primary {
+                            iced_runtime::core::Event::Mouse(event)
+                        } else {
+                            iced_runtime::core::Event::PlatformSpecific(
+                                PlatformSpecific::Wayland(
+                                    wayland::Event::Pointer(
+                                        event,
+                                        seat_id.clone(),
+                                    ),
+                                ),
+                            )
+                        }
                     })
-                    .into_iter()
-                    .collect(), // TODO Ashley: conversion
-            },
+                    .collect()
+            }
             SctkEvent::KeyboardEvent {
                 variant,
                 kbd_id: _,
@@ -592,6 +650,7 @@ impl SctkEvent {
                             location: location,
                             text: ke.utf8.map(|s| s.into()),
                             modifiers: modifiers_to_native(*modifiers),
+                            repeat: false,
                         },
                     ))
                     .into_iter()
@@ -607,6 +666,7 @@ impl SctkEvent {
                             location: location,
                             text: utf8.map(|s| s.into()),
                             modifiers: modifiers_to_native(*modifiers),
+                            repeat: true,
                         },
                     ))
                     .into_iter()
@@ -695,9 +755,28 @@ impl SctkEvent {
                         Default::default()
                     }
                 }
-                WindowEventVariant::ScaleFactorChanged(..) => {
-                    Default::default()
-                }
+                WindowEventVariant::ScaleFactorChanged(..)
+                | WindowEventVariant::Title(_) => Default::default(),
+                WindowEventVariant::FrameCallback(time) => surface_ids
+                    .get(&surface.id())
+                    .map(|id| {
+                        iced_runtime::core::Event::Window(
+                            id.inner(),
+                            window::Event::FrameCallback(time),
+                        )
+                    })
+                    .into_iter()
+                    .collect(),
+                WindowEventVariant::Ime(ime) => surface_ids
+                    .get(&surface.id())
+                    .map(|id| {
+                        iced_runtime::core::Event::Window(
+                            id.inner(),
+                            window::Event::Ime(ime),
+                        )
+                    })
+                    .into_iter()
+                    .collect(),
                 WindowEventVariant::StateChanged(s) => surface_ids
                     .get(&surface.id())
                     .map(|id| {
@@ -176,6 +176,23 @@ pub trait Application: Sized {
         1.0
     }
 
+    /// Returns the [`Graphics`] settings used to create the compositor of the
+    /// [`Application`], given its settings.
+    ///
+    /// It can be used to choose a [`Backend`], its present mode, or the
+    /// antialiasing strategy explicitly.
+    ///
+    /// By default, the [`Graphics`] settings are derived from the settings of
+    /// the [`Application`].
+    ///
+    /// [`Graphics`]: crate::settings::Graphics
+    /// [`Backend`]: crate::settings::Backend
+    fn graphics_settings(
+        settings: &Settings<Self::Flags>,
+    ) -> crate::settings::Graphics {
+        settings.into()
+    }
+
     /// Runs the [`Application`].
     ///
     /// On native platforms, this method will take control of the current thread
@@ -189,23 +206,13 @@ pub trait Application: Sized {
     where
         Self: 'static,
     {
-        #[allow(clippy::needless_update)]
-        let renderer_settings = crate::renderer::Settings {
-            default_font: settings.default_font,
-            default_text_size: settings.default_text_size,
-            antialiasing: if settings.antialiasing {
-                Some(crate::graphics::Antialiasing::MSAAx4)
-            } else {
-                None
-            },
-            ..crate::renderer::Settings::default()
-        };
+        let graphics_settings = Self::graphics_settings(&settings);
 
         Ok(crate::shell::application::run::<
             Instance<Self>,
             Self::Executor,
             crate::renderer::Compositor,
-        >(settings.into(), renderer_settings)?)
+        >(settings.into(), graphics_settings)?)
     }
 }
 
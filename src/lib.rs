@@ -225,7 +225,7 @@ pub use crate::core::{
 pub mod clipboard {
     //! Access the clipboard.
     pub use crate::runtime::clipboard::{
-        read, read_primary, write, write_primary,
+        read, read_primary, read_uris, write, write_html, write_primary, Html,
     };
     pub use dnd;
     pub use iced_core::clipboard::{read_data, read_primary_data};
@@ -284,6 +284,7 @@ pub mod command {
 
 pub mod subscription {
     //! Listen to external events in your application.
+    pub use crate::runtime::theme::palette_changes;
     pub use iced_futures::subscription::{
         channel, run, run_with_id, unfold, Subscription,
     };
@@ -292,7 +293,11 @@ pub mod subscription {
 #[cfg(feature = "system")]
 pub mod system {
     //! Retrieve system information.
-    pub use crate::runtime::system::Information;
+    pub use crate::runtime::system::{
+        Backend, CancellationToken, DiskInfo, DiskInfoError,
+        GlobalShortcutError, Information, KeyRepeat, MonitorLayout,
+        ProcessInfo, RenderStats,
+    };
     pub use crate::shell::system::*;
 }
 
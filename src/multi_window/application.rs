@@ -143,6 +143,20 @@ pub trait Application: Sized {
         Subscription::none()
     }
 
+    /// Returns the event [`Subscription`] of the `window` of the
+    /// [`Application`].
+    ///
+    /// It is only kept alive while the `window` is open, and it is cancelled
+    /// as soon as the `window` closes.
+    ///
+    /// By default, it returns an empty subscription.
+    fn window_subscription(
+        &self,
+        _window: window::Id,
+    ) -> Subscription<Self::Message> {
+        Subscription::none()
+    }
+
     /// Returns the scale factor of the `window` of the [`Application`].
     ///
     /// It can be used to dynamically control the size of the UI at runtime
@@ -157,6 +171,23 @@ pub trait Application: Sized {
         1.0
     }
 
+    /// Returns the [`Graphics`] settings used to create the compositor of the
+    /// [`Application`], given its settings.
+    ///
+    /// It can be used to choose a [`Backend`], its present mode, or the
+    /// antialiasing strategy explicitly.
+    ///
+    /// By default, the [`Graphics`] settings are derived from the settings of
+    /// the [`Application`].
+    ///
+    /// [`Graphics`]: crate::settings::Graphics
+    /// [`Backend`]: crate::settings::Backend
+    fn graphics_settings(
+        settings: &Settings<Self::Flags>,
+    ) -> crate::settings::Graphics {
+        settings.into()
+    }
+
     /// Runs the multi-window [`Application`].
     ///
     /// On native platforms, this method will take control of the current thread
@@ -170,23 +201,13 @@ pub trait Application: Sized {
     where
         Self: 'static,
     {
-        #[allow(clippy::needless_update)]
-        let renderer_settings = crate::renderer::Settings {
-            default_font: settings.default_font,
-            default_text_size: settings.default_text_size,
-            antialiasing: if settings.antialiasing {
-                Some(crate::graphics::Antialiasing::MSAAx4)
-            } else {
-                None
-            },
-            ..crate::renderer::Settings::default()
-        };
+        let graphics_settings = Self::graphics_settings(&settings);
 
         Ok(crate::shell::multi_window::run::<
             Instance<Self>,
             Self::Executor,
             crate::renderer::Compositor,
-        >(settings.into(), renderer_settings)?)
+        >(settings.into(), graphics_settings)?)
     }
 }
 
@@ -240,6 +261,13 @@ where
         self.0.subscription()
     }
 
+    fn window_subscription(
+        &self,
+        window: window::Id,
+    ) -> Subscription<Self::Message> {
+        self.0.window_subscription(window)
+    }
+
     fn scale_factor(&self, window: window::Id) -> f64 {
         self.0.scale_factor(window)
     }
@@ -1,5 +1,8 @@
 //! Configure your application
 
+use crate::graphics::Antialiasing;
+#[cfg(feature = "winit")]
+use crate::time::Duration;
 #[cfg(feature = "winit")]
 use crate::window;
 use crate::{Font, Pixels};
@@ -7,9 +10,24 @@ use crate::{Font, Pixels};
 #[cfg(feature = "wayland")]
 use iced_sctk::settings::InitialSurface;
 use std::borrow::Cow;
+use std::fmt;
+#[cfg(feature = "winit")]
+use std::sync::Arc;
+
+/// The settings of the graphics of an application, used to create its
+/// compositor.
+pub use crate::renderer::Settings as Graphics;
+
+pub use crate::renderer::graphics::compositor::Backend;
+
+#[cfg(feature = "wgpu")]
+pub use crate::renderer::wgpu::wgpu::{Backends, PresentMode};
+
+/// A raw window event of the windowing system, before it is converted.
+#[cfg(feature = "winit")]
+pub use iced_winit::winit::event::WindowEvent as RawWindowEvent;
 
 /// The settings of an application.
-#[derive(Debug)]
 pub struct Settings<Flags> {
     /// The identifier of the application.
     ///
@@ -53,6 +71,93 @@ pub struct Settings<Flags> {
 
     /// If set to true the application will exit when the main window is closed.
     pub exit_on_close_request: bool,
+
+    /// The maximum amount of pending messages to process right before drawing
+    /// a window.
+    ///
+    /// When set, messages that arrive while a redraw is pending are fed to the
+    /// application before the window is drawn, instead of waiting for the next
+    /// iteration of the event loop. The limit bounds the latency of the first
+    /// visible update under heavy load.
+    ///
+    /// By default, it is disabled.
+    #[cfg(feature = "winit")]
+    pub batch_messages_before_draw: Option<usize>,
+
+    /// Whether to collapse consecutive [`CursorMoved`] events into the latest
+    /// position before they are processed.
+    ///
+    /// This keeps the application responsive with high polling rate mice.
+    /// Windows that need every sample, like the canvas of a drawing
+    /// application, can opt out with [`window::set_precise_mouse_motion`].
+    ///
+    /// By default, it is disabled.
+    ///
+    /// [`CursorMoved`]: crate::mouse::Event::CursorMoved
+    /// [`window::set_precise_mouse_motion`]: crate::window::set_precise_mouse_motion
+    #[cfg(feature = "winit")]
+    pub coalesce_mouse_motion: bool,
+
+    /// The longest time a single iteration of the event loop may take before
+    /// a warning is logged, naming the phase of the application that stalled
+    /// it, like [`update`] or drawing.
+    ///
+    /// This helps finding what freezes the user interface during development.
+    ///
+    /// By default, it is disabled.
+    ///
+    /// [`update`]: crate::Application::update
+    #[cfg(feature = "winit")]
+    pub frame_budget: Option<Duration>,
+
+    /// A callback invoked with every [`RawWindowEvent`] received by the
+    /// application, before it is converted.
+    ///
+    /// This is meant for debugging and logging purposes only. The callback
+    /// runs inside the event loop, so it must be cheap to avoid slowing it
+    /// down.
+    ///
+    /// By default, it is disabled.
+    #[cfg(feature = "winit")]
+    #[allow(clippy::type_complexity)]
+    pub on_raw_event: Option<Arc<dyn Fn(&RawWindowEvent) + Send + Sync>>,
+}
+
+impl<Flags> fmt::Debug for Settings<Flags>
+where
+    Flags: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("Settings");
+
+        debug.field("id", &self.id);
+
+        #[cfg(feature = "winit")]
+        debug.field("window", &self.window);
+
+        #[cfg(feature = "wayland")]
+        debug.field("initial_surface", &self.initial_surface);
+
+        debug
+            .field("flags", &self.flags)
+            .field("fonts", &self.fonts)
+            .field("default_font", &self.default_font)
+            .field("default_text_size", &self.default_text_size)
+            .field("antialiasing", &self.antialiasing)
+            .field("exit_on_close_request", &self.exit_on_close_request);
+
+        #[cfg(feature = "winit")]
+        debug
+            .field(
+                "batch_messages_before_draw",
+                &self.batch_messages_before_draw,
+            )
+            .field("coalesce_mouse_motion", &self.coalesce_mouse_motion)
+            .field("frame_budget", &self.frame_budget)
+            .field("on_raw_event", &self.on_raw_event.is_some());
+
+        debug.finish()
+    }
 }
 
 #[cfg(not(any(feature = "winit", feature = "wayland")))]
@@ -106,6 +211,11 @@ impl<Flags> Settings<Flags> {
             default_text_size: default_settings.default_text_size,
             antialiasing: default_settings.antialiasing,
             exit_on_close_request: default_settings.exit_on_close_request,
+            batch_messages_before_draw: default_settings
+                .batch_messages_before_draw,
+            coalesce_mouse_motion: default_settings.coalesce_mouse_motion,
+            frame_budget: default_settings.frame_budget,
+            on_raw_event: default_settings.on_raw_event,
         }
     }
 }
@@ -125,6 +235,10 @@ where
             default_text_size: Pixels(14.0),
             antialiasing: false,
             exit_on_close_request: false,
+            batch_messages_before_draw: None,
+            coalesce_mouse_motion: false,
+            frame_budget: None,
+            on_raw_event: None,
         }
     }
 }
@@ -137,6 +251,10 @@ impl<Flags> From<Settings<Flags>> for iced_winit::Settings<Flags> {
             window: settings.window,
             flags: settings.flags,
             fonts: settings.fonts,
+            batch_messages_before_draw: settings.batch_messages_before_draw,
+            coalesce_mouse_motion: settings.coalesce_mouse_motion,
+            frame_budget: settings.frame_budget,
+            on_raw_event: settings.on_raw_event,
         }
     }
 }
@@ -194,3 +312,18 @@ impl<Flags> From<Settings<Flags>> for iced_sctk::Settings<Flags> {
         }
     }
 }
+
+impl<Flags> From<&Settings<Flags>> for Graphics {
+    fn from(settings: &Settings<Flags>) -> Graphics {
+        Graphics {
+            default_font: settings.default_font,
+            default_text_size: settings.default_text_size,
+            antialiasing: if settings.antialiasing {
+                Some(Antialiasing::MSAAx4)
+            } else {
+                None
+            },
+            ..Graphics::default()
+        }
+    }
+}
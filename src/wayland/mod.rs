@@ -5,7 +5,6 @@ use crate::{Command, Element, Executor, Settings as Settings_, Subscription};
 pub mod sandbox;
 pub use crate::runtime::command::platform_specific::wayland as actions;
 pub use crate::style::application::{Appearance, StyleSheet};
-use iced_renderer::graphics::Antialiasing;
 pub use iced_sctk::{application::SurfaceIdWrapper, commands::*, settings::*};
 
 /// A pure version of [`Application`].
@@ -102,6 +101,23 @@ pub trait Application: Sized {
         1.0
     }
 
+    /// Returns the [`Graphics`] settings used to create the compositor of the
+    /// [`Application`], given its settings.
+    ///
+    /// It can be used to choose a [`Backend`], its present mode, or the
+    /// antialiasing strategy explicitly.
+    ///
+    /// By default, the [`Graphics`] settings are derived from the settings of
+    /// the [`Application`].
+    ///
+    /// [`Graphics`]: crate::settings::Graphics
+    /// [`Backend`]: crate::settings::Backend
+    fn graphics_settings(
+        settings: &Settings_<Self::Flags>,
+    ) -> crate::settings::Graphics {
+        settings.into()
+    }
+
     /// Runs the [`Application`].
     ///
     /// On native platforms, this method will take control of the current thread
@@ -115,23 +131,13 @@ pub trait Application: Sized {
     where
         Self: 'static,
     {
-        #[allow(clippy::needless_update)]
-        let renderer_settings = crate::renderer::Settings {
-            default_font: settings.default_font,
-            default_text_size: settings.default_text_size,
-            antialiasing: if settings.antialiasing {
-                Some(Antialiasing::MSAAx4)
-            } else {
-                None
-            },
-            ..crate::renderer::Settings::default()
-        };
+        let graphics_settings = Self::graphics_settings(&settings);
 
         Ok(crate::shell::application::run::<
             Instance<Self>,
             Self::Executor,
             crate::renderer::Compositor,
-        >(settings.into(), renderer_settings)?)
+        >(settings.into(), graphics_settings)?)
     }
 }
 
@@ -1,4 +1,5 @@
 //! Change the appearance of an application.
+use iced_core::theme::Palette;
 use iced_core::Color;
 
 /// A set of rules that dictate the style of an application.
@@ -10,6 +11,24 @@ pub trait StyleSheet {
     ///
     /// [`Style`]: Self::Style
     fn appearance(&self, style: &Self::Style) -> Appearance;
+
+    /// Returns the logical width of the resize border of the windows without
+    /// decorations for the provided [`Style`], if the theme provides one.
+    ///
+    /// Windows only follow it once they are synced with the theme.
+    ///
+    /// [`Style`]: Self::Style
+    fn resize_border(&self, _style: &Self::Style) -> Option<u32> {
+        None
+    }
+
+    /// Returns the [`Palette`] of the theme, if it has one.
+    ///
+    /// Applications are notified whenever the [`Palette`] of their theme
+    /// changes.
+    fn palette(&self) -> Option<Palette> {
+        None
+    }
 }
 
 /// The appearance of an application.
@@ -2,6 +2,7 @@
 pub mod palette;
 
 pub use self::palette::Palette;
+pub use crate::core::theme::Mode;
 
 use crate::application;
 use crate::button;
@@ -148,6 +149,17 @@ impl application::StyleSheet for Theme {
             Application::Custom(custom) => custom.appearance(self),
         }
     }
+
+    fn resize_border(&self, style: &Self::Style) -> Option<u32> {
+        match style {
+            Application::Default => None,
+            Application::Custom(custom) => custom.resize_border(self),
+        }
+    }
+
+    fn palette(&self) -> Option<Palette> {
+        Some(Theme::palette(self))
+    }
 }
 
 impl<T: Fn(&Theme) -> application::Appearance> application::StyleSheet for T {
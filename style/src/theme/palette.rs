@@ -1,74 +1,13 @@
 //! Define the colors of a theme.
 use iced_core::Color;
 
+pub use iced_core::theme::Palette;
+
 use once_cell::sync::Lazy;
 use palette::color_difference::Wcag21RelativeContrast;
 use palette::rgb::Rgb;
 use palette::{FromColor, Hsl, Mix};
 
-/// A color palette.
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct Palette {
-    /// The background [`Color`] of the [`Palette`].
-    pub background: Color,
-    /// The text [`Color`] of the [`Palette`].
-    pub text: Color,
-    /// The primary [`Color`] of the [`Palette`].
-    pub primary: Color,
-    /// The success [`Color`] of the [`Palette`].
-    pub success: Color,
-    /// The danger [`Color`] of the [`Palette`].
-    pub danger: Color,
-}
-
-impl Palette {
-    /// The built-in light variant of a [`Palette`].
-    pub const LIGHT: Self = Self {
-        background: Color::WHITE,
-        text: Color::BLACK,
-        primary: Color::from_rgb(
-            0x5E as f32 / 255.0,
-            0x7C as f32 / 255.0,
-            0xE2 as f32 / 255.0,
-        ),
-        success: Color::from_rgb(
-            0x12 as f32 / 255.0,
-            0x66 as f32 / 255.0,
-            0x4F as f32 / 255.0,
-        ),
-        danger: Color::from_rgb(
-            0xC3 as f32 / 255.0,
-            0x42 as f32 / 255.0,
-            0x3F as f32 / 255.0,
-        ),
-    };
-
-    /// The built-in dark variant of a [`Palette`].
-    pub const DARK: Self = Self {
-        background: Color::from_rgb(
-            0x20 as f32 / 255.0,
-            0x22 as f32 / 255.0,
-            0x25 as f32 / 255.0,
-        ),
-        text: Color::from_rgb(0.90, 0.90, 0.90),
-        primary: Color::from_rgb(
-            0x5E as f32 / 255.0,
-            0x7C as f32 / 255.0,
-            0xE2 as f32 / 255.0,
-        ),
-        success: Color::from_rgb(
-            0x12 as f32 / 255.0,
-            0x66 as f32 / 255.0,
-            0x4F as f32 / 255.0,
-        ),
-        danger: Color::from_rgb(
-            0xC3 as f32 / 255.0,
-            0x42 as f32 / 255.0,
-            0x3F as f32 / 255.0,
-        ),
-    };
-}
-
 /// An extended set of colors generated from a [`Palette`].
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Extended {
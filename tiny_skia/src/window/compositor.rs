@@ -1,7 +1,8 @@
+use crate::core::window::{PixelFormat, SurfaceFormat};
 use crate::core::{Color, Rectangle, Size};
 use crate::graphics::compositor::{self, Information};
 use crate::graphics::damage;
-use crate::graphics::{Error, Viewport};
+use crate::graphics::{ColorProfile, Error, Viewport};
 use crate::{Backend, Primitive, Renderer, Settings};
 
 use std::collections::VecDeque;
@@ -89,9 +90,35 @@ impl crate::graphics::Compositor for Compositor {
     }
 
     fn fetch_information(&self) -> Information {
-        Information {
-            adapter: String::from("CPU"),
-            backend: String::from("tiny-skia"),
+        Information::new("CPU", "tiny-skia")
+    }
+
+    fn backend(&self) -> Option<compositor::Backend> {
+        Some(compositor::Backend::TinySkia)
+    }
+
+    fn fetch_surface_format(
+        &self,
+        _surface: &Self::Surface,
+    ) -> Option<SurfaceFormat> {
+        // Frames are drawn to RGBA pixmaps, like the bytes of screenshots.
+        // Packing them as `0RGB` for `softbuffer` is an implementation detail
+        Some(SurfaceFormat {
+            pixel_format: PixelFormat::Rgba8,
+            srgb: true,
+        })
+    }
+
+    fn set_color_profile(
+        &mut self,
+        _renderer: &mut Self::Renderer,
+        profile: Option<ColorProfile>,
+    ) {
+        if profile.is_some() {
+            log::warn!(
+                "Color profiles are not supported by tiny-skia; \
+                frames will be presented as sRGB"
+            );
         }
     }
 
@@ -134,6 +161,28 @@ impl crate::graphics::Compositor for Compositor {
             )
         })
     }
+
+    fn render_offscreen<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    ) -> Option<Vec<u8>> {
+        let size = viewport.physical_size();
+        let mut clip_mask = tiny_skia::Mask::new(size.width, size.height)?;
+
+        Some(renderer.with_primitives(|backend, primitives| {
+            render_offscreen(
+                &mut clip_mask,
+                backend,
+                primitives,
+                viewport,
+                background_color,
+                overlay,
+            )
+        }))
+    }
 }
 
 pub fn new<W: compositor::Window>(
@@ -218,6 +267,26 @@ pub fn screenshot<T: AsRef<str>>(
     viewport: &Viewport,
     background_color: Color,
     overlay: &[T],
+) -> Vec<u8> {
+    render_offscreen(
+        &mut surface.clip_mask,
+        backend,
+        primitives,
+        viewport,
+        background_color,
+        overlay,
+    )
+}
+
+/// Draws the given primitives to an offscreen buffer with the given clip
+/// mask, which must have the physical size of the [`Viewport`].
+fn render_offscreen<T: AsRef<str>>(
+    clip_mask: &mut tiny_skia::Mask,
+    backend: &mut Backend,
+    primitives: &[Primitive],
+    viewport: &Viewport,
+    background_color: Color,
+    overlay: &[T],
 ) -> Vec<u8> {
     let size = viewport.physical_size();
 
@@ -231,7 +300,7 @@ pub fn screenshot<T: AsRef<str>>(
             size.height,
         )
         .expect("Create offscreen pixel map"),
-        &mut surface.clip_mask,
+        clip_mask,
         primitives,
         viewport,
         &[Rectangle::with_size(Size::new(
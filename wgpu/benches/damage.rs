@@ -0,0 +1,172 @@
+//! Compares presenting a dashboard with a static chart and a live ticker
+//! with and without retained frames.
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use iced_wgpu::core::{Background, Border, Color, Rectangle, Shadow, Size};
+use iced_wgpu::graphics::renderer::Frames;
+use iced_wgpu::graphics::Viewport;
+use iced_wgpu::{wgpu, Backend, Primitive, Settings};
+
+const SIZE: Size<u32> = Size::new(1920, 1080);
+const FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Bgra8UnormSrgb;
+const BARS: usize = 2_000;
+
+criterion_main!(benches);
+criterion_group!(benches, present);
+
+fn present(c: &mut Criterion) {
+    let Some((device, queue)) = pollster::block_on(device()) else {
+        eprintln!("No adapter available, skipping benchmark");
+        return;
+    };
+
+    let target = device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("iced_wgpu.bench.target"),
+        size: wgpu::Extent3d {
+            width: SIZE.width,
+            height: SIZE.height,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: FORMAT,
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+        view_formats: &[],
+    });
+
+    let view = target.create_view(&wgpu::TextureViewDescriptor::default());
+    let viewport = Viewport::with_physical_size(SIZE, 1.0);
+
+    let mut group = c.benchmark_group("dashboard");
+
+    for retained in [false, true] {
+        let name = if retained { "retained" } else { "full" };
+
+        let mut backend =
+            Backend::new(&device, &queue, Settings::default(), FORMAT);
+
+        let mut tick = 0;
+        let mut previous = dashboard(tick);
+
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                tick += 1;
+
+                let current = dashboard(tick);
+
+                let mut encoder = device.create_command_encoder(
+                    &wgpu::CommandEncoderDescriptor {
+                        label: Some("iced_wgpu.bench.encoder"),
+                    },
+                );
+
+                if retained {
+                    backend.present_retained::<&str>(
+                        &device,
+                        &queue,
+                        &mut encoder,
+                        Some(Color::WHITE),
+                        FORMAT,
+                        &view,
+                        Frames {
+                            number: tick,
+                            current: &current,
+                            previous: &previous,
+                        },
+                        &viewport,
+                        &[],
+                    );
+                } else {
+                    backend.present::<&str>(
+                        &device,
+                        &queue,
+                        &mut encoder,
+                        Some(Color::WHITE),
+                        FORMAT,
+                        &view,
+                        &current,
+                        &viewport,
+                        &[],
+                    );
+                }
+
+                let _ = queue.submit(Some(encoder.finish()));
+                let _ = device.poll(wgpu::Maintain::Wait);
+
+                previous = current;
+            });
+        });
+
+        let stats = backend.render_stats();
+
+        println!(
+            "dashboard/{name}: {} quads and {} draw calls per frame",
+            stats.quads, stats.draw_calls
+        );
+    }
+
+    group.finish();
+}
+
+async fn device() -> Option<(wgpu::Device, wgpu::Queue)> {
+    let instance = wgpu::Instance::default();
+
+    let adapter = instance
+        .request_adapter(&wgpu::RequestAdapterOptions::default())
+        .await?;
+
+    adapter
+        .request_device(
+            &wgpu::DeviceDescriptor {
+                label: Some("iced_wgpu.bench.device"),
+                required_features: wgpu::Features::empty(),
+                required_limits: wgpu::Limits::downlevel_defaults(),
+            },
+            None,
+        )
+        .await
+        .ok()
+}
+
+/// A static chart made of many bars and a ticker that changes every frame.
+fn dashboard(tick: u64) -> Vec<Primitive> {
+    let bar_width = SIZE.width as f32 / BARS as f32;
+
+    let chart = (0..BARS)
+        .map(|i| {
+            let height = (i * 7919 % 800) as f32;
+
+            quad(
+                Rectangle {
+                    x: i as f32 * bar_width,
+                    y: 900.0 - height,
+                    width: bar_width,
+                    height,
+                },
+                Color::from_rgb8(0x33, 0x66, 0x99),
+            )
+        })
+        .collect();
+
+    let ticker = quad(
+        Rectangle {
+            x: 20.0,
+            y: 960.0,
+            width: (tick % 400) as f32 + 1.0,
+            height: 60.0,
+        },
+        Color::from_rgb8(0xCC, 0x33, 0x33),
+    );
+
+    vec![Primitive::group(chart), ticker]
+}
+
+fn quad(bounds: Rectangle, color: Color) -> Primitive {
+    Primitive::Quad {
+        bounds,
+        background: Background::Color(color),
+        border: Border::default(),
+        shadow: Shadow::default(),
+    }
+}
@@ -1,6 +1,13 @@
+use crate::color_profile;
 use crate::core::{Color, Size};
+#[cfg(feature = "damage")]
+use crate::damage;
 use crate::graphics::backend;
 use crate::graphics::color;
+use crate::graphics::compositor::RenderStats;
+#[cfg(feature = "damage")]
+use crate::graphics::renderer::Frames;
+use crate::graphics::ColorProfile;
 use crate::graphics::{Transformation, Viewport};
 use crate::primitive::pipeline;
 use crate::primitive::{self, Primitive};
@@ -27,6 +34,14 @@ pub struct Backend {
     text_pipeline: text::Pipeline,
     triangle_pipeline: triangle::Pipeline,
     pipeline_storage: pipeline::Storage,
+    render_stats: RenderStats,
+    color_profile: Option<ColorProfile>,
+    color_profile_pipeline: Option<color_profile::Pipeline>,
+
+    #[cfg(feature = "damage")]
+    damage_tracking: bool,
+    #[cfg(feature = "damage")]
+    damage_pipeline: Option<damage::Pipeline>,
 
     #[cfg(any(feature = "image", feature = "svg"))]
     image_pipeline: image::Pipeline,
@@ -53,6 +68,14 @@ impl Backend {
             text_pipeline,
             triangle_pipeline,
             pipeline_storage: pipeline::Storage::default(),
+            render_stats: RenderStats::default(),
+            color_profile: settings.color_profile,
+            color_profile_pipeline: None,
+
+            #[cfg(feature = "damage")]
+            damage_tracking: true,
+            #[cfg(feature = "damage")]
+            damage_pipeline: None,
 
             #[cfg(any(feature = "image", feature = "svg"))]
             image_pipeline,
@@ -89,6 +112,8 @@ impl Backend {
             layers.push(Layer::overlay(overlay_text, viewport));
         }
 
+        self.render_stats = render_stats(&layers, scale_factor);
+
         self.prepare(
             device,
             queue,
@@ -100,16 +125,201 @@ impl Backend {
             &layers,
         );
 
-        self.render(
+        if let Some(profile) = self.color_profile {
+            let mut pipeline = self
+                .color_profile_pipeline
+                .take()
+                .filter(|pipeline| pipeline.format() == format)
+                .unwrap_or_else(|| {
+                    color_profile::Pipeline::new(device, format)
+                });
+
+            self.render(
+                device,
+                encoder,
+                pipeline.target(device, target_size),
+                clear_color,
+                scale_factor,
+                target_size,
+                &layers,
+            );
+
+            pipeline.apply(queue, encoder, &profile, frame);
+
+            self.color_profile_pipeline = Some(pipeline);
+        } else {
+            self.render(
+                device,
+                encoder,
+                frame,
+                clear_color,
+                scale_factor,
+                target_size,
+                &layers,
+            );
+        }
+
+        self.end_frame();
+    }
+
+    /// Draws the current primitives of the provided [`Frames`] in the given
+    /// `TextureView`.
+    ///
+    /// If damage tracking is enabled and the background is opaque, the frame
+    /// is retained between calls and only the regions where the primitives
+    /// changed since the previous frame are drawn again. Otherwise, this is
+    /// the same as [`present`].
+    ///
+    /// [`present`]: Self::present
+    #[cfg(feature = "damage")]
+    pub fn present_retained<T: AsRef<str>>(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        encoder: &mut wgpu::CommandEncoder,
+        clear_color: Option<Color>,
+        format: wgpu::TextureFormat,
+        frame: &wgpu::TextureView,
+        frames: Frames<'_, primitive::Custom>,
+        viewport: &Viewport,
+        overlay_text: &[T],
+    ) {
+        let background_color = clear_color.filter(|color| {
+            self.damage_tracking
+                && color.a >= 1.0
+                && self.color_profile.is_none()
+                && overlay_text.is_empty()
+        });
+
+        let Some(background_color) = background_color else {
+            self.present(
+                device,
+                queue,
+                encoder,
+                clear_color,
+                format,
+                frame,
+                frames.current,
+                viewport,
+                overlay_text,
+            );
+
+            return;
+        };
+
+        log::debug!("Drawing damage");
+        #[cfg(feature = "tracing")]
+        let _ = info_span!("Wgpu::Backend", "PRESENT").entered();
+
+        self.present_damage(
             device,
+            queue,
             encoder,
+            background_color,
+            format,
             frame,
-            clear_color,
-            scale_factor,
-            target_size,
-            &layers,
+            frames,
+            viewport,
         );
 
+        self.end_frame();
+    }
+
+    /// Draws the damaged regions of the provided [`Frames`] in the retained
+    /// frame, and copies it to the given `TextureView`.
+    #[cfg(feature = "damage")]
+    fn present_damage(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        encoder: &mut wgpu::CommandEncoder,
+        background_color: Color,
+        format: wgpu::TextureFormat,
+        frame: &wgpu::TextureView,
+        frames: Frames<'_, primitive::Custom>,
+        viewport: &Viewport,
+    ) {
+        use crate::core::Background;
+        use crate::quad::Quad;
+
+        let target_size = viewport.physical_size();
+        let scale_factor = viewport.scale_factor() as f32;
+        let transformation = viewport.projection();
+
+        let mut pipeline = self
+            .damage_pipeline
+            .take()
+            .filter(|pipeline| pipeline.format() == format)
+            .unwrap_or_else(|| damage::Pipeline::new(device, format));
+
+        let region = pipeline.damage(frames, viewport, background_color);
+
+        if let Some(region) = region {
+            let mut layers = Layer::generate(frames.current, viewport);
+
+            // Only draw the layers inside the damaged region
+            layers.retain_mut(|layer| {
+                match layer.bounds.intersection(&region) {
+                    Some(bounds) => {
+                        layer.bounds = bounds;
+                        true
+                    }
+                    None => false,
+                }
+            });
+
+            // The background is opaque, so it replaces the stale contents of
+            // the damaged region
+            let mut background = Layer::new(region);
+
+            background.quads.add(
+                Quad {
+                    position: [region.x, region.y],
+                    size: [region.width, region.height],
+                    border_color: color::pack(Color::TRANSPARENT),
+                    border_radius: [0.0; 4],
+                    border_width: 0.0,
+                    shadow_color: [0.0; 4],
+                    shadow_offset: [0.0; 2],
+                    shadow_blur_radius: 0.0,
+                },
+                &Background::Color(background_color),
+            );
+
+            layers.insert(0, background);
+
+            self.render_stats = render_stats(&layers, scale_factor);
+
+            self.prepare(
+                device,
+                queue,
+                format,
+                encoder,
+                scale_factor,
+                target_size,
+                transformation,
+                &layers,
+            );
+
+            self.render(
+                device,
+                encoder,
+                pipeline.target(device, target_size),
+                None,
+                scale_factor,
+                target_size,
+                &layers,
+            );
+        } else {
+            self.render_stats = RenderStats::default();
+        }
+
+        pipeline.present(encoder, frame);
+
+        self.damage_pipeline = Some(pipeline);
+    }
+
+    fn end_frame(&mut self) {
         self.quad_pipeline.end_frame();
         self.text_pipeline.end_frame();
         self.triangle_pipeline.end_frame();
@@ -118,6 +328,40 @@ impl Backend {
         self.image_pipeline.end_frame();
     }
 
+    /// Sets the output [`ColorProfile`] applied to the frames presented by
+    /// the [`Backend`], or resets it to sRGB when `None`.
+    pub fn set_color_profile(&mut self, profile: Option<ColorProfile>) {
+        self.color_profile = profile;
+
+        if profile.is_none() {
+            self.color_profile_pipeline = None;
+        }
+    }
+
+    /// Sets whether the [`Backend`] retains its frames between presents and
+    /// only draws their damaged regions again.
+    ///
+    /// This has no effect unless the `damage` feature is enabled.
+    pub fn set_damage_tracking(&mut self, enabled: bool) {
+        #[cfg(feature = "damage")]
+        {
+            self.damage_tracking = enabled;
+
+            if !enabled {
+                self.damage_pipeline = None;
+            }
+        }
+
+        #[cfg(not(feature = "damage"))]
+        let _ = enabled;
+    }
+
+    /// Returns the [`RenderStats`] of the last frame presented by the
+    /// [`Backend`].
+    pub fn render_stats(&self) -> RenderStats {
+        self.render_stats
+    }
+
     fn prepare(
         &mut self,
         device: &wgpu::Device,
@@ -367,6 +611,31 @@ impl Backend {
     }
 }
 
+/// Counts the primitives and draw calls needed to render the given layers.
+fn render_stats(layers: &[Layer<'_>], scale_factor: f32) -> RenderStats {
+    let mut stats = RenderStats::default();
+
+    for layer in layers {
+        let bounds = (layer.bounds * scale_factor).snap();
+
+        if bounds.width < 1 || bounds.height < 1 {
+            continue;
+        }
+
+        stats.quads += layer.quads.len();
+        stats.meshes += layer.meshes.len();
+        stats.text_runs += layer.text.len();
+
+        stats.draw_calls += layer.quads.draw_calls()
+            + layer.meshes.len()
+            + usize::from(!layer.images.is_empty())
+            + usize::from(!layer.text.is_empty())
+            + layer.pipelines.len();
+    }
+
+    stats
+}
+
 impl crate::graphics::Backend for Backend {
     type Primitive = primitive::Custom;
 }
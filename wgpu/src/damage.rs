@@ -0,0 +1,251 @@
+use crate::core::{Color, Rectangle, Size};
+use crate::graphics::damage;
+use crate::graphics::renderer::Frames;
+use crate::graphics::Viewport;
+use crate::primitive;
+
+use std::borrow::Cow;
+
+/// A render pipeline that retains the last frame presented in an intermediate
+/// texture, so that only its damaged regions need to be drawn again before
+/// copying it to its final target.
+#[derive(Debug)]
+pub struct Pipeline {
+    pipeline: wgpu::RenderPipeline,
+    layout: wgpu::BindGroupLayout,
+    format: wgpu::TextureFormat,
+    target: Option<Target>,
+    last: Option<Frame>,
+}
+
+#[derive(Debug)]
+struct Target {
+    size: Size<u32>,
+    view: wgpu::TextureView,
+    bind_group: wgpu::BindGroup,
+}
+
+/// The last frame drawn in the [`Target`].
+#[derive(Debug)]
+struct Frame {
+    number: u64,
+    scale_factor: f64,
+    background_color: Color,
+}
+
+impl Pipeline {
+    pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Self {
+        let layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("iced_wgpu.damage.bind_group_layout"),
+                entries: &[wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float {
+                            filterable: false,
+                        },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                }],
+            });
+
+        let pipeline_layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("iced_wgpu.damage.pipeline_layout"),
+                bind_group_layouts: &[&layout],
+                push_constant_ranges: &[],
+            });
+
+        let shader =
+            device.create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: Some("iced_wgpu.damage.shader"),
+                source: wgpu::ShaderSource::Wgsl(Cow::Borrowed(include_str!(
+                    "shader/damage.wgsl"
+                ))),
+            });
+
+        let pipeline =
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some("iced_wgpu.damage.pipeline"),
+                layout: Some(&pipeline_layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: "vs_main",
+                    buffers: &[],
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: "fs_main",
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format,
+                        blend: None,
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                }),
+                primitive: wgpu::PrimitiveState {
+                    topology: wgpu::PrimitiveTopology::TriangleList,
+                    front_face: wgpu::FrontFace::Cw,
+                    ..wgpu::PrimitiveState::default()
+                },
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState::default(),
+                multiview: None,
+            });
+
+        Self {
+            pipeline,
+            layout,
+            format,
+            target: None,
+            last: None,
+        }
+    }
+
+    /// Returns the [`wgpu::TextureFormat`] of the targets of the [`Pipeline`].
+    pub fn format(&self) -> wgpu::TextureFormat {
+        self.format
+    }
+
+    /// Computes the logical region of the retained frame that must be drawn
+    /// again to display the current [`Frames`], if any.
+    ///
+    /// The whole viewport is damaged if the size, scale factor, or background
+    /// color of the frame changed since the retained frame was drawn, or if
+    /// the retained frame is not the previous one.
+    pub fn damage(
+        &mut self,
+        frames: Frames<'_, primitive::Custom>,
+        viewport: &Viewport,
+        background_color: Color,
+    ) -> Option<Rectangle> {
+        let size = viewport.physical_size();
+        let scale_factor = viewport.scale_factor();
+        let bounds = Rectangle::with_size(viewport.logical_size());
+
+        let is_retained =
+            self.target.as_ref().map(|target| target.size) == Some(size);
+
+        let regions = match &self.last {
+            Some(last)
+                if is_retained
+                    && last.scale_factor == scale_factor
+                    && last.background_color == background_color =>
+            {
+                if last.number == frames.number {
+                    Vec::new()
+                } else if last.number.wrapping_add(1) == frames.number {
+                    damage::list(frames.previous, frames.current)
+                } else {
+                    vec![bounds]
+                }
+            }
+            _ => vec![bounds],
+        };
+
+        self.last = Some(Frame {
+            number: frames.number,
+            scale_factor,
+            background_color,
+        });
+
+        let region = regions
+            .into_iter()
+            .reduce(|a, b| a.union(&b))?
+            .intersection(&bounds)?;
+
+        // Align the region to the physical pixel grid, so that clearing it
+        // does not leave any partially covered pixels behind
+        let scale_factor = scale_factor as f32;
+        let snapped = (region * scale_factor).snap();
+
+        Some(Rectangle {
+            x: snapped.x as f32 / scale_factor,
+            y: snapped.y as f32 / scale_factor,
+            width: snapped.width as f32 / scale_factor,
+            height: snapped.height as f32 / scale_factor,
+        })
+    }
+
+    /// Returns the intermediate texture holding the retained frame of the
+    /// given size.
+    ///
+    /// The retained frame is discarded if its size changed, so the damage
+    /// must be computed before calling this method.
+    pub fn target(
+        &mut self,
+        device: &wgpu::Device,
+        size: Size<u32>,
+    ) -> &wgpu::TextureView {
+        if self.target.as_ref().map(|target| target.size) != Some(size) {
+            let texture = device.create_texture(&wgpu::TextureDescriptor {
+                label: Some("iced_wgpu.damage.texture"),
+                size: wgpu::Extent3d {
+                    width: size.width,
+                    height: size.height,
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: self.format,
+                usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+                    | wgpu::TextureUsages::TEXTURE_BINDING,
+                view_formats: &[],
+            });
+
+            let view =
+                texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+            let bind_group =
+                device.create_bind_group(&wgpu::BindGroupDescriptor {
+                    label: Some("iced_wgpu.damage.bind_group"),
+                    layout: &self.layout,
+                    entries: &[wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: wgpu::BindingResource::TextureView(&view),
+                    }],
+                });
+
+            self.target = Some(Target {
+                size,
+                view,
+                bind_group,
+            });
+        }
+
+        &self.target.as_ref().expect("Damage target must exist").view
+    }
+
+    /// Copies the retained frame into the given `frame`.
+    pub fn present(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        frame: &wgpu::TextureView,
+    ) {
+        let Some(target) = &self.target else {
+            return;
+        };
+
+        let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("iced_wgpu.damage.render_pass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view: frame,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Load,
+                    store: wgpu::StoreOp::Store,
+                },
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+
+        pass.set_pipeline(&self.pipeline);
+        pass.set_bind_group(0, &target.bind_group, &[]);
+        pass.draw(0..6, 0..1);
+    }
+}
@@ -40,6 +40,9 @@ pub mod geometry;
 mod backend;
 mod buffer;
 mod color;
+mod color_profile;
+#[cfg(feature = "damage")]
+mod damage;
 mod quad;
 mod text;
 mod triangle;
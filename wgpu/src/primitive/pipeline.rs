@@ -28,7 +28,11 @@ impl Pipeline {
 
 impl PartialEq for Pipeline {
     fn eq(&self, other: &Self) -> bool {
-        self.primitive.type_id() == other.primitive.type_id()
+        // A custom primitive may draw something different every time it is
+        // rendered, so it only equals itself. Widgets create a new one on
+        // every draw, which means custom primitives are always damaged.
+        self.bounds == other.bounds
+            && Arc::ptr_eq(&self.primitive, &other.primitive)
     }
 }
 
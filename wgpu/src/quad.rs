@@ -231,6 +231,16 @@ impl Batch {
         self.solids.is_empty() && self.gradients.is_empty()
     }
 
+    /// Returns the amount of quads of any type in the [`Batch`].
+    pub fn len(&self) -> usize {
+        self.solids.len() + self.gradients.len()
+    }
+
+    /// Returns the amount of draw calls needed to render the [`Batch`].
+    pub fn draw_calls(&self) -> usize {
+        self.order.len()
+    }
+
     /// Adds a [`Quad`] with the provided `Background` type to the quad [`Layer`].
     pub fn add(&mut self, quad: Quad, background: &Background) {
         let kind = match background {
@@ -1,6 +1,6 @@
 //! Configure a renderer.
 use crate::core::{Font, Pixels};
-use crate::graphics::Antialiasing;
+use crate::graphics::{Antialiasing, ColorProfile};
 
 /// The settings of a [`Backend`].
 ///
@@ -27,6 +27,17 @@ pub struct Settings {
     ///
     /// By default, it is `None`.
     pub antialiasing: Option<Antialiasing>,
+
+    /// The output [`ColorProfile`] applied to the presented frames.
+    ///
+    /// By default, it is `None` and frames are presented as sRGB.
+    pub color_profile: Option<ColorProfile>,
+
+    /// Whether to share the graphics device with the other compositors of
+    /// the process.
+    ///
+    /// By default, it is `true`.
+    pub share_device: bool,
 }
 
 impl Settings {
@@ -61,6 +72,8 @@ impl Default for Settings {
             default_font: Font::default(),
             default_text_size: Pixels(14.0),
             antialiasing: None,
+            color_profile: None,
+            share_device: true,
         }
     }
 }
@@ -0,0 +1,72 @@
+var<private> positions: array<vec2<f32>, 6> = array<vec2<f32>, 6>(
+    vec2<f32>(-1.0, 1.0),
+    vec2<f32>(-1.0, -1.0),
+    vec2<f32>(1.0, -1.0),
+    vec2<f32>(-1.0, 1.0),
+    vec2<f32>(1.0, 1.0),
+    vec2<f32>(1.0, -1.0)
+);
+
+struct Uniforms {
+    // The rows of the linear sRGB to linear device matrix
+    red: vec4<f32>,
+    green: vec4<f32>,
+    blue: vec4<f32>,
+    // The inverse gamma of each channel, and whether the target is sRGB
+    inverse_gamma: vec4<f32>,
+}
+
+@group(0) @binding(0) var<uniform> uniforms: Uniforms;
+@group(0) @binding(1) var u_texture: texture_2d<f32>;
+
+fn to_linear(color: vec3<f32>) -> vec3<f32> {
+    let cutoff = color <= vec3<f32>(0.04045);
+    let lower = color / 12.92;
+    let higher = pow((color + 0.055) / 1.055, vec3<f32>(2.4));
+
+    return select(higher, lower, cutoff);
+}
+
+fn from_linear(color: vec3<f32>) -> vec3<f32> {
+    let cutoff = color <= vec3<f32>(0.0031308);
+    let lower = color * 12.92;
+    let higher = 1.055 * pow(color, vec3<f32>(1.0 / 2.4)) - 0.055;
+
+    return select(higher, lower, cutoff);
+}
+
+@vertex
+fn vs_main(@builtin(vertex_index) vertex_index: u32) -> @builtin(position) vec4<f32> {
+    return vec4<f32>(positions[vertex_index], 0.0, 1.0);
+}
+
+@fragment
+fn fs_main(@builtin(position) position: vec4<f32>) -> @location(0) vec4<f32> {
+    let color = textureLoad(u_texture, vec2<i32>(position.xy), 0);
+    let srgb_target = uniforms.inverse_gamma.w > 0.5;
+
+    // sRGB textures are decoded when loaded
+    var rgb = color.rgb;
+
+    if (!srgb_target) {
+        rgb = to_linear(rgb);
+    }
+
+    let device = max(
+        vec3<f32>(
+            dot(uniforms.red.xyz, rgb),
+            dot(uniforms.green.xyz, rgb),
+            dot(uniforms.blue.xyz, rgb),
+        ),
+        vec3<f32>(0.0),
+    );
+
+    var encoded = pow(device, uniforms.inverse_gamma.xyz);
+
+    // sRGB targets encode the stored values, so we compensate for it
+    if (srgb_target) {
+        encoded = to_linear(encoded);
+    }
+
+    return vec4<f32>(encoded, color.a);
+}
@@ -1,33 +1,61 @@
 //! Connect a window with a renderer.
+use crate::core::window::{PixelFormat, SurfaceFormat};
 use crate::core::{Color, Size};
 use crate::graphics;
 use crate::graphics::color;
 use crate::graphics::compositor;
-use crate::graphics::{Error, Viewport};
+use crate::graphics::renderer::Frames;
+use crate::graphics::{ColorProfile, Error, Viewport};
+use crate::primitive;
 use crate::{Backend, Primitive, Renderer, Settings};
 
 #[cfg(all(unix, not(target_os = "macos")))]
 use super::wayland::get_wayland_device_ids;
 
+use std::sync::atomic::{self, AtomicUsize};
+use std::sync::Arc;
+
+/// The amount of devices requested by the compositors of the process.
+static REQUESTED_DEVICES: AtomicUsize = AtomicUsize::new(0);
+
 /// A window graphics backend for iced powered by `wgpu`.
+///
+/// A [`Compositor`] owns a single [`wgpu::Device`] and [`wgpu::Queue`], which
+/// are shared by all the surfaces and renderers it creates.
+///
+/// Unless [`Settings::share_device`] is disabled, the [`wgpu::Device`] is
+/// also shared with the other compositors of the process.
 #[allow(missing_debug_implementations)]
 pub struct Compositor {
     settings: Settings,
-    instance: wgpu::Instance,
-    adapter: wgpu::Adapter,
-    device: wgpu::Device,
-    queue: wgpu::Queue,
+    instance: Arc<wgpu::Instance>,
+    adapter: Arc<wgpu::Adapter>,
+    device: Arc<wgpu::Device>,
+    queue: Arc<wgpu::Queue>,
     format: wgpu::TextureFormat,
 }
 
 impl Compositor {
     /// Requests a new [`Compositor`] with the given [`Settings`].
     ///
+    /// If [`Settings::share_device`] is enabled, the [`wgpu::Device`] of
+    /// another live [`Compositor`] is reused when it can present to the
+    /// `compatible_window`.
+    ///
     /// Returns `None` if no compatible graphics adapter could be found.
     pub async fn request<W: compositor::Window>(
         settings: Settings,
         compatible_window: Option<W>,
     ) -> Option<Self> {
+        #[cfg(not(target_arch = "wasm32"))]
+        if settings.share_device {
+            if let Some(compositor) =
+                shared::find(settings, compatible_window.as_ref())
+            {
+                return Some(compositor);
+            }
+        }
+
         #[cfg(all(unix, not(target_os = "macos")))]
         let ids = compatible_window.as_ref().and_then(get_wayland_device_ids);
 
@@ -126,23 +154,10 @@ impl Compositor {
 
         log::info!("Selected: {:#?}", adapter.get_info());
 
-        let format = compatible_surface.as_ref().and_then(|surface| {
-            let capabilities = surface.get_capabilities(&adapter);
-
-            let mut formats = capabilities.formats.iter().copied();
-
-            let format = if color::GAMMA_CORRECTION {
-                formats.find(wgpu::TextureFormat::is_srgb)
-            } else {
-                formats.find(|format| !wgpu::TextureFormat::is_srgb(format))
-            };
-
-            format.or_else(|| {
-                log::warn!("No format found!");
-
-                capabilities.formats.first().copied()
-            })
-        })?;
+        let format = match compatible_surface.as_ref() {
+            Some(surface) => surface_format(surface, &adapter)?,
+            None => HEADLESS_FORMAT,
+        };
 
         log::info!("Selected format: {format:?}");
 
@@ -178,20 +193,112 @@ impl Compositor {
                 }
             }?;
 
-        Some(Compositor {
-            instance,
+        let _ = REQUESTED_DEVICES.fetch_add(1, atomic::Ordering::Relaxed);
+
+        let compositor = Compositor {
+            instance: Arc::new(instance),
             settings,
-            adapter,
-            device,
-            queue,
+            adapter: Arc::new(adapter),
+            device: Arc::new(device),
+            queue: Arc::new(queue),
             format,
-        })
+        };
+
+        #[cfg(not(target_arch = "wasm32"))]
+        if settings.share_device {
+            shared::register(&compositor);
+        }
+
+        Some(compositor)
+    }
+
+    /// Returns the amount of [`wgpu::Device`]s requested by the compositors
+    /// of the process so far.
+    pub fn requested_devices() -> usize {
+        REQUESTED_DEVICES.load(atomic::Ordering::Relaxed)
+    }
+
+    /// Creates a new [`Compositor`] from an existing [`wgpu::Device`] and
+    /// [`wgpu::Queue`].
+    ///
+    /// This is useful to embed iced inside another `wgpu` application, since
+    /// the textures rendered with [`Compositor::present_to_texture`] must be
+    /// created with the same [`wgpu::Device`] used by the caller.
+    ///
+    /// The `format` is the [`wgpu::TextureFormat`] of the render targets that
+    /// the [`Compositor`] will draw into.
+    pub fn with_device(
+        settings: Settings,
+        instance: wgpu::Instance,
+        adapter: wgpu::Adapter,
+        device: wgpu::Device,
+        queue: wgpu::Queue,
+        format: wgpu::TextureFormat,
+    ) -> Self {
+        Self {
+            settings,
+            instance: Arc::new(instance),
+            adapter: Arc::new(adapter),
+            device: Arc::new(device),
+            queue: Arc::new(queue),
+            format,
+        }
+    }
+
+    /// Returns the [`wgpu::Device`] of the [`Compositor`].
+    pub fn device(&self) -> &wgpu::Device {
+        &self.device
+    }
+
+    /// Returns the [`wgpu::Queue`] of the [`Compositor`].
+    pub fn queue(&self) -> &wgpu::Queue {
+        &self.queue
+    }
+
+    /// Returns the [`wgpu::TextureFormat`] the [`Compositor`] renders with.
+    pub fn format(&self) -> wgpu::TextureFormat {
+        self.format
+    }
+
+    /// Returns the [`Settings`] of the [`Compositor`].
+    pub fn settings(&self) -> Settings {
+        self.settings
     }
 
     /// Creates a new rendering [`Backend`] for this [`Compositor`].
     pub fn create_backend(&self) -> Backend {
         Backend::new(&self.device, &self.queue, self.settings, self.format)
     }
+
+    /// Draws the primitives of the given [`Renderer`] into an externally
+    /// provided [`wgpu::TextureView`], instead of a surface.
+    ///
+    /// The texture must have been created with the [`wgpu::Device`] of the
+    /// [`Compositor`], have the [`wgpu::TextureUsages::RENDER_ATTACHMENT`]
+    /// usage and use the [`wgpu::TextureFormat`] of the [`Compositor`].
+    ///
+    /// If a `background_color` is provided, the texture is cleared with it
+    /// first; otherwise, the primitives are drawn over its current contents.
+    pub fn present_to_texture<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Renderer,
+        view: &wgpu::TextureView,
+        viewport: &Viewport,
+        background_color: Option<Color>,
+        overlay: &[T],
+    ) {
+        renderer.with_primitives(|backend, primitives| {
+            present_to_texture(
+                self,
+                backend,
+                view,
+                primitives,
+                viewport,
+                background_color,
+                overlay,
+            );
+        });
+    }
 }
 
 /// Creates a [`Compositor`] and its [`Backend`] for the given [`Settings`] and
@@ -209,12 +316,12 @@ pub fn new<W: compositor::Window>(
     Ok(compositor)
 }
 
-/// Presents the given primitives with the given [`Compositor`] and [`Backend`].
+/// Presents the given [`Frames`] with the given [`Compositor`] and [`Backend`].
 pub fn present<T: AsRef<str>>(
     compositor: &mut Compositor,
     backend: &mut Backend,
     surface: &mut wgpu::Surface<'static>,
-    primitives: &[Primitive],
+    frames: Frames<'_, primitive::Custom>,
     viewport: &Viewport,
     background_color: Color,
     overlay: &[T],
@@ -231,6 +338,20 @@ pub fn present<T: AsRef<str>>(
                 .texture
                 .create_view(&wgpu::TextureViewDescriptor::default());
 
+            #[cfg(feature = "damage")]
+            backend.present_retained(
+                &compositor.device,
+                &compositor.queue,
+                &mut encoder,
+                Some(background_color),
+                frame.texture.format(),
+                view,
+                frames,
+                viewport,
+                overlay,
+            );
+
+            #[cfg(not(feature = "damage"))]
             backend.present(
                 &compositor.device,
                 &compositor.queue,
@@ -238,7 +359,7 @@ pub fn present<T: AsRef<str>>(
                 Some(background_color),
                 frame.texture.format(),
                 view,
-                primitives,
+                frames.current,
                 viewport,
                 overlay,
             );
@@ -264,6 +385,155 @@ pub fn present<T: AsRef<str>>(
     }
 }
 
+/// Presents the given primitives with the given [`Compositor`] and [`Backend`]
+/// into the provided [`wgpu::TextureView`].
+pub fn present_to_texture<T: AsRef<str>>(
+    compositor: &Compositor,
+    backend: &mut Backend,
+    view: &wgpu::TextureView,
+    primitives: &[Primitive],
+    viewport: &Viewport,
+    background_color: Option<Color>,
+    overlay: &[T],
+) {
+    let mut encoder = compositor.device.create_command_encoder(
+        &wgpu::CommandEncoderDescriptor {
+            label: Some("iced_wgpu.texture.encoder"),
+        },
+    );
+
+    backend.present(
+        &compositor.device,
+        &compositor.queue,
+        &mut encoder,
+        background_color,
+        compositor.format,
+        view,
+        primitives,
+        viewport,
+        overlay,
+    );
+
+    let _submission = compositor.queue.submit(Some(encoder.finish()));
+}
+
+/// The [`wgpu::TextureFormat`] of compositors without a compatible window.
+const HEADLESS_FORMAT: wgpu::TextureFormat = if color::GAMMA_CORRECTION {
+    wgpu::TextureFormat::Bgra8UnormSrgb
+} else {
+    wgpu::TextureFormat::Bgra8Unorm
+};
+
+/// Selects the [`wgpu::TextureFormat`] to present to the given surface with.
+fn surface_format(
+    surface: &wgpu::Surface<'_>,
+    adapter: &wgpu::Adapter,
+) -> Option<wgpu::TextureFormat> {
+    let capabilities = surface.get_capabilities(adapter);
+
+    let mut formats = capabilities.formats.iter().copied();
+
+    let format = if color::GAMMA_CORRECTION {
+        formats.find(wgpu::TextureFormat::is_srgb)
+    } else {
+        formats.find(|format| !wgpu::TextureFormat::is_srgb(format))
+    };
+
+    format.or_else(|| {
+        log::warn!("No format found!");
+
+        capabilities.formats.first().copied()
+    })
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+mod shared {
+    use super::{surface_format, Compositor, HEADLESS_FORMAT};
+    use crate::graphics::compositor;
+    use crate::Settings;
+
+    use std::sync::{Arc, Mutex, Weak};
+
+    /// The devices of the compositors of the process that can be shared.
+    static DEVICES: Mutex<Vec<Device>> = Mutex::new(Vec::new());
+
+    struct Device {
+        backends: wgpu::Backends,
+        instance: Weak<wgpu::Instance>,
+        adapter: Weak<wgpu::Adapter>,
+        device: Weak<wgpu::Device>,
+        queue: Weak<wgpu::Queue>,
+    }
+
+    /// Creates a [`Compositor`] with the device of another live [`Compositor`]
+    /// that can present to the given window, if any.
+    pub fn find<W: compositor::Window>(
+        settings: Settings,
+        compatible_window: Option<&W>,
+    ) -> Option<Compositor> {
+        let mut devices = DEVICES.lock().ok()?;
+
+        devices.retain(|device| device.device.strong_count() > 0);
+
+        devices
+            .iter()
+            .filter(|device| device.backends == settings.internal_backend)
+            .find_map(|device| {
+                let instance = device.instance.upgrade()?;
+                let adapter = device.adapter.upgrade()?;
+
+                let format = match compatible_window {
+                    Some(window) => {
+                        // SAFETY: The surface is dropped before the window
+                        // is released
+                        #[allow(unsafe_code)]
+                        let surface = unsafe {
+                            instance.create_surface_unsafe(
+                                wgpu::SurfaceTargetUnsafe::from_window(window)
+                                    .ok()?,
+                            )
+                        }
+                        .ok()?;
+
+                        if !adapter.is_surface_supported(&surface) {
+                            return None;
+                        }
+
+                        surface_format(&surface, &adapter)?
+                    }
+                    None => HEADLESS_FORMAT,
+                };
+
+                log::info!("Sharing device: {:#?}", adapter.get_info());
+
+                Some(Compositor {
+                    settings,
+                    instance,
+                    adapter,
+                    device: device.device.upgrade()?,
+                    queue: device.queue.upgrade()?,
+                    format,
+                })
+            })
+    }
+
+    /// Makes the device of the given [`Compositor`] available to the
+    /// compositors requested later on.
+    pub fn register(compositor: &Compositor) {
+        let Ok(mut devices) = DEVICES.lock() else {
+            return;
+        };
+
+        devices.push(Device {
+            backends: compositor.settings.internal_backend,
+            instance: Arc::downgrade(&compositor.instance),
+            adapter: Arc::downgrade(&compositor.adapter),
+            device: Arc::downgrade(&compositor.device),
+            queue: Arc::downgrade(&compositor.queue),
+        });
+    }
+}
+
 impl graphics::Compositor for Compositor {
     type Settings = Settings;
     type Renderer = Renderer;
@@ -339,10 +609,56 @@ impl graphics::Compositor for Compositor {
     fn fetch_information(&self) -> compositor::Information {
         let information = self.adapter.get_info();
 
-        compositor::Information {
-            adapter: information.name,
-            backend: format!("{:?}", information.backend),
-        }
+        let mut result = compositor::Information::new(
+            information.name,
+            format!("{:?}", information.backend),
+        );
+
+        result.hardware_accelerated = matches!(
+            information.device_type,
+            wgpu::DeviceType::DiscreteGpu | wgpu::DeviceType::IntegratedGpu
+        );
+
+        result
+    }
+
+    fn backend(&self) -> Option<compositor::Backend> {
+        Some(compositor::Backend::Wgpu)
+    }
+
+    fn fetch_render_stats(
+        &self,
+        renderer: &Self::Renderer,
+    ) -> Option<compositor::RenderStats> {
+        Some(renderer.backend().render_stats())
+    }
+
+    fn fetch_surface_format(
+        &self,
+        _surface: &Self::Surface,
+    ) -> Option<SurfaceFormat> {
+        // Every surface is configured with the format of the compositor
+        Some(surface_format(self.format))
+    }
+
+    fn set_color_profile(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        profile: Option<ColorProfile>,
+    ) {
+        renderer.with_primitives(|backend, _primitives| {
+            backend.set_color_profile(profile);
+        });
+    }
+
+    fn set_damage_tracking(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        enabled: bool,
+    ) {
+        renderer.with_primitives(|backend, _primitives| {
+            backend.set_damage_tracking(enabled);
+        });
     }
 
     fn present<T: AsRef<str>>(
@@ -353,12 +669,12 @@ impl graphics::Compositor for Compositor {
         background_color: Color,
         overlay: &[T],
     ) -> Result<(), compositor::SurfaceError> {
-        renderer.with_primitives(|backend, primitives| {
+        renderer.with_frames(|backend, frames| {
             present(
                 self,
                 backend,
                 surface,
-                primitives,
+                frames,
                 viewport,
                 background_color,
                 overlay,
@@ -385,6 +701,31 @@ impl graphics::Compositor for Compositor {
             )
         })
     }
+
+    fn render_offscreen<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    ) -> Option<Vec<u8>> {
+        let size = viewport.physical_size();
+
+        if size.width == 0 || size.height == 0 {
+            return None;
+        }
+
+        Some(renderer.with_primitives(|backend, primitives| {
+            screenshot(
+                self,
+                backend,
+                primitives,
+                viewport,
+                background_color,
+                overlay,
+            )
+        }))
+    }
 }
 
 /// Renders the current surface to an offscreen buffer.
@@ -517,3 +858,83 @@ impl BufferDimensions {
         }
     }
 }
+
+fn surface_format(format: wgpu::TextureFormat) -> SurfaceFormat {
+    use wgpu::TextureFormat;
+
+    let pixel_format = match format {
+        TextureFormat::Rgba8Unorm | TextureFormat::Rgba8UnormSrgb => {
+            PixelFormat::Rgba8
+        }
+        TextureFormat::Bgra8Unorm | TextureFormat::Bgra8UnormSrgb => {
+            PixelFormat::Bgra8
+        }
+        TextureFormat::Rgb10a2Unorm => PixelFormat::Rgb10a2,
+        TextureFormat::Rgba16Float => PixelFormat::Rgba16Float,
+        _ => PixelFormat::Other,
+    };
+
+    SurfaceFormat {
+        pixel_format,
+        srgb: format.is_srgb(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use raw_window_handle::{
+        DisplayHandle, HandleError, HasDisplayHandle, HasWindowHandle,
+        WindowHandle,
+    };
+
+    struct Headless;
+
+    impl HasWindowHandle for Headless {
+        fn window_handle(&self) -> Result<WindowHandle<'_>, HandleError> {
+            Err(HandleError::Unavailable)
+        }
+    }
+
+    impl HasDisplayHandle for Headless {
+        fn display_handle(&self) -> Result<DisplayHandle<'_>, HandleError> {
+            Err(HandleError::Unavailable)
+        }
+    }
+
+    fn request(settings: Settings) -> Option<Compositor> {
+        futures::executor::block_on(Compositor::request(
+            settings,
+            None::<Headless>,
+        ))
+    }
+
+    #[test]
+    fn compositors_share_a_single_device() {
+        let Some(first) = request(Settings::default()) else {
+            // No graphics adapter is available
+            return;
+        };
+
+        let requested = Compositor::requested_devices();
+
+        let others: Vec<_> = (0..20)
+            .map(|_| request(Settings::default()).expect("Request compositor"))
+            .collect();
+
+        assert_eq!(Compositor::requested_devices(), requested);
+        assert!(others
+            .iter()
+            .all(|other| Arc::ptr_eq(&other.device, &first.device)));
+
+        let unshared = request(Settings {
+            share_device: false,
+            ..Settings::default()
+        })
+        .expect("Request compositor");
+
+        assert_eq!(Compositor::requested_devices(), requested + 1);
+        assert!(!Arc::ptr_eq(&unshared.device, &first.device));
+    }
+}
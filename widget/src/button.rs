@@ -241,7 +241,7 @@ where
         renderer: &Renderer,
         operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
     ) {
-        operation.container(None, layout.bounds(), &mut |operation| {
+        operation.button(Some(&self.id), layout.bounds(), &mut |operation| {
             self.content.as_widget().operate(
                 &mut tree.children[0],
                 layout.children().next().unwrap(),
@@ -663,3 +663,43 @@ impl operation::Focusable for State {
         State::unfocus(self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::renderer::Null;
+    use crate::core::widget::operation::LayoutDump;
+    use crate::runtime::user_interface::{Cache, UserInterface};
+    use crate::{column, Space, Theme};
+
+    #[test]
+    fn layout_dump_contains_button_bounds() {
+        let content: Element<'_, (), Theme, Null> = column![
+            Space::with_height(40),
+            Button::new(Space::new(100, 30))
+                .id(Id::new("submit"))
+                .padding(0),
+        ]
+        .into();
+
+        let mut renderer = Null::new();
+        let mut user_interface = UserInterface::build(
+            content,
+            Size::new(800.0, 600.0),
+            Cache::default(),
+            &mut renderer,
+        );
+
+        let mut dump = LayoutDump::new();
+        user_interface.operate(&renderer, &mut dump);
+
+        let json = dump.to_json();
+        let (_, button) = json
+            .split_once("\"kind\":\"button\",\"id\":\"submit\"")
+            .expect("Find button in layout dump");
+
+        assert!(button.starts_with(
+            ",\"bounds\":{\"x\":0,\"y\":40,\"width\":100,\"height\":30}"
+        ));
+    }
+}
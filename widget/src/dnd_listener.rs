@@ -1,5 +1,6 @@
 //! A container for capturing mouse events.
 
+use crate::core::clipboard;
 use crate::core::event::wayland::DndOfferEvent;
 use crate::core::event::{self, Event, PlatformSpecific};
 use crate::core::layout;
@@ -45,6 +46,10 @@ pub struct DndListener<'a, Message, Theme, Renderer> {
 
     /// Sets the message to emit on a Data event.
     on_data: Option<Box<dyn Fn(String, Vec<u8>) -> Message + 'a>>,
+
+    /// Sets the message to emit with the in-process payload of a drag drop.
+    #[allow(clippy::type_complexity)]
+    on_payload: Option<Box<dyn Fn(&mut dyn Clipboard) -> Option<Message> + 'a>>,
 }
 
 impl<'a, Message, Theme, Renderer> DndListener<'a, Message, Theme, Renderer> {
@@ -121,6 +126,25 @@ impl<'a, Message, Theme, Renderer> DndListener<'a, Message, Theme, Renderer> {
         self.on_data = Some(Box::new(message));
         self
     }
+
+    /// The message to emit with the payload of type `P` of a drag drop, when
+    /// the drag was started in the same application.
+    ///
+    /// The payload is moved directly from the [`DndSource`], even in another
+    /// window, without being serialized.
+    ///
+    /// [`DndSource`]: crate::dnd_source::DndSource
+    #[must_use]
+    pub fn on_payload<P: 'static>(
+        mut self,
+        message: impl Fn(P) -> Message + 'a,
+    ) -> Self {
+        self.on_payload =
+            Some(Box::new(move |clipboard: &mut dyn Clipboard| {
+                clipboard::take_dnd_payload::<P>(clipboard).map(&message)
+            }));
+        self
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
@@ -153,6 +177,7 @@ impl<'a, Message, Theme, Renderer> DndListener<'a, Message, Theme, Renderer> {
             on_source_actions: None,
             on_selected_action: None,
             on_data: None,
+            on_payload: None,
         }
     }
 }
@@ -241,6 +266,7 @@ where
             self,
             &event,
             layout,
+            clipboard,
             shell,
             tree.state.downcast_mut::<State>(),
         )
@@ -331,6 +357,7 @@ fn update<Message: Clone, Renderer, Theme>(
     widget: &mut DndListener<'_, Message, Theme, Renderer>,
     event: &Event,
     layout: Layout<'_>,
+    clipboard: &mut dyn Clipboard,
     shell: &mut Shell<'_, Message>,
     state: &mut State,
 ) -> event::Status {
@@ -434,10 +461,26 @@ fn update<Message: Clone, Renderer, Theme>(
         )) => {
             if matches!(state.dnd, DndState::Hovered(..)) {
                 state.dnd = DndState::Dropped;
+
+                let payload = widget
+                    .on_payload
+                    .as_ref()
+                    .and_then(|on_payload| on_payload(clipboard));
+
+                let captured = payload.is_some();
+
+                if let Some(message) = payload {
+                    shell.publish(message);
+                }
+
                 if let Some(message) = widget.on_drop.clone() {
                     shell.publish(message);
                     return event::Status::Captured;
                 }
+
+                if captured {
+                    return event::Status::Captured;
+                }
             }
         }
         Event::PlatformSpecific(PlatformSpecific::Wayland(
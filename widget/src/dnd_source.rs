@@ -11,6 +11,8 @@ use crate::core::widget::{
     operation::OperationOutputWrapper, tree, Operation, Tree,
 };
 
+use std::any::Any;
+
 /// A widget that can be dragged and dropped.
 #[allow(missing_debug_implementations)]
 pub struct DndSource<'a, Message, Theme, Renderer> {
@@ -28,6 +30,8 @@ pub struct DndSource<'a, Message, Theme, Renderer> {
 
     drag_threshold: f32,
 
+    payload: Option<Box<dyn Fn() -> Box<dyn Any> + 'a>>,
+
     /// Whether or not captured events should be handled by the widget.
     handle_captured_events: bool,
 }
@@ -85,6 +89,20 @@ impl<'a, Message, Widget, Renderer> DndSource<'a, Message, Widget, Renderer> {
         self
     }
 
+    /// The payload to move directly to a [`DndListener`] of the same
+    /// application once the drag starts, even in another window.
+    ///
+    /// The payload is discarded if the drop lands outside of the
+    /// application.
+    ///
+    /// [`DndListener`]: crate::dnd_listener::DndListener
+    #[must_use]
+    pub fn payload<P: 'static>(mut self, payload: impl Fn() -> P + 'a) -> Self {
+        self.payload =
+            Some(Box::new(move || -> Box<dyn Any> { Box::new(payload()) }));
+        self
+    }
+
     /// Whether or not captured events should be handled by the widget.
     #[must_use]
     pub fn handle_captured_events(
@@ -118,6 +136,7 @@ impl<'a, Message, Widget, Renderer> DndSource<'a, Message, Widget, Renderer> {
             on_dropped: None,
             on_selection_action: None,
             drag_threshold: 25.0,
+            payload: None,
             handle_captured_events: true,
         }
     }
@@ -349,6 +368,7 @@ where
                     cursor_position.y - layout.bounds().y,
                 );
                 shell.publish(on_drag(layout.bounds().size(), offset));
+                clipboard.set_dnd_payload(self.payload.as_ref().map(|p| p()));
                 state.is_dragging = true;
                 return event::Status::Captured;
             };
@@ -372,6 +392,7 @@ where
                     cursor_position.y - layout.bounds().y,
                 );
                 shell.publish(on_drag(layout.bounds().size(), offset));
+                clipboard.set_dnd_payload(self.payload.as_ref().map(|p| p()));
                 return event::Status::Captured;
             }
         }
@@ -505,6 +505,7 @@ where
         }
         Event::Mouse(mouse::Event::WheelScrolled {
             delta: mouse::ScrollDelta::Lines { y, .. },
+            ..
         }) => {
             let state = state();
 
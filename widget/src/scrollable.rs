@@ -11,9 +11,11 @@ use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
+use crate::core::time::{Duration, Instant};
 use crate::core::touch;
 use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
 use crate::core::{
     id::Internal, Background, Clipboard, Color, Element, Layout, Length,
     Pixels, Point, Rectangle, Shell, Size, Vector, Widget,
@@ -721,6 +723,101 @@ pub fn update<Message>(
     let (mouse_over_y_scrollbar, mouse_over_x_scrollbar) =
         scrollbars.is_mouse_over(cursor);
 
+    // Auto-scrolling runs regardless of whether the contents capture the
+    // drag and drop offer, so a list can scroll while dragging over its items
+    match &event {
+        Event::Dnd(DndEvent::Offer(
+            _,
+            dnd::OfferEvent::Enter { x, y, .. }
+            | dnd::OfferEvent::Motion { x, y },
+        )) => {
+            let velocity = auto_scroll_velocity(
+                Point::new(*x as f32, *y as f32),
+                bounds,
+                direction,
+            );
+
+            state.auto_scroll = velocity.map(|velocity| {
+                let scrolled_at = state
+                    .auto_scroll
+                    .map_or_else(Instant::now, |(_, scrolled_at)| scrolled_at);
+
+                (velocity, scrolled_at)
+            });
+
+            if state.auto_scroll.is_some() {
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+        }
+        Event::Dnd(DndEvent::Offer(
+            _,
+            dnd::OfferEvent::Leave | dnd::OfferEvent::DropPerformed,
+        )) => {
+            state.auto_scroll = None;
+        }
+        Event::Window(_, window::Event::RedrawRequested(now)) => {
+            if let Some((velocity, scrolled_at)) = state.auto_scroll {
+                let elapsed =
+                    now.saturating_duration_since(scrolled_at).as_secs_f32();
+
+                state.scroll(
+                    velocity * -elapsed,
+                    direction,
+                    bounds,
+                    content_bounds,
+                );
+                state.auto_scroll = Some((velocity, *now));
+
+                notify_on_scroll(
+                    state,
+                    on_scroll,
+                    bounds,
+                    content_bounds,
+                    shell,
+                );
+
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+
+            if let Some((velocity, scrolled_at)) = state.momentum {
+                let elapsed =
+                    now.saturating_duration_since(scrolled_at).as_secs_f32();
+                let translation =
+                    state.translation(direction, bounds, content_bounds);
+
+                state.scroll(
+                    velocity * elapsed,
+                    direction,
+                    bounds,
+                    content_bounds,
+                );
+
+                let velocity = velocity * (-MOMENTUM_DECAY * elapsed).exp();
+
+                // The momentum stops once it is too slow to be noticed or
+                // the contents cannot be scrolled any further
+                state.momentum = (velocity.x.hypot(velocity.y)
+                    >= MIN_MOMENTUM_SPEED
+                    && state.translation(direction, bounds, content_bounds)
+                        != translation)
+                    .then_some((velocity, *now));
+
+                notify_on_scroll(
+                    state,
+                    on_scroll,
+                    bounds,
+                    content_bounds,
+                    shell,
+                );
+
+                if state.momentum.is_some() {
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+            }
+        }
+        _ => {}
+    }
+
     let event_status = {
         let cursor = match cursor_over_scrollable {
             Some(cursor_position)
@@ -784,7 +881,7 @@ pub fn update<Message>(
     }
 
     match event {
-        Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+        Event::Mouse(mouse::Event::WheelScrolled { delta, phase }) => {
             if cursor_over_scrollable.is_none() {
                 return event::Status::Ignored;
             }
@@ -802,6 +899,59 @@ pub fn update<Message>(
                 }
                 mouse::ScrollDelta::Pixels { x, y } => Vector::new(x, y),
             };
+
+            let now = Instant::now();
+
+            match phase {
+                mouse::ScrollPhase::Started => {
+                    state.momentum = None;
+                    state.scroll_velocity = Some((Vector::ZERO, now));
+                }
+                mouse::ScrollPhase::Moved => {
+                    if let Some((velocity, scrolled_at)) = state.scroll_velocity
+                    {
+                        let elapsed = now
+                            .saturating_duration_since(scrolled_at)
+                            .as_secs_f32();
+
+                        if elapsed > 0.0 {
+                            state.scroll_velocity = Some((
+                                (velocity + delta * (1.0 / elapsed)) * 0.5,
+                                now,
+                            ));
+                        }
+                    } else {
+                        // Scrolling without a gesture stops the momentum
+                        state.momentum = None;
+                    }
+                }
+                mouse::ScrollPhase::Ended => {
+                    // Resting the fingers before lifting them cancels the
+                    // momentum of the gesture. macOS reports its own
+                    // momentum as a gesture of its own.
+                    state.momentum = state
+                        .scroll_velocity
+                        .take()
+                        .filter(|(velocity, scrolled_at)| {
+                            !cfg!(target_os = "macos")
+                                && now.saturating_duration_since(*scrolled_at)
+                                    < MOMENTUM_TIMEOUT
+                                && velocity.x.hypot(velocity.y)
+                                    >= MIN_MOMENTUM_SPEED
+                        })
+                        .map(|(velocity, _)| (velocity, now));
+
+                    if state.momentum.is_some() {
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
+                    }
+                }
+                mouse::ScrollPhase::Inertia => {
+                    // The platform already provides the momentum
+                    state.momentum = None;
+                    state.scroll_velocity = None;
+                }
+            }
+
             if matches!(direction, Direction::Vertical(_))
                 && delta.y.abs() < 0.1
                 || matches!(direction, Direction::Horizontal(_))
@@ -827,6 +977,7 @@ pub fn update<Message>(
                     };
 
                     state.scroll_area_touched_at = Some(cursor_position);
+                    state.momentum = None;
                 }
                 touch::Event::FingerMoved { .. } => {
                     if let Some(scroll_box_touched_at) =
@@ -1260,6 +1411,68 @@ fn notify_on_scroll<Message>(
     }
 }
 
+/// The distance from the edges of a [`Scrollable`] in which a drag and drop
+/// offer starts scrolling its contents.
+const AUTO_SCROLL_EDGE: f32 = 48.0;
+
+/// The speed, in logical pixels per second, at which the contents of a
+/// [`Scrollable`] are scrolled when a drag and drop offer is right at its edge.
+const AUTO_SCROLL_SPEED: f32 = 1200.0;
+
+/// The rate at which the momentum of a scroll gesture decays, as the
+/// exponent of its decay per second.
+const MOMENTUM_DECAY: f32 = 4.0;
+
+/// The speed, in logical pixels per second, below which the momentum of a
+/// scroll gesture stops.
+const MIN_MOMENTUM_SPEED: f32 = 20.0;
+
+/// The time after the last movement of a scroll gesture past which lifting
+/// the fingers does not produce any momentum.
+const MOMENTUM_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// Returns the velocity at which the contents of a [`Scrollable`] should be
+/// scrolled while a drag and drop offer is at the given position.
+///
+/// The closer the offer is to an edge, the faster the contents are scrolled.
+fn auto_scroll_velocity(
+    position: Point,
+    bounds: Rectangle,
+    direction: Direction,
+) -> Option<Vector> {
+    if !bounds.contains(position) {
+        return None;
+    }
+
+    let speed = |start: f32, length: f32, position: f32| {
+        let edge = AUTO_SCROLL_EDGE.min(length / 2.0);
+        let end = start + length;
+
+        if position < start + edge {
+            -AUTO_SCROLL_SPEED * (1.0 - (position - start) / edge)
+        } else if position > end - edge {
+            AUTO_SCROLL_SPEED * (1.0 - (end - position) / edge)
+        } else {
+            0.0
+        }
+    };
+
+    let velocity = Vector::new(
+        if direction.horizontal().is_some() {
+            speed(bounds.x, bounds.width, position.x)
+        } else {
+            0.0
+        },
+        if direction.vertical().is_some() {
+            speed(bounds.y, bounds.height, position.y)
+        } else {
+            0.0
+        },
+    );
+
+    (velocity != Vector::ZERO).then_some(velocity)
+}
+
 /// The local state of a [`Scrollable`].
 #[derive(Debug, Clone, Copy)]
 pub struct State {
@@ -1270,6 +1483,9 @@ pub struct State {
     x_scroller_grabbed_at: Option<f32>,
     keyboard_modifiers: keyboard::Modifiers,
     last_notified: Option<Viewport>,
+    auto_scroll: Option<(Vector, Instant)>,
+    scroll_velocity: Option<(Vector, Instant)>,
+    momentum: Option<(Vector, Instant)>,
 }
 
 impl Default for State {
@@ -1282,6 +1498,9 @@ impl Default for State {
             x_scroller_grabbed_at: None,
             keyboard_modifiers: keyboard::Modifiers::default(),
             last_notified: None,
+            auto_scroll: None,
+            scroll_velocity: None,
+            momentum: None,
         }
     }
 }
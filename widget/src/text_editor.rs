@@ -620,7 +620,7 @@ impl Update {
                     }
                     _ => None,
                 },
-                mouse::Event::WheelScrolled { delta }
+                mouse::Event::WheelScrolled { delta, .. }
                     if cursor.is_over(bounds) =>
                 {
                     action(Action::Scroll {
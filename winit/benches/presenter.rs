@@ -0,0 +1,108 @@
+//! Measures the frame times of an application whose `update` does heavy work
+//! while its frames take a while to be presented.
+//!
+//! Run it with and without the `threaded-rendering` feature to compare them.
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use iced_winit::application::Presenter;
+use iced_winit::core::renderer::Null;
+use iced_winit::core::{Color, Size};
+use iced_winit::graphics::compositor::{self, Information, SurfaceError};
+use iced_winit::graphics::{Error, Viewport};
+
+use std::time::{Duration, Instant};
+
+const UPDATE: Duration = Duration::from_millis(4);
+const PRESENT: Duration = Duration::from_millis(4);
+
+criterion_main!(benches);
+criterion_group!(benches, frame);
+
+fn frame(c: &mut Criterion) {
+    let mut renderer = Null::new();
+    let mut presenter = Presenter::new(Compositor, (), &mut renderer);
+    let viewport = Viewport::with_physical_size(Size::new(800, 600), 1.0);
+
+    c.bench_function("frame", |b| {
+        b.iter(|| {
+            busy(UPDATE);
+
+            presenter
+                .present::<&str>(&mut renderer, &viewport, Color::WHITE, &[])
+                .expect("Present frame");
+        });
+    });
+}
+
+/// Blocks the current thread without yielding, like a heavy `update`.
+fn busy(duration: Duration) {
+    let start = Instant::now();
+
+    while start.elapsed() < duration {
+        std::hint::spin_loop();
+    }
+}
+
+/// A compositor whose frames take [`PRESENT`] to be presented.
+struct Compositor;
+
+impl compositor::Compositor for Compositor {
+    type Settings = ();
+    type Renderer = Null;
+    type Surface = ();
+
+    fn new<W: compositor::Window + Clone>(
+        _settings: Self::Settings,
+        _compatible_window: W,
+    ) -> Result<Self, Error> {
+        Ok(Self)
+    }
+
+    fn create_renderer(&self) -> Self::Renderer {
+        Null::new()
+    }
+
+    fn create_surface<W: compositor::Window + Clone>(
+        &mut self,
+        _window: W,
+        _width: u32,
+        _height: u32,
+    ) -> Self::Surface {
+    }
+
+    fn configure_surface(
+        &mut self,
+        _surface: &mut Self::Surface,
+        _width: u32,
+        _height: u32,
+    ) {
+    }
+
+    fn fetch_information(&self) -> Information {
+        Information::new("Null", "Null")
+    }
+
+    fn present<T: AsRef<str>>(
+        &mut self,
+        _renderer: &mut Self::Renderer,
+        _surface: &mut Self::Surface,
+        _viewport: &Viewport,
+        _background_color: Color,
+        _overlay: &[T],
+    ) -> Result<(), SurfaceError> {
+        std::thread::sleep(PRESENT);
+
+        Ok(())
+    }
+
+    fn screenshot<T: AsRef<str>>(
+        &mut self,
+        _renderer: &mut Self::Renderer,
+        _surface: &mut Self::Surface,
+        _viewport: &Viewport,
+        _background_color: Color,
+        _overlay: &[T],
+    ) -> Vec<u8> {
+        Vec::new()
+    }
+}
@@ -1,5 +1,6 @@
 //! Create interactive, native cross-platform applications.
 mod drag_resize;
+mod presenter;
 mod state;
 
 use dnd::DndAction;
@@ -15,31 +16,39 @@ use iced_runtime::futures::futures::FutureExt;
 use iced_style::core::clipboard::DndSource;
 use iced_style::core::Clipboard as CoreClipboard;
 use iced_style::core::Length;
+pub use presenter::{Presenter, Sendable, Target};
 pub use state::State;
 use window_clipboard::mime;
 use window_clipboard::mime::ClipboardStoreData;
 
 use crate::conversion;
 use crate::core;
+use crate::core::keyboard;
 use crate::core::mouse;
 use crate::core::renderer;
-use crate::core::time::Instant;
+use crate::core::time::{Duration, Instant};
 use crate::core::widget::operation;
 use crate::core::window;
 use crate::core::{Event, Size};
+use crate::dim_overlay;
 use crate::futures::futures;
 use crate::futures::{Executor, Runtime, Subscription};
 use crate::graphics::compositor::{self, Compositor};
+use crate::letterbox;
 use crate::runtime::clipboard;
 use crate::runtime::program::Program;
 use crate::runtime::user_interface::{self, UserInterface};
 use crate::runtime::{Command, Debug};
+use crate::settings::WindowOptions;
 use crate::style::application::{Appearance, StyleSheet};
+use crate::watchdog::{self, Watchdog};
 use crate::{Clipboard, Error, Proxy, Settings};
 use futures::channel::mpsc;
 use futures::stream::StreamExt;
 
 use std::any::Any;
+use std::borrow::Cow;
+use std::collections::HashMap;
 use std::mem::ManuallyDrop;
 use std::sync::Arc;
 
@@ -48,6 +57,12 @@ pub use profiler::Profiler;
 #[cfg(feature = "trace")]
 use tracing::{info_span, instrument::Instrument};
 
+/// The longest time a window with [`wait_for_fonts`] enabled waits for the
+/// fonts loading in the background before being shown anyway.
+///
+/// [`wait_for_fonts`]: crate::core::window::Settings::wait_for_fonts
+pub(crate) const WAIT_FOR_FONTS_TIMEOUT: Duration = Duration::from_secs(3);
+
 /// Wrapper aroun application Messages to allow for more UserEvent variants
 pub enum UserEventWrapper<Message> {
     /// Application Message
@@ -74,6 +89,16 @@ pub enum UserEventWrapper<Message> {
     },
     /// Dnd Event
     Dnd(DndEvent<DndSurface>),
+    /// Window Event
+    Window(window::Id, window::Event),
+    /// A global keyboard shortcut was triggered
+    GlobalShortcut(keyboard::shortcut::Id),
+    /// The time to live of some contents written to the clipboard elapsed
+    ClipboardExpired(clipboard::Target, String),
+    /// The bytes of a font loaded in the background are available
+    FontLoaded(core::Font, Cow<'static, [u8]>),
+    /// An event injected by the application into a window
+    InjectedEvent(window::Id, core::Event),
 }
 
 unsafe impl<M> Send for UserEventWrapper<M> {}
@@ -98,6 +123,21 @@ impl<M: std::fmt::Debug> std::fmt::Debug for UserEventWrapper<M> {
                 internal, icon_surface.is_some(), actions
             ),
             UserEventWrapper::Dnd(_) => write!(f, "Dnd"),
+            UserEventWrapper::Window(id, event) => {
+                write!(f, "Window({:?}, {:?})", id, event)
+            }
+            UserEventWrapper::GlobalShortcut(id) => {
+                write!(f, "GlobalShortcut({:?})", id)
+            }
+            UserEventWrapper::ClipboardExpired(target, _) => {
+                write!(f, "ClipboardExpired({:?})", target)
+            }
+            UserEventWrapper::FontLoaded(font, _) => {
+                write!(f, "FontLoaded({:?})", font)
+            }
+            UserEventWrapper::InjectedEvent(id, event) => {
+                write!(f, "InjectedEvent({:?}, {:?})", id, event)
+            }
         }
     }
 }
@@ -191,7 +231,9 @@ pub fn run<A, E, C>(
 where
     A: Application + 'static,
     E: Executor + 'static,
-    C: Compositor<Renderer = A::Renderer> + 'static,
+    C: Compositor<Renderer = A::Renderer> + Sendable + 'static,
+    C::Surface: Sendable,
+    A::Renderer: Sendable,
     A::Theme: StyleSheet,
 {
     use futures::task;
@@ -201,7 +243,10 @@ where
     let mut debug = Debug::new();
     debug.startup_started();
 
-    let resize_border = settings.window.resize_border;
+    let batch_messages_before_draw = settings.batch_messages_before_draw;
+    let coalesce_mouse_motion = settings.coalesce_mouse_motion;
+    let frame_budget = settings.frame_budget;
+    let on_raw_event = settings.on_raw_event;
 
     #[cfg(feature = "trace")]
     let _ = info_span!("Application", "RUN").entered();
@@ -227,8 +272,7 @@ where
     #[cfg(target_arch = "wasm32")]
     let target = settings.window.platform_specific.target.clone();
 
-    let should_be_visible = settings.window.visible;
-    let exit_on_close_request = settings.window.exit_on_close_request;
+    let options = WindowOptions::new(&settings.window);
 
     let builder = conversion::window_settings(
         settings.window,
@@ -299,9 +343,11 @@ where
         control_sender,
         init_command,
         window,
-        should_be_visible,
-        exit_on_close_request,
-        resize_border,
+        options,
+        batch_messages_before_draw,
+        coalesce_mouse_motion,
+        frame_budget,
+        on_raw_event,
     ));
 
     let mut context = task::Context::from_waker(task::noop_waker_ref());
@@ -347,43 +393,52 @@ async fn run_instance<A, E, C>(
     mut control_sender: mpsc::UnboundedSender<winit::event_loop::ControlFlow>,
     init_command: Command<A::Message>,
     window: Arc<winit::window::Window>,
-    should_be_visible: bool,
-    exit_on_close_request: bool,
-    resize_border: u32,
+    options: WindowOptions,
+    batch_messages_before_draw: Option<usize>,
+    coalesce_mouse_motion: bool,
+    frame_budget: Option<Duration>,
+    on_raw_event: Option<Arc<dyn Fn(&winit::event::WindowEvent) + Send + Sync>>,
 ) where
     A: Application + 'static,
     E: Executor + 'static,
-    C: Compositor<Renderer = A::Renderer> + 'static,
+    C: Compositor<Renderer = A::Renderer> + Sendable + 'static,
+    C::Surface: Sendable,
+    A::Renderer: Sendable,
     A::Theme: StyleSheet,
     A::Message: Send + 'static,
 {
     use winit::event;
     use winit::event_loop::ControlFlow;
 
-    let mut state = State::new(&application, &window);
+    let mut state = State::new(&application, &window, options.level);
     let mut viewport_version = state.viewport_version();
     let physical_size = state.physical_size();
 
     let mut clipboard =
         Clipboard::connect(&window, crate::proxy::Proxy::new(proxy.clone()));
     let mut cache = user_interface::Cache::default();
-    let mut surface = compositor.create_surface(
+    let surface = compositor.create_surface(
         window.clone(),
         physical_size.width,
         physical_size.height,
     );
+    let mut presenter = Presenter::new(compositor, surface, &mut renderer);
+    let mut surface_size = physical_size;
     let mut should_exit = false;
 
-    if should_be_visible {
-        window.set_visible(true);
+    if options.visible {
+        if options.wait_for_fonts {
+            state.wait_for_fonts(WAIT_FOR_FONTS_TIMEOUT);
+        } else {
+            window.set_visible(true);
+        }
     }
 
     run_command(
         &application,
-        &mut compositor,
-        &mut surface,
+        &mut presenter,
         &mut cache,
-        &state,
+        &mut state,
         &mut renderer,
         init_command,
         &mut runtime,
@@ -393,6 +448,13 @@ async fn run_instance<A, E, C>(
         &mut debug,
         &window,
     );
+
+    // The window is shown right away if the initial command did not load any
+    // fonts in the background
+    if state.take_reveal() {
+        window.set_visible(true);
+    }
+
     runtime.track(
         application
             .subscription()
@@ -404,22 +466,23 @@ async fn run_instance<A, E, C>(
         &application,
         cache,
         &mut renderer,
-        state.logical_size(),
+        state.content_bounds().size(),
         &mut debug,
     ));
 
     let mut prev_dnd_rectangles_count = 0;
 
-    // Creates closure for handling the window drag resize state with winit.
-    let mut drag_resize_window_func = drag_resize::event_func(
+    // Keeps track of the window drag resize state with winit.
+    let mut drag_resize = drag_resize::DragResize::new(
         &window,
-        resize_border as f64 * window.scale_factor(),
+        options.resize_border as f64 * window.scale_factor(),
     );
 
     let mut mouse_interaction = mouse::Interaction::default();
     let mut events = Vec::new();
     let mut messages = Vec::new();
     let mut redraw_pending = false;
+    let mut redraw_reason = None;
     #[cfg(feature = "a11y")]
     let mut commands: Vec<Command<A::Message>> = Vec::new();
 
@@ -458,12 +521,42 @@ async fn run_instance<A, E, C>(
 
     debug.startup_finished();
 
-    while let Some(event) = event_receiver.next().await {
+    let mut emulate_about_to_wait = false;
+    let mut watchdog = Watchdog::new(frame_budget);
+
+    loop {
+        watchdog.finish();
+
+        // The event loop does not resume during an interactive resize on
+        // Windows, so `AboutToWait` is emulated after every `Resized` event
+        // of a window that renders while being resized.
+        let event = if std::mem::take(&mut emulate_about_to_wait) {
+            event::Event::AboutToWait
+        } else if let Some(event) = event_receiver.next().await {
+            event
+        } else {
+            break;
+        };
+
+        watchdog.start(watchdog::phase(&event));
+
+        if let (Some(on_raw_event), event::Event::WindowEvent { event, .. }) =
+            (&on_raw_event, &event)
+        {
+            on_raw_event(event);
+        }
+
         match event {
             event::Event::NewEvents(
-                event::StartCause::Init
-                | event::StartCause::ResumeTimeReached { .. },
+                cause @ (event::StartCause::Init
+                | event::StartCause::ResumeTimeReached { .. }),
             ) if !redraw_pending => {
+                if matches!(cause, event::StartCause::ResumeTimeReached { .. })
+                {
+                    let _ = redraw_reason
+                        .get_or_insert(window::RedrawReason::Timer);
+                }
+
                 window.request_redraw();
                 redraw_pending = true;
             }
@@ -505,7 +598,9 @@ async fn run_instance<A, E, C>(
                         content,
                         actions,
                     } => {
-                        let mut renderer = compositor.create_renderer();
+                        let mut target = presenter.lock();
+                        let Target { compositor, .. } = &mut *target;
+                        let renderer = compositor.create_renderer();
                         let icon_surface = icon_surface
                             .map(|i| {
                                 let i: Box<dyn Any> = i;
@@ -523,7 +618,7 @@ async fn run_instance<A, E, C>(
                                 )>>()
                                 .ok()
                             })
-                            .map(|e| {
+                            .and_then(|e| {
                                 let e = Arc::into_inner(*e).unwrap();
                                 let (mut e, widget_state) = e;
                                 let lim = core::layout::Limits::new(
@@ -553,51 +648,35 @@ async fn run_instance<A, E, C>(
                                     Length::Shrink,
                                     size.size(),
                                 );
-                                let mut surface = compositor.create_surface(
-                                    window.clone(),
-                                    size.width.ceil() as u32,
-                                    size.height.ceil() as u32,
-                                );
-                                let viewport = Viewport::with_logical_size(
-                                    size,
-                                    state.viewport().scale_factor(),
-                                );
-
-                                let mut ui = UserInterface::build(
+                                let screenshot = render_element_to_image(
+                                    compositor,
                                     e,
                                     size,
-                                    user_interface::Cache::default(),
-                                    &mut renderer,
-                                );
-                                _ = ui.draw(
-                                    &mut renderer,
+                                    state.viewport().scale_factor(),
                                     state.theme(),
                                     &renderer::Style {
                                         icon_color: state.icon_color(),
                                         text_color: state.text_color(),
                                         scale_factor: state.scale_factor(),
                                     },
-                                    Default::default(),
-                                );
-                                let mut bytes = compositor.screenshot(
-                                    &mut renderer,
-                                    &mut surface,
-                                    &viewport,
-                                    core::Color::TRANSPARENT,
-                                    &debug.overlay(),
-                                );
+                                )?;
+                                let mut bytes =
+                                    Arc::try_unwrap(screenshot.bytes)
+                                        .unwrap_or_else(|bytes| bytes.to_vec());
                                 for pix in bytes.chunks_exact_mut(4) {
                                     // rgba -> argb little endian
                                     pix.swap(0, 2);
                                 }
-                                Icon::Buffer {
+                                Some(Icon::Buffer {
                                     data: Arc::new(bytes),
-                                    width: viewport.physical_width(),
-                                    height: viewport.physical_height(),
+                                    width: screenshot.size.width,
+                                    height: screenshot.size.height,
                                     transparent: true,
-                                }
+                                })
                             });
 
+                        drop(target);
+
                         clipboard.start_dnd_winit(
                             internal,
                             DndSurface(Arc::new(Box::new(window.clone()))),
@@ -606,7 +685,42 @@ async fn run_instance<A, E, C>(
                             actions,
                         );
                     }
-                    UserEventWrapper::Dnd(e) => events.push(Event::Dnd(e)),
+                    UserEventWrapper::Dnd(e) => {
+                        events.push(state.content_event(Event::Dnd(e)));
+                    }
+                    UserEventWrapper::Window(id, event) => {
+                        events.push(Event::Window(id, event));
+                    }
+                    UserEventWrapper::GlobalShortcut(id) => {
+                        events.push(Event::GlobalShortcut(id));
+                    }
+                    UserEventWrapper::InjectedEvent(_id, event) => {
+                        state.update_injected(&event);
+
+                        events.push(event);
+                    }
+                    UserEventWrapper::FontLoaded(font, bytes) => {
+                        use crate::core::text::Renderer;
+
+                        presenter.for_each_renderer(
+                            &mut renderer,
+                            |_, renderer| {
+                                renderer.load_font(bytes.clone());
+                            },
+                        );
+
+                        state.font_load_finished();
+
+                        if state.take_reveal() {
+                            window.set_visible(true);
+                        }
+
+                        events.push(Event::FontLoaded(font));
+                    }
+                    UserEventWrapper::ClipboardExpired(target, contents) => {
+                        let _ = target
+                            .clear_if_unchanged(&mut clipboard, &contents);
+                    }
                 };
             }
             event::Event::WindowEvent {
@@ -619,10 +733,61 @@ async fn run_instance<A, E, C>(
                     continue;
                 }
 
+                if let Some(at) = state.deferred_frame() {
+                    let _ =
+                        control_sender.start_send(ControlFlow::WaitUntil(at));
+
+                    redraw_pending = false;
+                    continue;
+                }
+
+                if let Some(limit) =
+                    batch_messages_before_draw.filter(|_| !messages.is_empty())
+                {
+                    let mut cache =
+                        ManuallyDrop::into_inner(user_interface).into_cache();
+
+                    let mut batch: Vec<_> =
+                        messages.drain(..messages.len().min(limit)).collect();
+
+                    update(
+                        &mut application,
+                        &mut presenter,
+                        &mut cache,
+                        &mut state,
+                        &mut renderer,
+                        &mut runtime,
+                        &mut clipboard,
+                        &mut should_exit,
+                        &mut proxy,
+                        &mut debug,
+                        &mut batch,
+                        &window,
+                    );
+
+                    user_interface = ManuallyDrop::new(build_user_interface(
+                        &application,
+                        cache,
+                        &mut renderer,
+                        state.content_bounds().size(),
+                        &mut debug,
+                    ));
+
+                    if should_exit {
+                        break;
+                    }
+                }
+
+                let mut reason = redraw_reason
+                    .take()
+                    .unwrap_or(window::RedrawReason::Unconditional);
+
                 let current_viewport_version = state.viewport_version();
 
                 if viewport_version != current_viewport_version {
-                    let logical_size = state.logical_size();
+                    reason = window::RedrawReason::Layout;
+
+                    let logical_size = state.content_bounds().size();
 
                     debug.layout_started();
                     user_interface = ManuallyDrop::new(
@@ -631,13 +796,25 @@ async fn run_instance<A, E, C>(
                     );
                     debug.layout_finished();
 
-                    compositor.configure_surface(
-                        &mut surface,
+                    if options.auto_reconfigure_surface {
+                        presenter.configure_surface(
+                            physical_size.width,
+                            physical_size.height,
+                        );
+
+                        surface_size = physical_size;
+                    }
+
+                    viewport_version = current_viewport_version;
+                }
+
+                if state.take_surface_reconfigure_request() {
+                    presenter.configure_surface(
                         physical_size.width,
                         physical_size.height,
                     );
 
-                    viewport_version = current_viewport_version;
+                    surface_size = physical_size;
                 }
 
                 // TODO: Avoid redrawing all the time by forcing widgets to
@@ -652,7 +829,7 @@ async fn run_instance<A, E, C>(
 
                 let (interface_state, _) = user_interface.update(
                     &[redraw_event.clone()],
-                    state.cursor(),
+                    state.content_cursor(),
                     &mut renderer,
                     &mut clipboard,
                     &mut messages,
@@ -663,6 +840,9 @@ async fn run_instance<A, E, C>(
                         redraw_request: Some(redraw_request),
                     } => match redraw_request {
                         window::RedrawRequest::NextFrame => {
+                            let _ = redraw_reason
+                                .get_or_insert(window::RedrawReason::Timer);
+
                             window.request_redraw();
 
                             ControlFlow::Wait
@@ -674,23 +854,101 @@ async fn run_instance<A, E, C>(
                     _ => ControlFlow::Wait,
                 });
 
+                debug.log_redraw(reason);
+
+                #[cfg(feature = "debug")]
+                runtime.broadcast(
+                    Event::Window(
+                        window::Id::MAIN,
+                        window::Event::RedrawReason(reason),
+                    ),
+                    core::event::Status::Ignored,
+                );
+
                 runtime.broadcast(redraw_event, core::event::Status::Ignored);
 
+                if state.take_frame_callback_request() {
+                    runtime.broadcast(
+                        Event::Window(
+                            window::Id::MAIN,
+                            window::Event::FrameCallback(Instant::now()),
+                        ),
+                        core::event::Status::Ignored,
+                    );
+                }
+
+                if let Some(palette) = state.take_palette_change() {
+                    runtime.broadcast(
+                        Event::ThemePaletteChanged(palette),
+                        core::event::Status::Ignored,
+                    );
+                }
+
                 debug.draw_started();
-                let new_mouse_interaction = user_interface.draw(
+                let new_mouse_interaction = letterbox::draw(
                     &mut renderer,
-                    state.theme(),
-                    &renderer::Style {
-                        icon_color: state.icon_color(),
-                        text_color: state.text_color(),
-                        scale_factor: state.scale_factor(),
+                    state.content_bounds(),
+                    |renderer| {
+                        user_interface.draw(
+                            renderer,
+                            state.theme(),
+                            &renderer::Style {
+                                icon_color: state.icon_color(),
+                                text_color: state.text_color(),
+                                scale_factor: state.scale_factor(),
+                            },
+                            state.content_cursor(),
+                        )
                     },
-                    state.cursor(),
                 );
 
+                if let (Some(drag_resize), Some(color)) =
+                    (drag_resize.as_ref(), state.resize_border_highlight())
+                {
+                    drag_resize.draw_highlight(
+                        &mut renderer,
+                        state.logical_size(),
+                        state.scale_factor(),
+                        color,
+                    );
+                }
+
+                if state.resize_grip_visible()
+                    && drag_resize::is_resize_grip_available(
+                        &window,
+                        state.resize_directions(),
+                    )
+                {
+                    drag_resize::draw_resize_grip(
+                        &mut renderer,
+                        state.logical_size(),
+                        core::Color {
+                            a: 0.5,
+                            ..state.text_color()
+                        },
+                    );
+                }
+
+                if let Some(color) = state.dim_overlay() {
+                    dim_overlay::draw(
+                        &mut renderer,
+                        state.logical_size(),
+                        color,
+                    );
+                }
+
+                if state.is_dim_overlay_fading() {
+                    window.request_redraw();
+                }
+
                 debug.draw_finished();
 
-                if new_mouse_interaction != mouse_interaction {
+                let new_mouse_interaction =
+                    state.cursor_override().unwrap_or(new_mouse_interaction);
+
+                if new_mouse_interaction != mouse_interaction
+                    && !state.has_custom_cursor()
+                {
                     window.set_cursor_icon(conversion::mouse_interaction(
                         new_mouse_interaction,
                     ));
@@ -715,7 +973,8 @@ async fn run_instance<A, E, C>(
                         A11yId, A11yNode, A11yTree,
                     };
                     // TODO send a11y tree
-                    let child_tree = user_interface.a11y_nodes(state.cursor());
+                    let child_tree =
+                        user_interface.a11y_nodes(state.content_cursor());
                     let mut root = NodeBuilder::new(Role::Window);
                     root.set_name(state.title());
 
@@ -779,24 +1038,55 @@ async fn run_instance<A, E, C>(
                     });
                 }
 
+                // A surface that has not been reconfigured yet is presented
+                // with its previous size.
+                let stale_viewport;
+                let viewport = if surface_size == physical_size {
+                    state.viewport()
+                } else {
+                    stale_viewport = Viewport::with_physical_size(
+                        surface_size,
+                        state.scale_factor(),
+                    );
+
+                    &stale_viewport
+                };
+
                 debug.render_started();
-                match compositor.present(
+                match presenter.present(
                     &mut renderer,
-                    &mut surface,
-                    state.viewport(),
+                    viewport,
                     state.background_color(),
                     &debug.overlay(),
                 ) {
                     Ok(()) => {
                         debug.render_finished();
+                        state.frame_presented();
 
                         // TODO: Handle animations!
                         // Maybe we can use `ControlFlow::WaitUntil` for this.
                     }
                     Err(error) => match error {
-                        // This is an unrecoverable error.
                         compositor::SurfaceError::OutOfMemory => {
-                            panic!("{error:?}");
+                            // This is an unrecoverable error, unless a
+                            // software fallback is available.
+                            //
+                            // The user interface and its caches are kept
+                            // untouched, only the renderer and surface of
+                            // the previous backend are discarded.
+                            if !presenter.fall_back(
+                                window.clone(),
+                                &mut renderer,
+                                physical_size.width,
+                                physical_size.height,
+                            ) {
+                                panic!("{error:?}");
+                            }
+
+                            debug.render_finished();
+                            surface_size = physical_size;
+
+                            window.request_redraw();
                         }
                         _ => {
                             debug.render_finished();
@@ -811,40 +1101,169 @@ async fn run_instance<A, E, C>(
                 event: window_event,
                 ..
             } => {
+                #[cfg(target_os = "windows")]
+                if state.live_resize()
+                    && matches!(window_event, event::WindowEvent::Resized(_))
+                {
+                    emulate_about_to_wait = true;
+                }
+
+                if state.resize_grip_visible()
+                    && drag_resize::update_resize_grip(
+                        &window,
+                        &window_event,
+                        state.cursor(),
+                        state.logical_size(),
+                        state.scale_factor(),
+                        state.resize_directions(),
+                    )
+                {
+                    continue;
+                }
+
                 // Initiates a drag resize window state when found.
-                if let Some(func) = drag_resize_window_func.as_mut() {
-                    if func(&window, &window_event) {
+                if let Some(drag_resize) = drag_resize.as_mut() {
+                    drag_resize.set_border_size(
+                        state.theme_resize_border().map(|border| {
+                            f64::from(border) * state.scale_factor()
+                        }),
+                    );
+                    drag_resize.set_hit_thickness(
+                        state.resize_hit_thickness().map(|thickness| {
+                            f64::from(thickness) * state.scale_factor()
+                        }),
+                    );
+                    drag_resize.set_corner_size(
+                        state
+                            .resize_corner_size()
+                            .map(|size| f64::from(size) * state.scale_factor()),
+                    );
+                    drag_resize.set_directions(state.resize_directions());
+
+                    let hovered_direction = drag_resize.hovered_direction();
+                    let captured = drag_resize.update(&window, &window_event);
+
+                    if state.resize_border_highlight().is_some()
+                        && drag_resize.hovered_direction() != hovered_direction
+                    {
+                        window.request_redraw();
+                    }
+
+                    if captured {
                         continue;
                     }
                 }
 
                 if requests_exit(&window_event, state.modifiers())
-                    && exit_on_close_request
+                    && options.exit_on_close_request
                 {
                     break;
                 }
 
+                let old_scale_factor = state.scale_factor();
+
                 state.update(&window, &window_event, &mut debug);
 
+                if matches!(
+                    window_event,
+                    event::WindowEvent::ScaleFactorChanged { .. }
+                ) && state.scale_factor() != old_scale_factor
+                {
+                    events.push(Event::Window(
+                        window::Id::MAIN,
+                        window::Event::ScaleFactorChanged {
+                            old: old_scale_factor,
+                            new: state.scale_factor(),
+                        },
+                    ));
+                }
+
+                if !options.auto_reconfigure_surface
+                    && matches!(
+                        window_event,
+                        event::WindowEvent::Resized(_)
+                            | event::WindowEvent::ScaleFactorChanged { .. }
+                    )
+                {
+                    let physical_size = state.physical_size();
+
+                    events.push(Event::Window(
+                        window::Id::MAIN,
+                        window::Event::SurfaceNeedsReconfigure {
+                            width: physical_size.width,
+                            height: physical_size.height,
+                        },
+                    ));
+                }
+
+                if conversion::is_user_interaction(&window_event) {
+                    messages.extend(state.drain_interaction_waiters());
+                }
+
+                if let Some(event) = state.touchpad_event(&window_event) {
+                    events.push(Event::Touchpad(event));
+                }
+
                 if let Some(event) = conversion::window_event(
                     window::Id::MAIN,
                     window_event,
                     state.scale_factor(),
                     state.modifiers(),
                 ) {
+                    // Only keep the latest position of a window being moved
+                    if is_moved(&event) {
+                        events.retain(|event| !is_moved(event));
+                    }
+
+                    events.push(state.content_event(event));
+                }
+            }
+            event::Event::DeviceEvent { event, .. } => {
+                // Raw mouse motion is only useful while the cursor is grabbed
+                if state.cursor_grab() == window::CursorGrabMode::None {
+                    continue;
+                }
+
+                if let Some(event) = conversion::device_event(&event) {
                     events.push(event);
                 }
             }
             event::Event::AboutToWait => {
+                let reveal_deadline = if state.take_reveal() {
+                    window.set_visible(true);
+
+                    None
+                } else {
+                    state.reveal_deadline()
+                };
+
+                // Only one change of the control flow is applied per
+                // iteration, so only the earliest deadline is sent
+                if let Some(deadline) =
+                    [reveal_deadline, state.revert_input_passthrough(&window)]
+                        .into_iter()
+                        .flatten()
+                        .min()
+                {
+                    let _ = control_sender
+                        .start_send(ControlFlow::WaitUntil(deadline));
+                }
+
                 if events.is_empty() && messages.is_empty() {
+                    state.apply_title(&window);
+
                     continue;
                 }
 
                 debug.event_processing_started();
 
+                if coalesce_mouse_motion && !state.precise_mouse_motion() {
+                    coalesce_cursor_moves(&mut events);
+                }
+
                 let (interface_state, statuses) = user_interface.update(
                     &events,
-                    state.cursor(),
+                    state.content_cursor(),
                     &mut renderer,
                     &mut clipboard,
                     &mut messages,
@@ -852,26 +1271,44 @@ async fn run_instance<A, E, C>(
 
                 debug.event_processing_finished();
 
+                // Lay out the interface once for every font loaded this frame
+                let fonts_loaded = events
+                    .iter()
+                    .any(|event| matches!(event, Event::FontLoaded(_)));
+
+                // Discard the in-process payload of a DnD operation once it
+                // ends, in case it was dropped outside of the application
+                if events.iter().any(ends_dnd) {
+                    clipboard.set_dnd_payload(None);
+                }
+
+                // Raw mouse motion does not change the interface by itself
+                let is_raw_motion_only = events.iter().all(is_raw_motion);
+
                 for (event, status) in
                     events.drain(..).zip(statuses.into_iter())
                 {
+                    state.update_title_bar(&window, &event, status);
+
                     runtime.broadcast(event, status);
                 }
 
                 if !messages.is_empty()
+                    || fonts_loaded
                     || matches!(
                         interface_state,
                         user_interface::State::Outdated
                     )
                 {
+                    redraw_reason = Some(window::RedrawReason::Message);
+
                     let mut cache =
                         ManuallyDrop::into_inner(user_interface).into_cache();
 
                     // Update application
                     update(
                         &mut application,
-                        &mut compositor,
-                        &mut surface,
+                        &mut presenter,
                         &mut cache,
                         &mut state,
                         &mut renderer,
@@ -884,11 +1321,17 @@ async fn run_instance<A, E, C>(
                         &window,
                     );
 
+                    if !options.keep_cache_when_hidden
+                        && window.is_visible() == Some(false)
+                    {
+                        cache = user_interface::Cache::default();
+                    }
+
                     user_interface = ManuallyDrop::new(build_user_interface(
                         &application,
                         cache,
                         &mut renderer,
-                        state.logical_size(),
+                        state.content_bounds().size(),
                         &mut debug,
                     ));
 
@@ -913,6 +1356,24 @@ async fn run_instance<A, E, C>(
                     }
                 }
 
+                // Only set the latest title of this event loop iteration
+                state.apply_title(&window);
+
+                if is_raw_motion_only
+                    && redraw_reason.is_none()
+                    && !matches!(
+                        interface_state,
+                        user_interface::State::Updated {
+                            redraw_request: Some(_)
+                        }
+                    )
+                {
+                    continue;
+                }
+
+                let _ =
+                    redraw_reason.get_or_insert(window::RedrawReason::Input);
+
                 if !redraw_pending {
                     window.request_redraw();
                     redraw_pending = true;
@@ -922,6 +1383,8 @@ async fn run_instance<A, E, C>(
         }
     }
 
+    crate::global_shortcut::unregister_all();
+
     // Manually drop the user interface
     drop(ManuallyDrop::into_inner(user_interface));
 }
@@ -973,6 +1436,55 @@ where
     user_interface
 }
 
+/// Renders the given [`core::Element`] off-screen with the given logical
+/// `size` and `scale_factor`, returning a [`Screenshot`] of the result.
+///
+/// No surface is created, so the contents of the windows are left untouched.
+/// Returns `None` if the [`Compositor`] cannot render off-screen.
+///
+/// [`Screenshot`]: crate::runtime::window::Screenshot
+pub fn render_element_to_image<Message, Theme, C>(
+    compositor: &mut C,
+    element: core::Element<'_, Message, Theme, C::Renderer>,
+    size: Size,
+    scale_factor: f64,
+    theme: &Theme,
+    style: &renderer::Style,
+) -> Option<crate::runtime::window::Screenshot>
+where
+    C: Compositor,
+{
+    let mut renderer = compositor.create_renderer();
+    let viewport = Viewport::with_logical_size(size, scale_factor);
+    let physical_size = viewport.physical_size();
+
+    let mut user_interface = UserInterface::build(
+        element,
+        size,
+        user_interface::Cache::default(),
+        &mut renderer,
+    );
+
+    let _ = user_interface.draw(
+        &mut renderer,
+        theme,
+        style,
+        mouse::Cursor::Unavailable,
+    );
+
+    let bytes = compositor.render_offscreen(
+        &mut renderer,
+        &viewport,
+        core::Color::TRANSPARENT,
+        &[] as &[&str],
+    )?;
+
+    Some(crate::runtime::window::Screenshot::new(
+        bytes,
+        physical_size,
+    ))
+}
+
 /// subscription mapper helper
 pub fn subscription_map<A, E>(e: A::Message) -> UserEventWrapper<A::Message>
 where
@@ -987,8 +1499,7 @@ where
 /// resulting [`Command`], and tracking its [`Subscription`].
 pub fn update<A: Application + 'static, C, E: Executor + 'static>(
     application: &mut A,
-    compositor: &mut C,
-    surface: &mut C::Surface,
+    presenter: &mut Presenter<C>,
     cache: &mut user_interface::Cache,
     state: &mut State<A>,
     renderer: &mut A::Renderer,
@@ -1002,7 +1513,7 @@ pub fn update<A: Application + 'static, C, E: Executor + 'static>(
     proxy: &mut winit::event_loop::EventLoopProxy<UserEventWrapper<A::Message>>,
     debug: &mut Debug,
     messages: &mut Vec<A::Message>,
-    window: &winit::window::Window,
+    window: &Arc<winit::window::Window>,
 ) where
     C: Compositor<Renderer = A::Renderer> + 'static,
     A::Theme: StyleSheet,
@@ -1016,8 +1527,7 @@ pub fn update<A: Application + 'static, C, E: Executor + 'static>(
 
         run_command(
             application,
-            compositor,
-            surface,
+            presenter,
             cache,
             state,
             renderer,
@@ -1044,10 +1554,9 @@ pub fn update<A: Application + 'static, C, E: Executor + 'static>(
 /// Runs the actions of a [`Command`].
 pub fn run_command<A, C, E>(
     application: &A,
-    compositor: &mut C,
-    surface: &mut C::Surface,
+    presenter: &mut Presenter<C>,
     cache: &mut user_interface::Cache,
-    state: &State<A>,
+    state: &mut State<A>,
     renderer: &mut A::Renderer,
     command: Command<A::Message>,
     runtime: &mut Runtime<
@@ -1059,7 +1568,7 @@ pub fn run_command<A, C, E>(
     should_exit: &mut bool,
     proxy: &mut winit::event_loop::EventLoopProxy<UserEventWrapper<A::Message>>,
     debug: &mut Debug,
-    window: &winit::window::Window,
+    window: &Arc<winit::window::Window>,
 ) where
     A: Application,
     E: Executor,
@@ -1070,7 +1579,11 @@ pub fn run_command<A, C, E>(
     use crate::runtime::system;
     use crate::runtime::window;
 
-    for action in command.actions() {
+    for action in command
+        .actions()
+        .into_iter()
+        .flat_map(command::Action::flatten)
+    {
         match action {
             command::Action::Future(future) => {
                 runtime.spawn(Box::pin(future.map(UserEventWrapper::Message)));
@@ -1097,6 +1610,23 @@ pub fn run_command<A, C, E>(
                     let message = to_msg(contents);
                     _ = proxy.send_event(UserEventWrapper::Message(message));
                 }
+                clipboard::Action::ReadUris(to_msg) => {
+                    let paths = clipboard
+                        .read_data(vec![clipboard::URI_LIST_MIME_TYPE.into()])
+                        .map(|(bytes, _)| clipboard::file_paths(&bytes))
+                        .unwrap_or_default();
+                    let message = to_msg(paths);
+                    _ = proxy.send_event(UserEventWrapper::Message(message));
+                }
+                clipboard::Action::WriteHtml { html, plain } => clipboard
+                    .write_data(ClipboardStoreData(Box::new(
+                        clipboard::Html { html, plain },
+                    ))),
+                clipboard::Action::ReadAvailableTypes(target, to_msg) => {
+                    let types = target.available_types(clipboard);
+                    let message = to_msg(types);
+                    _ = proxy.send_event(UserEventWrapper::Message(message));
+                }
                 clipboard::Action::ReadPrimary(s_to_msg) => {
                     let contents = clipboard.read_primary();
                     let message = s_to_msg(contents);
@@ -1113,6 +1643,15 @@ pub fn run_command<A, C, E>(
                     let message = to_msg(contents);
                     _ = proxy.send_event(UserEventWrapper::Message(message));
                 }
+                clipboard::Action::WriteWithExpiry {
+                    target,
+                    contents,
+                    ttl,
+                } => {
+                    target.write(clipboard, contents.clone());
+
+                    schedule_clipboard_clear(proxy, target, contents, ttl);
+                }
             },
             command::Action::Window(action) => match action {
                 window::Action::Close(_id) => {
@@ -1144,6 +1683,13 @@ pub fn run_command<A, C, E>(
                         )))
                         .expect("Send message to event loop");
                 }
+                window::Action::FetchFrameInsets(_id, callback) => {
+                    proxy
+                        .send_event(UserEventWrapper::Message(callback(
+                            conversion::frame_insets(window),
+                        )))
+                        .expect("Send message to event loop");
+                }
                 window::Action::FetchMaximized(_id, callback) => {
                     proxy
                         .send_event(UserEventWrapper::Message(callback(
@@ -1164,6 +1710,17 @@ pub fn run_command<A, C, E>(
                 window::Action::Minimize(_id, minimized) => {
                     window.set_minimized(minimized);
                 }
+                window::Action::MinimizeAll => {
+                    if window.is_minimized() != Some(true) {
+                        window.set_minimized(true);
+                        state.set_minimized_all(true);
+                    }
+                }
+                window::Action::RestoreAll => {
+                    if state.take_minimized_all() {
+                        window.set_minimized(false);
+                    }
+                }
                 window::Action::Move(_id, position) => {
                     window.set_outer_position(winit::dpi::LogicalPosition {
                         x: position.x,
@@ -1194,19 +1751,270 @@ pub fn run_command<A, C, E>(
                 window::Action::ToggleMaximize(_id) => {
                     window.set_maximized(!window.is_maximized());
                 }
-                window::Action::ToggleDecorations(_id) => {
-                    window.set_decorations(!window.is_decorated());
-                }
-                window::Action::RequestUserAttention(_id, user_attention) => {
-                    window.request_user_attention(
-                        user_attention.map(conversion::user_attention),
-                    );
+                window::Action::GetDecorationMode(_id, tag) => {
+                    // `winit` does not expose the decorations negotiated with
+                    // the Wayland compositor
+                    proxy
+                        .send_event(UserEventWrapper::Message(tag(None)))
+                        .expect("Send message to event loop");
+                }
+                window::Action::GetCurrentOutputName(_id, tag) => {
+                    let name = window
+                        .current_monitor()
+                        .and_then(|monitor| monitor.name());
+
+                    proxy
+                        .send_event(UserEventWrapper::Message(tag(name)))
+                        .expect("Send message to event loop");
+                }
+                window::Action::GetSurfaceFormat(_id, tag) => {
+                    let format = {
+                        let target = presenter.lock();
+
+                        target.compositor.fetch_surface_format(&target.surface)
+                    };
+
+                    proxy
+                        .send_event(UserEventWrapper::Message(tag(format)))
+                        .expect("Send message to event loop");
+                }
+                window::Action::ToggleDecorations(_id) => {
+                    window.set_decorations(!window.is_decorated());
+                }
+                window::Action::RequestUserAttention(_id, user_attention) => {
+                    window.request_user_attention(
+                        user_attention.map(conversion::user_attention),
+                    );
+                }
+                window::Action::SetUrgent(_id, urgent) => {
+                    window.request_user_attention(urgent.then_some(
+                        winit::window::UserAttentionType::Informational,
+                    ));
+
+                    state.set_urgent(urgent);
                 }
                 window::Action::GainFocus(_id) => {
                     window.focus_window();
                 }
                 window::Action::ChangeLevel(_id, level) => {
-                    window.set_window_level(conversion::window_level(level));
+                    state.set_level(window, level);
+                }
+                window::Action::Batch(_) => {
+                    // Batches are flattened before running their actions
+                }
+                window::Action::SetKeepAbove(_id, above) => {
+                    state.set_keep_level(
+                        window,
+                        window::Level::AlwaysOnTop,
+                        above,
+                    );
+                }
+                window::Action::SetKeepBelow(_id, below) => {
+                    state.set_keep_level(
+                        window,
+                        window::Level::AlwaysOnBottom,
+                        below,
+                    );
+                }
+                window::Action::SetPreferredTheme(_id, theme) => {
+                    window.set_theme(theme.map(conversion::theme_mode));
+                }
+                window::Action::SetResizeIncrements(_id, increments) => {
+                    window.set_resize_increments(increments.map(|size| {
+                        winit::dpi::LogicalSize {
+                            width: size.width,
+                            height: size.height,
+                        }
+                    }));
+                }
+                window::Action::SetBaseSize(_id, size) => {
+                    // `winit` can only set the base size on creation, but
+                    // window managers use the minimum size in its place
+                    window.set_min_inner_size(Some(winit::dpi::LogicalSize {
+                        width: size.width,
+                        height: size.height,
+                    }));
+                }
+                window::Action::SetImeArea(_id, area) => {
+                    let content_bounds = state.content_bounds();
+
+                    let (position, size) = conversion::ime_area(
+                        area + core::Vector::new(
+                            content_bounds.x,
+                            content_bounds.y,
+                        ),
+                        state.logical_size(),
+                        state.scale_factor(),
+                    );
+
+                    window.set_ime_cursor_area(position, size);
+                }
+                window::Action::SetImeAllowed(_id, allowed) => {
+                    window.set_ime_allowed(allowed);
+                }
+                window::Action::SetInputCapture(_id, _capture) => {}
+                window::Action::SetResizeBorderHighlight(_id, color) => {
+                    state.set_resize_border_highlight(color);
+                    window.request_redraw();
+                }
+                window::Action::SetResizeHitThickness(_id, thickness) => {
+                    state.set_resize_hit_thickness(thickness);
+                }
+                window::Action::SetResizeCornerSize(_id, size) => {
+                    state.set_resize_corner_size(size);
+                }
+                window::Action::SetResizeDirections(_id, directions) => {
+                    state.set_resize_directions(directions);
+                }
+                window::Action::SetAspectRatio(_id, aspect_ratio, mode) => {
+                    state.set_aspect_ratio(window, aspect_ratio, mode);
+                    window.request_redraw();
+                }
+                window::Action::ShowResizeGrip(_id, visible) => {
+                    state.set_resize_grip_visible(visible);
+
+                    window.request_redraw();
+                }
+                window::Action::SyncResizeBorderWithTheme(_id, synced) => {
+                    state.set_resize_border_synced_with_theme(synced);
+                }
+                window::Action::SetTitleBarRegion(_id, region) => {
+                    state.set_title_bar_region(region);
+                }
+                window::Action::Snap(_id, position) => {
+                    state.snap(window, position);
+                }
+                window::Action::SetBackdrop(_id, _material) => {
+                    #[cfg(target_os = "windows")]
+                    {
+                        if _material != window::BackdropMaterial::None
+                            && state.background_color().a >= 1.0
+                        {
+                            log::warn!(
+                                "The background of the window is opaque; \
+                                its backdrop material will not be visible"
+                            );
+                        }
+
+                        crate::windows::set_backdrop(window, _material);
+                    }
+                }
+                window::Action::SetTrafficLightOffset(_id, _offset) => {
+                    #[cfg(target_os = "macos")]
+                    state.set_traffic_light_offset(window, _offset);
+                }
+                window::Action::SetDocumentEdited(_id, _edited) => {
+                    #[cfg(target_os = "macos")]
+                    {
+                        use winit::platform::macos::WindowExtMacOS;
+
+                        window.set_document_edited(_edited);
+                    }
+                }
+                window::Action::SetDocumentPath(_id, _path) => {
+                    #[cfg(target_os = "macos")]
+                    crate::macos::set_document_path(window, &_path);
+                }
+                window::Action::SetMinimizeAnimation(_id, _enabled) => {
+                    #[cfg(target_os = "macos")]
+                    crate::macos::set_minimize_animation(window, _enabled);
+
+                    #[cfg(target_os = "windows")]
+                    crate::windows::set_minimize_animation(window, _enabled);
+                }
+                window::Action::SetCursorTheme(_id, _theme, _size) => {
+                    // The cursor theme is chosen by the environment
+                }
+                window::Action::RequestFrameCallback(_id) => {
+                    if state.request_frame_callback() {
+                        window.request_redraw();
+                    }
+                }
+                window::Action::PlayAttentionSound(_id) => {
+                    if state.attention_sound_enabled() {
+                        crate::attention_sound::play();
+                    }
+                }
+                window::Action::SetAttentionSoundEnabled(_id, enabled) => {
+                    state.set_attention_sound_enabled(enabled);
+                }
+                window::Action::SetInputPassthrough(
+                    id,
+                    passthrough,
+                    duration,
+                ) => {
+                    state.set_input_passthrough(window, passthrough, duration);
+
+                    // The revert is scheduled once the event loop is about
+                    // to wait again
+                    window.request_redraw();
+                }
+                window::Action::SetCursorGrab(_id, mode, callback) => {
+                    let result = window
+                        .set_cursor_grab(conversion::cursor_grab_mode(mode));
+
+                    match &result {
+                        Ok(()) => state.set_cursor_grab(mode),
+                        Err(error) => {
+                            log::warn!("Failed to grab the cursor: {error}");
+                        }
+                    }
+
+                    if let Some(callback) = callback {
+                        proxy
+                            .send_event(UserEventWrapper::Message(callback(
+                                result.is_ok(),
+                            )))
+                            .expect("Send message to event loop");
+                    }
+                }
+                window::Action::SetFrameInterval(_id, interval) => {
+                    state.set_frame_interval(interval);
+                }
+                window::Action::SetPreciseMouseMotion(_id, precise) => {
+                    state.set_precise_mouse_motion(precise);
+                }
+                window::Action::SetLiveResize(_id, live) => {
+                    state.set_live_resize(live);
+                }
+                window::Action::SetGlobalScaleFactor(scale_factor) => {
+                    if !scale_factor.is_finite() || scale_factor <= 0.0 {
+                        log::warn!(
+                            "Ignoring invalid global scale factor: \
+                            {scale_factor}"
+                        );
+                        continue;
+                    }
+
+                    state.set_global_scale_factor(scale_factor);
+                    state.synchronize(application, window);
+
+                    window.request_redraw();
+                }
+                window::Action::FollowGlobalScaleFactor(_id, follows) => {
+                    state.set_follows_global_scale_factor(follows);
+                    state.synchronize(application, window);
+
+                    window.request_redraw();
+                }
+                window::Action::SetColorProfile(_id, icc) => {
+                    let profile = conversion::color_profile(&icc);
+
+                    presenter.for_each_renderer(
+                        renderer,
+                        |compositor, renderer| {
+                            compositor.set_color_profile(renderer, profile);
+                        },
+                    );
+                    window.request_redraw();
+                }
+                window::Action::SetWindowRole(_id, _role) => {
+                    #[cfg(all(target_os = "linux", feature = "x11"))]
+                    crate::x11::set_window_role(window, &_role);
+                }
+                window::Action::SetFrameExtents(_id, _extents) => {
+                    #[cfg(all(target_os = "linux", feature = "x11"))]
+                    state.set_frame_extents(window, _extents);
                 }
                 window::Action::ShowWindowMenu(_id) => {
                     if let mouse::Cursor::Available(point) = state.cursor() {
@@ -1223,7 +2031,69 @@ pub fn run_command<A, C, E>(
                             .into())))
                         .expect("Send message to event loop");
                 }
+                window::Action::FetchAvailableMonitors(_id, tag) => {
+                    let monitors = window
+                        .available_monitors()
+                        .map(|monitor| conversion::monitor(&monitor))
+                        .collect();
+
+                    proxy
+                        .send_event(UserEventWrapper::Message(tag(monitors)))
+                        .expect("Send message to event loop");
+                }
+                window::Action::FetchVideoModes(_id, tag) => {
+                    let video_modes = window
+                        .current_monitor()
+                        .map(|monitor| {
+                            monitor
+                                .video_modes()
+                                .map(|video_mode| {
+                                    conversion::video_mode(&video_mode)
+                                })
+                                .collect()
+                        })
+                        .unwrap_or_default();
+
+                    proxy
+                        .send_event(UserEventWrapper::Message(tag(video_modes)))
+                        .expect("Send message to event loop");
+                }
+                window::Action::SetExclusiveFullscreen(id, video_mode) => {
+                    let monitor = window.current_monitor();
+
+                    if let Some(fullscreen) = conversion::exclusive_fullscreen(
+                        monitor.as_ref(),
+                        video_mode,
+                    ) {
+                        window.set_fullscreen(Some(fullscreen));
+                    }
+
+                    // Fall back to borderless if the mode was rejected
+                    if !matches!(
+                        window.fullscreen(),
+                        Some(winit::window::Fullscreen::Exclusive(_))
+                    ) {
+                        window.set_fullscreen(Some(
+                            winit::window::Fullscreen::Borderless(monitor),
+                        ));
+
+                        let event =
+                            core::window::Event::ExclusiveFullscreenRejected(
+                                video_mode,
+                            );
+
+                        proxy
+                            .send_event(UserEventWrapper::Window(id, event))
+                            .expect("Send message to event loop");
+                    }
+                }
                 window::Action::Screenshot(_id, tag) => {
+                    let mut target = presenter.lock();
+                    let Target {
+                        compositor,
+                        surface,
+                    } = &mut *target;
+
                     let bytes = compositor.screenshot(
                         renderer,
                         surface,
@@ -1241,12 +2111,136 @@ pub fn run_command<A, C, E>(
                         )))
                         .expect("Send message to event loop.");
                 }
+                window::Action::RenderElement(_id, element, size, tag) => {
+                    let Ok(element) = element.downcast::<(
+                        core::Element<
+                            'static,
+                            A::Message,
+                            A::Theme,
+                            A::Renderer,
+                        >,
+                        A::Theme,
+                    )>() else {
+                        log::warn!(
+                            "The element to render does not belong to the \
+                            application"
+                        );
+
+                        proxy
+                            .send_event(UserEventWrapper::Message(tag(None)))
+                            .expect("Send message to event loop.");
+
+                        continue;
+                    };
+
+                    let (element, theme) = *element;
+                    let mut target = presenter.lock();
+
+                    let screenshot = render_element_to_image(
+                        &mut target.compositor,
+                        element,
+                        size,
+                        state.viewport().scale_factor(),
+                        &theme,
+                        &renderer::Style {
+                            icon_color: state.icon_color(),
+                            text_color: state.text_color(),
+                            scale_factor: state.scale_factor(),
+                        },
+                    );
+
+                    proxy
+                        .send_event(UserEventWrapper::Message(tag(screenshot)))
+                        .expect("Send message to event loop.");
+                }
+                window::Action::DumpLayout(_id, tag) => {
+                    let mut user_interface = build_user_interface(
+                        application,
+                        std::mem::take(cache),
+                        renderer,
+                        state.content_bounds().size(),
+                        debug,
+                    );
+
+                    let mut dump = operation::LayoutDump::new();
+                    user_interface.operate(renderer, &mut dump);
+
+                    *cache = user_interface.into_cache();
+
+                    proxy
+                        .send_event(UserEventWrapper::Message(tag(
+                            dump.to_json()
+                        )))
+                        .expect("Send message to event loop");
+                }
+                window::Action::DropCache(_id) => {
+                    *cache = user_interface::Cache::default();
+                }
+                window::Action::SetCursorIcon(_id, interaction) => {
+                    state.set_cursor_override(interaction);
+
+                    if let Some(interaction) =
+                        interaction.filter(|_| !state.has_custom_cursor())
+                    {
+                        window.set_cursor_icon(conversion::mouse_interaction(
+                            interaction,
+                        ));
+                    }
+
+                    window.request_redraw();
+                }
+                window::Action::SetCustomCursor(_id, cursor) => {
+                    state.set_custom_cursor(window, cursor);
+                    window.request_redraw();
+                }
+                window::Action::SetDimOverlay(_id, color) => {
+                    state.set_dim_overlay(color);
+                    window.request_redraw();
+                }
+                window::Action::SetTitle(_id, title) => {
+                    state.set_title(title);
+                    state.apply_title(window);
+                }
+                window::Action::FetchTitle(_id, callback) => {
+                    proxy
+                        .send_event(UserEventWrapper::Message(callback(Some(
+                            state.title().to_owned(),
+                        ))))
+                        .expect("Send message to event loop");
+                }
+                window::Action::FetchSurfaceScale(_id, callback) => {
+                    proxy
+                        .send_event(UserEventWrapper::Message(callback(Some(
+                            window.scale_factor(),
+                        ))))
+                        .expect("Send message to event loop");
+                }
+                window::Action::SetRenderPriority(_id, _priority) => {
+                    // A single window is never rendered after another one
+                }
+                window::Action::FitToContent(_id) => {
+                    debug.view_started();
+                    let content = application.view();
+                    debug.view_finished();
+
+                    crate::fit::fit_to_content(
+                        window,
+                        &content,
+                        renderer,
+                        state.scale_factor(),
+                    );
+                }
+                window::Action::ReconfigureSurface(_id) => {
+                    state.request_surface_reconfigure();
+                    window.request_redraw();
+                }
             },
             command::Action::System(action) => match action {
                 system::Action::QueryInformation(_tag) => {
                     #[cfg(feature = "system")]
                     {
-                        let graphics_info = compositor.fetch_information();
+                        let graphics_info =
+                            presenter.lock().compositor.fetch_information();
                         let proxy = proxy.clone();
 
                         let _ = std::thread::spawn(move || {
@@ -1261,8 +2255,320 @@ pub fn run_command<A, C, E>(
                         });
                     }
                 }
+                system::Action::QueryInformationCancellable(_token, _tag) => {
+                    #[cfg(feature = "system")]
+                    {
+                        let graphics_info =
+                            presenter.lock().compositor.fetch_information();
+                        let proxy = proxy.clone();
+
+                        let _ = std::thread::spawn(move || {
+                            let information =
+                                crate::system::information(graphics_info);
+
+                            // Sending under the lock of the token cannot race
+                            // against its cancellation
+                            let _ = _token.unless_cancelled(|| {
+                                let message = _tag(information);
+
+                                proxy
+                                    .send_event(UserEventWrapper::Message(
+                                        message,
+                                    ))
+                                    .expect("Send message to event loop")
+                            });
+                        });
+                    }
+                }
+                system::Action::QueryDoubleClickInterval(_tag) => {
+                    #[cfg(feature = "system")]
+                    {
+                        let proxy = proxy.clone();
+
+                        let _ = std::thread::spawn(move || {
+                            let interval =
+                                crate::system::double_click_interval();
+
+                            let message = _tag(interval);
+
+                            proxy
+                                .send_event(UserEventWrapper::Message(message))
+                                .expect("Send message to event loop");
+                        });
+                    }
+                }
+                system::Action::QueryDoubleClickIntervalCancellable(
+                    _token,
+                    _tag,
+                ) => {
+                    #[cfg(feature = "system")]
+                    {
+                        let proxy = proxy.clone();
+
+                        let _ = std::thread::spawn(move || {
+                            let interval =
+                                crate::system::double_click_interval();
+
+                            // Sending under the lock of the token cannot race
+                            // against its cancellation
+                            let _ = _token.unless_cancelled(|| {
+                                let message = _tag(interval);
+
+                                proxy
+                                    .send_event(UserEventWrapper::Message(
+                                        message,
+                                    ))
+                                    .expect("Send message to event loop");
+                            });
+                        });
+                    }
+                }
+                system::Action::GetRenderStats(_id, tag) => {
+                    let stats = presenter
+                        .lock()
+                        .compositor
+                        .fetch_render_stats(renderer);
+
+                    proxy
+                        .send_event(UserEventWrapper::Message(tag(stats)))
+                        .expect("Send message to event loop");
+                }
+                system::Action::AwaitInteraction(tag) => {
+                    state.await_interaction(tag);
+                }
+                system::Action::GetActiveBackend(tag) => {
+                    let backend = presenter.lock().compositor.backend();
+
+                    proxy
+                        .send_event(UserEventWrapper::Message(tag(backend)))
+                        .expect("Send message to event loop");
+                }
+                system::Action::IsHardwareAccelerated(tag) => {
+                    let hardware_accelerated = presenter
+                        .lock()
+                        .compositor
+                        .fetch_information()
+                        .hardware_accelerated;
+
+                    proxy
+                        .send_event(UserEventWrapper::Message(tag(
+                            hardware_accelerated,
+                        )))
+                        .expect("Send message to event loop");
+                }
+                system::Action::CaptureAllWindows(tag) => {
+                    let mut screenshots = HashMap::new();
+
+                    if window.is_minimized() != Some(true)
+                        && window.is_visible() != Some(false)
+                    {
+                        let mut target = presenter.lock();
+                        let Target {
+                            compositor,
+                            surface,
+                        } = &mut *target;
+
+                        let bytes = compositor.screenshot(
+                            renderer,
+                            surface,
+                            state.viewport(),
+                            state.background_color(),
+                            &debug.overlay(),
+                        );
+
+                        let _ = screenshots.insert(
+                            window::Id::MAIN,
+                            window::Screenshot::new(
+                                bytes,
+                                state.physical_size(),
+                            ),
+                        );
+                    }
+
+                    proxy
+                        .send_event(UserEventWrapper::Message(tag(screenshots)))
+                        .expect("Send message to event loop");
+                }
+                system::Action::GetWindowManagerName(_token, _tag) => {
+                    #[cfg(feature = "system")]
+                    {
+                        let proxy = proxy.clone();
+
+                        let _ = std::thread::spawn(move || {
+                            let name = crate::system::window_manager_name();
+
+                            // Sending under the lock of the token cannot race
+                            // against its cancellation
+                            let _ = _token.unless_cancelled(|| {
+                                let message = _tag(name);
+
+                                proxy
+                                    .send_event(UserEventWrapper::Message(
+                                        message,
+                                    ))
+                                    .expect("Send message to event loop");
+                            });
+                        });
+                    }
+                }
+                system::Action::GetKeyRepeat(_token, _tag) => {
+                    #[cfg(feature = "system")]
+                    {
+                        let proxy = proxy.clone();
+
+                        let _ = std::thread::spawn(move || {
+                            let key_repeat = crate::system::key_repeat();
+
+                            // Sending under the lock of the token cannot race
+                            // against its cancellation
+                            let _ = _token.unless_cancelled(|| {
+                                let message = _tag(key_repeat);
+
+                                proxy
+                                    .send_event(UserEventWrapper::Message(
+                                        message,
+                                    ))
+                                    .expect("Send message to event loop");
+                            });
+                        });
+                    }
+                }
+                system::Action::RegisterGlobalShortcut(shortcut, tag) => {
+                    let trigger = proxy.clone();
+                    let reply = proxy.clone();
+
+                    crate::global_shortcut::register(
+                        &shortcut,
+                        move |id| {
+                            let _ = trigger.send_event(
+                                UserEventWrapper::GlobalShortcut(id),
+                            );
+                        },
+                        move |result| {
+                            // The event loop may be gone by now
+                            let _ = reply.send_event(
+                                UserEventWrapper::Message(tag(result)),
+                            );
+                        },
+                    );
+                }
+                system::Action::UnregisterGlobalShortcut(id) => {
+                    crate::global_shortcut::unregister(id);
+                }
+                system::Action::InjectEvent(id, event) => {
+                    proxy
+                        .send_event(UserEventWrapper::InjectedEvent(id, event))
+                        .expect("Send message to event loop");
+                }
+                system::Action::GetMonitorScaleFactor(monitor, tag) => {
+                    let scale_factor =
+                        crate::monitor::scale_factor(window, &monitor);
+
+                    proxy
+                        .send_event(UserEventWrapper::Message(tag(
+                            scale_factor,
+                        )))
+                        .expect("Send message to event loop");
+                }
+                system::Action::GetDesktopScaling(tag) => {
+                    let monitors = window
+                        .available_monitors()
+                        .map(|monitor| conversion::monitor(&monitor))
+                        .collect();
+
+                    proxy
+                        .send_event(UserEventWrapper::Message(tag(monitors)))
+                        .expect("Send message to event loop");
+                }
+                system::Action::GetSystemFonts(tag) => {
+                    let proxy = proxy.clone();
+
+                    let _ = std::thread::spawn(move || {
+                        let families = crate::graphics::text::font_families();
+
+                        proxy
+                            .send_event(UserEventWrapper::Message(tag(
+                                families,
+                            )))
+                            .expect("Send message to event loop");
+                    });
+                }
+                system::Action::GetProcessInfo(token, tag) => {
+                    #[cfg(feature = "system")]
+                    {
+                        let proxy = proxy.clone();
+
+                        let _ = std::thread::spawn(move || {
+                            let info = crate::system::process_info();
+
+                            // Sending under the lock of the token cannot race
+                            // against its cancellation
+                            let _ = token.unless_cancelled(|| {
+                                let message = tag(info);
+
+                                proxy
+                                    .send_event(UserEventWrapper::Message(
+                                        message,
+                                    ))
+                                    .expect("Send message to event loop");
+                            });
+                        });
+                    }
+
+                    #[cfg(not(feature = "system"))]
+                    let _ = token.unless_cancelled(|| {
+                        proxy
+                            .send_event(UserEventWrapper::Message(tag(
+                                system::ProcessInfo::current(),
+                            )))
+                            .expect("Send message to event loop");
+                    });
+                }
+                system::Action::GetDiskInfo(token, path, tag) => {
+                    #[cfg(feature = "system")]
+                    {
+                        let proxy = proxy.clone();
+
+                        let _ = std::thread::spawn(move || {
+                            let info = crate::system::disk_info(&path);
+
+                            // Sending under the lock of the token cannot race
+                            // against its cancellation
+                            let _ = token.unless_cancelled(|| {
+                                let message = tag(info);
+
+                                proxy
+                                    .send_event(UserEventWrapper::Message(
+                                        message,
+                                    ))
+                                    .expect("Send message to event loop");
+                            });
+                        });
+                    }
+
+                    #[cfg(not(feature = "system"))]
+                    {
+                        let _ = path;
+
+                        let _ = token.unless_cancelled(|| {
+                            proxy
+                                .send_event(UserEventWrapper::Message(tag(
+                                    Err(system::DiskInfoError::Unsupported),
+                                )))
+                                .expect("Send message to event loop");
+                        });
+                    }
+                }
+                system::Action::GetMonitorLayout(tag) => {
+                    let layout = crate::monitor::layout(window);
+
+                    proxy
+                        .send_event(UserEventWrapper::Message(tag(layout)))
+                        .expect("Send message to event loop");
+                }
             },
-            command::Action::Widget(action) => {
+            command::Action::Widget(action)
+            | command::Action::WidgetFor(_, action) => {
                 let mut current_cache = std::mem::take(cache);
                 let mut current_operation =
                     Some(Box::new(OperationWrapper::Message(action)));
@@ -1270,7 +2576,7 @@ pub fn run_command<A, C, E>(
                     application,
                     current_cache,
                     renderer,
-                    state.logical_size(),
+                    state.content_bounds().size(),
                     debug,
                 );
 
@@ -1309,12 +2615,21 @@ pub fn run_command<A, C, E>(
                 use crate::core::text::Renderer;
 
                 // TODO: Error handling (?)
-                renderer.load_font(bytes);
+                presenter.for_each_renderer(renderer, |_, renderer| {
+                    renderer.load_font(bytes.clone());
+                });
 
                 proxy
                     .send_event(UserEventWrapper::Message(tagger(Ok(()))))
                     .expect("Send message to event loop");
             }
+            command::Action::LoadFontAsync { font, bytes } => {
+                state.font_load_started();
+
+                runtime.spawn(Box::pin(bytes.map(move |bytes| {
+                    UserEventWrapper::FontLoaded(font, bytes)
+                })));
+            }
             command::Action::PlatformSpecific(_) => todo!(),
             command::Action::Dnd(a) => match a {
                 iced_runtime::dnd::DndAction::RegisterDndDestination {
@@ -1353,3 +2668,92 @@ pub fn run_command<A, C, E>(
         }
     }
 }
+
+/// Returns whether the given event reports that a window was moved.
+fn is_moved(event: &Event) -> bool {
+    matches!(event, Event::Window(_, window::Event::Moved { .. }))
+}
+
+/// Returns whether the given event is a raw mouse motion, which is not tied
+/// to the cursor position.
+pub(crate) fn is_raw_motion(event: &Event) -> bool {
+    matches!(event, Event::Mouse(mouse::Event::RawMotion { .. }))
+}
+
+/// Returns whether the given event reports that the DnD operation started by
+/// the application ended, either inside or outside of it.
+pub(crate) fn ends_dnd(event: &Event) -> bool {
+    matches!(
+        event,
+        Event::Dnd(DndEvent::Source(
+            dnd::SourceEvent::Cancelled | dnd::SourceEvent::Finished
+        ))
+    )
+}
+
+/// Collapses every run of consecutive [`mouse::Event::CursorMoved`] events
+/// into its latest position.
+pub(crate) fn coalesce_cursor_moves(events: &mut Vec<Event>) {
+    let is_cursor_moved = |event: &Event| {
+        matches!(event, Event::Mouse(mouse::Event::CursorMoved { .. }))
+    };
+
+    events.dedup_by(|next, previous| {
+        if is_cursor_moved(next) && is_cursor_moved(previous) {
+            std::mem::swap(next, previous);
+
+            true
+        } else {
+            false
+        }
+    });
+}
+
+/// Clears the given contents from the [`clipboard::Target`] once `ttl`
+/// elapses, unless the clipboard holds different contents by then.
+pub(crate) fn schedule_clipboard_clear<Message>(
+    proxy: &winit::event_loop::EventLoopProxy<UserEventWrapper<Message>>,
+    target: clipboard::Target,
+    contents: String,
+    ttl: Duration,
+) {
+    let proxy = proxy.clone();
+
+    let _ = std::thread::spawn(move || {
+        std::thread::sleep(ttl);
+
+        // The event loop may be gone by now
+        let _ = proxy
+            .send_event(UserEventWrapper::ClipboardExpired(target, contents));
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::Point;
+
+    #[test]
+    fn consecutive_cursor_moves_are_coalesced_into_the_latest() {
+        let moved = |x| {
+            Event::Mouse(mouse::Event::CursorMoved {
+                position: Point::new(x, 0.0),
+            })
+        };
+        let pressed =
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left));
+
+        let mut events = vec![
+            moved(1.0),
+            moved(2.0),
+            moved(3.0),
+            pressed.clone(),
+            moved(4.0),
+        ];
+
+        coalesce_cursor_moves(&mut events);
+
+        assert_eq!(events, vec![moved(3.0), pressed, moved(4.0)]);
+    }
+}
@@ -1,58 +1,331 @@
+use crate::core::window::ResizeDirections;
+use crate::core::{self, mouse, renderer, Color, Point, Rectangle, Size};
+
 use winit::window::{CursorIcon, ResizeDirection};
 
-/// If supported by winit, returns a closure that implements cursor resize support.
-pub fn event_func(
-    window: &winit::window::Window,
+/// The largest fraction of the smallest dimension of the window that the
+/// resize border can cover when hit testing.
+const MAX_HIT_THICKNESS_RATIO: f64 = 0.25;
+
+/// The logical size of the resize grip drawn at the bottom-right corner of a
+/// window.
+const RESIZE_GRIP_SIZE: f32 = 16.0;
+
+/// Tracks the resize border of an undecorated window and initiates drag
+/// resizes when it is pressed.
+pub struct DragResize {
     border_size: f64,
-) -> Option<
-    Box<dyn FnMut(&winit::window::Window, &winit::event::WindowEvent) -> bool>,
-> {
-    if window.drag_resize_window(ResizeDirection::East).is_ok() {
-        // Keep track of cursor when it is within a resizeable border.
-        let mut cursor_prev_resize_direction = None;
-
-        Some(Box::new(
-            move |window: &winit::window::Window,
-                  window_event: &winit::event::WindowEvent|
-                  -> bool {
-                // Keep track of border resize state and set cursor icon when in range
-                match window_event {
-                    winit::event::WindowEvent::CursorMoved {
-                        position, ..
-                    } => {
-                        if !window.is_decorated() {
-                            let location = cursor_resize_direction(
-                                window.inner_size(),
-                                *position,
-                                border_size,
-                            );
-                            if location != cursor_prev_resize_direction {
-                                window.set_cursor_icon(
-                                    resize_direction_cursor_icon(location),
-                                );
-                                cursor_prev_resize_direction = location;
-                                return true;
-                            }
-                        }
-                    }
-                    winit::event::WindowEvent::MouseInput {
-                        state: winit::event::ElementState::Pressed,
-                        button: winit::event::MouseButton::Left,
-                        ..
-                    } => {
-                        if let Some(direction) = cursor_prev_resize_direction {
-                            let _res = window.drag_resize_window(direction);
-                            return true;
-                        }
+    border_size_override: Option<f64>,
+    hit_thickness: Option<f64>,
+    corner_size: Option<f64>,
+    directions: ResizeDirections,
+    cursor_prev_resize_direction: Option<ResizeDirection>,
+    is_resizing: bool,
+}
+
+impl DragResize {
+    /// If supported by winit, returns a [`DragResize`] that implements cursor
+    /// resize support.
+    pub fn new(
+        window: &winit::window::Window,
+        border_size: f64,
+    ) -> Option<Self> {
+        if window.drag_resize_window(ResizeDirection::East).is_ok() {
+            Some(Self {
+                border_size,
+                border_size_override: None,
+                hit_thickness: None,
+                corner_size: None,
+                directions: ResizeDirections::ALL,
+                cursor_prev_resize_direction: None,
+                is_resizing: false,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Sets the physical size of the resize border, overriding the one the
+    /// [`DragResize`] was created with.
+    ///
+    /// The original size is used when `None`.
+    pub fn set_border_size(&mut self, size: Option<f64>) {
+        self.border_size_override = size;
+    }
+
+    /// Returns the physical size of the resize border.
+    fn border_size(&self) -> f64 {
+        self.border_size_override.unwrap_or(self.border_size)
+    }
+
+    /// Sets the physical thickness of the area that initiates drag resizes,
+    /// independently of the drawn resize border.
+    ///
+    /// The size of the resize border is used when `None`.
+    pub fn set_hit_thickness(&mut self, thickness: Option<f64>) {
+        self.hit_thickness = thickness;
+    }
+
+    /// Sets the physical size of the square areas at the corners of the
+    /// window that initiate diagonal drag resizes, independently of the
+    /// thickness of the edges.
+    ///
+    /// The thickness of the edges is used when `None`.
+    pub fn set_corner_size(&mut self, size: Option<f64>) {
+        self.corner_size = size;
+    }
+
+    /// Sets the edges of the window that initiate drag resizes.
+    pub fn set_directions(&mut self, directions: ResizeDirections) {
+        self.directions = directions;
+    }
+
+    /// Returns the physical thickness of the area that initiates drag
+    /// resizes, capped so that it never covers the whole window.
+    fn hit_thickness(&self, size: winit::dpi::PhysicalSize<u32>) -> f64 {
+        let max =
+            f64::from(size.width.min(size.height)) * MAX_HIT_THICKNESS_RATIO;
+
+        self.hit_thickness.unwrap_or(self.border_size()).min(max)
+    }
+
+    /// Returns the physical size of the corners that initiate diagonal drag
+    /// resizes, which are never smaller than the edges.
+    fn corner_size(&self, size: winit::dpi::PhysicalSize<u32>) -> f64 {
+        let max =
+            f64::from(size.width.min(size.height)) * MAX_HIT_THICKNESS_RATIO;
+        let thickness = self.hit_thickness(size);
+
+        self.corner_size
+            .map_or(thickness, |corner| corner.min(max).max(thickness))
+    }
+
+    /// Processes the given window event, returning `true` if it was captured
+    /// by the resize border.
+    pub fn update(
+        &mut self,
+        window: &winit::window::Window,
+        window_event: &winit::event::WindowEvent,
+    ) -> bool {
+        // Keep track of border resize state and set cursor icon when in range
+        match window_event {
+            winit::event::WindowEvent::CursorMoved { position, .. } => {
+                // The drag resize is over once the cursor moves again
+                self.is_resizing = false;
+
+                if !window.is_decorated() {
+                    let size = window.inner_size();
+                    let location = cursor_resize_direction(
+                        size,
+                        *position,
+                        self.hit_thickness(size),
+                        self.corner_size(size),
+                        self.directions,
+                    );
+                    if location != self.cursor_prev_resize_direction {
+                        window.set_cursor_icon(resize_direction_cursor_icon(
+                            location,
+                        ));
+                        self.cursor_prev_resize_direction = location;
+                        return true;
                     }
-                    _ => (),
                 }
+            }
+            winit::event::WindowEvent::CursorLeft { .. } => {
+                self.cursor_prev_resize_direction = None;
+            }
+            winit::event::WindowEvent::MouseInput {
+                state: winit::event::ElementState::Pressed,
+                button: winit::event::MouseButton::Left,
+                ..
+            } => {
+                if let Some(direction) = self.cursor_prev_resize_direction {
+                    self.is_resizing =
+                        window.drag_resize_window(direction).is_ok();
+                    return true;
+                }
+            }
+            _ => (),
+        }
 
-                false
-            },
-        ))
-    } else {
-        None
+        false
+    }
+
+    /// Returns the edge of the resize border that is currently hovered, if
+    /// any.
+    ///
+    /// No edge is considered hovered while a drag resize is in progress.
+    pub fn hovered_direction(&self) -> Option<ResizeDirection> {
+        if self.is_resizing {
+            None
+        } else {
+            self.cursor_prev_resize_direction
+        }
+    }
+
+    /// Draws a highlight over the hovered edge of the resize border, if any.
+    pub fn draw_highlight<Renderer>(
+        &self,
+        renderer: &mut Renderer,
+        logical_size: Size,
+        scale_factor: f64,
+        color: Color,
+    ) where
+        Renderer: core::Renderer,
+    {
+        let Some(direction) = self.hovered_direction() else {
+            return;
+        };
+
+        let border = (self.border_size() / scale_factor) as f32;
+        let viewport = Rectangle::with_size(logical_size);
+
+        let north = Rectangle {
+            height: border,
+            ..viewport
+        };
+        let south = Rectangle {
+            y: viewport.height - border,
+            height: border,
+            ..viewport
+        };
+        let west = Rectangle {
+            width: border,
+            ..viewport
+        };
+        let east = Rectangle {
+            x: viewport.width - border,
+            width: border,
+            ..viewport
+        };
+
+        let edges = match direction {
+            ResizeDirection::North => vec![north],
+            ResizeDirection::South => vec![south],
+            ResizeDirection::West => vec![west],
+            ResizeDirection::East => vec![east],
+            ResizeDirection::NorthWest => vec![north, west],
+            ResizeDirection::NorthEast => vec![north, east],
+            ResizeDirection::SouthWest => vec![south, west],
+            ResizeDirection::SouthEast => vec![south, east],
+        };
+
+        renderer.with_layer(viewport, |renderer| {
+            for bounds in edges {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        ..renderer::Quad::default()
+                    },
+                    color,
+                );
+            }
+        });
+    }
+}
+
+/// Returns whether the resize grip can be shown in the given window, which is
+/// not the case while it is maximized, not resizable, or when its bottom and
+/// right edges cannot be dragged.
+pub fn is_resize_grip_available(
+    window: &winit::window::Window,
+    directions: ResizeDirections,
+) -> bool {
+    window.is_resizable()
+        && !window.is_maximized()
+        && directions.contains(ResizeDirections::SOUTH | ResizeDirections::EAST)
+}
+
+/// Initiates a diagonal drag resize if the given window event presses the
+/// resize grip, returning `true` if it was captured.
+pub fn update_resize_grip(
+    window: &winit::window::Window,
+    window_event: &winit::event::WindowEvent,
+    cursor: mouse::Cursor,
+    logical_size: Size,
+    scale_factor: f64,
+    directions: ResizeDirections,
+) -> bool {
+    let position = match window_event {
+        winit::event::WindowEvent::MouseInput {
+            state: winit::event::ElementState::Pressed,
+            button: winit::event::MouseButton::Left,
+            ..
+        } => cursor.position(),
+        winit::event::WindowEvent::Touch(winit::event::Touch {
+            phase: winit::event::TouchPhase::Started,
+            location,
+            ..
+        }) => {
+            let location = location.to_logical::<f32>(scale_factor);
+
+            Some(Point::new(location.x, location.y))
+        }
+        _ => None,
+    };
+
+    let Some(position) = position else {
+        return false;
+    };
+
+    is_resize_grip_available(window, directions)
+        && resize_grip_bounds(logical_size).contains(position)
+        && window
+            .drag_resize_window(ResizeDirection::SouthEast)
+            .is_ok()
+}
+
+/// Draws the resize grip at the bottom-right corner of a window of the given
+/// logical size, as a triangle of dots.
+pub fn draw_resize_grip<Renderer>(
+    renderer: &mut Renderer,
+    logical_size: Size,
+    color: Color,
+) where
+    Renderer: core::Renderer,
+{
+    const DOTS: usize = 3;
+
+    let bounds = resize_grip_bounds(logical_size);
+    let spacing = bounds.width / DOTS as f32;
+    let dot = spacing / 2.0;
+
+    renderer.with_layer(bounds, |renderer| {
+        for row in 0..DOTS {
+            for column in (DOTS - 1 - row)..DOTS {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: bounds.x
+                                + column as f32 * spacing
+                                + (spacing - dot) / 2.0,
+                            y: bounds.y
+                                + row as f32 * spacing
+                                + (spacing - dot) / 2.0,
+                            width: dot,
+                            height: dot,
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    color,
+                );
+            }
+        }
+    });
+}
+
+/// Returns the logical bounds of the resize grip of a window of the given
+/// logical size.
+fn resize_grip_bounds(logical_size: Size) -> Rectangle {
+    let size = RESIZE_GRIP_SIZE
+        .min(logical_size.width)
+        .min(logical_size.height);
+
+    Rectangle {
+        x: logical_size.width - size,
+        y: logical_size.height - size,
+        width: size,
+        height: size,
     }
 }
 
@@ -75,12 +348,64 @@ fn resize_direction_cursor_icon(
     }
 }
 
+/// Returns the edges of the window that are moved by a drag resize in the
+/// given direction.
+fn resize_direction_edges(direction: ResizeDirection) -> ResizeDirections {
+    match direction {
+        ResizeDirection::North => ResizeDirections::NORTH,
+        ResizeDirection::South => ResizeDirections::SOUTH,
+        ResizeDirection::East => ResizeDirections::EAST,
+        ResizeDirection::West => ResizeDirections::WEST,
+        ResizeDirection::NorthEast => {
+            ResizeDirections::NORTH | ResizeDirections::EAST
+        }
+        ResizeDirection::NorthWest => {
+            ResizeDirections::NORTH | ResizeDirections::WEST
+        }
+        ResizeDirection::SouthEast => {
+            ResizeDirections::SOUTH | ResizeDirections::EAST
+        }
+        ResizeDirection::SouthWest => {
+            ResizeDirections::SOUTH | ResizeDirections::WEST
+        }
+    }
+}
+
+/// Restricts the given resize direction to the allowed edges.
+///
+/// A diagonal direction with a single allowed edge is narrowed down to it.
+fn restrict_resize_direction(
+    direction: ResizeDirection,
+    allowed: ResizeDirections,
+) -> Option<ResizeDirection> {
+    let edges = resize_direction_edges(direction) & allowed;
+
+    if edges == resize_direction_edges(direction) {
+        return Some(direction);
+    }
+
+    [
+        ResizeDirection::North,
+        ResizeDirection::South,
+        ResizeDirection::East,
+        ResizeDirection::West,
+    ]
+    .into_iter()
+    .find(|edge| resize_direction_edges(*edge) == edges)
+}
+
 /// Identifies resize direction based on cursor position and window dimensions.
+///
+/// The corners take priority over the edges, so that diagonal resizes are
+/// initiated wherever both regions overlap. Only the `allowed` edges, and the
+/// corners between two of them, produce a direction.
 #[allow(clippy::similar_names)]
 fn cursor_resize_direction(
     win_size: winit::dpi::PhysicalSize<u32>,
     position: winit::dpi::PhysicalPosition<f64>,
     border_size: f64,
+    corner_size: f64,
+    allowed: ResizeDirections,
 ) -> Option<ResizeDirection> {
     enum XDirection {
         West,
@@ -94,6 +419,25 @@ fn cursor_resize_direction(
         Default,
     }
 
+    let west = position.x < corner_size;
+    let east = position.x > (win_size.width as f64 - corner_size);
+    let north = position.y < corner_size;
+    let south = position.y > (win_size.height as f64 - corner_size);
+
+    let corner = match (west, east, north, south) {
+        (true, _, true, _) => Some(ResizeDirection::NorthWest),
+        (_, true, true, _) => Some(ResizeDirection::NorthEast),
+        (true, _, _, true) => Some(ResizeDirection::SouthWest),
+        (_, true, _, true) => Some(ResizeDirection::SouthEast),
+        _ => None,
+    };
+
+    if let Some(corner) = corner {
+        if allowed.contains(resize_direction_edges(corner)) {
+            return Some(corner);
+        }
+    }
+
     let xdir = if position.x < border_size {
         XDirection::West
     } else if position.x > (win_size.width as f64 - border_size) {
@@ -110,7 +454,7 @@ fn cursor_resize_direction(
         YDirection::Default
     };
 
-    Some(match xdir {
+    let direction = match xdir {
         XDirection::West => match ydir {
             YDirection::North => ResizeDirection::NorthWest,
             YDirection::South => ResizeDirection::SouthWest,
@@ -128,5 +472,60 @@ fn cursor_resize_direction(
             YDirection::South => ResizeDirection::South,
             YDirection::Default => return None,
         },
-    })
+    };
+
+    restrict_resize_direction(direction, allowed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use winit::dpi::{PhysicalPosition, PhysicalSize};
+
+    #[test]
+    fn corners_take_priority_over_edges() {
+        let size = PhysicalSize::new(800, 600);
+        let direction = |x, y| {
+            cursor_resize_direction(
+                size,
+                PhysicalPosition::new(x, y),
+                4.0,
+                32.0,
+                ResizeDirections::ALL,
+            )
+        };
+
+        assert_eq!(direction(2.0, 20.0), Some(ResizeDirection::NorthWest));
+        assert_eq!(direction(20.0, 2.0), Some(ResizeDirection::NorthWest));
+        assert_eq!(direction(798.0, 580.0), Some(ResizeDirection::SouthEast));
+        assert_eq!(direction(2.0, 300.0), Some(ResizeDirection::West));
+        assert_eq!(direction(400.0, 598.0), Some(ResizeDirection::South));
+        assert_eq!(direction(20.0, 20.0), Some(ResizeDirection::NorthWest));
+        assert_eq!(direction(40.0, 40.0), None);
+    }
+
+    #[test]
+    fn only_allowed_edges_show_resize_cursors() {
+        let size = PhysicalSize::new(800, 600);
+        let cursor = |x, y| {
+            resize_direction_cursor_icon(cursor_resize_direction(
+                size,
+                PhysicalPosition::new(x, y),
+                4.0,
+                32.0,
+                ResizeDirections::SOUTH | ResizeDirections::EAST,
+            ))
+        };
+
+        assert_eq!(cursor(798.0, 300.0), CursorIcon::EResize);
+        assert_eq!(cursor(400.0, 598.0), CursorIcon::SResize);
+        assert_eq!(cursor(798.0, 580.0), CursorIcon::SeResize);
+        assert_eq!(cursor(2.0, 300.0), CursorIcon::Default);
+        assert_eq!(cursor(400.0, 2.0), CursorIcon::Default);
+        assert_eq!(cursor(20.0, 20.0), CursorIcon::Default);
+        assert_eq!(cursor(798.0, 2.0), CursorIcon::EResize);
+        assert_eq!(cursor(770.0, 20.0), CursorIcon::Default);
+        assert_eq!(cursor(2.0, 598.0), CursorIcon::SResize);
+    }
 }
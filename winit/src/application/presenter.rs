@@ -0,0 +1,391 @@
+use crate::core::Color;
+use crate::graphics::compositor::{self, Compositor};
+use crate::graphics::Viewport;
+
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// The compositor and window surface of a [`Presenter`].
+#[allow(missing_debug_implementations)]
+pub struct Target<C: Compositor> {
+    /// The [`Compositor`] of the window.
+    pub compositor: C,
+    /// The surface of the window.
+    pub surface: C::Surface,
+}
+
+/// Presents the frames of a window, either in place or in a dedicated
+/// rendering thread.
+///
+/// When the `threaded-rendering` feature is enabled, the [`Compositor`] and
+/// the surface of the window are moved to a rendering thread, which presents
+/// a frame while the event loop keeps processing events and updating the
+/// application. At most one frame is in flight at any time.
+///
+/// The frames are drawn into two renderers that are swapped on every
+/// present, so the [`Compositor`], its surface, and its renderer must be
+/// [`Send`]. Since consecutive frames are drawn by different renderers,
+/// their damage tracking is disabled. Errors of a frame are reported when
+/// presenting the next one, and screenshots may lag one frame behind.
+#[allow(missing_debug_implementations)]
+pub struct Presenter<C: Compositor> {
+    target: Arc<Mutex<Target<C>>>,
+    #[cfg(feature = "threaded-rendering")]
+    thread: thread::Thread<C>,
+}
+
+impl<C: Compositor> Presenter<C> {
+    /// Creates a new [`Presenter`] for the given [`Compositor`], surface, and
+    /// renderer.
+    pub fn new(
+        #[cfg_attr(not(feature = "threaded-rendering"), allow(unused_mut))]
+        mut compositor: C,
+        surface: C::Surface,
+        renderer: &mut C::Renderer,
+    ) -> Self
+    where
+        C: Sendable,
+        C::Surface: Sendable,
+        C::Renderer: Sendable,
+    {
+        #[cfg(feature = "threaded-rendering")]
+        let spare = thread::spare(&mut compositor, renderer);
+
+        #[cfg(not(feature = "threaded-rendering"))]
+        let _ = renderer;
+
+        let target = Arc::new(Mutex::new(Target {
+            compositor,
+            surface,
+        }));
+
+        Self {
+            #[cfg(feature = "threaded-rendering")]
+            thread: thread::Thread::spawn(target.clone(), spare),
+            target,
+        }
+    }
+
+    /// Locks the [`Target`] of the [`Presenter`], waiting for the rendering
+    /// thread to release it.
+    pub fn lock(&self) -> MutexGuard<'_, Target<C>> {
+        self.target.lock().expect("Lock rendering target")
+    }
+
+    /// Configures the surface of the window with the given physical
+    /// dimensions.
+    pub fn configure_surface(&self, width: u32, height: u32) {
+        let mut target = self.lock();
+        let Target {
+            compositor,
+            surface,
+        } = &mut *target;
+
+        compositor.configure_surface(surface, width, height);
+    }
+
+    /// Presents the primitives recorded in the given renderer.
+    pub fn present<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut C::Renderer,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    ) -> Result<(), compositor::SurfaceError> {
+        #[cfg(feature = "threaded-rendering")]
+        {
+            self.thread
+                .present(renderer, viewport, background_color, overlay)
+        }
+
+        #[cfg(not(feature = "threaded-rendering"))]
+        {
+            let mut target = self.lock();
+            let Target {
+                compositor,
+                surface,
+            } = &mut *target;
+
+            compositor.present(
+                renderer,
+                surface,
+                viewport,
+                background_color,
+                overlay,
+            )
+        }
+    }
+
+    /// Runs the given closure with the [`Compositor`] and every renderer of
+    /// the [`Presenter`], starting with the given one.
+    ///
+    /// This is meant for changes that must apply to every frame, like
+    /// loading a font or setting a color profile.
+    pub fn for_each_renderer(
+        &mut self,
+        renderer: &mut C::Renderer,
+        mut f: impl FnMut(&mut C, &mut C::Renderer),
+    ) {
+        #[cfg(feature = "threaded-rendering")]
+        let mut spare = self.thread.wait();
+
+        let mut target = self.target.lock().expect("Lock rendering target");
+
+        f(&mut target.compositor, renderer);
+
+        #[cfg(feature = "threaded-rendering")]
+        f(&mut target.compositor, &mut spare);
+
+        drop(target);
+
+        #[cfg(feature = "threaded-rendering")]
+        self.thread.release(spare);
+    }
+
+    /// Replaces the [`Compositor`] of the [`Presenter`] with its software
+    /// fallback, creating the given renderer and the surface of the window
+    /// again.
+    ///
+    /// Returns `false` if no fallback is available.
+    pub fn fall_back<W: compositor::Window + Clone>(
+        &mut self,
+        window: W,
+        renderer: &mut C::Renderer,
+        width: u32,
+        height: u32,
+    ) -> bool {
+        #[cfg(feature = "threaded-rendering")]
+        let spare = self.thread.wait();
+
+        let mut target = self.target.lock().expect("Lock rendering target");
+
+        if !target.compositor.fall_back(window.clone()) {
+            #[cfg(feature = "threaded-rendering")]
+            self.thread.release(spare);
+
+            return false;
+        }
+
+        *renderer = target.compositor.create_renderer();
+        target.surface =
+            target.compositor.create_surface(window, width, height);
+
+        #[cfg(feature = "threaded-rendering")]
+        {
+            drop(spare);
+
+            let spare = thread::spare(&mut target.compositor, renderer);
+            self.thread.release(spare);
+        }
+
+        true
+    }
+}
+
+/// A type that can be moved to the rendering thread of a [`Presenter`].
+///
+/// This is [`Send`] when the `threaded-rendering` feature is enabled, and
+/// implemented by every type otherwise.
+#[cfg(feature = "threaded-rendering")]
+pub trait Sendable: Send + 'static {}
+
+#[cfg(feature = "threaded-rendering")]
+impl<T> Sendable for T where T: Send + 'static {}
+
+/// A type that can be moved to the rendering thread of a [`Presenter`].
+///
+/// This is [`Send`] when the `threaded-rendering` feature is enabled, and
+/// implemented by every type otherwise.
+#[cfg(not(feature = "threaded-rendering"))]
+pub trait Sendable {}
+
+#[cfg(not(feature = "threaded-rendering"))]
+impl<T> Sendable for T {}
+
+#[cfg(feature = "threaded-rendering")]
+mod thread {
+    use super::{Sendable, Target};
+
+    use crate::core::Color;
+    use crate::graphics::compositor::{self, Compositor};
+    use crate::graphics::Viewport;
+
+    use std::sync::mpsc;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    /// A frame sent to the rendering thread.
+    struct Frame<R> {
+        renderer: R,
+        viewport: Viewport,
+        background_color: Color,
+        overlay: Vec<String>,
+    }
+
+    /// A frame presented by the rendering thread.
+    struct Presented<R> {
+        renderer: R,
+        result: Result<(), compositor::SurfaceError>,
+    }
+
+    pub struct Thread<C: Compositor> {
+        frames: Option<mpsc::Sender<Frame<C::Renderer>>>,
+        presented: mpsc::Receiver<Presented<C::Renderer>>,
+        spare: Option<C::Renderer>,
+        handle: Option<thread::JoinHandle<()>>,
+    }
+
+    impl<C: Compositor> Thread<C> {
+        pub fn spawn(target: Arc<Mutex<Target<C>>>, spare: C::Renderer) -> Self
+        where
+            C: Sendable,
+            C::Surface: Sendable,
+            C::Renderer: Sendable,
+        {
+            let (frames, frame_receiver) = mpsc::channel();
+            let (presented_sender, presented) = mpsc::channel();
+
+            let handle = thread::Builder::new()
+                .name(String::from("iced_winit rendering"))
+                .spawn(move || {
+                    for frame in frame_receiver {
+                        let Frame {
+                            mut renderer,
+                            viewport,
+                            background_color,
+                            overlay,
+                        } = frame;
+
+                        let result = {
+                            let mut target =
+                                target.lock().expect("Lock rendering target");
+                            let Target {
+                                compositor,
+                                surface,
+                            } = &mut *target;
+
+                            compositor.present(
+                                &mut renderer,
+                                surface,
+                                &viewport,
+                                background_color,
+                                &overlay,
+                            )
+                        };
+
+                        if presented_sender
+                            .send(Presented { renderer, result })
+                            .is_err()
+                        {
+                            break;
+                        }
+                    }
+                })
+                .expect("Spawn rendering thread");
+
+            Self {
+                frames: Some(frames),
+                presented,
+                spare: Some(spare),
+                handle: Some(handle),
+            }
+        }
+
+        /// Hands the given renderer to the rendering thread and replaces it
+        /// with the renderer of the previous frame, waiting for it to be
+        /// presented if needed.
+        ///
+        /// The result of the previous frame is returned. If it failed, the
+        /// given renderer is kept and nothing is presented.
+        pub fn present<T: AsRef<str>>(
+            &mut self,
+            renderer: &mut C::Renderer,
+            viewport: &Viewport,
+            background_color: Color,
+            overlay: &[T],
+        ) -> Result<(), compositor::SurfaceError> {
+            let (spare, result) = match self.spare.take() {
+                Some(spare) => (spare, Ok(())),
+                None => self.receive(),
+            };
+
+            if result.is_err() {
+                self.spare = Some(spare);
+
+                return result;
+            }
+
+            let frame = Frame {
+                renderer: std::mem::replace(renderer, spare),
+                viewport: viewport.clone(),
+                background_color,
+                overlay: overlay
+                    .iter()
+                    .map(|line| line.as_ref().to_owned())
+                    .collect(),
+            };
+
+            self.frames
+                .as_ref()
+                .expect("Rendering thread must exist")
+                .send(frame)
+                .expect("Send frame to rendering thread");
+
+            Ok(())
+        }
+
+        /// Takes the spare renderer, waiting for the frame in flight to be
+        /// presented if needed.
+        ///
+        /// The renderer must be given back with [`Thread::release`].
+        pub fn wait(&mut self) -> C::Renderer {
+            match self.spare.take() {
+                Some(spare) => spare,
+                None => self.receive().0,
+            }
+        }
+
+        /// Gives back the spare renderer taken with [`Thread::wait`].
+        pub fn release(&mut self, spare: C::Renderer) {
+            self.spare = Some(spare);
+        }
+
+        fn receive(
+            &mut self,
+        ) -> (C::Renderer, Result<(), compositor::SurfaceError>) {
+            let Presented { renderer, result } = self
+                .presented
+                .recv()
+                .expect("Receive frame from rendering thread");
+
+            (renderer, result)
+        }
+    }
+
+    /// Creates the spare renderer of a [`Thread`] for the given renderer.
+    ///
+    /// Both renderers present frames to the same surface in turns, so their
+    /// damage tracking is disabled.
+    pub fn spare<C: Compositor>(
+        compositor: &mut C,
+        renderer: &mut C::Renderer,
+    ) -> C::Renderer {
+        let mut spare = compositor.create_renderer();
+
+        compositor.set_damage_tracking(renderer, false);
+        compositor.set_damage_tracking(&mut spare, false);
+
+        spare
+    }
+
+    impl<C: Compositor> Drop for Thread<C> {
+        fn drop(&mut self) {
+            // Closing the channel stops the rendering thread once the frame
+            // in flight is presented
+            drop(self.frames.take());
+
+            if let Some(handle) = self.handle.take() {
+                let _ = handle.join();
+            }
+        }
+    }
+}
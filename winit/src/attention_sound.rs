@@ -0,0 +1,54 @@
+//! Play the alert sound of the operating system.
+
+/// Plays the alert sound of the operating system.
+///
+/// This is a no-op if no sound service is available.
+#[cfg(target_os = "windows")]
+pub(crate) fn play() {
+    use winapi::um::winuser::{MessageBeep, MB_OK};
+
+    #[allow(unsafe_code)]
+    let _ = unsafe { MessageBeep(MB_OK) };
+}
+
+/// Plays the alert sound of the operating system.
+///
+/// This is a no-op if no sound service is available.
+#[cfg(target_os = "macos")]
+pub(crate) fn play() {
+    #[allow(unsafe_code)]
+    unsafe {
+        objc2_app_kit::NSBeep();
+    }
+}
+
+/// Plays the alert sound of the operating system.
+///
+/// The sound is played by ringing the bell of the Wayland compositor or,
+/// if it does not support `xdg_system_bell_v1`, of the X server. Desktops
+/// map both to the alert sound of their sound theme.
+///
+/// This is a no-op if neither is available.
+#[cfg(all(
+    unix,
+    not(any(target_os = "macos", target_os = "ios", target_os = "android"))
+))]
+pub(crate) fn play() {
+    #[cfg(all(target_os = "linux", feature = "wayland"))]
+    if crate::wayland::bell() {
+        return;
+    }
+
+    #[cfg(all(target_os = "linux", feature = "x11"))]
+    let _ = crate::x11::bell();
+}
+
+/// Plays the alert sound of the operating system.
+///
+/// This is a no-op on this platform.
+#[cfg(any(
+    not(any(target_os = "windows", unix)),
+    target_os = "ios",
+    target_os = "android"
+))]
+pub(crate) fn play() {}
@@ -1,6 +1,9 @@
 //! Access the clipboard.
 
-use std::{any::Any, borrow::Cow};
+use std::{
+    any::{Any, TypeId},
+    borrow::Cow,
+};
 
 use crate::futures::futures::Sink;
 use dnd::{DndAction, DndDestinationRectangle, DndSurface, Icon};
@@ -17,13 +20,243 @@ use crate::{application::UserEventWrapper, Proxy};
 #[allow(missing_debug_implementations)]
 pub struct Clipboard<M: 'static> {
     state: State<M>,
+    dnd_payload: Option<Box<dyn Any>>,
 }
 
 enum State<M: 'static> {
     Connected(window_clipboard::Clipboard, Proxy<UserEventWrapper<M>>),
+    Backend(Box<dyn Backend>),
     Unavailable,
 }
 
+/// The contents a [`Backend`] can store besides plain text.
+pub type Data =
+    ClipboardStoreData<Box<dyn Send + Sync + 'static + mime::AsMimeTypes>>;
+
+/// The storage a [`Clipboard`] delegates its contents to.
+///
+/// By default, a [`Clipboard`] connected to a window uses the system
+/// clipboard. A custom [`Backend`] can be provided with
+/// [`Clipboard::with_backend`] for testing or headless use.
+pub trait Backend {
+    /// Reads the current content of the [`Backend`] as text.
+    fn read(&self) -> Option<String>;
+
+    /// Writes the given text contents to the [`Backend`].
+    fn write(&mut self, contents: String);
+
+    /// Reads the current content of the primary selection as text.
+    fn read_primary(&self) -> Option<String>;
+
+    /// Writes the given text contents to the primary selection.
+    fn write_primary(&mut self, contents: String);
+
+    /// Reads the current content of the [`Backend`] in the first of the
+    /// given mime types that is available.
+    fn read_data(&self, mimes: Vec<String>) -> Option<(Vec<u8>, String)>;
+
+    /// Writes the given [`Data`] to the [`Backend`].
+    fn write_data(&mut self, contents: Data);
+
+    /// Reads the current content of the primary selection in the first of
+    /// the given mime types that is available.
+    fn read_primary_data(
+        &self,
+        mimes: Vec<String>,
+    ) -> Option<(Vec<u8>, String)>;
+
+    /// Writes the given [`Data`] to the primary selection.
+    fn write_primary_data(&mut self, contents: Data);
+
+    /// Returns the MIME types the current content of the [`Backend`] is
+    /// available in, or an empty list if it cannot list them.
+    fn available_types(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Returns the MIME types the current content of the primary selection
+    /// is available in, or an empty list if it cannot list them.
+    fn primary_available_types(&self) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+// `window_clipboard` cannot list the MIME types of the offers of the system
+// clipboard, so it keeps the empty lists of `Backend::available_types`.
+
+impl Backend for window_clipboard::Clipboard {
+    fn read(&self) -> Option<String> {
+        window_clipboard::Clipboard::read(self).ok()
+    }
+
+    fn write(&mut self, contents: String) {
+        match window_clipboard::Clipboard::write(self, contents) {
+            Ok(()) => {}
+            Err(error) => {
+                log::warn!("error writing to clipboard: {error}");
+            }
+        }
+    }
+
+    fn read_primary(&self) -> Option<String> {
+        window_clipboard::Clipboard::read_primary(self).and_then(|res| res.ok())
+    }
+
+    fn write_primary(&mut self, contents: String) {
+        match window_clipboard::Clipboard::write_primary(self, contents) {
+            Some(Ok(())) => {}
+            Some(Err(error)) => {
+                log::warn!("error writing to clipboard: {error}");
+            }
+            None => {} //Primary not available
+        }
+    }
+
+    fn read_data(&self, mimes: Vec<String>) -> Option<(Vec<u8>, String)> {
+        self.read_raw(mimes).and_then(|res| res.ok())
+    }
+
+    fn write_data(&mut self, contents: Data) {
+        _ = window_clipboard::Clipboard::write_data(self, contents);
+    }
+
+    fn read_primary_data(
+        &self,
+        mimes: Vec<String>,
+    ) -> Option<(Vec<u8>, String)> {
+        self.read_primary_raw(mimes).and_then(|res| res.ok())
+    }
+
+    fn write_primary_data(&mut self, contents: Data) {
+        _ = window_clipboard::Clipboard::write_primary_data(self, contents);
+    }
+}
+
+/// An in-memory [`Backend`] that is not shared with other applications.
+///
+/// This is useful for testing and headless use.
+#[derive(Debug, Clone, Default)]
+pub struct Memory {
+    standard: Selection,
+    primary: Selection,
+}
+
+impl Memory {
+    /// Creates a new, empty [`Memory`] backend.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct Selection {
+    data: Vec<(String, Vec<u8>)>,
+}
+
+impl Selection {
+    const TEXT_MIME_TYPES: &'static [&'static str] = &[
+        "text/plain;charset=utf-8",
+        "text/plain;charset=UTF-8",
+        "UTF8_STRING",
+        "STRING",
+        "text/plain",
+        "TEXT",
+    ];
+
+    fn read(&self) -> Option<String> {
+        let (bytes, _) = self.read_data(
+            Self::TEXT_MIME_TYPES
+                .iter()
+                .map(|mime_type| mime_type.to_string())
+                .collect(),
+        )?;
+
+        String::from_utf8(bytes).ok()
+    }
+
+    fn write(&mut self, contents: String) {
+        self.data = Self::TEXT_MIME_TYPES
+            .iter()
+            .map(|mime_type| (mime_type.to_string(), contents.clone().into()))
+            .collect();
+    }
+
+    fn available_types(&self) -> Vec<String> {
+        self.data
+            .iter()
+            .map(|(mime_type, _)| mime_type.clone())
+            .collect()
+    }
+
+    fn read_data(&self, mimes: Vec<String>) -> Option<(Vec<u8>, String)> {
+        mimes.into_iter().find_map(|mime_type| {
+            self.data
+                .iter()
+                .find(|(available, _)| *available == mime_type)
+                .map(|(_, bytes)| (bytes.clone(), mime_type))
+        })
+    }
+
+    fn write_data(&mut self, contents: Data) {
+        let ClipboardStoreData(contents) = contents;
+
+        self.data = contents
+            .available()
+            .iter()
+            .filter_map(|mime_type| {
+                let bytes = contents.as_bytes(mime_type)?;
+
+                Some((mime_type.clone(), bytes.into_owned()))
+            })
+            .collect();
+    }
+}
+
+impl Backend for Memory {
+    fn read(&self) -> Option<String> {
+        self.standard.read()
+    }
+
+    fn write(&mut self, contents: String) {
+        self.standard.write(contents);
+    }
+
+    fn read_primary(&self) -> Option<String> {
+        self.primary.read()
+    }
+
+    fn write_primary(&mut self, contents: String) {
+        self.primary.write(contents);
+    }
+
+    fn read_data(&self, mimes: Vec<String>) -> Option<(Vec<u8>, String)> {
+        self.standard.read_data(mimes)
+    }
+
+    fn write_data(&mut self, contents: Data) {
+        self.standard.write_data(contents);
+    }
+
+    fn read_primary_data(
+        &self,
+        mimes: Vec<String>,
+    ) -> Option<(Vec<u8>, String)> {
+        self.primary.read_data(mimes)
+    }
+
+    fn write_primary_data(&mut self, contents: Data) {
+        self.primary.write_data(contents);
+    }
+
+    fn available_types(&self) -> Vec<String> {
+        self.standard.available_types()
+    }
+
+    fn primary_available_types(&self) -> Vec<String> {
+        self.primary.available_types()
+    }
+}
+
 impl<M: Send + 'static> Clipboard<M> {
     /// Creates a new [`Clipboard`] for the given window.
     pub fn connect(
@@ -42,7 +275,10 @@ impl<M: Send + 'static> Clipboard<M> {
             clipboard.init_dnd(Box::new(proxy));
         }
 
-        Clipboard { state }
+        Clipboard {
+            state,
+            dnd_payload: None,
+        }
     }
 
     /// Creates a new [`Clipboard`] that isn't associated with a window.
@@ -50,53 +286,42 @@ impl<M: Send + 'static> Clipboard<M> {
     pub fn unconnected() -> Clipboard<M> {
         Clipboard {
             state: State::Unavailable,
+            dnd_payload: None,
+        }
+    }
+
+    /// Creates a new [`Clipboard`] that delegates its contents to the given
+    /// [`Backend`].
+    ///
+    /// Drag and drop is unavailable with a custom [`Backend`].
+    pub fn with_backend(backend: impl Backend + 'static) -> Clipboard<M> {
+        Clipboard {
+            state: State::Backend(Box::new(backend)),
+            dnd_payload: None,
         }
     }
 
     /// Reads the current content of the [`Clipboard`] as text.
     pub fn read(&self) -> Option<String> {
-        match &self.state {
-            State::Connected(clipboard, _) => clipboard.read().ok(),
-            State::Unavailable => None,
-        }
+        self.backend()?.read()
     }
 
     /// Writes the given text contents to the [`Clipboard`].
     pub fn write(&mut self, contents: String) {
-        match &mut self.state {
-            State::Connected(clipboard, _) => match clipboard.write(contents) {
-                Ok(()) => {}
-                Err(error) => {
-                    log::warn!("error writing to clipboard: {error}");
-                }
-            },
-            State::Unavailable => {}
+        if let Some(backend) = self.backend_mut() {
+            backend.write(contents);
         }
     }
 
     /// Reads the current content of the Primary as text.
     pub fn read_primary(&self) -> Option<String> {
-        match &self.state {
-            State::Connected(clipboard, _) => {
-                clipboard.read_primary().and_then(|res| res.ok())
-            }
-            State::Unavailable => None,
-        }
+        self.backend()?.read_primary()
     }
 
     /// Writes the given text contents to the Primary.
     pub fn write_primary(&mut self, contents: String) {
-        match &mut self.state {
-            State::Connected(clipboard, _) => {
-                match clipboard.write_primary(contents) {
-                    Some(Ok(())) => {}
-                    Some(Err(error)) => {
-                        log::warn!("error writing to clipboard: {error}");
-                    }
-                    None => {} //Primary not available
-                }
-            }
-            State::Unavailable => {}
+        if let Some(backend) = self.backend_mut() {
+            backend.write_primary(contents);
         }
     }
 
@@ -119,51 +344,52 @@ impl<M: Send + 'static> Clipboard<M> {
                     actions,
                 )
             }
-            State::Unavailable => {}
+            State::Backend(_) | State::Unavailable => {}
         }
     }
 }
 
-impl<M> crate::core::Clipboard for Clipboard<M> {
-    fn read(&self) -> Option<String> {
+impl<M> Clipboard<M> {
+    fn backend(&self) -> Option<&dyn Backend> {
         match &self.state {
-            State::Connected(clipboard, _) => clipboard.read().ok(),
+            State::Connected(clipboard, _) => Some(clipboard),
+            State::Backend(backend) => Some(backend.as_ref()),
             State::Unavailable => None,
         }
     }
 
-    fn write(&mut self, contents: String) {
+    fn backend_mut(&mut self) -> Option<&mut dyn Backend> {
         match &mut self.state {
-            State::Connected(clipboard, _) => _ = clipboard.write(contents),
-            State::Unavailable => {}
+            State::Connected(clipboard, _) => Some(clipboard),
+            State::Backend(backend) => Some(backend.as_mut()),
+            State::Unavailable => None,
         }
     }
+}
 
-    fn read_primary(&self) -> Option<String> {
-        match &self.state {
-            State::Connected(clipboard, _) => {
-                clipboard.read_primary().and_then(|res| res.ok())
-            }
-            State::Unavailable => None,
+impl<M> crate::core::Clipboard for Clipboard<M> {
+    fn read(&self) -> Option<String> {
+        self.backend()?.read()
+    }
+
+    fn write(&mut self, contents: String) {
+        if let Some(backend) = self.backend_mut() {
+            backend.write(contents);
         }
     }
 
+    fn read_primary(&self) -> Option<String> {
+        self.backend()?.read_primary()
+    }
+
     fn write_primary(&mut self, contents: String) {
-        match &mut self.state {
-            State::Connected(clipboard, _) => {
-                _ = clipboard.write_primary(contents)
-            }
-            State::Unavailable => {}
+        if let Some(backend) = self.backend_mut() {
+            backend.write_primary(contents);
         }
     }
 
     fn read_data(&self, mimes: Vec<String>) -> Option<(Vec<u8>, String)> {
-        match &self.state {
-            State::Connected(clipboard, _) => {
-                clipboard.read_raw(mimes).and_then(|res| res.ok())
-            }
-            State::Unavailable => None,
-        }
+        self.backend()?.read_data(mimes)
     }
 
     fn write_data(
@@ -172,11 +398,8 @@ impl<M> crate::core::Clipboard for Clipboard<M> {
             Box<dyn Send + Sync + 'static + mime::AsMimeTypes>,
         >,
     ) {
-        match &mut self.state {
-            State::Connected(clipboard, _) => {
-                _ = clipboard.write_data(contents)
-            }
-            State::Unavailable => {}
+        if let Some(backend) = self.backend_mut() {
+            backend.write_data(contents);
         }
     }
 
@@ -184,12 +407,7 @@ impl<M> crate::core::Clipboard for Clipboard<M> {
         &self,
         mimes: Vec<String>,
     ) -> Option<(Vec<u8>, String)> {
-        match &self.state {
-            State::Connected(clipboard, _) => {
-                clipboard.read_primary_raw(mimes).and_then(|res| res.ok())
-            }
-            State::Unavailable => None,
-        }
+        self.backend()?.read_primary_data(mimes)
     }
 
     fn write_primary_data(
@@ -198,14 +416,23 @@ impl<M> crate::core::Clipboard for Clipboard<M> {
             Box<dyn Send + Sync + 'static + mime::AsMimeTypes>,
         >,
     ) {
-        match &mut self.state {
-            State::Connected(clipboard, _) => {
-                _ = clipboard.write_primary_data(contents)
-            }
-            State::Unavailable => {}
+        if let Some(backend) = self.backend_mut() {
+            backend.write_primary_data(contents);
         }
     }
 
+    fn available_types(&self) -> Vec<String> {
+        self.backend()
+            .map(Backend::available_types)
+            .unwrap_or_default()
+    }
+
+    fn primary_available_types(&self) -> Vec<String> {
+        self.backend()
+            .map(Backend::primary_available_types)
+            .unwrap_or_default()
+    }
+
     fn start_dnd(
         &self,
         internal: bool,
@@ -224,7 +451,7 @@ impl<M> crate::core::Clipboard for Clipboard<M> {
                     actions,
                 });
             }
-            State::Unavailable => {}
+            State::Backend(_) | State::Unavailable => {}
         }
     }
 
@@ -237,14 +464,14 @@ impl<M> crate::core::Clipboard for Clipboard<M> {
             State::Connected(clipboard, _) => {
                 _ = clipboard.register_dnd_destination(surface, rectangles)
             }
-            State::Unavailable => {}
+            State::Backend(_) | State::Unavailable => {}
         }
     }
 
     fn end_dnd(&self) {
         match &self.state {
             State::Connected(clipboard, _) => _ = clipboard.end_dnd(),
-            State::Unavailable => {}
+            State::Backend(_) | State::Unavailable => {}
         }
     }
 
@@ -254,14 +481,116 @@ impl<M> crate::core::Clipboard for Clipboard<M> {
                 .peek_offer::<ClipboardData>(Some(Cow::Owned(mime)))
                 .ok()
                 .map(|res| (res.0, res.1)),
-            State::Unavailable => None,
+            State::Backend(_) | State::Unavailable => None,
         }
     }
 
     fn set_action(&self, action: DndAction) {
         match &self.state {
             State::Connected(clipboard, _) => _ = clipboard.set_action(action),
-            State::Unavailable => {}
+            State::Backend(_) | State::Unavailable => {}
+        }
+    }
+
+    fn set_dnd_payload(&mut self, payload: Option<Box<dyn Any>>) {
+        self.dnd_payload = payload;
+    }
+
+    fn take_dnd_payload(&mut self, type_id: TypeId) -> Option<Box<dyn Any>> {
+        if (**self.dnd_payload.as_ref()?).type_id() != type_id {
+            return None;
+        }
+
+        self.dnd_payload.take()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Clipboard as _;
+
+    struct Html(&'static str);
+
+    impl mime::AsMimeTypes for Html {
+        fn available(&self) -> Cow<'static, [String]> {
+            Cow::Owned(vec![String::from("text/html")])
         }
+
+        fn as_bytes(&self, mime_type: &str) -> Option<Cow<'static, [u8]>> {
+            (mime_type == "text/html")
+                .then(|| Cow::Owned(self.0.as_bytes().to_vec()))
+        }
+    }
+
+    #[test]
+    fn memory_copy_paste() {
+        let mut clipboard = Clipboard::<()>::with_backend(Memory::new());
+
+        assert_eq!(clipboard.read(), None);
+
+        clipboard.write(String::from("iced"));
+        clipboard.write_primary(String::from("primary"));
+
+        assert_eq!(clipboard.read(), Some(String::from("iced")));
+        assert_eq!(clipboard.read_primary(), Some(String::from("primary")));
+
+        clipboard.write_data(ClipboardStoreData(Box::new(Html("<b>iced</b>"))));
+
+        assert_eq!(clipboard.read(), None);
+        assert_eq!(
+            clipboard.read_data(vec![
+                String::from("image/png"),
+                String::from("text/html"),
+            ]),
+            Some((b"<b>iced</b>".to_vec(), String::from("text/html")))
+        );
+        assert_eq!(clipboard.read_primary(), Some(String::from("primary")));
+    }
+
+    #[test]
+    fn memory_available_types() {
+        let mut clipboard = Clipboard::<()>::with_backend(Memory::new());
+
+        assert!(clipboard.available_types().is_empty());
+
+        clipboard.write_data(ClipboardStoreData(Box::new(Html("<b>iced</b>"))));
+        clipboard.write_primary(String::from("primary"));
+
+        assert_eq!(
+            clipboard.available_types(),
+            vec![String::from("text/html")]
+        );
+        assert_eq!(
+            clipboard.primary_available_types(),
+            vec![
+                String::from("text/plain;charset=utf-8"),
+                String::from("text/plain;charset=UTF-8"),
+                String::from("UTF8_STRING"),
+                String::from("STRING"),
+                String::from("text/plain"),
+                String::from("TEXT"),
+            ]
+        );
+    }
+
+    #[test]
+    fn dnd_payload_is_taken_once_with_its_type() {
+        let mut clipboard = Clipboard::<()>::with_backend(Memory::new());
+
+        clipboard.set_dnd_payload(Some(Box::new(42_u32)));
+
+        assert_eq!(
+            crate::core::clipboard::take_dnd_payload::<String>(&mut clipboard),
+            None
+        );
+        assert_eq!(
+            crate::core::clipboard::take_dnd_payload::<u32>(&mut clipboard),
+            Some(42)
+        );
+        assert_eq!(
+            crate::core::clipboard::take_dnd_payload::<u32>(&mut clipboard),
+            None
+        );
     }
 }
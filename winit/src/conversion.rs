@@ -7,9 +7,12 @@ use winit::platform::modifier_supplement::KeyEventExtModifierSupplement;
 
 use crate::core::keyboard;
 use crate::core::mouse;
+use crate::core::theme;
 use crate::core::touch;
+use crate::core::touchpad;
 use crate::core::window;
-use crate::core::{Event, Point, Size};
+use crate::core::{Event, Padding, Point, Rectangle, Size, Vector};
+use crate::graphics::ColorProfile;
 
 /// Converts some [`window::Settings`] into a `WindowBuilder` from `winit`.
 pub fn window_settings(
@@ -131,6 +134,26 @@ pub fn window_settings(
     window_builder
 }
 
+/// Returns whether the given winit window event is caused by genuine user
+/// input, i.e. keyboard, mouse, or touch interaction.
+///
+/// Synthetic keyboard events emitted by winit when a window gains focus are
+/// not considered user input.
+pub fn is_user_interaction(event: &winit::event::WindowEvent) -> bool {
+    use winit::event::WindowEvent;
+
+    match event {
+        WindowEvent::KeyboardInput { is_synthetic, .. } => !is_synthetic,
+        WindowEvent::MouseInput { .. }
+        | WindowEvent::MouseWheel { .. }
+        | WindowEvent::CursorMoved { .. }
+        | WindowEvent::Touch(_)
+        | WindowEvent::TouchpadMagnify { .. }
+        | WindowEvent::TouchpadRotate { .. } => true,
+        _ => false,
+    }
+}
+
 /// Converts a winit window event into an iced event.
 pub fn window_event(
     id: window::Id,
@@ -180,24 +203,30 @@ pub fn window_event(
                 }
             }))
         }
-        WindowEvent::MouseWheel { delta, .. } => match delta {
-            winit::event::MouseScrollDelta::LineDelta(delta_x, delta_y) => {
-                Some(Event::Mouse(mouse::Event::WheelScrolled {
-                    delta: mouse::ScrollDelta::Lines {
-                        x: delta_x,
-                        y: delta_y,
-                    },
-                }))
-            }
-            winit::event::MouseScrollDelta::PixelDelta(position) => {
-                Some(Event::Mouse(mouse::Event::WheelScrolled {
-                    delta: mouse::ScrollDelta::Pixels {
-                        x: position.x as f32,
-                        y: position.y as f32,
-                    },
-                }))
+        WindowEvent::MouseWheel { delta, phase, .. } => {
+            let phase = scroll_phase(phase);
+
+            match delta {
+                winit::event::MouseScrollDelta::LineDelta(delta_x, delta_y) => {
+                    Some(Event::Mouse(mouse::Event::WheelScrolled {
+                        delta: mouse::ScrollDelta::Lines {
+                            x: delta_x,
+                            y: delta_y,
+                        },
+                        phase,
+                    }))
+                }
+                winit::event::MouseScrollDelta::PixelDelta(position) => {
+                    Some(Event::Mouse(mouse::Event::WheelScrolled {
+                        delta: mouse::ScrollDelta::Pixels {
+                            x: position.x as f32,
+                            y: position.y as f32,
+                        },
+                        phase,
+                    }))
+                }
             }
-        },
+        }
         WindowEvent::KeyboardInput { event, .. } => {
             let text_with_modifiers =
                 event.text_with_all_modifiers().map(|t| SmolStr::new(t));
@@ -206,6 +235,7 @@ pub fn window_event(
                 state,
                 text: _text,
                 location,
+                repeat,
                 ..
             } = event;
             Some(Event::Keyboard({
@@ -234,6 +264,7 @@ pub fn window_event(
                             modifiers,
                             location,
                             text: text_with_modifiers,
+                            repeat,
                         }
                     }
                     winit::event::ElementState::Released => {
@@ -259,6 +290,9 @@ pub fn window_event(
                 window::Event::Unfocused
             },
         )),
+        WindowEvent::Ime(event) => {
+            Some(Event::Window(id, window::Event::Ime(ime(event))))
+        }
         WindowEvent::HoveredFile(path) => {
             Some(Event::Window(id, window::Event::FileHovered(path.clone())))
         }
@@ -281,6 +315,32 @@ pub fn window_event(
     }
 }
 
+/// Converts a winit device event into an iced event.
+///
+/// Device events are not tied to any window.
+pub fn device_event(event: &winit::event::DeviceEvent) -> Option<Event> {
+    match event {
+        winit::event::DeviceEvent::MouseMotion { delta: (x, y) } => {
+            Some(Event::Mouse(mouse::Event::RawMotion {
+                delta: Vector::new(*x as f32, *y as f32),
+            }))
+        }
+        _ => None,
+    }
+}
+
+/// Converts a `winit` IME event to a [`window::Ime`] event.
+pub fn ime(ime: winit::event::Ime) -> window::Ime {
+    match ime {
+        winit::event::Ime::Enabled => window::Ime::Enabled,
+        winit::event::Ime::Preedit(content, cursor) => {
+            window::Ime::Preedit { content, cursor }
+        }
+        winit::event::Ime::Commit(content) => window::Ime::Commit(content),
+        winit::event::Ime::Disabled => window::Ime::Disabled,
+    }
+}
+
 /// Converts a [`window::Level`] to a [`winit`] window level.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
@@ -337,6 +397,102 @@ pub fn position(
     }
 }
 
+/// Converts a `MonitorHandle` from [`winit`] into a [`window::Monitor`]
+/// descriptor.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+pub fn monitor(monitor: &winit::monitor::MonitorHandle) -> window::Monitor {
+    let position = monitor.position();
+    let size = monitor.size();
+
+    window::Monitor {
+        name: monitor.name(),
+        position: (position.x, position.y),
+        size: (size.width, size.height),
+        scale_factor: monitor.scale_factor(),
+        refresh_rate_millihertz: monitor.refresh_rate_millihertz(),
+    }
+}
+
+/// Parses the output [`ColorProfile`] of the given ICC data.
+///
+/// Empty or invalid data produces `None`, which falls back to sRGB.
+pub fn color_profile(icc: &[u8]) -> Option<ColorProfile> {
+    if icc.is_empty() {
+        return None;
+    }
+
+    ColorProfile::from_icc(icc)
+        .map_err(|error| {
+            log::warn!("Invalid color profile, falling back to sRGB: {error}");
+        })
+        .ok()
+}
+
+/// Computes the logical insets of the decorations of a [`winit`] window from
+/// the difference between its outer and inner bounds.
+///
+/// The insets are zero when the outer position of the window is unavailable,
+/// like on Wayland.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+pub fn frame_insets(window: &winit::window::Window) -> Padding {
+    let (Ok(outer_position), Ok(inner_position)) =
+        (window.outer_position(), window.inner_position())
+    else {
+        return Padding::ZERO;
+    };
+
+    let outer_size = window.outer_size();
+    let inner_size = window.inner_size();
+    let scale_factor = window.scale_factor() as f32;
+
+    let left = inner_position.x.saturating_sub(outer_position.x).max(0) as u32;
+    let top = inner_position.y.saturating_sub(outer_position.y).max(0) as u32;
+    let right = outer_size
+        .width
+        .saturating_sub(inner_size.width)
+        .saturating_sub(left);
+    let bottom = outer_size
+        .height
+        .saturating_sub(inner_size.height)
+        .saturating_sub(top);
+
+    Padding {
+        top: top as f32 / scale_factor,
+        right: right as f32 / scale_factor,
+        bottom: bottom as f32 / scale_factor,
+        left: left as f32 / scale_factor,
+    }
+}
+
+/// Converts a [`winit`] video mode to a [`window::VideoMode`].
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+pub fn video_mode(video_mode: &winit::monitor::VideoMode) -> window::VideoMode {
+    let size = video_mode.size();
+
+    window::VideoMode {
+        size: (size.width, size.height),
+        bit_depth: video_mode.bit_depth(),
+        refresh_rate_millihertz: video_mode.refresh_rate_millihertz(),
+    }
+}
+
+/// Converts a [`window::VideoMode`] to a [`winit`] exclusive fullscreen mode,
+/// if it is supported by the given monitor.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+pub fn exclusive_fullscreen(
+    monitor: Option<&winit::monitor::MonitorHandle>,
+    video_mode: window::VideoMode,
+) -> Option<winit::window::Fullscreen> {
+    monitor?
+        .video_modes()
+        .find(|candidate| self::video_mode(candidate) == video_mode)
+        .map(winit::window::Fullscreen::Exclusive)
+}
+
 /// Converts a [`window::Mode`] to a [`winit`] fullscreen mode.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
@@ -409,6 +565,20 @@ pub fn mouse_button(mouse_button: winit::event::MouseButton) -> mouse::Button {
     }
 }
 
+/// Converts the `TouchPhase` of a scroll event from [`winit`] to an [`iced`]
+/// scroll phase.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+/// [`iced`]: https://github.com/iced-rs/iced/tree/0.10
+pub fn scroll_phase(phase: winit::event::TouchPhase) -> mouse::ScrollPhase {
+    match phase {
+        winit::event::TouchPhase::Started => mouse::ScrollPhase::Started,
+        winit::event::TouchPhase::Moved => mouse::ScrollPhase::Moved,
+        winit::event::TouchPhase::Ended
+        | winit::event::TouchPhase::Cancelled => mouse::ScrollPhase::Ended,
+    }
+}
+
 /// Converts some `ModifiersState` from [`winit`] to an [`iced`] modifiers
 /// state.
 ///
@@ -437,6 +607,32 @@ pub fn cursor_position(
     Point::new(logical_position.x, logical_position.y)
 }
 
+/// Converts a logical IME cursor area into the physical position and size
+/// expected by [`winit`], clamping it within the given logical `bounds` of
+/// the window.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+pub fn ime_area(
+    area: Rectangle,
+    bounds: Size,
+    scale_factor: f64,
+) -> (
+    winit::dpi::PhysicalPosition<f64>,
+    winit::dpi::PhysicalSize<f64>,
+) {
+    let x = area.x.clamp(0.0, bounds.width);
+    let y = area.y.clamp(0.0, bounds.height);
+    let width = area.width.min(bounds.width - x).max(0.0);
+    let height = area.height.min(bounds.height - y).max(0.0);
+
+    (
+        winit::dpi::LogicalPosition::new(f64::from(x), f64::from(y))
+            .to_physical(scale_factor),
+        winit::dpi::LogicalSize::new(f64::from(width), f64::from(height))
+            .to_physical(scale_factor),
+    )
+}
+
 /// Converts a `Touch` from [`winit`] to an [`iced`] touch event.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
@@ -468,6 +664,83 @@ pub fn touch_event(
     }
 }
 
+/// The touchpad gestures in progress in a window, keeping track of their
+/// total change since they started.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TouchpadGestures {
+    pinch: f32,
+    rotation: f32,
+}
+
+impl TouchpadGestures {
+    /// Converts a touchpad gesture from [`winit`] to an [`iced`] touchpad
+    /// event, accumulating its change since the gesture started.
+    ///
+    /// Returns `None` if the event is not a supported touchpad gesture.
+    ///
+    /// [`winit`]: https://github.com/rust-windowing/winit
+    /// [`iced`]: https://github.com/iced-rs/iced/tree/0.10
+    pub fn convert(
+        &mut self,
+        event: &winit::event::WindowEvent,
+    ) -> Option<touchpad::Event> {
+        use winit::event::WindowEvent;
+
+        fn accumulate(
+            total: &mut f32,
+            delta: f32,
+            phase: winit::event::TouchPhase,
+        ) -> (touchpad::Phase, f32) {
+            let phase = touchpad_phase(phase);
+
+            if phase == touchpad::Phase::Started {
+                *total = 0.0;
+            }
+
+            *total += delta;
+
+            (phase, *total)
+        }
+
+        match *event {
+            WindowEvent::TouchpadMagnify { delta, phase, .. } => {
+                let delta = delta as f32;
+                let (phase, total) = accumulate(&mut self.pinch, delta, phase);
+
+                Some(touchpad::Event::Pinched {
+                    phase,
+                    delta,
+                    total,
+                })
+            }
+            WindowEvent::TouchpadRotate { delta, phase, .. } => {
+                let (phase, total) =
+                    accumulate(&mut self.rotation, delta, phase);
+
+                Some(touchpad::Event::Rotated {
+                    phase,
+                    delta,
+                    total,
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Converts a `TouchPhase` from [`winit`] to an [`iced`] touchpad phase.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+/// [`iced`]: https://github.com/iced-rs/iced/tree/0.10
+pub fn touchpad_phase(phase: winit::event::TouchPhase) -> touchpad::Phase {
+    match phase {
+        winit::event::TouchPhase::Started => touchpad::Phase::Started,
+        winit::event::TouchPhase::Moved => touchpad::Phase::Updated,
+        winit::event::TouchPhase::Ended => touchpad::Phase::Ended,
+        winit::event::TouchPhase::Cancelled => touchpad::Phase::Cancelled,
+    }
+}
+
 /// Converts a `VirtualKeyCode` from [`winit`] to an [`iced`] key code.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
@@ -797,6 +1070,14 @@ pub fn key(key: winit::keyboard::Key) -> keyboard::Key {
     }
 }
 
+/// Converts a [`theme::Mode`] into its `winit` counterpart.
+pub fn theme_mode(mode: theme::Mode) -> winit::window::Theme {
+    match mode {
+        theme::Mode::Light => winit::window::Theme::Light,
+        theme::Mode::Dark => winit::window::Theme::Dark,
+    }
+}
+
 /// Converts some [`UserAttention`] into it's `winit` counterpart.
 ///
 /// [`UserAttention`]: window::UserAttention
@@ -813,6 +1094,19 @@ pub fn user_attention(
     }
 }
 
+/// Converts a [`window::CursorGrabMode`] into its `winit` counterpart.
+pub fn cursor_grab_mode(
+    mode: window::CursorGrabMode,
+) -> winit::window::CursorGrabMode {
+    match mode {
+        window::CursorGrabMode::None => winit::window::CursorGrabMode::None,
+        window::CursorGrabMode::Confined => {
+            winit::window::CursorGrabMode::Confined
+        }
+        window::CursorGrabMode::Locked => winit::window::CursorGrabMode::Locked,
+    }
+}
+
 /// Converts some [`window::Icon`] into it's `winit` counterpart.
 ///
 /// Returns `None` if there is an error during the conversion.
@@ -831,3 +1125,186 @@ pub(crate) fn a11y(
         iced_runtime::core::id::Id::from(u128::from(event.target.0) as u64);
     Event::A11y(id, event)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use winit::event::MouseButton;
+
+    #[test]
+    fn mouse_buttons() {
+        assert_eq!(mouse_button(MouseButton::Left), mouse::Button::Left);
+        assert_eq!(mouse_button(MouseButton::Right), mouse::Button::Right);
+        assert_eq!(mouse_button(MouseButton::Middle), mouse::Button::Middle);
+        assert_eq!(mouse_button(MouseButton::Back), mouse::Button::Back);
+        assert_eq!(mouse_button(MouseButton::Forward), mouse::Button::Forward);
+        assert_eq!(
+            mouse_button(MouseButton::Other(8)),
+            mouse::Button::Other(8)
+        );
+    }
+
+    #[test]
+    fn scroll_phases() {
+        use winit::event::TouchPhase;
+
+        assert_eq!(
+            scroll_phase(TouchPhase::Started),
+            mouse::ScrollPhase::Started
+        );
+        assert_eq!(scroll_phase(TouchPhase::Moved), mouse::ScrollPhase::Moved);
+        assert_eq!(scroll_phase(TouchPhase::Ended), mouse::ScrollPhase::Ended);
+        assert_eq!(
+            scroll_phase(TouchPhase::Cancelled),
+            mouse::ScrollPhase::Ended
+        );
+    }
+
+    #[test]
+    fn user_interactions() {
+        use winit::event::{DeviceId, ElementState, WindowEvent};
+
+        #[allow(unsafe_code)]
+        let device_id = unsafe { DeviceId::dummy() };
+
+        assert!(is_user_interaction(&WindowEvent::MouseInput {
+            device_id,
+            state: ElementState::Pressed,
+            button: MouseButton::Left,
+        }));
+        assert!(is_user_interaction(&WindowEvent::CursorMoved {
+            device_id,
+            position: winit::dpi::PhysicalPosition::new(10.0, 20.0),
+        }));
+
+        assert!(is_user_interaction(&WindowEvent::TouchpadMagnify {
+            device_id,
+            delta: 0.1,
+            phase: winit::event::TouchPhase::Moved,
+        }));
+
+        assert!(!is_user_interaction(&WindowEvent::RedrawRequested));
+        assert!(!is_user_interaction(&WindowEvent::Focused(true)));
+        assert!(!is_user_interaction(&WindowEvent::Resized(
+            winit::dpi::PhysicalSize::new(800, 600)
+        )));
+    }
+
+    #[test]
+    fn ime_composition() {
+        let id = window::Id::MAIN;
+
+        let events = [
+            winit::event::Ime::Enabled,
+            winit::event::Ime::Preedit(String::from("か"), Some((0, 3))),
+            winit::event::Ime::Preedit(String::new(), None),
+            winit::event::Ime::Commit(String::from("か")),
+            winit::event::Ime::Disabled,
+        ]
+        .map(|event| {
+            window_event(
+                id,
+                winit::event::WindowEvent::Ime(event),
+                1.0,
+                winit::keyboard::ModifiersState::empty(),
+            )
+        });
+
+        assert_eq!(
+            events,
+            [
+                window::Ime::Enabled,
+                window::Ime::Preedit {
+                    content: String::from("か"),
+                    cursor: Some((0, 3)),
+                },
+                window::Ime::Preedit {
+                    content: String::new(),
+                    cursor: None,
+                },
+                window::Ime::Commit(String::from("か")),
+                window::Ime::Disabled,
+            ]
+            .map(|ime| Some(Event::Window(id, window::Event::Ime(ime))))
+        );
+    }
+
+    #[test]
+    fn raw_mouse_motion() {
+        assert_eq!(
+            device_event(&winit::event::DeviceEvent::MouseMotion {
+                delta: (3.0, -2.5),
+            }),
+            Some(Event::Mouse(mouse::Event::RawMotion {
+                delta: Vector::new(3.0, -2.5),
+            }))
+        );
+    }
+
+    #[test]
+    fn modifier_only_changes() {
+        use winit::keyboard::ModifiersState;
+
+        let events =
+            [ModifiersState::CONTROL, ModifiersState::empty()].map(|state| {
+                window_event(
+                    window::Id::MAIN,
+                    winit::event::WindowEvent::ModifiersChanged(state.into()),
+                    1.0,
+                    state,
+                )
+            });
+
+        assert_eq!(
+            events,
+            [keyboard::Modifiers::CTRL, keyboard::Modifiers::empty()].map(
+                |modifiers| Some(Event::Keyboard(
+                    keyboard::Event::ModifiersChanged(modifiers)
+                ))
+            )
+        );
+    }
+
+    #[test]
+    fn touchpad_gestures_accumulate_until_restarted() {
+        use winit::event::{DeviceId, TouchPhase, WindowEvent};
+
+        #[allow(unsafe_code)]
+        let device_id = unsafe { DeviceId::dummy() };
+
+        let magnify = |delta, phase| WindowEvent::TouchpadMagnify {
+            device_id,
+            delta,
+            phase,
+        };
+
+        let mut gestures = TouchpadGestures::default();
+
+        let totals: Vec<_> = [
+            magnify(0.5, TouchPhase::Started),
+            magnify(0.25, TouchPhase::Moved),
+            magnify(0.0, TouchPhase::Ended),
+            magnify(-0.5, TouchPhase::Started),
+        ]
+        .iter()
+        .filter_map(|event| match gestures.convert(event)? {
+            touchpad::Event::Pinched { phase, total, .. } => {
+                Some((phase, total))
+            }
+            touchpad::Event::Rotated { .. } => None,
+        })
+        .collect();
+
+        assert_eq!(
+            totals,
+            vec![
+                (touchpad::Phase::Started, 0.5),
+                (touchpad::Phase::Updated, 0.75),
+                (touchpad::Phase::Ended, 0.75),
+                (touchpad::Phase::Started, -0.5),
+            ]
+        );
+
+        assert_eq!(gestures.convert(&WindowEvent::RedrawRequested), None);
+    }
+}
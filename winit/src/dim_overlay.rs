@@ -0,0 +1,99 @@
+//! Dim windows behind modal dialogs.
+use crate::core::time::{Duration, Instant};
+use crate::core::{self, renderer, Color, Rectangle, Size};
+
+/// The time it takes for a [`DimOverlay`] to fade in or out.
+const FADE_DURATION: Duration = Duration::from_millis(150);
+
+/// A translucent overlay drawn over the whole contents of a window, which
+/// fades between colors.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct DimOverlay {
+    from: Color,
+    to: Color,
+    fade_started: Option<Instant>,
+}
+
+impl DimOverlay {
+    /// Creates a new, transparent [`DimOverlay`].
+    pub fn new() -> Self {
+        Self {
+            from: Color::TRANSPARENT,
+            to: Color::TRANSPARENT,
+            fade_started: None,
+        }
+    }
+
+    /// Fades the [`DimOverlay`] to the given [`Color`], or out when `None`.
+    pub fn set(&mut self, color: Option<Color>) {
+        let current = self.color();
+
+        let to = color.unwrap_or(Color { a: 0.0, ..current });
+        let from = if current.a == 0.0 {
+            Color { a: 0.0, ..to }
+        } else {
+            current
+        };
+
+        if to == self.to {
+            return;
+        }
+
+        self.from = from;
+        self.to = to;
+        self.fade_started = Some(Instant::now());
+    }
+
+    /// Returns the current [`Color`] of the [`DimOverlay`].
+    pub fn color(&self) -> Color {
+        let Some(started) = self.fade_started else {
+            return self.to;
+        };
+
+        let progress = (started.elapsed().as_secs_f32()
+            / FADE_DURATION.as_secs_f32())
+        .min(1.0);
+
+        // Ease out, so the overlay reacts immediately
+        let t = 1.0 - (1.0 - progress).powi(2);
+
+        Color {
+            r: self.from.r + (self.to.r - self.from.r) * t,
+            g: self.from.g + (self.to.g - self.from.g) * t,
+            b: self.from.b + (self.to.b - self.from.b) * t,
+            a: self.from.a + (self.to.a - self.from.a) * t,
+        }
+    }
+
+    /// Returns whether the [`DimOverlay`] is still fading.
+    pub fn is_fading(&self) -> bool {
+        self.fade_started
+            .is_some_and(|started| started.elapsed() < FADE_DURATION)
+    }
+}
+
+/// Draws a dim overlay of the given [`Color`] over a window of the given
+/// logical size.
+pub(crate) fn draw<Renderer>(
+    renderer: &mut Renderer,
+    logical_size: Size,
+    color: Color,
+) where
+    Renderer: core::Renderer,
+{
+    if color.a <= 0.0 {
+        return;
+    }
+
+    let bounds = Rectangle::with_size(logical_size);
+
+    renderer.with_layer(bounds, |renderer| {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                ..renderer::Quad::default()
+            },
+            color,
+        );
+    });
+}
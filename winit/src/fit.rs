@@ -0,0 +1,64 @@
+//! Resize windows to the natural size of their contents.
+use crate::core::layout;
+use crate::core::widget::Tree;
+use crate::core::{Element, Size};
+
+/// Requests the given `window` to be resized to the natural size of the
+/// given `content`.
+///
+/// The content is laid out in the logical pixels of the user interface, as
+/// given by its `scale_factor`, with shrinking limits bounded by the work
+/// area of the current monitor of the window, so it never grows past the
+/// screen. The minimum and maximum sizes of the window are enforced by the
+/// windowing system when the new size is requested.
+pub(crate) fn fit_to_content<Message, Theme, Renderer>(
+    window: &winit::window::Window,
+    content: &Element<'_, Message, Theme, Renderer>,
+    renderer: &Renderer,
+    scale_factor: f64,
+) where
+    Renderer: crate::core::Renderer,
+{
+    let scale_factor = scale_factor as f32;
+
+    let max = crate::snap::work_area(window)
+        .map(|area| {
+            let inner_size = window.inner_size();
+            let outer_size = window.outer_size();
+
+            Size::new(
+                (area.width
+                    - outer_size.width.saturating_sub(inner_size.width) as f32)
+                    / scale_factor,
+                (area.height
+                    - outer_size.height.saturating_sub(inner_size.height)
+                        as f32)
+                    / scale_factor,
+            )
+        })
+        .unwrap_or(Size::INFINITY);
+
+    let mut tree = Tree::new(content.as_widget());
+    let size = content
+        .as_widget()
+        .layout(&mut tree, renderer, &layout::Limits::new(Size::ZERO, max))
+        .size();
+
+    let size =
+        Size::new(size.width.min(max.width), size.height.min(max.height));
+
+    // Filling content without a work area to bound it has no natural size
+    if !size.width.is_finite()
+        || !size.height.is_finite()
+        || size.width < 1.0
+        || size.height < 1.0
+    {
+        log::warn!("Cannot fit window to content of size {size:?}");
+        return;
+    }
+
+    let _ = window.request_inner_size(winit::dpi::PhysicalSize {
+        width: (size.width * scale_factor).ceil() as u32,
+        height: (size.height * scale_factor).ceil() as u32,
+    });
+}
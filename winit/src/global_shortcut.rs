@@ -0,0 +1,379 @@
+//! Register keyboard shortcuts that are triggered even when none of the
+//! windows of the application have focus.
+use crate::core::keyboard::shortcut::{self, Shortcut};
+use crate::runtime::system::GlobalShortcutError;
+
+/// Registers the given [`Shortcut`] with the system, calling `on_trigger`
+/// with its [`shortcut::Id`] every time it is pressed.
+///
+/// `on_result` is called once the registration completes, which may be after
+/// the user confirms it.
+#[cfg(target_os = "windows")]
+pub(crate) fn register(
+    shortcut: &Shortcut,
+    on_trigger: impl Fn(shortcut::Id) + Send + 'static,
+    on_result: impl FnOnce(Result<shortcut::Id, GlobalShortcutError>)
+        + Send
+        + 'static,
+) {
+    on_result(windows::register(shortcut, Box::new(on_trigger)));
+}
+
+/// Registers the given [`Shortcut`] with the system, calling `on_trigger`
+/// with its [`shortcut::Id`] every time it is pressed.
+///
+/// `on_result` is called once the registration completes, which may be after
+/// the user confirms it.
+#[cfg(all(feature = "portal", unix, not(target_os = "macos")))]
+pub(crate) fn register(
+    shortcut: &Shortcut,
+    on_trigger: impl Fn(shortcut::Id) + Send + 'static,
+    on_result: impl FnOnce(Result<shortcut::Id, GlobalShortcutError>)
+        + Send
+        + 'static,
+) {
+    crate::runtime::system::portal::register(shortcut, on_trigger, on_result);
+}
+
+/// Registers the given [`Shortcut`] with the system, calling `on_trigger`
+/// with its [`shortcut::Id`] every time it is pressed.
+///
+/// `on_result` is called once the registration completes, which may be after
+/// the user confirms it.
+#[cfg(not(any(
+    target_os = "windows",
+    all(feature = "portal", unix, not(target_os = "macos"))
+)))]
+pub(crate) fn register(
+    _shortcut: &Shortcut,
+    _on_trigger: impl Fn(shortcut::Id) + Send + 'static,
+    on_result: impl FnOnce(Result<shortcut::Id, GlobalShortcutError>)
+        + Send
+        + 'static,
+) {
+    on_result(Err(GlobalShortcutError::Unsupported));
+}
+
+/// Unregisters the [`Shortcut`] with the given [`shortcut::Id`].
+pub(crate) fn unregister(_id: shortcut::Id) {
+    #[cfg(target_os = "windows")]
+    windows::unregister(_id);
+
+    #[cfg(all(feature = "portal", unix, not(target_os = "macos")))]
+    crate::runtime::system::portal::unregister(_id);
+}
+
+/// Unregisters every [`Shortcut`] registered by the application.
+pub(crate) fn unregister_all() {
+    #[cfg(target_os = "windows")]
+    windows::unregister_all();
+
+    #[cfg(all(feature = "portal", unix, not(target_os = "macos")))]
+    crate::runtime::system::portal::unregister_all();
+}
+
+#[cfg(target_os = "windows")]
+mod windows {
+    use super::{shortcut, GlobalShortcutError, Shortcut};
+    use crate::core::keyboard::key::{self, Key};
+    use crate::core::keyboard::Modifiers;
+
+    use winapi::um::processthreadsapi::GetCurrentThreadId;
+    use winapi::um::winuser::{
+        self, GetMessageW, PeekMessageW, PostThreadMessageW, RegisterHotKey,
+        UnregisterHotKey, MSG, PM_NOREMOVE, WM_APP, WM_HOTKEY,
+    };
+
+    use std::collections::HashMap;
+    use std::ptr;
+    use std::sync::{mpsc, Mutex, PoisonError};
+
+    type Trigger = Box<dyn Fn(shortcut::Id) + Send>;
+
+    enum Request {
+        Register {
+            id: shortcut::Id,
+            modifiers: u32,
+            virtual_key: u32,
+            on_trigger: Trigger,
+            result: mpsc::Sender<bool>,
+        },
+        Unregister(shortcut::Id),
+        UnregisterAll,
+    }
+
+    /// The thread owning the registered hot keys.
+    ///
+    /// `WM_HOTKEY` messages are posted to the message queue of the thread
+    /// that registered them, which is not necessarily the thread running the
+    /// event loop.
+    struct Thread {
+        id: u32,
+        sender: mpsc::Sender<Request>,
+    }
+
+    static THREAD: Mutex<Option<Thread>> = Mutex::new(None);
+
+    pub(super) fn register(
+        shortcut: &Shortcut,
+        on_trigger: Trigger,
+    ) -> Result<shortcut::Id, GlobalShortcutError> {
+        let virtual_key = virtual_key(&shortcut.key)
+            .ok_or(GlobalShortcutError::InvalidKey)?;
+
+        let id = shortcut::Id::unique();
+        let (result, receiver) = mpsc::channel();
+
+        let sent = send(Request::Register {
+            id,
+            modifiers: modifiers(shortcut.modifiers),
+            virtual_key,
+            on_trigger,
+            result,
+        });
+
+        if !sent {
+            return Err(GlobalShortcutError::Unsupported);
+        }
+
+        match receiver.recv() {
+            Ok(true) => Ok(id),
+            Ok(false) => Err(GlobalShortcutError::AlreadyRegistered),
+            Err(_) => Err(GlobalShortcutError::Unsupported),
+        }
+    }
+
+    pub(super) fn unregister(id: shortcut::Id) {
+        let _ = send(Request::Unregister(id));
+    }
+
+    pub(super) fn unregister_all() {
+        let thread = THREAD.lock().unwrap_or_else(PoisonError::into_inner);
+
+        // Avoid spawning the thread if nothing was ever registered
+        if thread.is_some() {
+            drop(thread);
+
+            let _ = send(Request::UnregisterAll);
+        }
+    }
+
+    fn send(request: Request) -> bool {
+        let mut thread = THREAD.lock().unwrap_or_else(PoisonError::into_inner);
+
+        if thread.is_none() {
+            *thread = spawn();
+        }
+
+        let Some(thread) = thread.as_ref() else {
+            return false;
+        };
+
+        #[allow(unsafe_code)]
+        let posted =
+            || unsafe { PostThreadMessageW(thread.id, WM_APP, 0, 0) != 0 };
+
+        thread.sender.send(request).is_ok() && posted()
+    }
+
+    fn spawn() -> Option<Thread> {
+        let (sender, requests) = mpsc::channel();
+        let (id_sender, id_receiver) = mpsc::channel();
+
+        let _ = std::thread::Builder::new()
+            .name(String::from("iced global shortcuts"))
+            .spawn(move || {
+                #[allow(unsafe_code)]
+                let mut message: MSG = unsafe { std::mem::zeroed() };
+
+                // The message queue of a thread is created on its first call
+                // to a message function, and `PostThreadMessageW` fails
+                // until then
+                #[allow(unsafe_code)]
+                let id = unsafe {
+                    let _ = PeekMessageW(
+                        &mut message,
+                        ptr::null_mut(),
+                        WM_APP,
+                        WM_APP,
+                        PM_NOREMOVE,
+                    );
+
+                    GetCurrentThreadId()
+                };
+
+                let _ = id_sender.send(id);
+
+                let mut triggers: HashMap<shortcut::Id, Trigger> =
+                    HashMap::new();
+
+                #[allow(unsafe_code)]
+                while unsafe {
+                    GetMessageW(&mut message, ptr::null_mut(), 0, 0)
+                } > 0
+                {
+                    match message.message {
+                        WM_HOTKEY => {
+                            if let Some((id, on_trigger)) =
+                                triggers.iter().find(|(id, _)| {
+                                    id.as_u32() as usize == message.wParam
+                                })
+                            {
+                                on_trigger(*id);
+                            }
+                        }
+                        WM_APP => {
+                            for request in requests.try_iter() {
+                                handle(request, &mut triggers);
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            })
+            .ok()?;
+
+        Some(Thread {
+            id: id_receiver.recv().ok()?,
+            sender,
+        })
+    }
+
+    #[allow(unsafe_code)]
+    fn handle(request: Request, triggers: &mut HashMap<shortcut::Id, Trigger>) {
+        match request {
+            Request::Register {
+                id,
+                modifiers,
+                virtual_key,
+                on_trigger,
+                result,
+            } => {
+                let registered = unsafe {
+                    RegisterHotKey(
+                        ptr::null_mut(),
+                        id.as_u32() as i32,
+                        modifiers,
+                        virtual_key,
+                    ) != 0
+                };
+
+                if registered {
+                    let _ = triggers.insert(id, on_trigger);
+                }
+
+                let _ = result.send(registered);
+            }
+            Request::Unregister(id) => {
+                if triggers.remove(&id).is_some() {
+                    let _ = unsafe {
+                        UnregisterHotKey(ptr::null_mut(), id.as_u32() as i32)
+                    };
+                }
+            }
+            Request::UnregisterAll => {
+                for (id, _) in triggers.drain() {
+                    let _ = unsafe {
+                        UnregisterHotKey(ptr::null_mut(), id.as_u32() as i32)
+                    };
+                }
+            }
+        }
+    }
+
+    fn modifiers(modifiers: Modifiers) -> u32 {
+        // Holding the shortcut down should not trigger it repeatedly
+        let mut flags = winuser::MOD_NOREPEAT as u32;
+
+        if modifiers.shift() {
+            flags |= winuser::MOD_SHIFT as u32;
+        }
+
+        if modifiers.control() {
+            flags |= winuser::MOD_CONTROL as u32;
+        }
+
+        if modifiers.alt() {
+            flags |= winuser::MOD_ALT as u32;
+        }
+
+        if modifiers.logo() {
+            flags |= winuser::MOD_WIN as u32;
+        }
+
+        flags
+    }
+
+    fn virtual_key(key: &Key) -> Option<u32> {
+        use key::Named;
+
+        let code = match key {
+            Key::Character(c) => {
+                let mut chars = c.chars();
+                let c = chars.next()?.to_ascii_uppercase();
+
+                if chars.next().is_some() || !c.is_ascii_alphanumeric() {
+                    return None;
+                }
+
+                // Virtual key codes of letters and digits match their ASCII
+                // uppercase representation
+                c as i32
+            }
+            Key::Named(named) => match named {
+                Named::Space => winuser::VK_SPACE,
+                Named::Enter => winuser::VK_RETURN,
+                Named::Escape => winuser::VK_ESCAPE,
+                Named::Tab => winuser::VK_TAB,
+                Named::Backspace => winuser::VK_BACK,
+                Named::Delete => winuser::VK_DELETE,
+                Named::Insert => winuser::VK_INSERT,
+                Named::Home => winuser::VK_HOME,
+                Named::End => winuser::VK_END,
+                Named::PageUp => winuser::VK_PRIOR,
+                Named::PageDown => winuser::VK_NEXT,
+                Named::ArrowLeft => winuser::VK_LEFT,
+                Named::ArrowRight => winuser::VK_RIGHT,
+                Named::ArrowUp => winuser::VK_UP,
+                Named::ArrowDown => winuser::VK_DOWN,
+                Named::PrintScreen => winuser::VK_SNAPSHOT,
+                Named::Pause => winuser::VK_PAUSE,
+                Named::MediaPlayPause => winuser::VK_MEDIA_PLAY_PAUSE,
+                Named::MediaStop => winuser::VK_MEDIA_STOP,
+                Named::MediaTrackNext => winuser::VK_MEDIA_NEXT_TRACK,
+                Named::MediaTrackPrevious => winuser::VK_MEDIA_PREV_TRACK,
+                Named::AudioVolumeUp => winuser::VK_VOLUME_UP,
+                Named::AudioVolumeDown => winuser::VK_VOLUME_DOWN,
+                Named::AudioVolumeMute => winuser::VK_VOLUME_MUTE,
+                Named::F1 => winuser::VK_F1,
+                Named::F2 => winuser::VK_F2,
+                Named::F3 => winuser::VK_F3,
+                Named::F4 => winuser::VK_F4,
+                Named::F5 => winuser::VK_F5,
+                Named::F6 => winuser::VK_F6,
+                Named::F7 => winuser::VK_F7,
+                Named::F8 => winuser::VK_F8,
+                Named::F9 => winuser::VK_F9,
+                Named::F10 => winuser::VK_F10,
+                Named::F11 => winuser::VK_F11,
+                Named::F12 => winuser::VK_F12,
+                Named::F13 => winuser::VK_F13,
+                Named::F14 => winuser::VK_F14,
+                Named::F15 => winuser::VK_F15,
+                Named::F16 => winuser::VK_F16,
+                Named::F17 => winuser::VK_F17,
+                Named::F18 => winuser::VK_F18,
+                Named::F19 => winuser::VK_F19,
+                Named::F20 => winuser::VK_F20,
+                Named::F21 => winuser::VK_F21,
+                Named::F22 => winuser::VK_F22,
+                Named::F23 => winuser::VK_F23,
+                Named::F24 => winuser::VK_F24,
+                _ => return None,
+            },
+            Key::Unidentified => return None,
+        };
+
+        Some(code as u32)
+    }
+}
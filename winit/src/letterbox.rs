@@ -0,0 +1,156 @@
+//! Maintain the aspect ratio of windows or their contents.
+use crate::core::{self, mouse, touch, Event, Point, Rectangle, Size, Vector};
+
+/// Returns the largest bounds with the given aspect ratio that fit in the
+/// given logical `size`, centered.
+pub(crate) fn content_bounds(size: Size, aspect_ratio: f32) -> Rectangle {
+    let content = if size.width > size.height * aspect_ratio {
+        Size::new(size.height * aspect_ratio, size.height)
+    } else {
+        Size::new(size.width, size.width / aspect_ratio)
+    };
+
+    Rectangle::new(
+        Point::new(
+            ((size.width - content.width) / 2.0).round(),
+            ((size.height - content.height) / 2.0).round(),
+        ),
+        content,
+    )
+}
+
+/// Returns the physical size a window of the given size must be resized to
+/// in order to match the given aspect ratio, if it does not match already.
+///
+/// The width of the window is kept, so that horizontal resizes are not
+/// fought against.
+pub(crate) fn constrained_size(
+    size: winit::dpi::PhysicalSize<u32>,
+    aspect_ratio: f32,
+) -> Option<winit::dpi::PhysicalSize<u32>> {
+    let height = (size.width as f32 / aspect_ratio).round().max(1.0) as u32;
+
+    (height.abs_diff(size.height) > 1)
+        .then_some(winit::dpi::PhysicalSize::new(size.width, height))
+}
+
+/// Draws contents with the given closure, clipped to and placed at the given
+/// `bounds`.
+///
+/// Nothing is drawn outside of the `bounds`, so the bars around letterboxed
+/// contents show the background color of the application.
+pub(crate) fn draw<Renderer, T>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    draw: impl FnOnce(&mut Renderer) -> T,
+) -> T
+where
+    Renderer: core::Renderer,
+{
+    if bounds.x == 0.0 && bounds.y == 0.0 {
+        return draw(renderer);
+    }
+
+    let mut output = None;
+
+    renderer.with_layer(bounds, |renderer| {
+        renderer.with_translation(
+            Vector::new(bounds.x, bounds.y),
+            |renderer| {
+                output = Some(draw(renderer));
+            },
+        );
+    });
+
+    output.expect("Draw letterboxed contents")
+}
+
+/// Translates the positions of the given [`Event`] to the coordinate system
+/// of letterboxed contents placed at the given `offset`.
+pub(crate) fn translate_event(event: Event, offset: Vector) -> Event {
+    map_positions(event, |position| position - offset)
+}
+
+/// Maps the positions of the given [`Event`] with the given function.
+pub(crate) fn map_positions(
+    mut event: Event,
+    f: impl Fn(Point) -> Point,
+) -> Event {
+    if let Event::Dnd(dnd::DndEvent::Offer(
+        _,
+        dnd::OfferEvent::Enter { x, y, .. } | dnd::OfferEvent::Motion { x, y },
+    )) = &mut event
+    {
+        let position = f(Point::new(*x as f32, *y as f32));
+
+        *x = position.x.into();
+        *y = position.y.into();
+
+        return event;
+    }
+
+    match event {
+        Event::Mouse(mouse::Event::CursorMoved { position }) => {
+            Event::Mouse(mouse::Event::CursorMoved {
+                position: f(position),
+            })
+        }
+        Event::Touch(touch::Event::FingerPressed { id, position }) => {
+            Event::Touch(touch::Event::FingerPressed {
+                id,
+                position: f(position),
+            })
+        }
+        Event::Touch(touch::Event::FingerMoved { id, position }) => {
+            Event::Touch(touch::Event::FingerMoved {
+                id,
+                position: f(position),
+            })
+        }
+        Event::Touch(touch::Event::FingerLifted { id, position }) => {
+            Event::Touch(touch::Event::FingerLifted {
+                id,
+                position: f(position),
+            })
+        }
+        Event::Touch(touch::Event::FingerLost { id, position }) => {
+            Event::Touch(touch::Event::FingerLost {
+                id,
+                position: f(position),
+            })
+        }
+        event => event,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wide_windows_have_vertical_bars() {
+        assert_eq!(
+            content_bounds(Size::new(1000.0, 400.0), 2.0),
+            Rectangle::new(Point::new(100.0, 0.0), Size::new(800.0, 400.0))
+        );
+    }
+
+    #[test]
+    fn tall_windows_have_horizontal_bars() {
+        assert_eq!(
+            content_bounds(Size::new(800.0, 600.0), 2.0),
+            Rectangle::new(Point::new(0.0, 100.0), Size::new(800.0, 400.0))
+        );
+    }
+
+    #[test]
+    fn constrained_size_keeps_width() {
+        use winit::dpi::PhysicalSize;
+
+        assert_eq!(
+            constrained_size(PhysicalSize::new(1600, 1000), 2.0),
+            Some(PhysicalSize::new(1600, 800))
+        );
+        assert_eq!(constrained_size(PhysicalSize::new(1600, 800), 2.0), None);
+    }
+}
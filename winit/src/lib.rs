@@ -44,8 +44,28 @@ pub mod settings;
 #[cfg(feature = "system")]
 pub mod system;
 
+mod attention_sound;
+mod dim_overlay;
 mod error;
+mod fit;
+mod global_shortcut;
+mod letterbox;
+mod monitor;
 mod proxy;
+mod snap;
+mod watchdog;
+
+#[cfg(target_os = "macos")]
+mod macos;
+
+#[cfg(target_os = "windows")]
+mod windows;
+
+#[cfg(all(target_os = "linux", feature = "wayland"))]
+mod wayland;
+
+#[cfg(all(target_os = "linux", feature = "x11"))]
+mod x11;
 
 #[cfg(feature = "application")]
 pub use application::Application;
@@ -0,0 +1,227 @@
+//! Platform-specific helpers for macOS.
+use crate::core::{Point, Rectangle};
+
+use objc2_app_kit::{
+    NSView, NSWindow, NSWindowAnimationBehavior, NSWindowButton,
+};
+use objc2_foundation::{NSPoint, NSRect, NSSize, NSString};
+use winit::raw_window_handle::{HasWindowHandle, RawWindowHandle};
+
+use std::path::Path;
+
+/// Moves the standard window buttons (i.e. the traffic lights) of the given
+/// `window`, so that the close button is placed at the given logical `offset`
+/// from the top-left corner of the title bar.
+pub(crate) fn set_traffic_light_offset(
+    window: &winit::window::Window,
+    offset: Point,
+) {
+    let Ok(handle) = window.window_handle() else {
+        return;
+    };
+
+    let RawWindowHandle::AppKit(handle) = handle.as_raw() else {
+        return;
+    };
+
+    #[allow(unsafe_code)]
+    unsafe {
+        let view: &NSView = handle.ns_view.cast().as_ref();
+
+        let Some(ns_window) = view.window() else {
+            return;
+        };
+
+        let buttons = [
+            NSWindowButton::NSWindowCloseButton,
+            NSWindowButton::NSWindowMiniaturizeButton,
+            NSWindowButton::NSWindowZoomButton,
+        ]
+        .map(|button| ns_window.standardWindowButton(button));
+
+        let [Some(close), Some(miniaturize), _] = &buttons else {
+            return;
+        };
+
+        let Some(title_bar) = close.superview() else {
+            return;
+        };
+
+        let spacing = miniaturize.frame().origin.x - close.frame().origin.x;
+        let title_bar_height = title_bar.frame().size.height;
+
+        for (i, button) in buttons.iter().enumerate() {
+            let Some(button) = button else {
+                continue;
+            };
+
+            let height = button.frame().size.height;
+
+            button.setFrameOrigin(NSPoint::new(
+                f64::from(offset.x) + spacing * i as f64,
+                title_bar_height - f64::from(offset.y) - height,
+            ));
+        }
+    }
+}
+
+/// Sets the path of the file represented by the given `window`, so that its
+/// icon is shown next to the title.
+pub(crate) fn set_document_path(window: &winit::window::Window, path: &Path) {
+    let Ok(handle) = window.window_handle() else {
+        return;
+    };
+
+    let RawWindowHandle::AppKit(handle) = handle.as_raw() else {
+        return;
+    };
+
+    let path = NSString::from_str(&path.to_string_lossy());
+
+    #[allow(unsafe_code)]
+    unsafe {
+        let view: &NSView = handle.ns_view.cast().as_ref();
+
+        if let Some(ns_window) = view.window() {
+            ns_window.setRepresentedFilename(&path);
+        }
+    }
+}
+
+/// Enables or disables the animations performed by the system for the given
+/// `window`, like the one shown when it is minimized.
+pub(crate) fn set_minimize_animation(
+    window: &winit::window::Window,
+    enabled: bool,
+) {
+    let Ok(handle) = window.window_handle() else {
+        return;
+    };
+
+    let RawWindowHandle::AppKit(handle) = handle.as_raw() else {
+        return;
+    };
+
+    let behavior = if enabled {
+        NSWindowAnimationBehavior::NSWindowAnimationBehaviorDefault
+    } else {
+        NSWindowAnimationBehavior::NSWindowAnimationBehaviorNone
+    };
+
+    #[allow(unsafe_code)]
+    unsafe {
+        let view: &NSView = handle.ns_view.cast().as_ref();
+
+        if let Some(ns_window) = view.window() {
+            ns_window.setAnimationBehavior(behavior);
+        }
+    }
+}
+
+/// Returns the visible frame of the screen of the given `window`, which
+/// excludes the menu bar and the Dock, in physical pixels.
+pub(crate) fn work_area(window: &winit::window::Window) -> Option<Rectangle> {
+    let primary_height = primary_height(window)?;
+
+    with_ns_window(window, |ns_window| {
+        let screen = ns_window.screen()?;
+
+        let frame = screen.visibleFrame();
+        let scale_factor = screen.backingScaleFactor();
+
+        // AppKit places the origin at the bottom-left corner of the primary
+        // screen, while winit places it at the top-left corner
+        Some(Rectangle {
+            x: (frame.origin.x * scale_factor) as f32,
+            y: ((primary_height - frame.origin.y - frame.size.height)
+                * scale_factor) as f32,
+            width: (frame.size.width * scale_factor) as f32,
+            height: (frame.size.height * scale_factor) as f32,
+        })
+    })
+    .flatten()
+}
+
+/// Moves and resizes the given `window` at once, so that its outer frame
+/// covers the given `bounds`, in physical pixels.
+///
+/// Returns `false` if the `window` is not an AppKit window.
+pub(crate) fn set_outer_frame(
+    window: &winit::window::Window,
+    bounds: Rectangle,
+) -> bool {
+    let Some(primary_height) = primary_height(window) else {
+        return false;
+    };
+
+    let scale_factor = window.scale_factor();
+
+    let x = f64::from(bounds.x) / scale_factor;
+    let y = f64::from(bounds.y) / scale_factor;
+    let width = f64::from(bounds.width) / scale_factor;
+    let height = f64::from(bounds.height) / scale_factor;
+
+    with_ns_window(window, |ns_window| {
+        ns_window.setFrame_display(
+            NSRect::new(
+                NSPoint::new(x, primary_height - y - height),
+                NSSize::new(width, height),
+            ),
+            true,
+        );
+    })
+    .is_some()
+}
+
+/// Returns the double click interval of the user.
+#[cfg(feature = "system")]
+pub(crate) fn double_click_interval() -> std::time::Duration {
+    #[allow(unsafe_code)]
+    let seconds = unsafe { objc2_app_kit::NSEvent::doubleClickInterval() };
+
+    std::time::Duration::from_secs_f64(seconds)
+}
+
+/// Returns the key repeat settings of the user.
+#[cfg(feature = "system")]
+pub(crate) fn key_repeat() -> crate::runtime::system::KeyRepeat {
+    use objc2_app_kit::NSEvent;
+
+    #[allow(unsafe_code)]
+    let (delay, interval) =
+        unsafe { (NSEvent::keyRepeatDelay(), NSEvent::keyRepeatInterval()) };
+
+    crate::runtime::system::KeyRepeat {
+        delay: std::time::Duration::from_secs_f64(delay),
+        interval: std::time::Duration::from_secs_f64(interval),
+    }
+}
+
+/// Returns the height of the primary monitor, in points.
+fn primary_height(window: &winit::window::Window) -> Option<f64> {
+    let monitor = window.primary_monitor()?;
+
+    Some(f64::from(monitor.size().height) / monitor.scale_factor())
+}
+
+/// Runs the given closure with the `NSWindow` of the given `window`, if it
+/// is an AppKit window.
+fn with_ns_window<T>(
+    window: &winit::window::Window,
+    f: impl FnOnce(&NSWindow) -> T,
+) -> Option<T> {
+    let handle = window.window_handle().ok()?;
+
+    let RawWindowHandle::AppKit(handle) = handle.as_raw() else {
+        return None;
+    };
+
+    #[allow(unsafe_code)]
+    let ns_window = unsafe {
+        let view: &NSView = handle.ns_view.cast().as_ref();
+
+        view.window()?
+    };
+
+    Some(f(&ns_window))
+}
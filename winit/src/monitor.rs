@@ -0,0 +1,36 @@
+//! Query the monitors of the virtual desktop.
+use crate::core::window::Monitor;
+use crate::runtime::system::MonitorLayout;
+
+/// Returns the [`MonitorLayout`] of the virtual desktop the given `window`
+/// belongs to.
+pub(crate) fn layout(window: &winit::window::Window) -> MonitorLayout {
+    let primary = window.primary_monitor();
+
+    let (monitors, primary) = window.available_monitors().enumerate().fold(
+        (Vec::new(), None),
+        |(mut monitors, mut primary_index), (i, monitor)| {
+            if primary.as_ref() == Some(&monitor) {
+                primary_index = Some(i);
+            }
+
+            monitors.push(crate::conversion::monitor(&monitor));
+
+            (monitors, primary_index)
+        },
+    );
+
+    MonitorLayout { monitors, primary }
+}
+
+/// Returns the scale factor of the given [`Monitor`] of the virtual desktop
+/// the given `window` belongs to, if it is still connected.
+pub(crate) fn scale_factor(
+    window: &winit::window::Window,
+    monitor: &Monitor,
+) -> Option<f64> {
+    window
+        .available_monitors()
+        .find(|handle| crate::conversion::monitor(handle).is_same(monitor))
+        .map(|handle| handle.scale_factor())
+}
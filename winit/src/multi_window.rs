@@ -4,7 +4,10 @@ mod drag_resize;
 mod state;
 mod window_manager;
 
-use crate::application::UserEventWrapper;
+use crate::application::{
+    coalesce_cursor_moves, ends_dnd, is_raw_motion, render_element_to_image,
+    schedule_clipboard_clear, UserEventWrapper, WAIT_FOR_FONTS_TIMEOUT,
+};
 use crate::conversion;
 use crate::core;
 use crate::core::mouse;
@@ -14,17 +17,21 @@ use crate::core::widget::Operation;
 use crate::core::window;
 use crate::core::Clipboard as CoreClipboard;
 use crate::core::Size;
+use crate::dim_overlay;
 use crate::futures::futures::channel::mpsc;
 use crate::futures::futures::{task, Future, StreamExt};
 use crate::futures::{Executor, Runtime, Subscription};
 use crate::graphics::{compositor, Compositor};
+use crate::letterbox;
 use crate::multi_window::operation::OperationWrapper;
 use crate::multi_window::window_manager::WindowManager;
 use crate::runtime::command::{self, Command};
 use crate::runtime::multi_window::Program;
 use crate::runtime::user_interface::{self, UserInterface};
 use crate::runtime::Debug;
+use crate::settings::WindowOptions;
 use crate::style::application::StyleSheet;
+use crate::watchdog::{self, Watchdog};
 use crate::{Clipboard, Error, Proxy, Settings};
 use dnd::DndSurface;
 use dnd::Icon;
@@ -39,7 +46,7 @@ use std::any::Any;
 use std::collections::HashMap;
 use std::mem::ManuallyDrop;
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 /// subscription mapper helper
 pub fn subscription_map<A, E>(e: A::Message) -> UserEventWrapper<A::Message>
@@ -106,6 +113,21 @@ where
         Subscription::none()
     }
 
+    /// Returns the event [`Subscription`] of the `window` of the
+    /// [`Application`].
+    ///
+    /// It is tracked together with the global
+    /// [`subscription`](#method.subscription) only while the `window` is
+    /// open, and it is cancelled as soon as the `window` closes.
+    ///
+    /// By default, it returns an empty subscription.
+    fn window_subscription(
+        &self,
+        _window: window::Id,
+    ) -> Subscription<Self::Message> {
+        Subscription::none()
+    }
+
     /// Returns the scale factor of the window of the [`Application`].
     ///
     /// It can be used to dynamically control the size of the UI at runtime
@@ -123,6 +145,10 @@ where
 
 /// Runs an [`Application`] with an executor, compositor, and the provided
 /// settings.
+///
+/// A single [`Compositor`] is created for the main window and reused by every
+/// window opened afterwards, so all the surfaces of the application share the
+/// same graphics device.
 pub fn run<A, E, C>(
     settings: Settings<A::Flags>,
     compositor_settings: C::Settings,
@@ -158,9 +184,11 @@ where
         runtime.enter(|| A::new(flags))
     };
 
-    let should_main_be_visible = settings.window.visible;
-    let exit_on_close_request = settings.window.exit_on_close_request;
-    let resize_border = settings.window.resize_border;
+    let main_window_options = WindowOptions::new(&settings.window);
+    let batch_messages_before_draw = settings.batch_messages_before_draw;
+    let coalesce_mouse_motion = settings.coalesce_mouse_motion;
+    let frame_budget = settings.frame_budget;
+    let on_raw_event = settings.on_raw_event;
 
     let builder = conversion::window_settings(
         settings.window,
@@ -216,8 +244,7 @@ where
         main_window,
         &application,
         &mut compositor,
-        exit_on_close_request,
-        resize_border,
+        main_window_options,
     );
 
     let (mut event_sender, event_receiver) = mpsc::unbounded();
@@ -233,8 +260,11 @@ where
         control_sender,
         init_command,
         window_manager,
-        should_main_be_visible,
-        resize_border,
+        main_window_options,
+        batch_messages_before_draw,
+        coalesce_mouse_motion,
+        frame_budget,
+        on_raw_event,
     ));
 
     let mut context = task::Context::from_waker(task::noop_waker_ref());
@@ -258,10 +288,14 @@ where
                             use winit::event_loop::ControlFlow;
 
                             match (event_loop.control_flow(), flow) {
+                                // Keep the earliest pending deadline, since
+                                // every window redraws and requests its own
+                                // deadline again once it is reached
                                 (
                                     ControlFlow::WaitUntil(current),
                                     ControlFlow::WaitUntil(new),
-                                ) if new < current => {}
+                                ) if current < new
+                                    && current > Instant::now() => {}
                                 (
                                     ControlFlow::WaitUntil(target),
                                     ControlFlow::Wait,
@@ -277,12 +311,14 @@ where
                             title,
                             monitor,
                         } => {
-                            let exit_on_close_request =
-                                settings.exit_on_close_request;
+                            let options = WindowOptions::new(&settings);
+                            let wait_for_fonts =
+                                options.visible && options.wait_for_fonts;
 
                             let window = conversion::window_settings(
                                 settings, &title, monitor, None,
                             )
+                            .with_visible(!wait_for_fonts)
                             .build(event_loop)
                             .expect("Failed to build window");
 
@@ -290,10 +326,20 @@ where
                                 .start_send(Event::WindowCreated {
                                     id,
                                     window,
-                                    exit_on_close_request,
+                                    options,
                                 })
                                 .expect("Send event");
                         }
+                        Control::QueryMonitors(callback) => {
+                            callback(
+                                event_loop
+                                    .available_monitors()
+                                    .map(|monitor| {
+                                        conversion::monitor(&monitor)
+                                    })
+                                    .collect(),
+                            );
+                        }
                         Control::Exit => {
                             event_loop.exit();
                         }
@@ -317,7 +363,7 @@ enum Event<Message: 'static> {
     WindowCreated {
         id: window::Id,
         window: winit::window::Window,
-        exit_on_close_request: bool,
+        options: WindowOptions,
     },
     EventLoopAwakened(winit::event::Event<Message>),
 }
@@ -331,6 +377,7 @@ enum Control {
         title: String,
         monitor: Option<winit::monitor::MonitorHandle>,
     },
+    QueryMonitors(Box<dyn FnOnce(Vec<window::Monitor>)>),
 }
 
 async fn run_instance<A, E, C>(
@@ -349,8 +396,11 @@ async fn run_instance<A, E, C>(
     mut control_sender: mpsc::UnboundedSender<Control>,
     init_command: Command<A::Message>,
     mut window_manager: WindowManager<A, C>,
-    should_main_window_be_visible: bool,
-    resize_border: u32,
+    main_window_options: WindowOptions,
+    batch_messages_before_draw: Option<usize>,
+    coalesce_mouse_motion: bool,
+    frame_budget: Option<Duration>,
+    on_raw_event: Option<Arc<dyn Fn(&winit::event::WindowEvent) + Send + Sync>>,
 ) where
     A: Application + 'static,
     E: Executor + 'static,
@@ -365,8 +415,12 @@ async fn run_instance<A, E, C>(
         .get_mut(window::Id::MAIN)
         .expect("Get main window");
 
-    if should_main_window_be_visible {
-        main_window.raw.set_visible(true);
+    if main_window_options.visible {
+        if main_window_options.wait_for_fonts {
+            main_window.state.wait_for_fonts(WAIT_FOR_FONTS_TIMEOUT);
+        } else {
+            main_window.raw.set_visible(true);
+        }
     }
 
     let mut clipboard =
@@ -443,11 +497,12 @@ async fn run_instance<A, E, C>(
         &mut ui_caches,
     );
 
+    // The main window is shown right away if the initial command did not
+    // load any fonts in the background
+    let _ = window_manager.reveal_windows();
+
     runtime.track(
-        application
-            .subscription()
-            .map(subscription_map::<A, E>)
-            .into_recipes(),
+        subscription::<A, E, C>(&application, &window_manager).into_recipes(),
     );
 
     let mut messages = Vec::new();
@@ -456,23 +511,52 @@ async fn run_instance<A, E, C>(
 
     let mut cur_dnd_surface: Option<window::Id> = None;
 
-    'main: while let Some(event) = event_receiver.next().await {
+    let mut emulate_about_to_wait = false;
+
+    // Every window synchronizes its own theme, but the palette changes are
+    // shared by the whole application.
+    let mut last_palette = None;
+
+    let mut watchdog = Watchdog::new(frame_budget);
+
+    'main: loop {
+        watchdog.finish();
+
+        // The event loop does not resume during an interactive resize on
+        // Windows, so `AboutToWait` is emulated after every `Resized` event
+        // of a window that renders while being resized.
+        let event = if std::mem::take(&mut emulate_about_to_wait) {
+            Event::EventLoopAwakened(event::Event::AboutToWait)
+        } else if let Some(event) = event_receiver.next().await {
+            event
+        } else {
+            break;
+        };
+
+        watchdog.start(match &event {
+            Event::WindowCreated { .. } => "creating a window",
+            Event::EventLoopAwakened(event) => watchdog::phase(event),
+        });
+
         match event {
             Event::WindowCreated {
                 id,
                 window,
-                exit_on_close_request,
+                options,
             } => {
                 let window = window_manager.insert(
                     id,
                     Arc::new(window),
                     &application,
                     &mut compositor,
-                    exit_on_close_request,
-                    resize_border,
+                    options,
                 );
 
-                let logical_size = window.state.logical_size();
+                if options.visible && options.wait_for_fonts {
+                    window.state.wait_for_fonts(WAIT_FOR_FONTS_TIMEOUT);
+                }
+
+                let logical_size = window.state.content_bounds().size();
 
                 let _ = user_interfaces.insert(
                     id,
@@ -487,6 +571,11 @@ async fn run_instance<A, E, C>(
                 );
                 let _ = ui_caches.insert(id, user_interface::Cache::default());
 
+                runtime.track(
+                    subscription::<A, E, C>(&application, &window_manager)
+                        .into_recipes(),
+                );
+
                 events.push((
                     Some(id),
                     core::Event::Window(
@@ -497,14 +586,37 @@ async fn run_instance<A, E, C>(
                         },
                     ),
                 ));
+
+                let _ = window_manager.reveal_windows();
             }
             Event::EventLoopAwakened(event) => {
+                if let (
+                    Some(on_raw_event),
+                    event::Event::WindowEvent { event, .. },
+                ) = (&on_raw_event, &event)
+                {
+                    on_raw_event(event);
+                }
+
                 match event {
                     event::Event::NewEvents(
-                        event::StartCause::Init
-                        | event::StartCause::ResumeTimeReached { .. },
+                        cause @ (event::StartCause::Init
+                        | event::StartCause::ResumeTimeReached {
+                            ..
+                        }),
                     ) => {
-                        for (_id, window) in window_manager.iter_mut() {
+                        for (_id, window) in
+                            window_manager.iter_mut_by_priority()
+                        {
+                            if matches!(
+                                cause,
+                                event::StartCause::ResumeTimeReached { .. }
+                            ) {
+                                window.state.note_redraw_reason(
+                                    window::RedrawReason::Timer,
+                                );
+                            }
+
                             // TODO once widgets can request to be redrawn, we can avoid always requesting a
                             // redraw
                             window.raw.request_redraw();
@@ -531,6 +643,74 @@ async fn run_instance<A, E, C>(
                         event: event::WindowEvent::RedrawRequested,
                         ..
                     } => {
+                        if let Some((id, at)) = window_manager
+                            .get_mut_alias(id)
+                            .map(|(id, window)| {
+                                (id, window.state.deferred_frame())
+                            })
+                            .and_then(|(id, at)| {
+                                at.or_else(|| window_manager.deferred_frame(id))
+                                    .map(|at| (id, at))
+                            })
+                        {
+                            // The frame is lost if the control flow changes
+                            // before it is due, so it is requested again in
+                            // `AboutToWait`
+                            window_manager.defer_frame(id, at);
+
+                            let _ = control_sender.start_send(
+                                Control::ChangeFlow(ControlFlow::WaitUntil(at)),
+                            );
+
+                            continue;
+                        }
+
+                        if let Some(limit) = batch_messages_before_draw
+                            .filter(|_| !messages.is_empty())
+                        {
+                            let mut cached_interfaces: HashMap<
+                                window::Id,
+                                user_interface::Cache,
+                            > = ManuallyDrop::into_inner(user_interfaces)
+                                .drain()
+                                .map(|(id, ui)| (id, ui.into_cache()))
+                                .collect();
+
+                            let mut batch: Vec<_> = messages
+                                .drain(..messages.len().min(limit))
+                                .collect();
+
+                            update(
+                                &mut application,
+                                &mut compositor,
+                                &mut runtime,
+                                &mut clipboard,
+                                &mut control_sender,
+                                &mut proxy,
+                                &mut debug,
+                                &mut batch,
+                                &mut window_manager,
+                                &mut cached_interfaces,
+                            );
+
+                            for (id, window) in window_manager.iter_mut() {
+                                window.state.synchronize(
+                                    &application,
+                                    id,
+                                    &window.raw,
+                                );
+                            }
+
+                            user_interfaces =
+                                ManuallyDrop::new(build_user_interfaces(
+                                    &application,
+                                    &mut debug,
+                                    &mut window_manager,
+                                    cached_interfaces,
+                                    &mut clipboard,
+                                ));
+                        }
+
                         let Some((id, window)) =
                             window_manager.get_mut_alias(id)
                         else {
@@ -542,12 +722,24 @@ async fn run_instance<A, E, C>(
                         //
                         // Then, we can use the `interface_state` here to decide if a redraw
                         // is needed right away, or simply wait until a specific time.
+                        let reason = if window.viewport_version
+                            != window.state.viewport_version()
+                        {
+                            let _ = window.state.take_redraw_reason();
+
+                            window::RedrawReason::Layout
+                        } else {
+                            window.state.take_redraw_reason()
+                        };
+
+                        debug.log_redraw(reason);
+
                         let redraw_event = core::Event::Window(
                             id,
                             window::Event::RedrawRequested(Instant::now()),
                         );
 
-                        let cursor = window.state.cursor();
+                        let cursor = window.state.content_cursor();
 
                         let ui = user_interfaces
                             .get_mut(&id)
@@ -562,19 +754,74 @@ async fn run_instance<A, E, C>(
                         );
 
                         debug.draw_started();
-                        let new_mouse_interaction = ui.draw(
+                        let new_mouse_interaction = letterbox::draw(
                             &mut window.renderer,
-                            window.state.theme(),
-                            &renderer::Style {
-                                icon_color: window.state.icon_color(),
-                                text_color: window.state.text_color(),
-                                scale_factor: window.state.scale_factor(),
+                            window.state.content_bounds(),
+                            |renderer| {
+                                ui.draw(
+                                    renderer,
+                                    window.state.theme(),
+                                    &renderer::Style {
+                                        icon_color: window.state.icon_color(),
+                                        text_color: window.state.text_color(),
+                                        scale_factor: window
+                                            .state
+                                            .scale_factor(),
+                                    },
+                                    cursor,
+                                )
                             },
-                            cursor,
                         );
+
+                        if let (Some(drag_resize), Some(color)) = (
+                            window.drag_resize.as_ref(),
+                            window.state.resize_border_highlight(),
+                        ) {
+                            drag_resize.draw_highlight(
+                                &mut window.renderer,
+                                window.state.logical_size(),
+                                window.state.scale_factor(),
+                                color,
+                            );
+                        }
+
+                        if window.state.resize_grip_visible()
+                            && drag_resize::is_resize_grip_available(
+                                &window.raw,
+                                window.state.resize_directions(),
+                            )
+                        {
+                            drag_resize::draw_resize_grip(
+                                &mut window.renderer,
+                                window.state.logical_size(),
+                                core::Color {
+                                    a: 0.5,
+                                    ..window.state.text_color()
+                                },
+                            );
+                        }
+
+                        if let Some(color) = window.state.dim_overlay() {
+                            dim_overlay::draw(
+                                &mut window.renderer,
+                                window.state.logical_size(),
+                                color,
+                            );
+                        }
+
+                        if window.state.is_dim_overlay_fading() {
+                            window.raw.request_redraw();
+                        }
                         debug.draw_finished();
 
-                        if new_mouse_interaction != window.mouse_interaction {
+                        let new_mouse_interaction = window
+                            .state
+                            .cursor_override()
+                            .unwrap_or(new_mouse_interaction);
+
+                        if new_mouse_interaction != window.mouse_interaction
+                            && !window.state.has_custom_cursor()
+                        {
                             window.raw.set_cursor_icon(
                                 conversion::mouse_interaction(
                                     new_mouse_interaction,
@@ -584,17 +831,55 @@ async fn run_instance<A, E, C>(
                             window.mouse_interaction = new_mouse_interaction;
                         }
 
+                        #[cfg(feature = "debug")]
+                        runtime.broadcast(
+                            core::Event::Window(
+                                id,
+                                window::Event::RedrawReason(reason),
+                            ),
+                            core::event::Status::Ignored,
+                        );
+
                         runtime.broadcast(
                             redraw_event.clone(),
                             core::event::Status::Ignored,
                         );
 
+                        if window.state.take_frame_callback_request() {
+                            runtime.broadcast(
+                                core::Event::Window(
+                                    id,
+                                    window::Event::FrameCallback(
+                                        Instant::now(),
+                                    ),
+                                ),
+                                core::event::Status::Ignored,
+                            );
+                        }
+
+                        if let Some(palette) = window
+                            .state
+                            .take_palette_change()
+                            .filter(|palette| Some(*palette) != last_palette)
+                        {
+                            last_palette = Some(palette);
+
+                            runtime.broadcast(
+                                core::Event::ThemePaletteChanged(palette),
+                                core::event::Status::Ignored,
+                            );
+                        }
+
                         let _ = control_sender.start_send(Control::ChangeFlow(
                             match ui_state {
                                 user_interface::State::Updated {
                                     redraw_request: Some(redraw_request),
                                 } => match redraw_request {
                                     window::RedrawRequest::NextFrame => {
+                                        window.state.note_redraw_reason(
+                                            window::RedrawReason::Timer,
+                                        );
+
                                         window.raw.request_redraw();
 
                                         ControlFlow::Wait
@@ -617,7 +902,8 @@ async fn run_instance<A, E, C>(
                         if window.viewport_version
                             != window.state.viewport_version()
                         {
-                            let logical_size = window.state.logical_size();
+                            let logical_size =
+                                window.state.content_bounds().size();
 
                             debug.layout_started();
                             let ui = user_interfaces
@@ -631,24 +917,41 @@ async fn run_instance<A, E, C>(
                             debug.layout_finished();
 
                             debug.draw_started();
-                            let new_mouse_interaction = user_interfaces
+                            let ui = user_interfaces
                                 .get_mut(&id)
-                                .expect("Get user interface")
-                                .draw(
-                                    &mut window.renderer,
-                                    window.state.theme(),
-                                    &renderer::Style {
-                                        icon_color: window.state.icon_color(),
-                                        text_color: window.state.text_color(),
-                                        scale_factor: window
-                                            .state
-                                            .scale_factor(),
-                                    },
-                                    window.state.cursor(),
-                                );
+                                .expect("Get user interface");
+
+                            let new_mouse_interaction = letterbox::draw(
+                                &mut window.renderer,
+                                window.state.content_bounds(),
+                                |renderer| {
+                                    ui.draw(
+                                        renderer,
+                                        window.state.theme(),
+                                        &renderer::Style {
+                                            icon_color: window
+                                                .state
+                                                .icon_color(),
+                                            text_color: window
+                                                .state
+                                                .text_color(),
+                                            scale_factor: window
+                                                .state
+                                                .scale_factor(),
+                                        },
+                                        window.state.content_cursor(),
+                                    )
+                                },
+                            );
                             debug.draw_finished();
 
+                            let new_mouse_interaction = window
+                                .state
+                                .cursor_override()
+                                .unwrap_or(new_mouse_interaction);
+
                             if new_mouse_interaction != window.mouse_interaction
+                                && !window.state.has_custom_cursor()
                             {
                                 window.raw.set_cursor_icon(
                                     conversion::mouse_interaction(
@@ -660,34 +963,96 @@ async fn run_instance<A, E, C>(
                                     new_mouse_interaction;
                             }
 
+                            if window.auto_reconfigure_surface {
+                                compositor.configure_surface(
+                                    &mut window.surface,
+                                    physical_size.width,
+                                    physical_size.height,
+                                );
+
+                                window.surface_size = physical_size;
+                            }
+
+                            window.viewport_version =
+                                window.state.viewport_version();
+                        }
+
+                        if window.state.take_surface_reconfigure_request() {
                             compositor.configure_surface(
                                 &mut window.surface,
                                 physical_size.width,
                                 physical_size.height,
                             );
 
-                            window.viewport_version =
-                                window.state.viewport_version();
+                            window.surface_size = physical_size;
                         }
 
+                        // A surface that has not been reconfigured yet is
+                        // presented with its previous size.
+                        let stale_viewport;
+                        let viewport = if window.surface_size == physical_size {
+                            window.state.viewport()
+                        } else {
+                            stale_viewport = Viewport::with_physical_size(
+                                window.surface_size,
+                                window.state.scale_factor(),
+                            );
+
+                            &stale_viewport
+                        };
+
                         debug.render_started();
+                        let present_started = Instant::now();
+
                         match compositor.present(
                             &mut window.renderer,
                             &mut window.surface,
-                            window.state.viewport(),
+                            viewport,
                             window.state.background_color(),
                             &debug.overlay(),
                         ) {
                             Ok(()) => {
                                 debug.render_finished();
+                                window.state.frame_presented();
+                                window
+                                    .frame_presented(present_started.elapsed());
 
                                 // TODO: Handle animations!
                                 // Maybe we can use `ControlFlow::WaitUntil` for this.
                             }
                             Err(error) => match error {
-                                // This is an unrecoverable error.
                                 compositor::SurfaceError::OutOfMemory => {
-                                    panic!("{:?}", error);
+                                    // This is an unrecoverable error, unless
+                                    // a software fallback is available.
+                                    if !compositor.fall_back(window.raw.clone())
+                                    {
+                                        panic!("{:?}", error);
+                                    }
+
+                                    debug.render_finished();
+
+                                    // The user interfaces and their caches
+                                    // are kept untouched, only the renderers
+                                    // and surfaces of the previous backend
+                                    // are discarded.
+                                    for (_id, window) in
+                                        window_manager.iter_mut()
+                                    {
+                                        let physical_size =
+                                            window.state.physical_size();
+
+                                        window.renderer =
+                                            compositor.create_renderer();
+                                        window.surface = compositor
+                                            .create_surface(
+                                                window.raw.clone(),
+                                                physical_size.width,
+                                                physical_size.height,
+                                            );
+                                        window.surface_size = physical_size;
+
+                                        window.raw.request_redraw();
+                                    }
                                 }
                                 _ => {
                                     debug.render_finished();
@@ -717,11 +1082,70 @@ async fn run_instance<A, E, C>(
                             continue;
                         };
 
-                        // Initiates a drag resize window state when found.
-                        if let Some(func) =
-                            window.drag_resize_window_func.as_mut()
+                        #[cfg(target_os = "windows")]
+                        if window.state.live_resize()
+                            && matches!(
+                                window_event,
+                                winit::event::WindowEvent::Resized(_)
+                            )
+                        {
+                            emulate_about_to_wait = true;
+                        }
+
+                        if window.state.resize_grip_visible()
+                            && drag_resize::update_resize_grip(
+                                &window.raw,
+                                &window_event,
+                                window.state.cursor(),
+                                window.state.logical_size(),
+                                window.state.scale_factor(),
+                                window.state.resize_directions(),
+                            )
                         {
-                            if func(&window.raw, &window_event) {
+                            continue;
+                        }
+
+                        // Initiates a drag resize window state when found.
+                        if let Some(drag_resize) = window.drag_resize.as_mut() {
+                            drag_resize.set_border_size(
+                                window.state.theme_resize_border().map(
+                                    |border| {
+                                        f64::from(border)
+                                            * window.state.scale_factor()
+                                    },
+                                ),
+                            );
+                            drag_resize.set_hit_thickness(
+                                window.state.resize_hit_thickness().map(
+                                    |thickness| {
+                                        f64::from(thickness)
+                                            * window.state.scale_factor()
+                                    },
+                                ),
+                            );
+                            drag_resize.set_corner_size(
+                                window.state.resize_corner_size().map(|size| {
+                                    f64::from(size)
+                                        * window.state.scale_factor()
+                                }),
+                            );
+                            drag_resize.set_directions(
+                                window.state.resize_directions(),
+                            );
+
+                            let hovered_direction =
+                                drag_resize.hovered_direction();
+                            let captured =
+                                drag_resize.update(&window.raw, &window_event);
+
+                            if window.state.resize_border_highlight().is_some()
+                                && drag_resize.hovered_direction()
+                                    != hovered_direction
+                            {
+                                window.raw.request_redraw();
+                            }
+
+                            if captured {
                                 continue;
                             }
                         }
@@ -743,6 +1167,14 @@ async fn run_instance<A, E, C>(
                                     Vec::new(),
                                 );
                             }
+                            runtime.track(
+                                subscription::<A, E, C>(
+                                    &application,
+                                    &window_manager,
+                                )
+                                .into_recipes(),
+                            );
+
                             events.push((
                                 None,
                                 core::Event::Window(id, window::Event::Closed),
@@ -752,32 +1184,176 @@ async fn run_instance<A, E, C>(
                                 break 'main;
                             }
                         } else {
+                            let old_scale_factor = window.state.scale_factor();
+
                             window.state.update(
                                 &window.raw,
                                 &window_event,
                                 &mut debug,
                             );
 
-                            if let Some(event) = conversion::window_event(
-                                id,
+                            if matches!(
                                 window_event,
-                                window.state.scale_factor(),
-                                window.state.modifiers(),
-                            ) {
-                                events.push((Some(id), event));
+                                winit::event::WindowEvent::ScaleFactorChanged { .. }
+                            ) && window.state.scale_factor()
+                                != old_scale_factor
+                            {
+                                events.push((
+                                    Some(id),
+                                    core::Event::Window(
+                                        id,
+                                        window::Event::ScaleFactorChanged {
+                                            old: old_scale_factor,
+                                            new: window.state.scale_factor(),
+                                        },
+                                    ),
+                                ));
                             }
-                        }
-                    }
-                    event::Event::AboutToWait => {
-                        if events.is_empty() && messages.is_empty() {
-                            continue;
-                        }
 
-                        debug.event_processing_started();
-                        let mut uis_stale = false;
+                            if !window.auto_reconfigure_surface
+                                && matches!(
+                                    window_event,
+                                    winit::event::WindowEvent::Resized(_)
+                                        | winit::event::WindowEvent::ScaleFactorChanged { .. }
+                                )
+                            {
+                                let physical_size =
+                                    window.state.physical_size();
 
-                        for (id, window) in window_manager.iter_mut() {
-                            let mut window_events = vec![];
+                                events.push((
+                                    Some(id),
+                                    core::Event::Window(
+                                        id,
+                                        window::Event::SurfaceNeedsReconfigure {
+                                            width: physical_size.width,
+                                            height: physical_size.height,
+                                        },
+                                    ),
+                                ));
+                            }
+
+                            if let Some(event) =
+                                window.state.touchpad_event(&window_event)
+                            {
+                                events.push((
+                                    Some(id),
+                                    core::Event::Touchpad(event),
+                                ));
+                            }
+
+                            let is_user_interaction =
+                                conversion::is_user_interaction(&window_event);
+
+                            if let Some(event) = conversion::window_event(
+                                id,
+                                window_event,
+                                window.state.scale_factor(),
+                                window.state.modifiers(),
+                            ) {
+                                // Only keep the latest position of a window
+                                // being moved
+                                if is_moved(&event) {
+                                    events.retain(|(window, event)| {
+                                        *window != Some(id) || !is_moved(event)
+                                    });
+                                }
+
+                                events.push((
+                                    Some(id),
+                                    window.state.content_event(event),
+                                ));
+                            }
+
+                            if is_user_interaction {
+                                messages.extend(
+                                    window_manager.drain_interaction_waiters(),
+                                );
+                            }
+                        }
+                    }
+                    event::Event::DeviceEvent { event, .. } => {
+                        // Device events are not tied to any window, and raw
+                        // mouse motion is only useful while the cursor is
+                        // grabbed
+                        if let Some(event) = conversion::device_event(&event) {
+                            for (id, window) in window_manager.iter_mut() {
+                                if window.state.cursor_grab()
+                                    != window::CursorGrabMode::None
+                                {
+                                    events.push((Some(id), event.clone()));
+                                }
+                            }
+                        }
+                    }
+                    event::Event::AboutToWait => {
+                        // Wait until the earliest deadline with a single
+                        // change of the control flow
+                        if let Some(deadline) = [
+                            window_manager.reveal_windows(),
+                            window_manager.revert_input_passthrough(),
+                            window_manager.redraw_deferred(),
+                        ]
+                        .into_iter()
+                        .flatten()
+                        .min()
+                        {
+                            let _ =
+                                control_sender.start_send(Control::ChangeFlow(
+                                    ControlFlow::WaitUntil(deadline),
+                                ));
+                        }
+
+                        if events.is_empty() && messages.is_empty() {
+                            for (_, window) in window_manager.iter_mut() {
+                                window.state.apply_title(&window.raw);
+                            }
+
+                            continue;
+                        }
+
+                        debug.event_processing_started();
+
+                        // Lay out the interfaces once for every font loaded
+                        // this frame
+                        let mut uis_stale = events.iter().any(|(_, event)| {
+                            matches!(event, core::Event::FontLoaded(_))
+                        });
+
+                        let dnd_ended =
+                            events.iter().any(|(_, event)| ends_dnd(event));
+
+                        // Raw mouse motion does not change the interfaces by
+                        // itself
+                        let is_raw_motion_only = events
+                            .iter()
+                            .all(|(_, event)| is_raw_motion(event));
+                        let mut redraw_requested = false;
+
+                        if let Some(capture) = window_manager.input_capture() {
+                            for (window_id, event) in &mut events {
+                                let Some(source) = *window_id else {
+                                    continue;
+                                };
+
+                                if source != capture
+                                    && matches!(
+                                        event,
+                                        core::Event::Keyboard(_)
+                                            | core::Event::Mouse(_)
+                                    )
+                                {
+                                    *event = window_manager.relocate_event(
+                                        event.clone(),
+                                        source,
+                                        capture,
+                                    );
+                                    *window_id = Some(capture);
+                                }
+                            }
+                        }
+
+                        for (id, window) in window_manager.iter_mut() {
+                            let mut window_events = vec![];
 
                             events.retain(|(window_id, event)| {
                                 if *window_id == Some(id) || window_id.is_none()
@@ -793,18 +1369,34 @@ async fn run_instance<A, E, C>(
                                 continue;
                             }
 
+                            if coalesce_mouse_motion
+                                && !window.state.precise_mouse_motion()
+                            {
+                                coalesce_cursor_moves(&mut window_events);
+                            }
+
                             let (ui_state, statuses) = user_interfaces
                                 .get_mut(&id)
                                 .expect("Get user interface")
                                 .update(
                                     &window_events,
-                                    window.state.cursor(),
+                                    window.state.content_cursor(),
                                     &mut window.renderer,
                                     &mut clipboard,
                                     &mut messages,
                                 );
 
-                            window.raw.request_redraw();
+                            if !is_raw_motion_only
+                                || matches!(
+                                    ui_state,
+                                    user_interface::State::Updated {
+                                        redraw_request: Some(_)
+                                    }
+                                )
+                            {
+                                window.raw.request_redraw();
+                                redraw_requested = true;
+                            }
 
                             if !uis_stale {
                                 uis_stale = matches!(
@@ -817,12 +1409,33 @@ async fn run_instance<A, E, C>(
                                 .into_iter()
                                 .zip(statuses.into_iter())
                             {
+                                window.state.update_title_bar(
+                                    &window.raw,
+                                    &event,
+                                    status,
+                                );
+
                                 runtime.broadcast(event, status);
                             }
                         }
 
                         debug.event_processing_finished();
 
+                        // Discard the in-process payload of a DnD operation
+                        // once it ends, in case it was dropped outside of the
+                        // application
+                        if dnd_ended {
+                            clipboard.set_dnd_payload(None);
+                        }
+
+                        if is_raw_motion_only
+                            && !redraw_requested
+                            && messages.is_empty()
+                            && !uis_stale
+                        {
+                            continue;
+                        }
+
                         // TODO mw application update returns which window IDs to update
                         if !messages.is_empty() || uis_stale {
                             let mut cached_interfaces: HashMap<
@@ -856,6 +1469,19 @@ async fn run_instance<A, E, C>(
                                     &window.raw,
                                 );
 
+                                if !window.keep_cache_when_hidden
+                                    && window.raw.is_visible() == Some(false)
+                                {
+                                    let _ = cached_interfaces.insert(
+                                        id,
+                                        user_interface::Cache::default(),
+                                    );
+                                }
+
+                                window.state.note_redraw_reason(
+                                    window::RedrawReason::Message,
+                                );
+
                                 // TODO once widgets can request to be redrawn, we can avoid always requesting a
                                 // redraw
                                 window.raw.request_redraw();
@@ -874,18 +1500,24 @@ async fn run_instance<A, E, C>(
 
                         debug.draw_started();
 
-                        for (id, window) in window_manager.iter_mut() {
+                        for (id, window) in
+                            window_manager.iter_mut_by_priority()
+                        {
                             // TODO: Avoid redrawing all the time by forcing widgets to
                             //  request redraws on state changes
                             //
                             // Then, we can use the `interface_state` here to decide if a redraw
                             // is needed right away, or simply wait until a specific time.
+                            window.state.note_redraw_reason(
+                                window::RedrawReason::Input,
+                            );
+
                             let redraw_event = core::Event::Window(
                                 id,
                                 window::Event::RedrawRequested(Instant::now()),
                             );
 
-                            let cursor = window.state.cursor();
+                            let cursor = window.state.content_cursor();
 
                             let ui = user_interfaces
                                 .get_mut(&id)
@@ -902,19 +1534,32 @@ async fn run_instance<A, E, C>(
                             let new_mouse_interaction = {
                                 let state = &window.state;
 
-                                ui.draw(
+                                letterbox::draw(
                                     &mut window.renderer,
-                                    state.theme(),
-                                    &renderer::Style {
-                                        icon_color: state.icon_color(),
-                                        text_color: state.text_color(),
-                                        scale_factor: state.scale_factor(),
+                                    state.content_bounds(),
+                                    |renderer| {
+                                        ui.draw(
+                                            renderer,
+                                            state.theme(),
+                                            &renderer::Style {
+                                                icon_color: state.icon_color(),
+                                                text_color: state.text_color(),
+                                                scale_factor: state
+                                                    .scale_factor(),
+                                            },
+                                            cursor,
+                                        )
                                     },
-                                    cursor,
                                 )
                             };
 
+                            let new_mouse_interaction = window
+                                .state
+                                .cursor_override()
+                                .unwrap_or(new_mouse_interaction);
+
                             if new_mouse_interaction != window.mouse_interaction
+                                && !window.state.has_custom_cursor()
                             {
                                 window.raw.set_cursor_icon(
                                     conversion::mouse_interaction(
@@ -930,6 +1575,17 @@ async fn run_instance<A, E, C>(
                             // redraw
                             window.raw.request_redraw();
 
+                            #[cfg(feature = "debug")]
+                            runtime.broadcast(
+                                core::Event::Window(
+                                    id,
+                                    window::Event::RedrawReason(
+                                        window.state.redraw_reason(),
+                                    ),
+                                ),
+                                core::event::Status::Ignored,
+                            );
+
                             runtime.broadcast(
                                 redraw_event.clone(),
                                 core::event::Status::Ignored,
@@ -953,6 +1609,12 @@ async fn run_instance<A, E, C>(
                         }
 
                         debug.draw_finished();
+
+                        // Only set the latest title of this event loop
+                        // iteration
+                        for (_, window) in window_manager.iter_mut() {
+                            window.state.apply_title(&window.raw);
+                        }
                     }
                     event::Event::PlatformSpecific(
                         event::PlatformSpecific::MacOS(
@@ -1075,8 +1737,8 @@ async fn run_instance<A, E, C>(
                                         )
                                         .ok()
                                     })
-                                    .map(|e| {
-                                        let mut renderer =
+                                    .and_then(|e| {
+                                        let renderer =
                                             compositor.create_renderer();
 
                                         let e = Arc::into_inner(*e).unwrap();
@@ -1112,51 +1774,37 @@ async fn run_instance<A, E, C>(
                                             Length::Shrink,
                                             size.size(),
                                         );
-                                        let mut surface = compositor
-                                            .create_surface(
-                                                window.raw.clone(),
-                                                size.width.ceil() as u32,
-                                                size.height.ceil() as u32,
-                                            );
-                                        let viewport =
-                                            Viewport::with_logical_size(
+                                        let screenshot =
+                                            render_element_to_image(
+                                                &mut compositor,
+                                                e,
                                                 size,
                                                 state.viewport().scale_factor(),
-                                            );
-                                        let mut ui = UserInterface::build(
-                                            e,
-                                            size,
-                                            user_interface::Cache::default(),
-                                            &mut renderer,
-                                        );
-                                        _ = ui.draw(
-                                            &mut renderer,
-                                            state.theme(),
-                                            &renderer::Style {
-                                                icon_color: state.icon_color(),
-                                                text_color: state.text_color(),
-                                                scale_factor: state
-                                                    .scale_factor(),
-                                            },
-                                            Default::default(),
-                                        );
-                                        let mut bytes = compositor.screenshot(
-                                            &mut renderer,
-                                            &mut surface,
-                                            &viewport,
-                                            core::Color::TRANSPARENT,
-                                            &debug.overlay(),
-                                        );
+                                                state.theme(),
+                                                &renderer::Style {
+                                                    icon_color: state
+                                                        .icon_color(),
+                                                    text_color: state
+                                                        .text_color(),
+                                                    scale_factor: state
+                                                        .scale_factor(),
+                                                },
+                                            )?;
+                                        let mut bytes =
+                                            Arc::try_unwrap(screenshot.bytes)
+                                                .unwrap_or_else(|bytes| {
+                                                    bytes.to_vec()
+                                                });
                                         for pix in bytes.chunks_exact_mut(4) {
                                             // rgba -> argb little endian
                                             pix.swap(0, 2);
                                         }
-                                        Icon::Buffer {
+                                        Some(Icon::Buffer {
                                             data: Arc::new(bytes),
-                                            width: viewport.physical_width(),
-                                            height: viewport.physical_height(),
+                                            width: screenshot.size.width,
+                                            height: screenshot.size.height,
                                             transparent: true,
-                                        }
+                                        })
                                     });
 
                                 clipboard.start_dnd_winit(
@@ -1206,19 +1854,70 @@ async fn run_instance<A, E, C>(
                                     cur_dnd_surface = window_id;
                                     events.push((
                                         cur_dnd_surface,
-                                        core::Event::Dnd(e),
+                                        window_manager.content_event(
+                                            cur_dnd_surface,
+                                            core::Event::Dnd(e),
+                                        ),
                                     ));
                                 }
                                 dnd::DndEvent::Offer(..) => {
                                     events.push((
                                         cur_dnd_surface,
-                                        core::Event::Dnd(e),
+                                        window_manager.content_event(
+                                            cur_dnd_surface,
+                                            core::Event::Dnd(e),
+                                        ),
                                     ));
                                 }
                                 dnd::DndEvent::Source(_) => {
                                     events.push((None, core::Event::Dnd(e)))
                                 }
                             },
+                            UserEventWrapper::Window(id, event) => {
+                                events.push((
+                                    Some(id),
+                                    core::Event::Window(id, event),
+                                ));
+                            }
+                            UserEventWrapper::GlobalShortcut(id) => {
+                                events.push((
+                                    None,
+                                    core::Event::GlobalShortcut(id),
+                                ));
+                            }
+                            UserEventWrapper::InjectedEvent(id, event) => {
+                                if let Some(window) = window_manager.get_mut(id)
+                                {
+                                    window.state.update_injected(&event);
+                                }
+
+                                events.push((Some(id), event));
+                            }
+                            UserEventWrapper::FontLoaded(font, bytes) => {
+                                use crate::core::text::Renderer;
+
+                                for (_, window) in window_manager.iter_mut() {
+                                    window.renderer.load_font(bytes.clone());
+                                }
+
+                                window_manager.font_load_finished();
+
+                                let _ = window_manager.reveal_windows();
+
+                                events.push((
+                                    None,
+                                    core::Event::FontLoaded(font),
+                                ));
+                            }
+                            UserEventWrapper::ClipboardExpired(
+                                target,
+                                contents,
+                            ) => {
+                                let _ = target.clear_if_unchanged(
+                                    &mut clipboard,
+                                    &contents,
+                                );
+                            }
                         };
                     }
                     event::Event::WindowEvent {
@@ -1247,6 +1946,14 @@ async fn run_instance<A, E, C>(
                                 );
                             }
 
+                            runtime.track(
+                                subscription::<A, E, C>(
+                                    &application,
+                                    &window_manager,
+                                )
+                                .into_recipes(),
+                            );
+
                             events.push((
                                 None,
                                 core::Event::Window(id, window::Event::Closed),
@@ -1258,19 +1965,88 @@ async fn run_instance<A, E, C>(
                                 break 'main;
                             }
                         } else {
+                            let old_scale_factor = window.state.scale_factor();
+
                             window.state.update(
                                 &window.raw,
                                 &window_event,
                                 &mut debug,
                             );
 
+                            if matches!(
+                                window_event,
+                                winit::event::WindowEvent::ScaleFactorChanged { .. }
+                            ) && window.state.scale_factor()
+                                != old_scale_factor
+                            {
+                                events.push((
+                                    Some(id),
+                                    core::Event::Window(
+                                        id,
+                                        window::Event::ScaleFactorChanged {
+                                            old: old_scale_factor,
+                                            new: window.state.scale_factor(),
+                                        },
+                                    ),
+                                ));
+                            }
+
+                            if !window.auto_reconfigure_surface
+                                && matches!(
+                                    window_event,
+                                    winit::event::WindowEvent::Resized(_)
+                                        | winit::event::WindowEvent::ScaleFactorChanged { .. }
+                                )
+                            {
+                                let physical_size =
+                                    window.state.physical_size();
+
+                                events.push((
+                                    Some(id),
+                                    core::Event::Window(
+                                        id,
+                                        window::Event::SurfaceNeedsReconfigure {
+                                            width: physical_size.width,
+                                            height: physical_size.height,
+                                        },
+                                    ),
+                                ));
+                            }
+
+                            if let Some(event) =
+                                window.state.touchpad_event(&window_event)
+                            {
+                                events.push((
+                                    Some(id),
+                                    core::Event::Touchpad(event),
+                                ));
+                            }
+
+                            let is_user_interaction =
+                                conversion::is_user_interaction(&window_event);
+
                             if let Some(event) = conversion::window_event(
                                 id,
                                 window_event,
                                 window.state.scale_factor(),
                                 window.state.modifiers(),
                             ) {
-                                events.push((Some(id), event));
+                                if is_moved(&event) {
+                                    events.retain(|(window, event)| {
+                                        *window != Some(id) || !is_moved(event)
+                                    });
+                                }
+
+                                events.push((
+                                    Some(id),
+                                    window.state.content_event(event),
+                                ));
+                            }
+
+                            if is_user_interaction {
+                                messages.extend(
+                                    window_manager.drain_interaction_waiters(),
+                                );
                             }
                         }
                     }
@@ -1280,6 +2056,8 @@ async fn run_instance<A, E, C>(
         }
     }
 
+    crate::global_shortcut::unregister_all();
+
     let _ = ManuallyDrop::into_inner(user_interfaces);
 }
 
@@ -1349,11 +2127,37 @@ fn update<A: Application + 'static, C, E: Executor + 'static>(
         );
     }
 
-    let subscription = application
-        .subscription()
-        .map(subscription_map::<A, E>)
-        .into_recipes();
-    runtime.track(subscription);
+    runtime.track(
+        subscription::<A, E, C>(application, window_manager).into_recipes(),
+    );
+}
+
+/// Returns the global [`Subscription`] of the [`Application`], merged with the
+/// subscriptions of all of its open windows.
+fn subscription<A, E, C>(
+    application: &A,
+    window_manager: &WindowManager<A, C>,
+) -> Subscription<UserEventWrapper<A::Message>>
+where
+    A: Application,
+    E: Executor,
+    C: Compositor<Renderer = A::Renderer>,
+    A::Message: Send + 'static,
+    A::Theme: StyleSheet,
+{
+    // The window id is part of the identity of the subscriptions of each
+    // window, so that closing a window only stops its own subscriptions
+    let windows = window_manager.ids().map(|id| {
+        application
+            .window_subscription(id)
+            .with(id)
+            .map(|(_id, message)| message)
+    });
+
+    Subscription::batch(
+        std::iter::once(application.subscription()).chain(windows),
+    )
+    .map(subscription_map::<A, E>)
 }
 
 /// Runs the actions of a [`Command`].
@@ -1384,7 +2188,11 @@ fn run_command<A, C, E>(
     use crate::runtime::system;
     use crate::runtime::window;
 
-    for action in command.actions() {
+    for action in command
+        .actions()
+        .into_iter()
+        .flat_map(command::Action::flatten)
+    {
         match action {
             command::Action::Future(future) => {
                 runtime.spawn(Box::pin(future.map(UserEventWrapper::Message)));
@@ -1411,6 +2219,23 @@ fn run_command<A, C, E>(
                     let message = to_msg(contents);
                     _ = proxy.send_event(UserEventWrapper::Message(message));
                 }
+                clipboard::Action::ReadUris(to_msg) => {
+                    let paths = clipboard
+                        .read_data(vec![clipboard::URI_LIST_MIME_TYPE.into()])
+                        .map(|(bytes, _)| clipboard::file_paths(&bytes))
+                        .unwrap_or_default();
+                    let message = to_msg(paths);
+                    _ = proxy.send_event(UserEventWrapper::Message(message));
+                }
+                clipboard::Action::WriteHtml { html, plain } => clipboard
+                    .write_data(ClipboardStoreData(Box::new(
+                        clipboard::Html { html, plain },
+                    ))),
+                clipboard::Action::ReadAvailableTypes(target, to_msg) => {
+                    let types = target.available_types(clipboard);
+                    let message = to_msg(types);
+                    _ = proxy.send_event(UserEventWrapper::Message(message));
+                }
                 clipboard::Action::ReadPrimary(s_to_msg) => {
                     let contents = clipboard.read_primary();
                     let message = s_to_msg(contents);
@@ -1427,6 +2252,15 @@ fn run_command<A, C, E>(
                     let message = to_msg(contents);
                     _ = proxy.send_event(UserEventWrapper::Message(message));
                 }
+                clipboard::Action::WriteWithExpiry {
+                    target,
+                    contents,
+                    ttl,
+                } => {
+                    target.write(clipboard, contents.clone());
+
+                    schedule_clipboard_clear(proxy, target, contents, ttl);
+                }
             },
             command::Action::Window(action) => match action {
                 window::Action::Spawn(id, settings) => {
@@ -1488,6 +2322,15 @@ fn run_command<A, C, E>(
                             .expect("Send message to event loop");
                     }
                 }
+                window::Action::FetchFrameInsets(id, callback) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        proxy
+                            .send_event(UserEventWrapper::Message(callback(
+                                conversion::frame_insets(&window.raw),
+                            )))
+                            .expect("Send message to event loop");
+                    }
+                }
                 window::Action::FetchMaximized(id, callback) => {
                     if let Some(window) = window_manager.get_mut(id) {
                         proxy
@@ -1516,6 +2359,12 @@ fn run_command<A, C, E>(
                         window.raw.set_minimized(minimized);
                     }
                 }
+                window::Action::MinimizeAll => {
+                    window_manager.minimize_all();
+                }
+                window::Action::RestoreAll => {
+                    window_manager.restore_all();
+                }
                 window::Action::Move(id, position) => {
                     if let Some(window) = window_manager.get_mut(id) {
                         window.raw.set_outer_position(
@@ -1558,6 +2407,33 @@ fn run_command<A, C, E>(
                         window.raw.set_maximized(!window.raw.is_maximized());
                     }
                 }
+                window::Action::GetDecorationMode(_id, tag) => {
+                    // `winit` does not expose the decorations negotiated with
+                    // the Wayland compositor
+                    proxy
+                        .send_event(UserEventWrapper::Message(tag(None)))
+                        .expect("Event loop doesn't exist.");
+                }
+                window::Action::GetCurrentOutputName(id, tag) => {
+                    let name = window_manager
+                        .get_mut(id)
+                        .and_then(|window| window.raw.current_monitor())
+                        .and_then(|monitor| monitor.name());
+
+                    proxy
+                        .send_event(UserEventWrapper::Message(tag(name)))
+                        .expect("Event loop doesn't exist.");
+                }
+                window::Action::GetSurfaceFormat(id, tag) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        let format =
+                            compositor.fetch_surface_format(&window.surface);
+
+                        proxy
+                            .send_event(UserEventWrapper::Message(tag(format)))
+                            .expect("Event loop doesn't exist.");
+                    }
+                }
                 window::Action::ToggleDecorations(id) => {
                     if let Some(window) = window_manager.get_mut(id) {
                         window.raw.set_decorations(!window.raw.is_decorated());
@@ -1570,6 +2446,15 @@ fn run_command<A, C, E>(
                         );
                     }
                 }
+                window::Action::SetUrgent(id, urgent) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        window.raw.request_user_attention(urgent.then_some(
+                            winit::window::UserAttentionType::Informational,
+                        ));
+
+                        window.state.set_urgent(urgent);
+                    }
+                }
                 window::Action::GainFocus(id) => {
                     if let Some(window) = window_manager.get_mut(id) {
                         window.raw.focus_window();
@@ -1577,22 +2462,386 @@ fn run_command<A, C, E>(
                 }
                 window::Action::ChangeLevel(id, level) => {
                     if let Some(window) = window_manager.get_mut(id) {
-                        window
-                            .raw
-                            .set_window_level(conversion::window_level(level));
+                        window.state.set_level(&window.raw, level);
                     }
                 }
-                window::Action::ShowWindowMenu(id) => {
+                window::Action::Batch(_) => {
+                    // Batches are flattened before running their actions
+                }
+                window::Action::SetKeepAbove(id, above) => {
                     if let Some(window) = window_manager.get_mut(id) {
-                        if let mouse::Cursor::Available(point) =
-                            window.state.cursor()
-                        {
-                            window.raw.show_window_menu(
-                                winit::dpi::LogicalPosition {
-                                    x: point.x,
-                                    y: point.y,
-                                },
-                            );
+                        window.state.set_keep_level(
+                            &window.raw,
+                            window::Level::AlwaysOnTop,
+                            above,
+                        );
+                    }
+                }
+                window::Action::SetKeepBelow(id, below) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        window.state.set_keep_level(
+                            &window.raw,
+                            window::Level::AlwaysOnBottom,
+                            below,
+                        );
+                    }
+                }
+                window::Action::SetPreferredTheme(id, theme) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        window.raw.set_theme(theme.map(conversion::theme_mode));
+                    }
+                }
+                window::Action::SetResizeIncrements(id, increments) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        window.raw.set_resize_increments(increments.map(
+                            |size| winit::dpi::LogicalSize {
+                                width: size.width,
+                                height: size.height,
+                            },
+                        ));
+                    }
+                }
+                window::Action::SetBaseSize(id, size) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        // `winit` can only set the base size on creation, but
+                        // window managers use the minimum size in its place
+                        window.raw.set_min_inner_size(Some(
+                            winit::dpi::LogicalSize {
+                                width: size.width,
+                                height: size.height,
+                            },
+                        ));
+                    }
+                }
+                window::Action::SetImeArea(id, area) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        let content_bounds = window.state.content_bounds();
+
+                        let (position, size) = conversion::ime_area(
+                            area + core::Vector::new(
+                                content_bounds.x,
+                                content_bounds.y,
+                            ),
+                            window.state.logical_size(),
+                            window.state.scale_factor(),
+                        );
+
+                        window.raw.set_ime_cursor_area(position, size);
+                    }
+                }
+                window::Action::SetImeAllowed(id, allowed) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        window.raw.set_ime_allowed(allowed);
+                    }
+                }
+                window::Action::SetInputCapture(id, capture) => {
+                    window_manager.set_input_capture(id, capture);
+                }
+                window::Action::SetTrafficLightOffset(_id, _offset) =>
+                {
+                    #[cfg(target_os = "macos")]
+                    if let Some(window) = window_manager.get_mut(_id) {
+                        window
+                            .state
+                            .set_traffic_light_offset(&window.raw, _offset);
+                    }
+                }
+                window::Action::SetDocumentEdited(_id, _edited) => {
+                    #[cfg(target_os = "macos")]
+                    if let Some(window) = window_manager.get_mut(_id) {
+                        use winit::platform::macos::WindowExtMacOS;
+
+                        window.raw.set_document_edited(_edited);
+                    }
+                }
+                window::Action::SetDocumentPath(_id, _path) => {
+                    #[cfg(target_os = "macos")]
+                    if let Some(window) = window_manager.get_mut(_id) {
+                        crate::macos::set_document_path(&window.raw, &_path);
+                    }
+                }
+                window::Action::SetMinimizeAnimation(_id, _enabled) =>
+                {
+                    #[cfg(any(target_os = "macos", target_os = "windows"))]
+                    if let Some(window) = window_manager.get_mut(_id) {
+                        #[cfg(target_os = "macos")]
+                        crate::macos::set_minimize_animation(
+                            &window.raw,
+                            _enabled,
+                        );
+
+                        #[cfg(target_os = "windows")]
+                        crate::windows::set_minimize_animation(
+                            &window.raw,
+                            _enabled,
+                        );
+                    }
+                }
+                window::Action::SetCursorTheme(_id, _theme, _size) => {
+                    // The cursor theme is chosen by the environment
+                }
+                window::Action::RequestFrameCallback(id) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        if window.state.request_frame_callback() {
+                            window.raw.request_redraw();
+                        }
+                    }
+                }
+                window::Action::PlayAttentionSound(id) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        if window.state.attention_sound_enabled() {
+                            crate::attention_sound::play();
+                        }
+                    }
+                }
+                window::Action::SetAttentionSoundEnabled(id, enabled) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        window.state.set_attention_sound_enabled(enabled);
+                    }
+                }
+                window::Action::SetInputPassthrough(
+                    id,
+                    passthrough,
+                    duration,
+                ) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        window.state.set_input_passthrough(
+                            &window.raw,
+                            passthrough,
+                            duration,
+                        );
+
+                        // The revert is scheduled once the event loop is about
+                        // to wait again
+                        window.raw.request_redraw();
+                    }
+                }
+                window::Action::SetCursorGrab(id, mode, callback) => {
+                    let grabbed =
+                        window_manager.get_mut(id).is_some_and(|window| {
+                            let result = window.raw.set_cursor_grab(
+                                conversion::cursor_grab_mode(mode),
+                            );
+
+                            match &result {
+                                Ok(()) => window.state.set_cursor_grab(mode),
+                                Err(error) => {
+                                    log::warn!(
+                                        "Failed to grab the cursor: {error}"
+                                    );
+                                }
+                            }
+
+                            result.is_ok()
+                        });
+
+                    if let Some(callback) = callback {
+                        proxy
+                            .send_event(UserEventWrapper::Message(callback(
+                                grabbed,
+                            )))
+                            .expect("Send message to event loop");
+                    }
+                }
+                window::Action::SetFrameInterval(id, interval) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        window.state.set_frame_interval(interval);
+                    }
+                }
+                window::Action::SetPreciseMouseMotion(id, precise) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        window.state.set_precise_mouse_motion(precise);
+                    }
+                }
+                window::Action::SetLiveResize(id, live) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        window.state.set_live_resize(live);
+                    }
+                }
+                window::Action::SetGlobalScaleFactor(scale_factor) => {
+                    if !scale_factor.is_finite() || scale_factor <= 0.0 {
+                        log::warn!(
+                            "Ignoring invalid global scale factor: \
+                            {scale_factor}"
+                        );
+                        continue;
+                    }
+
+                    window_manager.set_global_scale_factor(scale_factor);
+
+                    for (id, window) in window_manager.iter_mut() {
+                        window.state.synchronize(application, id, &window.raw);
+                        window.raw.request_redraw();
+                    }
+                }
+                window::Action::FollowGlobalScaleFactor(id, follows) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        window.state.set_follows_global_scale_factor(follows);
+                        window.state.synchronize(application, id, &window.raw);
+                        window.raw.request_redraw();
+                    }
+                }
+                window::Action::SetColorProfile(id, icc) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        compositor.set_color_profile(
+                            &mut window.renderer,
+                            conversion::color_profile(&icc),
+                        );
+                        window.raw.request_redraw();
+                    }
+                }
+                window::Action::SetWindowRole(_id, _role) => {
+                    #[cfg(all(target_os = "linux", feature = "x11"))]
+                    if let Some(window) = window_manager.get_mut(_id) {
+                        crate::x11::set_window_role(&window.raw, &_role);
+                    }
+                }
+                window::Action::SetFrameExtents(_id, _extents) => {
+                    #[cfg(all(target_os = "linux", feature = "x11"))]
+                    if let Some(window) = window_manager.get_mut(_id) {
+                        window.state.set_frame_extents(&window.raw, _extents);
+                    }
+                }
+                window::Action::SetResizeBorderHighlight(id, color) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        window.state.set_resize_border_highlight(color);
+                        window.raw.request_redraw();
+                    }
+                }
+                window::Action::SetResizeHitThickness(id, thickness) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        window.state.set_resize_hit_thickness(thickness);
+                    }
+                }
+                window::Action::SetResizeCornerSize(id, size) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        window.state.set_resize_corner_size(size);
+                    }
+                }
+                window::Action::SetResizeDirections(id, directions) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        window.state.set_resize_directions(directions);
+                    }
+                }
+                window::Action::SetCursorIcon(id, interaction) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        window.state.set_cursor_override(interaction);
+
+                        if let Some(interaction) = interaction
+                            .filter(|_| !window.state.has_custom_cursor())
+                        {
+                            window.raw.set_cursor_icon(
+                                conversion::mouse_interaction(interaction),
+                            );
+                        }
+
+                        window.raw.request_redraw();
+                    }
+                }
+                window::Action::SetCustomCursor(id, cursor) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        window.state.set_custom_cursor(&window.raw, cursor);
+                        window.raw.request_redraw();
+                    }
+                }
+                window::Action::SetAspectRatio(id, aspect_ratio, mode) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        window.state.set_aspect_ratio(
+                            &window.raw,
+                            aspect_ratio,
+                            mode,
+                        );
+                        window.raw.request_redraw();
+                    }
+                }
+                window::Action::SetDimOverlay(id, color) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        window.state.set_dim_overlay(color);
+                        window.raw.request_redraw();
+                    }
+                }
+                window::Action::SetTitle(id, title) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        window.state.set_title(title);
+                        window.state.apply_title(&window.raw);
+                    }
+                }
+                window::Action::FetchTitle(id, callback) => {
+                    let title = window_manager
+                        .get_mut(id)
+                        .map(|window| window.state.title().to_owned());
+
+                    proxy
+                        .send_event(UserEventWrapper::Message(callback(title)))
+                        .expect("Send message to event loop");
+                }
+                window::Action::FetchSurfaceScale(id, callback) => {
+                    let scale_factor = window_manager
+                        .get_mut(id)
+                        .map(|window| window.raw.scale_factor());
+
+                    proxy
+                        .send_event(UserEventWrapper::Message(callback(
+                            scale_factor,
+                        )))
+                        .expect("Send message to event loop");
+                }
+                window::Action::SetRenderPriority(id, priority) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        window.render_priority = priority;
+                        window.deferred_frames = 0;
+                    }
+                }
+                window::Action::ShowResizeGrip(id, visible) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        window.state.set_resize_grip_visible(visible);
+
+                        window.raw.request_redraw();
+                    }
+                }
+                window::Action::SyncResizeBorderWithTheme(id, synced) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        window
+                            .state
+                            .set_resize_border_synced_with_theme(synced);
+                    }
+                }
+                window::Action::SetTitleBarRegion(id, region) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        window.state.set_title_bar_region(region);
+                    }
+                }
+                window::Action::Snap(id, position) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        window.state.snap(&window.raw, position);
+                    }
+                }
+                window::Action::SetBackdrop(_id, _material) => {
+                    #[cfg(target_os = "windows")]
+                    if let Some(window) = window_manager.get_mut(_id) {
+                        if _material != window::BackdropMaterial::None
+                            && window.state.background_color().a >= 1.0
+                        {
+                            log::warn!(
+                                "The background of the window is opaque; \
+                                its backdrop material will not be visible"
+                            );
+                        }
+
+                        crate::windows::set_backdrop(&window.raw, _material);
+                    }
+                }
+                window::Action::ShowWindowMenu(id) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        if let mouse::Cursor::Available(point) =
+                            window.state.cursor()
+                        {
+                            window.raw.show_window_menu(
+                                winit::dpi::LogicalPosition {
+                                    x: point.x,
+                                    y: point.y,
+                                },
+                            );
                         }
                     }
                 }
@@ -1606,6 +2855,76 @@ fn run_command<A, C, E>(
                             .expect("Event loop doesn't exist.");
                     }
                 }
+                window::Action::FetchAvailableMonitors(id, tag) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        let monitors = window
+                            .raw
+                            .available_monitors()
+                            .map(|monitor| conversion::monitor(&monitor))
+                            .collect();
+
+                        proxy
+                            .send_event(UserEventWrapper::Message(tag(
+                                monitors,
+                            )))
+                            .expect("Event loop doesn't exist.");
+                    }
+                }
+                window::Action::FetchVideoModes(id, tag) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        let video_modes = window
+                            .raw
+                            .current_monitor()
+                            .map(|monitor| {
+                                monitor
+                                    .video_modes()
+                                    .map(|video_mode| {
+                                        conversion::video_mode(&video_mode)
+                                    })
+                                    .collect()
+                            })
+                            .unwrap_or_default();
+
+                        proxy
+                            .send_event(UserEventWrapper::Message(tag(
+                                video_modes,
+                            )))
+                            .expect("Event loop doesn't exist.");
+                    }
+                }
+                window::Action::SetExclusiveFullscreen(id, video_mode) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        let monitor = window.raw.current_monitor();
+
+                        if let Some(fullscreen) =
+                            conversion::exclusive_fullscreen(
+                                monitor.as_ref(),
+                                video_mode,
+                            )
+                        {
+                            window.raw.set_fullscreen(Some(fullscreen));
+                        }
+
+                        // Fall back to borderless if the mode was rejected
+                        if !matches!(
+                            window.raw.fullscreen(),
+                            Some(winit::window::Fullscreen::Exclusive(_))
+                        ) {
+                            window.raw.set_fullscreen(Some(
+                                winit::window::Fullscreen::Borderless(monitor),
+                            ));
+
+                            let event =
+                                core::window::Event::ExclusiveFullscreenRejected(
+                                    video_mode,
+                                );
+
+                            proxy
+                                .send_event(UserEventWrapper::Window(id, event))
+                                .expect("Event loop doesn't exist.");
+                        }
+                    }
+                }
                 window::Action::Screenshot(id, tag) => {
                     if let Some(window) = window_manager.get_mut(id) {
                         let bytes = compositor.screenshot(
@@ -1626,6 +2945,101 @@ fn run_command<A, C, E>(
                             .expect("Event loop doesn't exist.");
                     }
                 }
+                window::Action::RenderElement(id, element, size, tag) => {
+                    let Ok(element) = element.downcast::<(
+                        core::Element<
+                            'static,
+                            A::Message,
+                            A::Theme,
+                            A::Renderer,
+                        >,
+                        A::Theme,
+                    )>() else {
+                        log::warn!(
+                            "The element to render does not belong to the \
+                            application"
+                        );
+
+                        proxy
+                            .send_event(UserEventWrapper::Message(tag(None)))
+                            .expect("Event loop doesn't exist.");
+
+                        continue;
+                    };
+
+                    let screenshot =
+                        window_manager.get_mut(id).and_then(|window| {
+                            let (element, theme) = *element;
+
+                            render_element_to_image(
+                                compositor,
+                                element,
+                                size,
+                                window.state.viewport().scale_factor(),
+                                &theme,
+                                &renderer::Style {
+                                    icon_color: window.state.icon_color(),
+                                    text_color: window.state.text_color(),
+                                    scale_factor: window.state.scale_factor(),
+                                },
+                            )
+                        });
+
+                    proxy
+                        .send_event(UserEventWrapper::Message(tag(screenshot)))
+                        .expect("Event loop doesn't exist.");
+                }
+                window::Action::DumpLayout(id, tag) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        let cache = ui_caches.remove(&id).unwrap_or_default();
+
+                        let mut user_interface = build_user_interface(
+                            application,
+                            cache,
+                            &mut window.renderer,
+                            window.state.content_bounds().size(),
+                            debug,
+                            id,
+                        );
+
+                        let mut dump = operation::LayoutDump::new();
+                        user_interface.operate(&window.renderer, &mut dump);
+
+                        let _ =
+                            ui_caches.insert(id, user_interface.into_cache());
+
+                        proxy
+                            .send_event(UserEventWrapper::Message(tag(
+                                dump.to_json()
+                            )))
+                            .expect("Event loop doesn't exist.");
+                    }
+                }
+                window::Action::DropCache(id) => {
+                    if let Some(cache) = ui_caches.get_mut(&id) {
+                        *cache = user_interface::Cache::default();
+                    }
+                }
+                window::Action::FitToContent(id) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        debug.view_started();
+                        let content = application.view(id);
+                        debug.view_finished();
+
+                        crate::fit::fit_to_content(
+                            &window.raw,
+                            &content,
+                            &window.renderer,
+                            window.state.scale_factor(),
+                        );
+                    }
+                }
+                window::Action::ReconfigureSurface(id) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        window.state.request_surface_reconfigure();
+                        window.raw.request_redraw();
+                    }
+                }
             },
             command::Action::System(action) => match action {
                 system::Action::QueryInformation(_tag) => {
@@ -1646,6 +3060,325 @@ fn run_command<A, C, E>(
                         });
                     }
                 }
+                system::Action::QueryInformationCancellable(_token, _tag) => {
+                    #[cfg(feature = "system")]
+                    {
+                        let graphics_info = compositor.fetch_information();
+                        let proxy = proxy.clone();
+
+                        let _ = std::thread::spawn(move || {
+                            let information =
+                                crate::system::information(graphics_info);
+
+                            // Sending under the lock of the token cannot race
+                            // against its cancellation
+                            let _ = _token.unless_cancelled(|| {
+                                let message = _tag(information);
+
+                                proxy
+                                    .send_event(UserEventWrapper::Message(
+                                        message,
+                                    ))
+                                    .expect("Event loop doesn't exist.");
+                            });
+                        });
+                    }
+                }
+                system::Action::QueryDoubleClickInterval(_tag) => {
+                    #[cfg(feature = "system")]
+                    {
+                        let proxy = proxy.clone();
+
+                        let _ = std::thread::spawn(move || {
+                            let interval =
+                                crate::system::double_click_interval();
+
+                            let message = _tag(interval);
+
+                            proxy
+                                .send_event(UserEventWrapper::Message(message))
+                                .expect("Event loop doesn't exist.");
+                        });
+                    }
+                }
+                system::Action::QueryDoubleClickIntervalCancellable(
+                    _token,
+                    _tag,
+                ) => {
+                    #[cfg(feature = "system")]
+                    {
+                        let proxy = proxy.clone();
+
+                        let _ = std::thread::spawn(move || {
+                            let interval =
+                                crate::system::double_click_interval();
+
+                            // Sending under the lock of the token cannot race
+                            // against its cancellation
+                            let _ = _token.unless_cancelled(|| {
+                                let message = _tag(interval);
+
+                                proxy
+                                    .send_event(UserEventWrapper::Message(
+                                        message,
+                                    ))
+                                    .expect("Event loop doesn't exist.");
+                            });
+                        });
+                    }
+                }
+                system::Action::GetRenderStats(id, tag) => {
+                    let stats = window_manager.get_mut(id).and_then(|window| {
+                        compositor.fetch_render_stats(&window.renderer)
+                    });
+
+                    proxy
+                        .send_event(UserEventWrapper::Message(tag(stats)))
+                        .expect("Event loop doesn't exist.");
+                }
+                system::Action::AwaitInteraction(tag) => {
+                    window_manager.await_interaction(tag);
+                }
+                system::Action::GetActiveBackend(tag) => {
+                    let backend = compositor.backend();
+
+                    proxy
+                        .send_event(UserEventWrapper::Message(tag(backend)))
+                        .expect("Event loop doesn't exist.");
+                }
+                system::Action::IsHardwareAccelerated(tag) => {
+                    let hardware_accelerated =
+                        compositor.fetch_information().hardware_accelerated;
+
+                    proxy
+                        .send_event(UserEventWrapper::Message(tag(
+                            hardware_accelerated,
+                        )))
+                        .expect("Event loop doesn't exist.");
+                }
+                system::Action::CaptureAllWindows(tag) => {
+                    let screenshots = window_manager
+                        .iter_mut()
+                        .filter(|(_, window)| {
+                            window.raw.is_minimized() != Some(true)
+                                && window.raw.is_visible() != Some(false)
+                        })
+                        .map(|(id, window)| {
+                            let bytes = compositor.screenshot(
+                                &mut window.renderer,
+                                &mut window.surface,
+                                window.state.viewport(),
+                                window.state.background_color(),
+                                &debug.overlay(),
+                            );
+
+                            (
+                                id,
+                                window::Screenshot::new(
+                                    bytes,
+                                    window.state.physical_size(),
+                                ),
+                            )
+                        })
+                        .collect();
+
+                    proxy
+                        .send_event(UserEventWrapper::Message(tag(screenshots)))
+                        .expect("Event loop doesn't exist.");
+                }
+                system::Action::GetWindowManagerName(_token, _tag) => {
+                    #[cfg(feature = "system")]
+                    {
+                        let proxy = proxy.clone();
+
+                        let _ = std::thread::spawn(move || {
+                            let name = crate::system::window_manager_name();
+
+                            // Sending under the lock of the token cannot race
+                            // against its cancellation
+                            let _ = _token.unless_cancelled(|| {
+                                let message = _tag(name);
+
+                                proxy
+                                    .send_event(UserEventWrapper::Message(
+                                        message,
+                                    ))
+                                    .expect("Event loop doesn't exist.");
+                            });
+                        });
+                    }
+                }
+                system::Action::GetKeyRepeat(_token, _tag) => {
+                    #[cfg(feature = "system")]
+                    {
+                        let proxy = proxy.clone();
+
+                        let _ = std::thread::spawn(move || {
+                            let key_repeat = crate::system::key_repeat();
+
+                            // Sending under the lock of the token cannot race
+                            // against its cancellation
+                            let _ = _token.unless_cancelled(|| {
+                                let message = _tag(key_repeat);
+
+                                proxy
+                                    .send_event(UserEventWrapper::Message(
+                                        message,
+                                    ))
+                                    .expect("Event loop doesn't exist.");
+                            });
+                        });
+                    }
+                }
+                system::Action::RegisterGlobalShortcut(shortcut, tag) => {
+                    let trigger = proxy.clone();
+                    let reply = proxy.clone();
+
+                    crate::global_shortcut::register(
+                        &shortcut,
+                        move |id| {
+                            let _ = trigger.send_event(
+                                UserEventWrapper::GlobalShortcut(id),
+                            );
+                        },
+                        move |result| {
+                            // The event loop may be gone by now
+                            let _ = reply.send_event(
+                                UserEventWrapper::Message(tag(result)),
+                            );
+                        },
+                    );
+                }
+                system::Action::UnregisterGlobalShortcut(id) => {
+                    crate::global_shortcut::unregister(id);
+                }
+                system::Action::InjectEvent(id, event) => {
+                    proxy
+                        .send_event(UserEventWrapper::InjectedEvent(id, event))
+                        .expect("Event loop doesn't exist.");
+                }
+                system::Action::GetMonitorScaleFactor(monitor, tag) => {
+                    let scale_factor = window_manager
+                        .iter_mut()
+                        .next()
+                        .and_then(|(_, window)| {
+                            crate::monitor::scale_factor(&window.raw, &monitor)
+                        });
+
+                    proxy
+                        .send_event(UserEventWrapper::Message(tag(
+                            scale_factor,
+                        )))
+                        .expect("Event loop doesn't exist.");
+                }
+                system::Action::GetDesktopScaling(tag) => {
+                    let proxy = proxy.clone();
+
+                    // The monitors are queried by the event loop, since there
+                    // may not be any window to query them from
+                    control_sender
+                        .start_send(Control::QueryMonitors(Box::new(
+                            move |monitors| {
+                                proxy
+                                    .send_event(UserEventWrapper::Message(tag(
+                                        monitors,
+                                    )))
+                                    .expect("Event loop doesn't exist.");
+                            },
+                        )))
+                        .expect("Send control action");
+                }
+                system::Action::GetSystemFonts(tag) => {
+                    let proxy = proxy.clone();
+
+                    let _ = std::thread::spawn(move || {
+                        let families = crate::graphics::text::font_families();
+
+                        proxy
+                            .send_event(UserEventWrapper::Message(tag(
+                                families,
+                            )))
+                            .expect("Event loop doesn't exist.");
+                    });
+                }
+                system::Action::GetProcessInfo(token, tag) => {
+                    #[cfg(feature = "system")]
+                    {
+                        let proxy = proxy.clone();
+
+                        let _ = std::thread::spawn(move || {
+                            let info = crate::system::process_info();
+
+                            // Sending under the lock of the token cannot race
+                            // against its cancellation
+                            let _ = token.unless_cancelled(|| {
+                                let message = tag(info);
+
+                                proxy
+                                    .send_event(UserEventWrapper::Message(
+                                        message,
+                                    ))
+                                    .expect("Event loop doesn't exist.");
+                            });
+                        });
+                    }
+
+                    #[cfg(not(feature = "system"))]
+                    let _ = token.unless_cancelled(|| {
+                        proxy
+                            .send_event(UserEventWrapper::Message(tag(
+                                system::ProcessInfo::current(),
+                            )))
+                            .expect("Event loop doesn't exist.");
+                    });
+                }
+                system::Action::GetDiskInfo(token, path, tag) => {
+                    #[cfg(feature = "system")]
+                    {
+                        let proxy = proxy.clone();
+
+                        let _ = std::thread::spawn(move || {
+                            let info = crate::system::disk_info(&path);
+
+                            // Sending under the lock of the token cannot race
+                            // against its cancellation
+                            let _ = token.unless_cancelled(|| {
+                                let message = tag(info);
+
+                                proxy
+                                    .send_event(UserEventWrapper::Message(
+                                        message,
+                                    ))
+                                    .expect("Event loop doesn't exist.");
+                            });
+                        });
+                    }
+
+                    #[cfg(not(feature = "system"))]
+                    {
+                        let _ = path;
+
+                        let _ = token.unless_cancelled(|| {
+                            proxy
+                                .send_event(UserEventWrapper::Message(tag(
+                                    Err(system::DiskInfoError::Unsupported),
+                                )))
+                                .expect("Event loop doesn't exist.");
+                        });
+                    }
+                }
+                system::Action::GetMonitorLayout(tag) => {
+                    // Every window shares the same virtual desktop
+                    let layout = window_manager
+                        .iter_mut()
+                        .next()
+                        .map(|(_, window)| crate::monitor::layout(&window.raw))
+                        .unwrap_or_default();
+
+                    proxy
+                        .send_event(UserEventWrapper::Message(tag(layout)))
+                        .expect("Event loop doesn't exist.");
+                }
             },
             command::Action::Widget(action) => {
                 let mut current_operation =
@@ -1694,6 +3427,49 @@ fn run_command<A, C, E>(
                 *ui_caches =
                     uis.drain().map(|(id, ui)| (id, ui.into_cache())).collect();
             }
+            command::Action::WidgetFor(id, action) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    let cache = ui_caches.remove(&id).unwrap_or_default();
+
+                    let mut user_interface = build_user_interface(
+                        application,
+                        cache,
+                        &mut window.renderer,
+                        window.state.content_bounds().size(),
+                        debug,
+                        id,
+                    );
+
+                    let mut current_operation =
+                        Some(Box::new(OperationWrapper::Message(action)));
+
+                    while let Some(mut operation) = current_operation.take() {
+                        user_interface
+                            .operate(&window.renderer, operation.as_mut());
+
+                        match operation.finish() {
+                            operation::Outcome::None => {}
+                            operation::Outcome::Some(
+                                operation::OperationOutputWrapper::Message(m),
+                            ) => {
+                                proxy
+                                    .send_event(UserEventWrapper::Message(m))
+                                    .expect("Send message to event loop");
+                            }
+                            operation::Outcome::Some(
+                                operation::OperationOutputWrapper::Id(_),
+                            ) => {}
+                            operation::Outcome::Chain(next) => {
+                                current_operation = Some(Box::new(
+                                    OperationWrapper::Wrapper(next),
+                                ));
+                            }
+                        }
+                    }
+
+                    let _ = ui_caches.insert(id, user_interface.into_cache());
+                }
+            }
             command::Action::LoadFont { bytes, tagger } => {
                 use crate::core::text::Renderer;
 
@@ -1707,6 +3483,13 @@ fn run_command<A, C, E>(
                     .send_event(UserEventWrapper::Message(tagger(Ok(()))))
                     .expect("Send message to event loop");
             }
+            command::Action::LoadFontAsync { font, bytes } => {
+                window_manager.font_load_started();
+
+                runtime.spawn(Box::pin(bytes.map(move |bytes| {
+                    UserEventWrapper::FontLoaded(font, bytes)
+                })));
+            }
             command::Action::PlatformSpecific(_) => {
                 tracing::warn!("Platform specific commands are not supported yet in multi-window winit mode.");
             }
@@ -1748,6 +3531,11 @@ fn run_command<A, C, E>(
     }
 }
 
+/// Returns whether the given event reports that a window was moved.
+fn is_moved(event: &core::Event) -> bool {
+    matches!(event, core::Event::Window(_, window::Event::Moved { .. }))
+}
+
 /// Build the user interface for every window.
 pub fn build_user_interfaces<'a, A: Application, C: Compositor>(
     application: &'a A,
@@ -1768,7 +3556,7 @@ where
                 application,
                 cache,
                 &mut window.renderer,
-                window.state.logical_size(),
+                window.state.content_bounds().size(),
                 debug,
                 id,
             );
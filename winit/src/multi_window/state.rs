@@ -1,7 +1,10 @@
 use crate::conversion;
-use crate::core::{mouse, window};
-use crate::core::{Color, Size};
+use crate::core::theme::Palette;
+use crate::core::time::{Duration, Instant};
+use crate::core::{self, mouse, window};
+use crate::core::{Color, Rectangle, Size, Vector};
 use crate::graphics::Viewport;
+use crate::letterbox;
 use crate::multi_window::Application;
 use crate::style::application;
 use std::fmt::{Debug, Formatter};
@@ -16,6 +19,8 @@ where
     A::Theme: application::StyleSheet,
 {
     title: String,
+    title_changed: bool,
+    application_title: String,
     scale_factor: f64,
     viewport: Viewport,
     viewport_version: u64,
@@ -23,6 +28,43 @@ where
     modifiers: winit::keyboard::ModifiersState,
     theme: A::Theme,
     appearance: application::Appearance,
+    urgent: bool,
+    surface_reconfigure_requested: bool,
+    frame_callback_requested: bool,
+    redraw_reason: Option<window::RedrawReason>,
+    resize_border_highlight: Option<Color>,
+    resize_hit_thickness: Option<u32>,
+    resize_border_synced_with_theme: bool,
+    theme_resize_border: Option<u32>,
+    palette: Option<Palette>,
+    palette_changed: bool,
+    resize_corner_size: Option<u32>,
+    resize_directions: window::ResizeDirections,
+    resize_grip_visible: bool,
+    dim_overlay: crate::dim_overlay::DimOverlay,
+    cursor_override: Option<mouse::Interaction>,
+    custom_cursor: bool,
+    aspect_ratio: Option<(f32, window::AspectRatioMode)>,
+    global_scale_factor: f64,
+    follows_global_scale_factor: bool,
+    reveal_deadline: Option<Instant>,
+    title_bar_region: Option<Rectangle>,
+    title_bar_click: Option<mouse::Click>,
+    level: crate::core::window::Level,
+    attention_sound_enabled: bool,
+    frame_interval: Duration,
+    last_present: Option<Instant>,
+    input_since_present: bool,
+    input_passthrough: bool,
+    input_passthrough_revert: Option<Instant>,
+    precise_mouse_motion: bool,
+    cursor_grab: window::CursorGrabMode,
+    live_resize: bool,
+    touchpad_gestures: conversion::TouchpadGestures,
+    #[cfg(target_os = "macos")]
+    traffic_light_offset: Option<crate::core::Point>,
+    #[cfg(all(target_os = "linux", feature = "x11"))]
+    frame_extents: Option<crate::core::Padding>,
 }
 
 impl<A: Application> Debug for State<A>
@@ -50,11 +92,14 @@ where
         application: &A,
         window_id: window::Id,
         window: &Window,
+        level: window::Level,
     ) -> Self {
         let title = application.title(window_id);
         let scale_factor = application.scale_factor(window_id);
         let theme = application.theme(window_id);
         let appearance = theme.appearance(&application.style());
+        let theme_resize_border = theme.resize_border(&application.style());
+        let palette = theme.palette();
 
         let viewport = {
             let physical_size = window.inner_size();
@@ -66,7 +111,9 @@ where
         };
 
         Self {
+            application_title: title.clone(),
             title,
+            title_changed: false,
             scale_factor,
             viewport,
             viewport_version: 0,
@@ -74,6 +121,43 @@ where
             modifiers: winit::keyboard::ModifiersState::default(),
             theme,
             appearance,
+            urgent: false,
+            surface_reconfigure_requested: false,
+            frame_callback_requested: false,
+            redraw_reason: None,
+            resize_border_highlight: None,
+            resize_hit_thickness: None,
+            resize_border_synced_with_theme: false,
+            theme_resize_border,
+            palette,
+            palette_changed: false,
+            resize_corner_size: None,
+            resize_directions: window::ResizeDirections::ALL,
+            resize_grip_visible: false,
+            dim_overlay: crate::dim_overlay::DimOverlay::new(),
+            cursor_override: None,
+            custom_cursor: false,
+            aspect_ratio: None,
+            global_scale_factor: 1.0,
+            follows_global_scale_factor: true,
+            reveal_deadline: None,
+            title_bar_region: None,
+            title_bar_click: None,
+            level,
+            attention_sound_enabled: true,
+            frame_interval: Duration::ZERO,
+            last_present: None,
+            input_since_present: false,
+            input_passthrough: false,
+            input_passthrough_revert: None,
+            precise_mouse_motion: false,
+            cursor_grab: window::CursorGrabMode::None,
+            live_resize: false,
+            touchpad_gestures: conversion::TouchpadGestures::default(),
+            #[cfg(target_os = "macos")]
+            traffic_light_offset: None,
+            #[cfg(all(target_os = "linux", feature = "x11"))]
+            frame_extents: None,
         }
     }
 
@@ -117,11 +201,39 @@ where
             .unwrap_or(mouse::Cursor::Unavailable)
     }
 
+    /// Updates the [`State`] with an event injected by the application, as
+    /// if it had been received from the system.
+    pub fn update_injected(&mut self, event: &core::Event) {
+        match event {
+            core::Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                let scale_factor = self.viewport.scale_factor();
+
+                self.cursor_position = Some(winit::dpi::PhysicalPosition::new(
+                    f64::from(position.x) * scale_factor,
+                    f64::from(position.y) * scale_factor,
+                ));
+            }
+            core::Event::Mouse(mouse::Event::CursorLeft) => {
+                self.cursor_position = None;
+            }
+            _ => {}
+        }
+    }
+
     /// Returns the current keyboard modifiers of the [`State`].
     pub fn modifiers(&self) -> winit::keyboard::ModifiersState {
         self.modifiers
     }
 
+    /// Converts the given touchpad gesture of the window of the [`State`],
+    /// keeping track of its total change since it started.
+    pub fn touchpad_event(
+        &mut self,
+        event: &WindowEvent,
+    ) -> Option<crate::core::touchpad::Event> {
+        self.touchpad_gestures.convert(event)
+    }
+
     /// Returns the current theme of the [`State`].
     pub fn theme(&self) -> &A::Theme {
         &self.theme
@@ -142,6 +254,630 @@ where
         self.appearance.icon_color
     }
 
+    /// Marks whether the window of the [`State`] is currently urgent.
+    ///
+    /// An urgent window has its urgency hint cleared once it gains focus.
+    pub fn set_urgent(&mut self, urgent: bool) {
+        self.urgent = urgent;
+    }
+
+    /// Requests the surface of the window of the [`State`] to be reconfigured
+    /// the next time it is redrawn.
+    pub fn request_surface_reconfigure(&mut self) {
+        self.surface_reconfigure_requested = true;
+    }
+
+    /// Returns whether a reconfiguration of the surface was requested,
+    /// clearing the request.
+    pub fn take_surface_reconfigure_request(&mut self) -> bool {
+        std::mem::take(&mut self.surface_reconfigure_requested)
+    }
+
+    /// Requests a frame callback for the window of the [`State`], returning
+    /// whether a callback was not already pending.
+    ///
+    /// Multiple requests before the next frame are coalesced into a single
+    /// callback.
+    pub fn request_frame_callback(&mut self) -> bool {
+        !std::mem::replace(&mut self.frame_callback_requested, true)
+    }
+
+    /// Returns whether a frame callback was requested, clearing the request.
+    pub fn take_frame_callback_request(&mut self) -> bool {
+        std::mem::take(&mut self.frame_callback_requested)
+    }
+
+    /// Records why the window of the [`State`] needs to be redrawn, unless a
+    /// reason was already recorded for its next redraw.
+    pub fn note_redraw_reason(&mut self, reason: window::RedrawReason) {
+        let _ = self.redraw_reason.get_or_insert(reason);
+    }
+
+    /// Returns the reason of the next redraw of the window of the [`State`].
+    pub fn redraw_reason(&self) -> window::RedrawReason {
+        self.redraw_reason
+            .unwrap_or(window::RedrawReason::Unconditional)
+    }
+
+    /// Returns the reason of the next redraw of the window of the [`State`],
+    /// clearing it.
+    pub fn take_redraw_reason(&mut self) -> window::RedrawReason {
+        self.redraw_reason
+            .take()
+            .unwrap_or(window::RedrawReason::Unconditional)
+    }
+
+    /// Returns the new [`Palette`] of the theme of the [`State`], if it
+    /// changed since the last time it was taken.
+    pub fn take_palette_change(&mut self) -> Option<Palette> {
+        if std::mem::take(&mut self.palette_changed) {
+            self.palette
+        } else {
+            None
+        }
+    }
+
+    /// Returns the [`Color`] used to highlight the hovered edge of the resize
+    /// border of the window, if any.
+    pub fn resize_border_highlight(&self) -> Option<Color> {
+        self.resize_border_highlight
+    }
+
+    /// Sets the [`Color`] used to highlight the hovered edge of the resize
+    /// border of the window, disabling the highlight when `None`.
+    pub fn set_resize_border_highlight(&mut self, color: Option<Color>) {
+        self.resize_border_highlight = color;
+    }
+
+    /// Returns the logical thickness of the area of the window that initiates
+    /// a drag resize, if it differs from the resize border.
+    pub fn resize_hit_thickness(&self) -> Option<u32> {
+        self.resize_hit_thickness
+    }
+
+    /// Sets the logical thickness of the area of the window that initiates a
+    /// drag resize, independently of the resize border.
+    ///
+    /// This is an explicit override, so the resize border stops following the
+    /// theme until it is synced with it again.
+    pub fn set_resize_hit_thickness(&mut self, thickness: u32) {
+        self.resize_hit_thickness = Some(thickness);
+        self.resize_border_synced_with_theme = false;
+    }
+
+    /// Returns the logical width of the resize border provided by the theme,
+    /// if the window of the [`State`] is synced with it.
+    pub fn theme_resize_border(&self) -> Option<u32> {
+        self.resize_border_synced_with_theme
+            .then_some(self.theme_resize_border)
+            .flatten()
+    }
+
+    /// Sets whether the resize border of the window of the [`State`] follows
+    /// the width provided by the theme.
+    pub fn set_resize_border_synced_with_theme(&mut self, synced: bool) {
+        self.resize_border_synced_with_theme = synced;
+    }
+
+    /// Returns the logical size of the corners of the window that initiate a
+    /// diagonal drag resize, if it differs from the edges.
+    pub fn resize_corner_size(&self) -> Option<u32> {
+        self.resize_corner_size
+    }
+
+    /// Sets the logical size of the corners of the window that initiate a
+    /// diagonal drag resize, independently of the edges.
+    pub fn set_resize_corner_size(&mut self, size: u32) {
+        self.resize_corner_size = Some(size);
+    }
+
+    /// Returns the edges of the window of the [`State`] that initiate a drag
+    /// resize.
+    pub fn resize_directions(&self) -> window::ResizeDirections {
+        self.resize_directions
+    }
+
+    /// Sets the edges of the window of the [`State`] that initiate a drag
+    /// resize.
+    pub fn set_resize_directions(
+        &mut self,
+        directions: window::ResizeDirections,
+    ) {
+        self.resize_directions = directions;
+    }
+
+    /// Sets the scale factor applied to every window of the application on
+    /// top of its own, which only takes effect once the [`State`] is
+    /// synchronized.
+    pub fn set_global_scale_factor(&mut self, scale_factor: f64) {
+        self.global_scale_factor = scale_factor;
+    }
+
+    /// Sets whether the window of the [`State`] follows the scale factor
+    /// applied to every window of the application.
+    pub fn set_follows_global_scale_factor(&mut self, follows: bool) {
+        self.follows_global_scale_factor = follows;
+    }
+
+    /// Returns the scale factor applied to the window of the [`State`] on top
+    /// of its own.
+    fn global_scale_factor(&self) -> f64 {
+        if self.follows_global_scale_factor {
+            self.global_scale_factor
+        } else {
+            1.0
+        }
+    }
+
+    /// Returns whether a resize grip is drawn at the bottom-right corner of
+    /// the window of the [`State`].
+    pub fn resize_grip_visible(&self) -> bool {
+        self.resize_grip_visible
+    }
+
+    /// Sets whether a resize grip is drawn at the bottom-right corner of the
+    /// window of the [`State`].
+    pub fn set_resize_grip_visible(&mut self, visible: bool) {
+        self.resize_grip_visible = visible;
+    }
+
+    /// Returns the current [`Color`] of the dim overlay of the window of the
+    /// [`State`], if it is visible.
+    pub fn dim_overlay(&self) -> Option<Color> {
+        Some(self.dim_overlay.color()).filter(|color| color.a > 0.0)
+    }
+
+    /// Fades the dim overlay of the window of the [`State`] to the given
+    /// [`Color`], or out when `None`.
+    pub fn set_dim_overlay(&mut self, color: Option<Color>) {
+        self.dim_overlay.set(color);
+    }
+
+    /// Returns whether the dim overlay of the window of the [`State`] is
+    /// still fading, and needs to be redrawn.
+    pub fn is_dim_overlay_fading(&self) -> bool {
+        self.dim_overlay.is_fading()
+    }
+
+    /// Returns the [`mouse::Interaction`] forcing the cursor icon of the
+    /// window of the [`State`], if any.
+    ///
+    /// When set, it replaces the interaction returned by the widgets of the
+    /// window.
+    pub fn cursor_override(&self) -> Option<mouse::Interaction> {
+        self.cursor_override
+    }
+
+    /// Forces the cursor icon of the window of the [`State`] to the given
+    /// [`mouse::Interaction`] until it is cleared with `None`.
+    pub fn set_cursor_override(
+        &mut self,
+        interaction: Option<mouse::Interaction>,
+    ) {
+        self.cursor_override = interaction;
+    }
+
+    /// Returns whether the window of the [`State`] shows a
+    /// [`mouse::CustomCursor`] in place of the cursor icons.
+    pub fn has_custom_cursor(&self) -> bool {
+        self.custom_cursor
+    }
+
+    /// Replaces the cursor of the given `window` with the given
+    /// [`mouse::CustomCursor`], or goes back to the cursor icons with
+    /// `None`.
+    ///
+    /// Custom cursors are only supported on X11; a warning is logged on
+    /// other platforms and the cursor icons are kept.
+    pub fn set_custom_cursor(
+        &mut self,
+        window: &Window,
+        cursor: Option<mouse::CustomCursor>,
+    ) {
+        #[cfg(all(target_os = "linux", feature = "x11"))]
+        let applied = cursor.as_ref().is_some_and(|cursor| {
+            crate::x11::set_custom_cursor(window, cursor)
+        });
+
+        #[cfg(not(all(target_os = "linux", feature = "x11")))]
+        let applied = false;
+
+        if cursor.is_some() && !applied {
+            log::warn!("Custom cursors are not supported on this platform");
+        }
+
+        if self.custom_cursor && !applied {
+            // `winit` only defines the cursor icon when it changes, so it
+            // is hidden and shown again to replace the custom cursor
+            window.set_cursor_visible(false);
+            window.set_cursor_visible(true);
+        }
+
+        self.custom_cursor = applied;
+    }
+
+    /// Returns the aspect ratio maintained by the window of the [`State`],
+    /// and how it is maintained, if any.
+    pub fn aspect_ratio(&self) -> Option<(f32, window::AspectRatioMode)> {
+        self.aspect_ratio
+    }
+
+    /// Sets the aspect ratio maintained by the window of the [`State`], or
+    /// removes it with `None`.
+    ///
+    /// A constrained window is resized right away to match the aspect ratio.
+    pub fn set_aspect_ratio(
+        &mut self,
+        window: &Window,
+        aspect_ratio: Option<f32>,
+        mode: window::AspectRatioMode,
+    ) {
+        self.aspect_ratio = aspect_ratio
+            .filter(|ratio| ratio.is_finite() && *ratio > 0.0)
+            .map(|ratio| (ratio, mode));
+
+        // The contents need to be laid out again
+        self.viewport_version = self.viewport_version.wrapping_add(1);
+
+        self.constrain_aspect_ratio(window);
+    }
+
+    /// Requests the window to be resized to match its aspect ratio, if it is
+    /// constrained.
+    fn constrain_aspect_ratio(&self, window: &Window) {
+        if let Some((ratio, window::AspectRatioMode::ConstrainWindow)) =
+            self.aspect_ratio
+        {
+            if let Some(size) =
+                letterbox::constrained_size(window.inner_size(), ratio)
+            {
+                let _ = window.request_inner_size(size);
+            }
+        }
+    }
+
+    /// Returns the logical bounds of the contents of the window of the
+    /// [`State`].
+    ///
+    /// They cover the whole window, unless its contents are letterboxed.
+    pub fn content_bounds(&self) -> Rectangle {
+        let size = self.logical_size();
+
+        match self.aspect_ratio {
+            Some((ratio, window::AspectRatioMode::LetterboxContent)) => {
+                letterbox::content_bounds(size, ratio)
+            }
+            _ => Rectangle::with_size(size),
+        }
+    }
+
+    /// Returns the current cursor position of the [`State`], relative to the
+    /// contents of its window.
+    pub fn content_cursor(&self) -> mouse::Cursor {
+        self.cursor()
+            .position_from(self.content_bounds().position())
+            .map(mouse::Cursor::Available)
+            .unwrap_or(mouse::Cursor::Unavailable)
+    }
+
+    /// Translates the positions of the given event to the coordinate system
+    /// of the contents of the window of the [`State`].
+    pub fn content_event(&self, event: core::Event) -> core::Event {
+        let bounds = self.content_bounds();
+
+        if bounds.x == 0.0 && bounds.y == 0.0 {
+            return event;
+        }
+
+        letterbox::translate_event(event, Vector::new(bounds.x, bounds.y))
+    }
+
+    /// Returns the logical region of the window that behaves like its title
+    /// bar, if any.
+    pub fn title_bar_region(&self) -> Option<Rectangle> {
+        self.title_bar_region
+    }
+
+    /// Sets the logical region of the window that behaves like its title bar.
+    ///
+    /// Pressing this region drags the window, and double-clicking it toggles
+    /// the maximization of the window.
+    pub fn set_title_bar_region(&mut self, region: Option<Rectangle>) {
+        self.title_bar_region = region;
+        self.title_bar_click = None;
+    }
+
+    /// Processes an event after it was handled by the user interface,
+    /// dragging or toggling the maximization of the window if the title bar
+    /// region was pressed.
+    ///
+    /// Events captured by a widget, like a button inside the title bar, are
+    /// ignored.
+    pub fn update_title_bar(
+        &mut self,
+        window: &Window,
+        event: &core::Event,
+        status: core::event::Status,
+    ) {
+        let core::Event::Mouse(mouse::Event::ButtonPressed(
+            mouse::Button::Left,
+        )) = event
+        else {
+            return;
+        };
+
+        let Some(position) = self
+            .title_bar_region
+            .and_then(|region| self.cursor().position_over(region))
+        else {
+            self.title_bar_click = None;
+            return;
+        };
+
+        if status == core::event::Status::Captured {
+            self.title_bar_click = None;
+            return;
+        }
+
+        let click = mouse::Click::new(position, self.title_bar_click);
+        self.title_bar_click = Some(click);
+
+        if let mouse::click::Kind::Double = click.kind() {
+            window.set_maximized(!window.is_maximized());
+        } else {
+            let _ = window.drag_window();
+        }
+    }
+
+    /// Changes the [`Level`] of the window of the [`State`].
+    ///
+    /// [`Level`]: crate::core::window::Level
+    pub fn set_level(
+        &mut self,
+        window: &Window,
+        level: crate::core::window::Level,
+    ) {
+        window.set_window_level(conversion::window_level(level));
+
+        self.level = level;
+    }
+
+    /// Sets whether the window of the [`State`] is kept at the given
+    /// [`Level`].
+    ///
+    /// Keeping a window above others stops keeping it below them, and vice
+    /// versa. Releasing the window from a [`Level`] it is not kept at has no
+    /// effect.
+    ///
+    /// [`Level`]: crate::core::window::Level
+    pub fn set_keep_level(
+        &mut self,
+        window: &Window,
+        level: crate::core::window::Level,
+        keep: bool,
+    ) {
+        if keep {
+            self.set_level(window, level);
+        } else if self.level == level {
+            self.set_level(window, crate::core::window::Level::Normal);
+        }
+    }
+
+    /// Keeps the window of the [`State`] hidden until the fonts loading in
+    /// the background are available, or until the given timeout elapses.
+    pub fn wait_for_fonts(&mut self, timeout: Duration) {
+        self.reveal_deadline = Some(Instant::now() + timeout);
+    }
+
+    /// Returns the instant at which the window of the [`State`] stops waiting
+    /// for fonts, if it is waiting for them.
+    pub fn reveal_deadline(&self) -> Option<Instant> {
+        self.reveal_deadline
+    }
+
+    /// Returns whether the window of the [`State`] should be shown because it
+    /// is done waiting for the given number of pending fonts, clearing the
+    /// wait.
+    pub fn take_reveal(&mut self, pending_fonts: usize) -> bool {
+        let ready = self.reveal_deadline.is_some_and(|deadline| {
+            pending_fonts == 0 || Instant::now() >= deadline
+        });
+
+        if ready {
+            self.reveal_deadline = None;
+        }
+
+        ready
+    }
+
+    /// Returns whether the window of the [`State`] may play attention sounds.
+    pub fn attention_sound_enabled(&self) -> bool {
+        self.attention_sound_enabled
+    }
+
+    /// Enables or disables the attention sounds of the window of the
+    /// [`State`].
+    pub fn set_attention_sound_enabled(&mut self, enabled: bool) {
+        self.attention_sound_enabled = enabled;
+    }
+
+    /// Returns whether the window of the [`State`] renders continuously
+    /// while it is interactively resized.
+    pub fn live_resize(&self) -> bool {
+        self.live_resize
+    }
+
+    /// Sets whether the window of the [`State`] renders continuously while it
+    /// is interactively resized.
+    pub fn set_live_resize(&mut self, live: bool) {
+        self.live_resize = live;
+    }
+
+    /// Returns whether the window of the [`State`] receives every mouse
+    /// motion sample, even if mouse motion is coalesced.
+    pub fn precise_mouse_motion(&self) -> bool {
+        self.precise_mouse_motion
+    }
+
+    /// Sets whether the window of the [`State`] receives every mouse motion
+    /// sample, even if mouse motion is coalesced.
+    pub fn set_precise_mouse_motion(&mut self, precise: bool) {
+        self.precise_mouse_motion = precise;
+    }
+
+    /// Returns the [`window::CursorGrabMode`] of the window of the [`State`].
+    pub fn cursor_grab(&self) -> window::CursorGrabMode {
+        self.cursor_grab
+    }
+
+    /// Records the [`window::CursorGrabMode`] of the window of the [`State`].
+    ///
+    /// Raw mouse motion is only delivered while the cursor is grabbed.
+    pub fn set_cursor_grab(&mut self, mode: window::CursorGrabMode) {
+        self.cursor_grab = mode;
+    }
+
+    /// Makes the window of the [`State`] transparent to mouse input while
+    /// `passthrough` is enabled.
+    ///
+    /// If a `duration` is given, the request is reverted once it elapses.
+    /// Any pending revert of a previous request is discarded.
+    pub fn set_input_passthrough(
+        &mut self,
+        window: &Window,
+        passthrough: bool,
+        duration: Option<Duration>,
+    ) {
+        let _ = window.set_cursor_hittest(!passthrough);
+
+        self.input_passthrough = passthrough;
+        self.input_passthrough_revert =
+            duration.map(|duration| Instant::now() + duration);
+    }
+
+    /// Undoes the input passthrough request of the window of the [`State`]
+    /// if its duration elapsed, returning the instant at which it must be
+    /// undone otherwise.
+    pub fn revert_input_passthrough(
+        &mut self,
+        window: &Window,
+    ) -> Option<Instant> {
+        let deadline = self.input_passthrough_revert?;
+
+        if Instant::now() < deadline {
+            return Some(deadline);
+        }
+
+        self.set_input_passthrough(window, !self.input_passthrough, None);
+
+        None
+    }
+
+    /// Sets the minimum [`Duration`] between two consecutive presents of the
+    /// window of the [`State`].
+    pub fn set_frame_interval(&mut self, interval: Duration) {
+        self.frame_interval = interval;
+    }
+
+    /// Returns the [`Instant`] until which the next frame of the window of
+    /// the [`State`] must be deferred to honor its frame interval, if any.
+    ///
+    /// The first frame after user input is never deferred.
+    pub fn deferred_frame(&self) -> Option<Instant> {
+        if self.input_since_present {
+            return None;
+        }
+
+        let at = self.last_present? + self.frame_interval;
+
+        (at > Instant::now()).then_some(at)
+    }
+
+    /// Records that a frame of the window of the [`State`] was just
+    /// presented.
+    pub fn frame_presented(&mut self) {
+        self.last_present = Some(Instant::now());
+        self.input_since_present = false;
+    }
+
+    /// Moves the traffic lights of the window of the [`State`] to the given
+    /// logical `offset` from the top-left corner of its title bar.
+    ///
+    /// The offset is reapplied whenever the window is resized, since macOS
+    /// resets the position of the buttons on fullscreen transitions.
+    #[cfg(target_os = "macos")]
+    pub fn set_traffic_light_offset(
+        &mut self,
+        window: &Window,
+        offset: crate::core::Point,
+    ) {
+        crate::macos::set_traffic_light_offset(window, offset);
+
+        self.traffic_light_offset = Some(offset);
+    }
+
+    /// Moves and resizes the window of the [`State`] to the given
+    /// [`SnapPosition`] of the work area of its current monitor.
+    ///
+    /// [`SnapPosition`]: crate::core::window::SnapPosition
+    pub fn snap(
+        &self,
+        window: &Window,
+        position: crate::core::window::SnapPosition,
+    ) {
+        #[cfg(all(target_os = "linux", feature = "x11"))]
+        let frame_extents =
+            self.frame_extents.unwrap_or(crate::core::Padding::ZERO);
+
+        #[cfg(not(all(target_os = "linux", feature = "x11")))]
+        let frame_extents = crate::core::Padding::ZERO;
+
+        crate::snap::snap(window, position, frame_extents);
+    }
+
+    /// Declares the logical `extents` of the client-side shadows of the
+    /// window of the [`State`] to the X11 window manager.
+    ///
+    /// The extents are reapplied whenever the scale factor of the window
+    /// changes.
+    #[cfg(all(target_os = "linux", feature = "x11"))]
+    pub fn set_frame_extents(
+        &mut self,
+        window: &Window,
+        extents: crate::core::Padding,
+    ) {
+        crate::x11::set_frame_extents(window, extents, window.scale_factor());
+
+        self.frame_extents = Some(extents);
+    }
+
+    /// Returns the current title of the [`State`].
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Sets the title of the window of the [`State`], independently of the
+    /// title of the [`Application`].
+    ///
+    /// The title is kept until the title of the [`Application`] changes.
+    pub fn set_title(&mut self, title: String) {
+        if self.title != title {
+            self.title = title;
+            self.title_changed = true;
+        }
+    }
+
+    /// Applies the title of the [`State`] to its window, if it changed since
+    /// it was last applied.
+    ///
+    /// Title changes are coalesced by [`State::synchronize`], so only the
+    /// latest title is set once per event loop iteration.
+    pub fn apply_title(&mut self, window: &Window) {
+        if std::mem::take(&mut self.title_changed) {
+            window.set_title(&self.title);
+        }
+    }
+
     /// Processes the provided window event and updates the [`State`] accordingly.
     pub fn update(
         &mut self,
@@ -149,6 +885,17 @@ where
         event: &WindowEvent,
         _debug: &mut crate::runtime::Debug,
     ) {
+        if matches!(
+            event,
+            WindowEvent::KeyboardInput { .. }
+                | WindowEvent::MouseInput { .. }
+                | WindowEvent::MouseWheel { .. }
+                | WindowEvent::CursorMoved { .. }
+                | WindowEvent::Touch(_)
+        ) {
+            self.input_since_present = true;
+        }
+
         match event {
             WindowEvent::Resized(new_size) => {
                 let size = Size::new(new_size.width, new_size.height);
@@ -159,6 +906,13 @@ where
                 );
 
                 self.viewport_version = self.viewport_version.wrapping_add(1);
+
+                self.constrain_aspect_ratio(window);
+
+                #[cfg(target_os = "macos")]
+                if let Some(offset) = self.traffic_light_offset {
+                    crate::macos::set_traffic_light_offset(window, offset);
+                }
             }
             WindowEvent::ScaleFactorChanged {
                 scale_factor: new_scale_factor,
@@ -172,6 +926,15 @@ where
                 );
 
                 self.viewport_version = self.viewport_version.wrapping_add(1);
+
+                #[cfg(all(target_os = "linux", feature = "x11"))]
+                if let Some(extents) = self.frame_extents {
+                    crate::x11::set_frame_extents(
+                        window,
+                        extents,
+                        *new_scale_factor,
+                    );
+                }
             }
             WindowEvent::CursorMoved { position, .. }
             | WindowEvent::Touch(Touch {
@@ -185,6 +948,11 @@ where
             WindowEvent::ModifiersChanged(new_modifiers) => {
                 self.modifiers = new_modifiers.state();
             }
+            WindowEvent::Focused(true) if self.urgent => {
+                window.request_user_attention(None);
+
+                self.urgent = false;
+            }
             #[cfg(feature = "debug")]
             WindowEvent::KeyboardInput {
                 event:
@@ -207,6 +975,8 @@ where
     ///
     /// Normally, an [`Application`] should be synchronized with its [`State`]
     /// and window after calling [`State::update`].
+    ///
+    /// A new title is only set on the window by [`State::apply_title`].
     pub fn synchronize(
         &mut self,
         application: &A,
@@ -216,13 +986,15 @@ where
         // Update window title
         let new_title = application.title(window_id);
 
-        if self.title != new_title {
-            window.set_title(&new_title);
+        if self.application_title != new_title {
+            self.application_title = new_title.clone();
             self.title = new_title;
+            self.title_changed = true;
         }
 
         // Update scale factor and size
-        let new_scale_factor = application.scale_factor(window_id);
+        let new_scale_factor =
+            application.scale_factor(window_id) * self.global_scale_factor();
         let new_size = window.inner_size();
         let current_size = self.viewport.physical_size();
 
@@ -242,5 +1014,14 @@ where
         // Update theme and appearance
         self.theme = application.theme(window_id);
         self.appearance = self.theme.appearance(&application.style());
+        self.theme_resize_border =
+            self.theme.resize_border(&application.style());
+
+        let palette = self.theme.palette();
+
+        if palette != self.palette {
+            self.palette = palette;
+            self.palette_changed = true;
+        }
     }
 }
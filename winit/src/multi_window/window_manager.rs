@@ -1,14 +1,31 @@
-use crate::core::mouse;
-use crate::core::window::Id;
+use crate::core::time::{Duration, Instant};
+use crate::core::window::{Id, RenderPriority};
+use crate::core::{self, mouse};
 use crate::core::{Point, Size};
+use crate::futures::MaybeSend;
 use crate::graphics::Compositor;
+use crate::letterbox;
 use crate::multi_window::{Application, State};
+use crate::settings::WindowOptions;
 use crate::style::application::StyleSheet;
 
+use std::cmp::Reverse;
 use std::collections::BTreeMap;
 use std::sync::Arc;
 use winit::monitor::MonitorHandle;
 
+/// The time the windows above [`RenderPriority::Low`] can spend presenting
+/// a frame before low priority windows start lagging behind.
+const FRAME_BUDGET: Duration = Duration::from_millis(16);
+
+/// The amount of consecutive frames the rendering of a low priority window
+/// can be deferred before it is rendered anyway.
+const MAX_DEFERRED_FRAMES: u32 = 4;
+
+/// The time after its last present a window is considered idle and stops
+/// counting towards the [`FRAME_BUDGET`].
+const IDLE_TIMEOUT: Duration = Duration::from_millis(500);
+
 #[allow(missing_debug_implementations)]
 pub struct WindowManager<A: Application, C: Compositor>
 where
@@ -17,6 +34,11 @@ where
 {
     aliases: BTreeMap<winit::window::WindowId, Id>,
     entries: BTreeMap<Id, Window<A, C>>,
+    input_capture: Option<Id>,
+    minimized_all: Vec<Id>,
+    interaction_waiters: Vec<Box<dyn FnOnce() -> A::Message + MaybeSend>>,
+    pending_fonts: usize,
+    global_scale_factor: f64,
 }
 
 impl<A, C> WindowManager<A, C>
@@ -29,6 +51,11 @@ where
         Self {
             aliases: BTreeMap::new(),
             entries: BTreeMap::new(),
+            input_capture: None,
+            minimized_all: Vec::new(),
+            interaction_waiters: Vec::new(),
+            pending_fonts: 0,
+            global_scale_factor: 1.0,
         }
     }
 
@@ -38,10 +65,15 @@ where
         window: Arc<winit::window::Window>,
         application: &A,
         compositor: &mut C,
-        exit_on_close_request: bool,
-        resize_border: u32,
+        options: WindowOptions,
     ) -> &mut Window<A, C> {
-        let state = State::new(application, id, &window);
+        let mut state = State::new(application, id, &window, options.level);
+
+        if self.global_scale_factor != 1.0 {
+            state.set_global_scale_factor(self.global_scale_factor);
+            state.synchronize(application, id, &window);
+        }
+
         let viewport_version = state.viewport_version();
         let physical_size = state.physical_size();
         let surface = compositor.create_surface(
@@ -53,9 +85,9 @@ where
 
         let _ = self.aliases.insert(window.id(), id);
 
-        let drag_resize_window_func = super::drag_resize::event_func(
+        let drag_resize = super::drag_resize::DragResize::new(
             &window,
-            resize_border as f64 * window.scale_factor(),
+            options.resize_border as f64 * window.scale_factor(),
         );
 
         let _ = self.entries.insert(
@@ -64,12 +96,20 @@ where
                 raw: window,
                 state,
                 viewport_version,
-                exit_on_close_request,
-                drag_resize_window_func,
+                exit_on_close_request: options.exit_on_close_request,
+                keep_cache_when_hidden: options.keep_cache_when_hidden,
+                auto_reconfigure_surface: options.auto_reconfigure_surface,
+                surface_size: physical_size,
+                drag_resize,
                 surface,
                 renderer,
                 mouse_interaction: mouse::Interaction::Idle,
                 prev_dnd_destination_rectangles_count: 0,
+                render_priority: RenderPriority::Normal,
+                present_duration: Duration::ZERO,
+                presented_at: None,
+                deferred_frames: 0,
+                deferred_until: None,
             },
         );
 
@@ -82,12 +122,100 @@ where
         self.entries.is_empty()
     }
 
+    pub fn ids(&self) -> impl Iterator<Item = Id> + '_ {
+        self.entries.keys().copied()
+    }
+
     pub fn iter_mut(
         &mut self,
     ) -> impl Iterator<Item = (Id, &mut Window<A, C>)> {
         self.entries.iter_mut().map(|(k, v)| (*k, v))
     }
 
+    /// Iterates over the windows from the highest to the lowest
+    /// [`RenderPriority`].
+    pub fn iter_mut_by_priority(
+        &mut self,
+    ) -> impl Iterator<Item = (Id, &mut Window<A, C>)> {
+        let mut windows: Vec<_> =
+            self.entries.iter_mut().map(|(k, v)| (*k, v)).collect();
+
+        windows.sort_by_key(|(_, window)| Reverse(window.render_priority));
+
+        windows.into_iter()
+    }
+
+    /// Returns the [`Instant`] until which the frame of the given window must
+    /// be deferred because of its [`RenderPriority`], if any.
+    ///
+    /// The frames of low priority windows are deferred while the other
+    /// windows exceed the [`FRAME_BUDGET`], but no more than
+    /// [`MAX_DEFERRED_FRAMES`] times in a row. Idle windows do not count
+    /// towards the budget.
+    pub fn deferred_frame(&mut self, id: Id) -> Option<Instant> {
+        let load: Duration = self
+            .entries
+            .values_mut()
+            .filter(|window| window.render_priority > RenderPriority::Low)
+            .map(|window| {
+                let is_idle = window
+                    .presented_at
+                    .map_or(true, |at| at.elapsed() > IDLE_TIMEOUT);
+
+                if is_idle {
+                    window.present_duration = Duration::ZERO;
+                }
+
+                window.present_duration
+            })
+            .sum();
+
+        let window = self.entries.get_mut(&id)?;
+
+        if window.render_priority > RenderPriority::Low
+            || load <= FRAME_BUDGET
+            || window.deferred_frames >= MAX_DEFERRED_FRAMES
+        {
+            window.deferred_frames = 0;
+
+            return None;
+        }
+
+        window.deferred_frames += 1;
+
+        Some(Instant::now() + load - FRAME_BUDGET)
+    }
+
+    /// Defers the frame of the given window until the given [`Instant`],
+    /// when [`WindowManager::redraw_deferred`] requests it again.
+    pub fn defer_frame(&mut self, id: Id, at: Instant) {
+        if let Some(window) = self.entries.get_mut(&id) {
+            window.deferred_until = Some(at);
+        }
+    }
+
+    /// Requests a redraw of the windows whose deferred frame is due,
+    /// returning the earliest instant at which a frame is still deferred.
+    pub fn redraw_deferred(&mut self) -> Option<Instant> {
+        let now = Instant::now();
+
+        self.entries
+            .values_mut()
+            .filter_map(|window| {
+                let at = window.deferred_until?;
+
+                if at > now {
+                    return Some(at);
+                }
+
+                window.deferred_until = None;
+                window.raw.request_redraw();
+
+                None
+            })
+            .min()
+    }
+
     pub fn get_mut(&mut self, id: Id) -> Option<&mut Window<A, C>> {
         self.entries.get_mut(&id)
     }
@@ -105,9 +233,177 @@ where
         self.entries.values().last()?.raw.current_monitor()
     }
 
+    /// Sets the scale factor applied to every window on top of its own,
+    /// including the windows inserted afterwards.
+    pub fn set_global_scale_factor(&mut self, scale_factor: f64) {
+        self.global_scale_factor = scale_factor;
+
+        for window in self.entries.values_mut() {
+            window.state.set_global_scale_factor(scale_factor);
+        }
+    }
+
+    /// Keeps track of a font that started loading in the background, which
+    /// windows waiting for fonts are not revealed before.
+    pub fn font_load_started(&mut self) {
+        self.pending_fonts += 1;
+    }
+
+    /// Keeps track of a font that finished loading in the background,
+    /// successfully or not.
+    pub fn font_load_finished(&mut self) {
+        self.pending_fonts = self.pending_fonts.saturating_sub(1);
+    }
+
+    /// Shows the windows that are done waiting for fonts, returning the
+    /// earliest instant at which a window still waiting stops waiting.
+    pub fn reveal_windows(&mut self) -> Option<Instant> {
+        let pending_fonts = self.pending_fonts;
+
+        self.entries
+            .values_mut()
+            .filter_map(|window| {
+                if window.state.take_reveal(pending_fonts) {
+                    window.raw.set_visible(true);
+                }
+
+                window.state.reveal_deadline()
+            })
+            .min()
+    }
+
+    /// Undoes the input passthrough requests of the windows whose duration
+    /// elapsed, returning the earliest instant at which a pending request
+    /// must be undone.
+    pub fn revert_input_passthrough(&mut self) -> Option<Instant> {
+        self.entries
+            .values_mut()
+            .filter_map(|window| {
+                window.state.revert_input_passthrough(&window.raw)
+            })
+            .min()
+    }
+
+    pub fn input_capture(&self) -> Option<Id> {
+        self.input_capture
+    }
+
+    pub fn set_input_capture(&mut self, id: Id, capture: bool) {
+        if capture {
+            if self.entries.contains_key(&id) {
+                self.input_capture = Some(id);
+            }
+        } else if self.input_capture == Some(id) {
+            self.input_capture = None;
+        }
+    }
+
+    /// Translates the positions of an event received by the window with the
+    /// given [`Id`] to the coordinate system of its contents, if any.
+    pub fn content_event(
+        &self,
+        id: Option<Id>,
+        event: core::Event,
+    ) -> core::Event {
+        match id.and_then(|id| self.entries.get(&id)) {
+            Some(window) => window.state.content_event(event),
+            None => event,
+        }
+    }
+
+    /// Translates the positions of an event received by the `source` window
+    /// to the coordinate system of the contents of the `target` window.
+    ///
+    /// The event is returned unchanged if the position of any of the windows
+    /// is unknown.
+    pub fn relocate_event(
+        &self,
+        event: core::Event,
+        source: Id,
+        target: Id,
+    ) -> core::Event {
+        let (Some(source), Some(target)) =
+            (self.entries.get(&source), self.entries.get(&target))
+        else {
+            return event;
+        };
+
+        let (Ok(source_origin), Ok(target_origin)) =
+            (source.raw.inner_position(), target.raw.inner_position())
+        else {
+            return event;
+        };
+
+        let source_content = source.state.content_bounds();
+        let target_content = target.state.content_bounds();
+        let source_scale = source.state.scale_factor() as f32;
+        let target_scale = target.state.scale_factor() as f32;
+
+        letterbox::map_positions(event, |position| {
+            let x = (position.x + source_content.x) * source_scale
+                + source_origin.x as f32
+                - target_origin.x as f32;
+            let y = (position.y + source_content.y) * source_scale
+                + source_origin.y as f32
+                - target_origin.y as f32;
+
+            Point::new(
+                x / target_scale - target_content.x,
+                y / target_scale - target_content.y,
+            )
+        })
+    }
+
+    /// Minimizes all the windows that are not minimized already, remembering
+    /// them so that [`WindowManager::restore_all`] only restores those.
+    pub fn minimize_all(&mut self) {
+        for (id, window) in &self.entries {
+            if window.raw.is_minimized() == Some(true) {
+                continue;
+            }
+
+            window.raw.set_minimized(true);
+
+            if !self.minimized_all.contains(id) {
+                self.minimized_all.push(*id);
+            }
+        }
+    }
+
+    /// Restores the windows minimized by [`WindowManager::minimize_all`].
+    pub fn restore_all(&mut self) {
+        for id in self.minimized_all.drain(..) {
+            if let Some(window) = self.entries.get(&id) {
+                window.raw.set_minimized(false);
+            }
+        }
+    }
+
+    /// Registers a callback producing a message on the next user interaction
+    /// with any window.
+    pub fn await_interaction(
+        &mut self,
+        f: Box<dyn FnOnce() -> A::Message + MaybeSend>,
+    ) {
+        self.interaction_waiters.push(f);
+    }
+
+    /// Produces the messages of every callback awaiting a user interaction,
+    /// removing them from the [`WindowManager`].
+    pub fn drain_interaction_waiters(
+        &mut self,
+    ) -> impl Iterator<Item = A::Message> + '_ {
+        self.interaction_waiters.drain(..).map(|f| f())
+    }
+
     pub fn remove(&mut self, id: Id) -> Option<Window<A, C>> {
         let window = self.entries.remove(&id)?;
         let _ = self.aliases.remove(&window.raw.id());
+        self.minimized_all.retain(|minimized| *minimized != id);
+
+        if self.input_capture == Some(id) {
+            self.input_capture = None;
+        }
 
         Some(window)
     }
@@ -135,16 +431,17 @@ where
     pub state: State<A>,
     pub viewport_version: u64,
     pub exit_on_close_request: bool,
-    pub drag_resize_window_func: Option<
-        Box<
-            dyn FnMut(
-                &winit::window::Window,
-                &winit::event::WindowEvent,
-            ) -> bool,
-        >,
-    >,
+    pub keep_cache_when_hidden: bool,
+    pub auto_reconfigure_surface: bool,
+    pub surface_size: Size<u32>,
+    pub drag_resize: Option<super::drag_resize::DragResize>,
     pub prev_dnd_destination_rectangles_count: usize,
     pub mouse_interaction: mouse::Interaction,
+    pub render_priority: RenderPriority,
+    pub present_duration: Duration,
+    pub presented_at: Option<Instant>,
+    pub deferred_frames: u32,
+    pub deferred_until: Option<Instant>,
     pub surface: C::Surface,
     pub renderer: A::Renderer,
 }
@@ -171,4 +468,12 @@ where
 
         Size::new(size.width, size.height)
     }
+
+    /// Records that a frame of the [`Window`] was presented in the given
+    /// [`Duration`], averaging it with the previous ones.
+    pub fn frame_presented(&mut self, duration: Duration) {
+        self.present_duration = (self.present_duration * 3 + duration) / 4;
+        self.presented_at = Some(Instant::now());
+        self.deferred_until = None;
+    }
 }
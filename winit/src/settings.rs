@@ -1,10 +1,13 @@
 //! Configure your application.
+use crate::core::time::Duration;
 use crate::core::window;
 
 use std::borrow::Cow;
+use std::fmt;
+use std::sync::Arc;
 
 /// The settings of an application.
-#[derive(Debug, Clone, Default)]
+#[derive(Clone, Default)]
 pub struct Settings<Flags> {
     /// The identifier of the application.
     ///
@@ -22,4 +25,102 @@ pub struct Settings<Flags> {
 
     /// The fonts to load on boot.
     pub fonts: Vec<Cow<'static, [u8]>>,
+
+    /// The maximum amount of pending messages to process right before drawing
+    /// a window.
+    ///
+    /// When set, messages that arrive while a redraw is pending are fed to the
+    /// application before the window is drawn, instead of waiting for the next
+    /// iteration of the event loop. The limit bounds the latency of the first
+    /// visible update under heavy load.
+    ///
+    /// By default, it is disabled.
+    pub batch_messages_before_draw: Option<usize>,
+
+    /// Whether to collapse consecutive [`CursorMoved`] events into the latest
+    /// position before they are processed.
+    ///
+    /// This keeps the application responsive with high polling rate mice.
+    /// Windows that need every sample, like the canvas of a drawing
+    /// application, can opt out with [`window::set_precise_mouse_motion`].
+    ///
+    /// By default, it is disabled.
+    ///
+    /// [`CursorMoved`]: crate::core::mouse::Event::CursorMoved
+    /// [`window::set_precise_mouse_motion`]: crate::runtime::window::set_precise_mouse_motion
+    pub coalesce_mouse_motion: bool,
+
+    /// The longest time a single iteration of the event loop may take before
+    /// a warning is logged, naming the phase of the application that stalled
+    /// it, like [`update`] or drawing.
+    ///
+    /// This helps finding what freezes the user interface during development.
+    ///
+    /// By default, it is disabled.
+    ///
+    /// [`update`]: crate::Application::update
+    pub frame_budget: Option<Duration>,
+
+    /// A callback invoked with every raw [`winit::event::WindowEvent`]
+    /// received by the application, before it is converted.
+    ///
+    /// This is meant for debugging and logging purposes only. The callback
+    /// runs inside the event loop, so it must be cheap to avoid slowing it
+    /// down.
+    ///
+    /// By default, it is disabled.
+    #[allow(clippy::type_complexity)]
+    pub on_raw_event:
+        Option<Arc<dyn Fn(&winit::event::WindowEvent) + Send + Sync>>,
+}
+
+impl<Flags> fmt::Debug for Settings<Flags>
+where
+    Flags: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Settings")
+            .field("id", &self.id)
+            .field("window", &self.window)
+            .field("flags", &self.flags)
+            .field("fonts", &self.fonts)
+            .field(
+                "batch_messages_before_draw",
+                &self.batch_messages_before_draw,
+            )
+            .field("coalesce_mouse_motion", &self.coalesce_mouse_motion)
+            .field("frame_budget", &self.frame_budget)
+            .field("on_raw_event", &self.on_raw_event.is_some())
+            .finish()
+    }
+}
+
+/// The options of a window that are handled by the shell after the window is
+/// created.
+///
+/// See [`window::Settings`] for the meaning of each option.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct WindowOptions {
+    pub visible: bool,
+    pub wait_for_fonts: bool,
+    pub exit_on_close_request: bool,
+    pub keep_cache_when_hidden: bool,
+    pub auto_reconfigure_surface: bool,
+    pub resize_border: u32,
+    pub level: window::Level,
+}
+
+impl WindowOptions {
+    /// Returns the [`WindowOptions`] of the given [`window::Settings`].
+    pub fn new(settings: &window::Settings) -> Self {
+        Self {
+            visible: settings.visible,
+            wait_for_fonts: settings.wait_for_fonts,
+            exit_on_close_request: settings.exit_on_close_request,
+            keep_cache_when_hidden: settings.keep_cache_when_hidden,
+            auto_reconfigure_surface: settings.auto_reconfigure_surface,
+            resize_border: settings.resize_border,
+            level: settings.level,
+        }
+    }
 }
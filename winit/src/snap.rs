@@ -0,0 +1,120 @@
+//! Snap windows to regions of their monitor.
+use crate::core::window::SnapPosition;
+use crate::core::{Padding, Rectangle};
+
+/// Moves and resizes the given `window` to the [`SnapPosition`] of the work
+/// area of its current monitor.
+///
+/// The decorations of the window are kept inside of the snapped region,
+/// while its client-side shadows, described by the given logical
+/// `frame_extents`, are left outside of it.
+pub(crate) fn snap(
+    window: &winit::window::Window,
+    position: SnapPosition,
+    frame_extents: Padding,
+) {
+    let Some(area) = work_area(window) else {
+        return;
+    };
+
+    let bounds = position.bounds(area);
+
+    let scale_factor = window.scale_factor() as f32;
+    let outer_size = window.outer_size();
+    let inner_size = window.inner_size();
+
+    let decorations = (
+        outer_size.width.saturating_sub(inner_size.width) as f32,
+        outer_size.height.saturating_sub(inner_size.height) as f32,
+    );
+
+    let shadows = Padding {
+        top: frame_extents.top * scale_factor,
+        right: frame_extents.right * scale_factor,
+        bottom: frame_extents.bottom * scale_factor,
+        left: frame_extents.left * scale_factor,
+    };
+
+    if window.is_maximized() {
+        window.set_maximized(false);
+    }
+
+    let position = winit::dpi::PhysicalPosition::new(
+        (bounds.x - shadows.left).round() as i32,
+        (bounds.y - shadows.top).round() as i32,
+    );
+
+    let size = winit::dpi::PhysicalSize::new(
+        (bounds.width - decorations.0 + shadows.horizontal())
+            .max(1.0)
+            .round() as u32,
+        (bounds.height - decorations.1 + shadows.vertical())
+            .max(1.0)
+            .round() as u32,
+    );
+
+    // Moving and resizing the window separately makes it visibly jump
+    // around, so we use the native APIs to do both at once when possible
+    #[cfg(any(target_os = "macos", target_os = "windows"))]
+    {
+        let outer_frame = Rectangle {
+            x: position.x as f32,
+            y: position.y as f32,
+            width: size.width as f32 + decorations.0,
+            height: size.height as f32 + decorations.1,
+        };
+
+        #[cfg(target_os = "macos")]
+        if crate::macos::set_outer_frame(window, outer_frame) {
+            return;
+        }
+
+        #[cfg(target_os = "windows")]
+        if crate::windows::set_outer_frame(window, outer_frame) {
+            return;
+        }
+    }
+
+    #[cfg(all(target_os = "linux", feature = "x11"))]
+    if crate::x11::move_resize(window, position, size) {
+        return;
+    }
+
+    window.set_outer_position(position);
+    let _ = window.request_inner_size(size);
+}
+
+/// Returns the area of the current monitor of the given `window` that is
+/// not covered by panels or taskbars, in physical pixels.
+///
+/// The whole monitor is used if its work area cannot be determined.
+pub(crate) fn work_area(window: &winit::window::Window) -> Option<Rectangle> {
+    let monitor = window.current_monitor()?;
+
+    let position = monitor.position();
+    let size = monitor.size();
+
+    let bounds = Rectangle {
+        x: position.x as f32,
+        y: position.y as f32,
+        width: size.width as f32,
+        height: size.height as f32,
+    };
+
+    #[cfg(target_os = "macos")]
+    if let Some(work_area) = crate::macos::work_area(window) {
+        return Some(work_area);
+    }
+
+    #[cfg(target_os = "windows")]
+    if let Some(work_area) = crate::windows::work_area(window) {
+        return Some(work_area);
+    }
+
+    #[cfg(all(target_os = "linux", feature = "x11"))]
+    if let Some(work_area) = crate::x11::work_area(window) {
+        return bounds.intersection(&work_area).or(Some(bounds));
+    }
+
+    Some(bounds)
+}
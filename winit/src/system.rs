@@ -1,7 +1,24 @@
 //! Access the native system.
+use crate::core::keyboard::shortcut::{self, Shortcut};
+use crate::core::time::Duration;
+use crate::core::window::{Id, Monitor};
+use crate::core::Event;
 use crate::graphics::compositor;
 use crate::runtime::command::{self, Command};
-use crate::runtime::system::{Action, Information};
+use crate::runtime::system::query;
+pub use crate::runtime::system::{
+    fetch_disk_info_cancellable, fetch_double_click_interval_cancellable,
+    fetch_information_cancellable, fetch_key_repeat_cancellable,
+    fetch_process_info_cancellable, fetch_window_manager_name_cancellable,
+};
+use crate::runtime::system::{
+    Action, Backend, DiskInfo, DiskInfoError, GlobalShortcutError, Information,
+    KeyRepeat, MonitorLayout, ProcessInfo, RenderStats,
+};
+use crate::runtime::window::Screenshot;
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
 /// Query for available system information.
 pub fn fetch_information<Message>(
@@ -12,30 +29,318 @@ pub fn fetch_information<Message>(
     )))
 }
 
+/// Query for the double click interval of the system.
+pub fn fetch_double_click_interval<Message>(
+    f: impl FnOnce(Duration) -> Message + Send + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::System(Action::QueryDoubleClickInterval(
+        Box::new(f),
+    )))
+}
+
+/// Query for the [`RenderStats`] of the last frame drawn in the window with
+/// the given [`Id`].
+///
+/// The result is `None` if the renderer does not keep track of them.
+pub fn fetch_render_stats<Message>(
+    id: Id,
+    f: impl FnOnce(Option<RenderStats>) -> Message + Send + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::System(Action::GetRenderStats(
+        id,
+        Box::new(f),
+    )))
+}
+
+/// Produces a message as soon as the next keyboard, mouse, or touch input of
+/// the user is received.
+///
+/// Combined with a timer, this can be used to detect idleness.
+pub fn await_interaction<Message>(
+    f: impl FnOnce() -> Message + Send + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::System(Action::AwaitInteraction(
+        Box::new(f),
+    )))
+}
+
+/// Query for the graphics [`Backend`] currently used to render the windows
+/// of the application.
+///
+/// The result is `None` if the backend cannot be determined.
+pub fn fetch_active_backend<Message>(
+    f: impl FnOnce(Option<Backend>) -> Message + Send + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::System(Action::GetActiveBackend(
+        Box::new(f),
+    )))
+}
+
+/// Query whether the windows of the application are rendered with a
+/// hardware-accelerated graphics adapter.
+///
+/// Only discrete and integrated GPUs are considered hardware-accelerated, so
+/// expensive effects can be disabled on virtualized GPUs or software
+/// rasterizers.
+pub fn fetch_hardware_acceleration<Message>(
+    f: impl FnOnce(bool) -> Message + Send + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::System(Action::IsHardwareAccelerated(
+        Box::new(f),
+    )))
+}
+
+/// Capture a [`Screenshot`] of every visible window of the application, for
+/// instance to show thumbnails in a window switcher.
+///
+/// Every window is rendered again and read back synchronously, so this should
+/// not be used on every frame.
+pub fn capture_all_windows<Message>(
+    f: impl FnOnce(HashMap<Id, Screenshot>) -> Message + Send + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::System(Action::CaptureAllWindows(
+        Box::new(f),
+    )))
+}
+
+/// Query for the name of the window manager or compositor the application is
+/// running under.
+///
+/// The result is `None` if it cannot be detected.
+pub fn fetch_window_manager_name<Message>(
+    f: impl FnOnce(Option<String>) -> Message + Send + 'static,
+) -> Command<Message> {
+    fetch_window_manager_name_cancellable(f).0
+}
+
+/// Query for the key repeat settings of the system.
+pub fn fetch_key_repeat<Message>(
+    f: impl FnOnce(KeyRepeat) -> Message + Send + 'static,
+) -> Command<Message> {
+    fetch_key_repeat_cancellable(f).0
+}
+
+/// Register a [`Shortcut`] that is triggered even when none of the windows
+/// of the application have focus.
+///
+/// Every time the [`Shortcut`] is pressed, an [`Event::GlobalShortcut`] with
+/// the returned [`shortcut::Id`] is produced.
+///
+/// [`Event::GlobalShortcut`]: crate::core::Event::GlobalShortcut
+pub fn register_global_shortcut<Message>(
+    shortcut: Shortcut,
+    f: impl FnOnce(Result<shortcut::Id, GlobalShortcutError>) -> Message
+        + Send
+        + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::System(Action::RegisterGlobalShortcut(
+        shortcut,
+        Box::new(f),
+    )))
+}
+
+/// Unregister the global [`Shortcut`] with the given [`shortcut::Id`].
+pub fn unregister_global_shortcut<Message>(
+    id: shortcut::Id,
+) -> Command<Message> {
+    Command::single(command::Action::System(Action::UnregisterGlobalShortcut(
+        id,
+    )))
+}
+
+/// Query for the [`MonitorLayout`] of the virtual desktop, which positions
+/// every monitor in a unified coordinate space.
+pub fn fetch_monitor_layout<Message>(
+    f: impl FnOnce(MonitorLayout) -> Message + Send + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::System(Action::GetMonitorLayout(
+        Box::new(f),
+    )))
+}
+
+/// Query for the scale factor of the given [`Monitor`].
+///
+/// The result is `None` if the [`Monitor`] is not connected anymore.
+pub fn fetch_monitor_scale_factor<Message>(
+    monitor: Monitor,
+    f: impl FnOnce(Option<f64>) -> Message + Send + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::System(Action::GetMonitorScaleFactor(
+        monitor,
+        Box::new(f),
+    )))
+}
+
+/// Query for every [`Monitor`] of the desktop with its scale factor,
+/// independently of any window.
+///
+/// Fractional scale factors, like `1.25` or `1.5`, are reported as is.
+pub fn fetch_desktop_scaling<Message>(
+    f: impl FnOnce(Vec<Monitor>) -> Message + Send + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::System(Action::GetDesktopScaling(
+        Box::new(f),
+    )))
+}
+
+/// Query for the family names of the installed fonts, deduplicated and
+/// sorted alphabetically.
+///
+/// This can be used to populate a font picker.
+pub fn fetch_system_fonts<Message>(
+    f: impl FnOnce(Vec<String>) -> Message + Send + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::System(Action::GetSystemFonts(Box::new(
+        f,
+    ))))
+}
+
+/// Query for the [`ProcessInfo`] of the current process, such as its uptime
+/// and memory usage.
+pub fn fetch_process_info<Message>(
+    f: impl FnOnce(ProcessInfo) -> Message + Send + 'static,
+) -> Command<Message> {
+    fetch_process_info_cancellable(f).0
+}
+
+/// Query for the [`DiskInfo`] of the filesystem containing the given `path`,
+/// such as its available space.
+///
+/// If the `path` does not exist, its nearest existing ancestor is used.
+pub fn fetch_disk_info<Message>(
+    path: impl Into<PathBuf>,
+    f: impl FnOnce(Result<DiskInfo, DiskInfoError>) -> Message + Send + 'static,
+) -> Command<Message> {
+    fetch_disk_info_cancellable(path, f).0
+}
+
+/// Injects the given [`Event`] into the window with the given [`Id`], as if it
+/// had been received from the system.
+///
+/// Inject a [`mouse::Event::CursorMoved`] first to deliver mouse buttons at a
+/// specific position.
+///
+/// [`mouse::Event::CursorMoved`]: crate::core::mouse::Event::CursorMoved
+pub fn inject_event<Message>(id: Id, event: Event) -> Command<Message> {
+    Command::single(command::Action::System(Action::InjectEvent(id, event)))
+}
+
 pub(crate) fn information(
     graphics_info: compositor::Information,
 ) -> Information {
-    use sysinfo::{CpuExt, ProcessExt, System, SystemExt};
-    let mut system = System::new_all();
-    system.refresh_all();
-
-    let cpu = system.global_cpu_info();
-
-    let memory_used = sysinfo::get_current_pid()
-        .and_then(|pid| system.process(pid).ok_or("Process not found"))
-        .map(ProcessExt::memory)
-        .ok();
-
-    Information {
-        system_name: system.name(),
-        system_kernel: system.kernel_version(),
-        system_version: system.long_os_version(),
-        system_short_version: system.os_version(),
-        cpu_brand: cpu.brand().into(),
-        cpu_cores: system.physical_core_count(),
-        memory_total: system.total_memory(),
-        memory_used,
-        graphics_adapter: graphics_info.adapter,
-        graphics_backend: graphics_info.backend,
+    query::information(graphics_info.adapter, graphics_info.backend)
+}
+
+pub(crate) fn process_info() -> ProcessInfo {
+    query::process_info()
+}
+
+pub(crate) fn disk_info(path: &Path) -> Result<DiskInfo, DiskInfoError> {
+    query::disk_info(path)
+}
+
+pub(crate) fn double_click_interval() -> Duration {
+    #[cfg(target_os = "windows")]
+    let interval = {
+        #[allow(unsafe_code)]
+        let millis = unsafe { winapi::um::winuser::GetDoubleClickTime() };
+
+        Some(Duration::from_millis(u64::from(millis)))
+    };
+
+    #[cfg(target_os = "macos")]
+    let interval = Some(crate::macos::double_click_interval());
+
+    #[cfg(target_os = "linux")]
+    let interval = query::gtk_double_click_interval();
+
+    #[cfg(not(any(
+        target_os = "windows",
+        target_os = "macos",
+        target_os = "linux"
+    )))]
+    let interval = None;
+
+    interval.unwrap_or(query::DEFAULT_DOUBLE_CLICK_INTERVAL)
+}
+
+pub(crate) fn key_repeat() -> KeyRepeat {
+    #[cfg(target_os = "windows")]
+    let key_repeat = {
+        use winapi::um::winuser::{
+            SystemParametersInfoW, SPI_GETKEYBOARDDELAY, SPI_GETKEYBOARDSPEED,
+        };
+
+        let parameter = |action| {
+            let mut value: u32 = 0;
+
+            #[allow(unsafe_code)]
+            let result = unsafe {
+                SystemParametersInfoW(
+                    action,
+                    0,
+                    std::ptr::addr_of_mut!(value).cast(),
+                    0,
+                )
+            };
+
+            (result != 0).then_some(value)
+        };
+
+        // The delay ranges from 0 (250 ms) to 3 (1 s) and the speed from
+        // 0 (~2.5 repeats per second) to 31 (~30 repeats per second)
+        parameter(SPI_GETKEYBOARDDELAY)
+            .zip(parameter(SPI_GETKEYBOARDSPEED))
+            .map(|(delay, speed)| KeyRepeat {
+                delay: Duration::from_millis(250 * (u64::from(delay) + 1)),
+                interval: Duration::from_secs_f64(
+                    1.0 / (2.5 + f64::from(speed) * 27.5 / 31.0),
+                ),
+            })
+    };
+
+    #[cfg(target_os = "macos")]
+    let key_repeat = Some(crate::macos::key_repeat());
+
+    #[cfg(target_os = "linux")]
+    let key_repeat = {
+        // Wayland sessions may run XWayland, whose XKB controls do not
+        // reflect the settings of the compositor
+        #[cfg(feature = "wayland")]
+        let key_repeat = crate::wayland::key_repeat();
+
+        #[cfg(not(feature = "wayland"))]
+        let key_repeat = None;
+
+        #[cfg(feature = "x11")]
+        let key_repeat = key_repeat.or_else(crate::x11::key_repeat);
+
+        key_repeat
+    };
+
+    #[cfg(not(any(
+        target_os = "windows",
+        target_os = "macos",
+        target_os = "linux"
+    )))]
+    let key_repeat = None;
+
+    key_repeat.unwrap_or(query::DEFAULT_KEY_REPEAT)
+}
+
+#[cfg(target_os = "linux")]
+pub(crate) fn window_manager_name() -> Option<String> {
+    #[cfg(feature = "x11")]
+    if std::env::var_os("WAYLAND_DISPLAY").is_none() {
+        if let Some(name) = crate::x11::window_manager_name() {
+            return Some(name);
+        }
     }
+
+    query::desktop_name()
+}
+
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn window_manager_name() -> Option<String> {
+    None
 }
@@ -0,0 +1,65 @@
+//! Detect stalls of the event loop.
+use crate::core::time::{Duration, Instant};
+
+/// Warns when a single iteration of the event loop exceeds a frame budget.
+///
+/// Only a timestamp is taken and compared per iteration, so it is cheap
+/// enough to be enabled during development.
+#[derive(Debug)]
+pub(crate) struct Watchdog {
+    budget: Option<Duration>,
+    iteration: Option<(Instant, &'static str)>,
+}
+
+impl Watchdog {
+    /// Creates a new [`Watchdog`] with the given frame budget, which is
+    /// disabled when `None`.
+    pub fn new(budget: Option<Duration>) -> Self {
+        Self {
+            budget,
+            iteration: None,
+        }
+    }
+
+    /// Starts timing an iteration of the event loop spent in the given phase.
+    pub fn start(&mut self, phase: &'static str) {
+        if self.budget.is_some() {
+            self.iteration = Some((Instant::now(), phase));
+        }
+    }
+
+    /// Finishes timing the current iteration of the event loop, warning if it
+    /// exceeded the frame budget.
+    pub fn finish(&mut self) {
+        let (Some(budget), Some((started_at, phase))) =
+            (self.budget, self.iteration.take())
+        else {
+            return;
+        };
+
+        let elapsed = started_at.elapsed();
+
+        if elapsed > budget {
+            log::warn!(
+                "The event loop stalled for {elapsed:?} while {phase}, \
+                exceeding the frame budget of {budget:?}"
+            );
+        }
+    }
+}
+
+/// Returns the phase of the application that handles the given event.
+pub(crate) fn phase<T>(event: &winit::event::Event<T>) -> &'static str {
+    use winit::event::{Event, WindowEvent};
+
+    match event {
+        Event::AboutToWait => "updating the application",
+        Event::WindowEvent {
+            event: WindowEvent::RedrawRequested,
+            ..
+        } => "drawing a window",
+        Event::WindowEvent { .. } => "handling a window event",
+        Event::UserEvent(_) => "handling a user event",
+        _ => "handling an event",
+    }
+}
@@ -0,0 +1,155 @@
+//! Query the Wayland compositor of the session.
+//!
+//! winit does not expose its Wayland connection, so every query opens a
+//! short-lived connection of its own.
+use wayland_client::globals::{
+    registry_queue_init, GlobalList, GlobalListContents,
+};
+use wayland_client::protocol::wl_registry;
+use wayland_client::{Connection, Dispatch, EventQueue, QueueHandle};
+use wayland_protocols::xdg::system_bell::v1::client::xdg_system_bell_v1::XdgSystemBellV1;
+
+#[cfg(feature = "system")]
+use wayland_client::protocol::{wl_keyboard, wl_seat};
+#[cfg(feature = "system")]
+use wayland_client::WEnum;
+
+/// Rings the bell of the compositor through the `xdg_system_bell_v1`
+/// protocol, which plays the alert sound configured by the desktop.
+///
+/// Returns `false` if there is no compositor to connect to or it does not
+/// support the protocol.
+pub(crate) fn bell() -> bool {
+    let Some((connection, globals, queue)) = connect() else {
+        return false;
+    };
+
+    let Ok(bell) =
+        globals.bind::<XdgSystemBellV1, _, _>(&queue.handle(), 1..=1, ())
+    else {
+        return false;
+    };
+
+    bell.ring(None);
+    bell.destroy();
+
+    connection
+        .flush()
+        .map_err(|error| log::warn!("Failed to ring the bell: {error}"))
+        .is_ok()
+}
+
+/// Returns the key repeat settings the compositor sends to the keyboard of
+/// the first seat.
+///
+/// Returns `None` if there is no compositor to connect to, the seat has no
+/// keyboard, or key repeat is disabled.
+#[cfg(feature = "system")]
+pub(crate) fn key_repeat() -> Option<crate::runtime::system::KeyRepeat> {
+    let (_connection, globals, mut queue) = connect()?;
+    let handle = queue.handle();
+    let mut state = State::default();
+
+    // `repeat_info` was introduced in version 4 of `wl_keyboard`
+    let seat = globals
+        .bind::<wl_seat::WlSeat, _, _>(&handle, 4..=4, ())
+        .ok()?;
+    let _ = queue
+        .roundtrip(&mut state)
+        .map_err(|error| log::warn!("Failed to query the seat: {error}"))
+        .ok()?;
+
+    match state.capabilities? {
+        WEnum::Value(capabilities)
+            if capabilities.contains(wl_seat::Capability::Keyboard) => {}
+        _ => return None,
+    }
+
+    let keyboard = seat.get_keyboard(&handle, ());
+    let _ = queue
+        .roundtrip(&mut state)
+        .map_err(|error| log::warn!("Failed to query the keyboard: {error}"))
+        .ok()?;
+    keyboard.release();
+
+    state.key_repeat
+}
+
+/// Connects to the compositor of the session and lists its globals.
+///
+/// Returns `None` if there is no compositor to connect to.
+fn connect() -> Option<(Connection, GlobalList, EventQueue<State>)> {
+    let connection = Connection::connect_to_env().ok()?;
+
+    let (globals, queue) = registry_queue_init::<State>(&connection)
+        .map_err(|error| {
+            log::warn!("Failed to list the Wayland globals: {error}");
+        })
+        .ok()?;
+
+    Some((connection, globals, queue))
+}
+
+#[derive(Default)]
+struct State {
+    #[cfg(feature = "system")]
+    capabilities: Option<WEnum<wl_seat::Capability>>,
+    #[cfg(feature = "system")]
+    key_repeat: Option<crate::runtime::system::KeyRepeat>,
+}
+
+impl Dispatch<wl_registry::WlRegistry, GlobalListContents> for State {
+    fn event(
+        _state: &mut Self,
+        _registry: &wl_registry::WlRegistry,
+        _event: wl_registry::Event,
+        _data: &GlobalListContents,
+        _connection: &Connection,
+        _handle: &QueueHandle<Self>,
+    ) {
+    }
+}
+
+#[cfg(feature = "system")]
+impl Dispatch<wl_seat::WlSeat, ()> for State {
+    fn event(
+        state: &mut Self,
+        _seat: &wl_seat::WlSeat,
+        event: wl_seat::Event,
+        _data: &(),
+        _connection: &Connection,
+        _handle: &QueueHandle<Self>,
+    ) {
+        if let wl_seat::Event::Capabilities { capabilities } = event {
+            state.capabilities = Some(capabilities);
+        }
+    }
+}
+
+#[cfg(feature = "system")]
+impl Dispatch<wl_keyboard::WlKeyboard, ()> for State {
+    fn event(
+        state: &mut Self,
+        _keyboard: &wl_keyboard::WlKeyboard,
+        event: wl_keyboard::Event,
+        _data: &(),
+        _connection: &Connection,
+        _handle: &QueueHandle<Self>,
+    ) {
+        use std::time::Duration;
+
+        if let wl_keyboard::Event::RepeatInfo { rate, delay } = event {
+            // A rate of zero disables key repeat
+            state.key_repeat = u32::try_from(rate)
+                .ok()
+                .filter(|rate| *rate > 0)
+                .zip(u64::try_from(delay).ok())
+                .map(|(rate, delay)| crate::runtime::system::KeyRepeat {
+                    delay: Duration::from_millis(delay),
+                    interval: Duration::from_secs_f64(1.0 / f64::from(rate)),
+                });
+        }
+    }
+}
+
+wayland_client::delegate_noop!(State: XdgSystemBellV1);
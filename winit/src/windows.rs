@@ -0,0 +1,152 @@
+//! Platform-specific helpers for Windows.
+use crate::core::window::BackdropMaterial;
+use crate::core::Rectangle;
+
+use winapi::shared::minwindef::{BOOL, DWORD};
+use winapi::shared::windef::HWND;
+use winapi::um::dwmapi::{
+    DwmSetWindowAttribute, DWMWA_TRANSITIONS_FORCEDISABLED,
+};
+use winit::raw_window_handle::{HasWindowHandle, RawWindowHandle};
+
+/// The `DWMWA_SYSTEMBACKDROP_TYPE` attribute of the DWM, available since
+/// Windows 11 build 22621.
+const DWMWA_SYSTEMBACKDROP_TYPE: DWORD = 38;
+
+/// Sets the system backdrop material drawn by the DWM behind the given
+/// `window`.
+pub(crate) fn set_backdrop(
+    window: &winit::window::Window,
+    material: BackdropMaterial,
+) {
+    let Some(hwnd) = hwnd(window) else {
+        return;
+    };
+
+    // The values of `DWM_SYSTEMBACKDROP_TYPE`
+    let backdrop: DWORD = match material {
+        BackdropMaterial::None => 1,
+        BackdropMaterial::Mica => 2,
+        BackdropMaterial::Acrylic => 3,
+        BackdropMaterial::Tabbed => 4,
+    };
+
+    #[allow(unsafe_code)]
+    let result = unsafe {
+        DwmSetWindowAttribute(
+            hwnd,
+            DWMWA_SYSTEMBACKDROP_TYPE,
+            (&backdrop as *const DWORD).cast(),
+            std::mem::size_of::<DWORD>() as DWORD,
+        )
+    };
+
+    if result < 0 {
+        log::warn!(
+            "Failed to set the backdrop material of the window \
+            (HRESULT {result:#x}); backdrop materials require Windows 11"
+        );
+    }
+}
+
+/// Enables or disables the transitions animated by the DWM for the given
+/// `window`, like the one shown when it is minimized or restored.
+pub(crate) fn set_minimize_animation(
+    window: &winit::window::Window,
+    enabled: bool,
+) {
+    let Some(hwnd) = hwnd(window) else {
+        return;
+    };
+
+    let disabled = BOOL::from(!enabled);
+
+    #[allow(unsafe_code)]
+    let result = unsafe {
+        DwmSetWindowAttribute(
+            hwnd,
+            DWMWA_TRANSITIONS_FORCEDISABLED,
+            (&disabled as *const BOOL).cast(),
+            std::mem::size_of::<BOOL>() as DWORD,
+        )
+    };
+
+    if result < 0 {
+        log::warn!(
+            "Failed to toggle the minimize animation of the window \
+            (HRESULT {result:#x})"
+        );
+    }
+}
+
+/// Returns the area of the monitor of the given `window` that is not covered
+/// by the taskbar, in physical pixels.
+pub(crate) fn work_area(window: &winit::window::Window) -> Option<Rectangle> {
+    use winapi::um::winuser::{
+        GetMonitorInfoW, MonitorFromWindow, MONITORINFO,
+        MONITOR_DEFAULTTONEAREST,
+    };
+
+    let hwnd = hwnd(window)?;
+
+    #[allow(unsafe_code)]
+    let rect = unsafe {
+        let monitor = MonitorFromWindow(hwnd, MONITOR_DEFAULTTONEAREST);
+
+        let mut info: MONITORINFO = std::mem::zeroed();
+        info.cbSize = std::mem::size_of::<MONITORINFO>() as DWORD;
+
+        if GetMonitorInfoW(monitor, &mut info) == 0 {
+            return None;
+        }
+
+        info.rcWork
+    };
+
+    Some(Rectangle {
+        x: rect.left as f32,
+        y: rect.top as f32,
+        width: (rect.right - rect.left) as f32,
+        height: (rect.bottom - rect.top) as f32,
+    })
+}
+
+/// Moves and resizes the given `window` at once, so that its outer frame
+/// covers the given `bounds`, in physical pixels.
+///
+/// Returns `false` if the `window` is not a Win32 window.
+pub(crate) fn set_outer_frame(
+    window: &winit::window::Window,
+    bounds: Rectangle,
+) -> bool {
+    use winapi::um::winuser::{SetWindowPos, SWP_NOACTIVATE, SWP_NOZORDER};
+
+    let Some(hwnd) = hwnd(window) else {
+        return false;
+    };
+
+    #[allow(unsafe_code)]
+    let result = unsafe {
+        SetWindowPos(
+            hwnd,
+            std::ptr::null_mut(),
+            bounds.x.round() as i32,
+            bounds.y.round() as i32,
+            bounds.width.round() as i32,
+            bounds.height.round() as i32,
+            SWP_NOZORDER | SWP_NOACTIVATE,
+        )
+    };
+
+    result != 0
+}
+
+/// Returns the handle of the given `window`, if it is a Win32 window.
+fn hwnd(window: &winit::window::Window) -> Option<HWND> {
+    let handle = window.window_handle().ok()?;
+
+    match handle.as_raw() {
+        RawWindowHandle::Win32(handle) => Some(handle.hwnd.get() as HWND),
+        _ => None,
+    }
+}
@@ -0,0 +1,503 @@
+//! Platform-specific helpers for X11.
+use crate::core::{mouse, Padding, Rectangle};
+
+use std::sync::OnceLock;
+use winit::raw_window_handle::{HasWindowHandle, RawWindowHandle};
+use x11rb::connection::Connection;
+use x11rb::errors::{ReplyError, ReplyOrIdError};
+use x11rb::protocol::xproto::{self, AtomEnum, ConnectionExt as _, PropMode};
+use x11rb::rust_connection::RustConnection;
+use x11rb::wrapper::ConnectionExt as _;
+
+/// Sets the `_GTK_FRAME_EXTENTS` property of the given `window` to the given
+/// logical `extents`, scaled by the provided `scale_factor`.
+///
+/// This tells the window manager which area of a client-side decorated
+/// window is used by its shadows, so that it is excluded from placement,
+/// tiling and snapping.
+///
+/// Does nothing if the `window` is not an X11 window.
+pub(crate) fn set_frame_extents(
+    window: &winit::window::Window,
+    extents: Padding,
+    scale_factor: f64,
+) {
+    let Some(id) = window_id(window) else {
+        return;
+    };
+
+    let extents = [extents.left, extents.right, extents.top, extents.bottom]
+        .map(|inset| (f64::from(inset) * scale_factor).round() as u32);
+
+    let result = with_connection(|connection, _| {
+        let property = atom(connection, b"_GTK_FRAME_EXTENTS")?;
+
+        let _ = connection.change_property32(
+            PropMode::REPLACE,
+            id,
+            property,
+            AtomEnum::CARDINAL,
+            &extents,
+        )?;
+
+        Ok(())
+    });
+
+    if let Some(Err(error)) = result {
+        log::warn!("Failed to set _GTK_FRAME_EXTENTS: {error}");
+    }
+}
+
+/// Sets the `WM_WINDOW_ROLE` property of the given `window`, which is used
+/// by session managers to identify the window when restoring a session.
+///
+/// Does nothing if the `window` is not an X11 window.
+pub(crate) fn set_window_role(window: &winit::window::Window, role: &str) {
+    let Some(id) = window_id(window) else {
+        return;
+    };
+
+    let result = with_connection(|connection, _| {
+        let property = atom(connection, b"WM_WINDOW_ROLE")?;
+
+        let _ = connection.change_property8(
+            PropMode::REPLACE,
+            id,
+            property,
+            AtomEnum::STRING,
+            role.as_bytes(),
+        )?;
+
+        Ok(())
+    });
+
+    if let Some(Err(error)) = result {
+        log::warn!("Failed to set WM_WINDOW_ROLE: {error}");
+    }
+}
+
+/// Moves the given `window` so that its frame is placed at the given
+/// `position` and resizes its client area to the given `size` at once, in
+/// physical pixels, by sending a `_NET_MOVERESIZE_WINDOW` request to the
+/// window manager.
+///
+/// Returns `false` if the `window` is not an X11 window or the request
+/// could not be sent.
+pub(crate) fn move_resize(
+    window: &winit::window::Window,
+    position: winit::dpi::PhysicalPosition<i32>,
+    size: winit::dpi::PhysicalSize<u32>,
+) -> bool {
+    let Some(id) = window_id(window) else {
+        return false;
+    };
+
+    // `NorthWestGravity`, with `x`, `y`, `width` and `height` present and
+    // the request coming from a normal application
+    const FLAGS: u32 = 1 | (0b1111 << 8) | (1 << 12);
+
+    let result = with_connection(|connection, screen| {
+        let root = connection.setup().roots[screen].root;
+        let property = atom(connection, b"_NET_MOVERESIZE_WINDOW")?;
+
+        let event = xproto::ClientMessageEvent::new(
+            32,
+            id,
+            property,
+            [
+                FLAGS,
+                position.x as u32,
+                position.y as u32,
+                size.width,
+                size.height,
+            ],
+        );
+
+        let _ = connection.send_event(
+            false,
+            root,
+            xproto::EventMask::SUBSTRUCTURE_REDIRECT
+                | xproto::EventMask::SUBSTRUCTURE_NOTIFY,
+            event,
+        )?;
+
+        Ok(())
+    });
+
+    match result {
+        Some(Ok(())) => true,
+        Some(Err(error)) => {
+            log::warn!("Failed to send _NET_MOVERESIZE_WINDOW: {error}");
+            false
+        }
+        None => false,
+    }
+}
+
+/// Replaces the cursor of the given `window` with the given custom `cursor`,
+/// using the RENDER extension to keep the alpha channel of its image.
+///
+/// Returns `false` if the `window` is not an X11 window or the cursor could
+/// not be created.
+pub(crate) fn set_custom_cursor(
+    window: &winit::window::Window,
+    cursor: &mouse::CustomCursor,
+) -> bool {
+    use x11rb::protocol::render::{self, ConnectionExt as _, PictType};
+
+    let Some(id) = window_id(window) else {
+        return false;
+    };
+
+    let size = cursor.size();
+    let hotspot = cursor.hotspot();
+
+    let result = with_connection(|connection, _| {
+        let _ = connection.render_query_version(0, 5)?.reply()?;
+
+        let Some(format) = connection
+            .render_query_pict_formats()?
+            .reply()?
+            .formats
+            .iter()
+            .find(|format| {
+                format.type_ == PictType::DIRECT
+                    && format.depth == 32
+                    && format.direct.alpha_shift == 24
+                    && format.direct.red_shift == 16
+                    && format.direct.green_shift == 8
+                    && format.direct.blue_shift == 0
+            })
+            .map(|format| format.id)
+        else {
+            return Ok(false);
+        };
+
+        let image = premultiplied_argb(
+            cursor.rgba(),
+            connection.setup().image_byte_order
+                == xproto::ImageOrder::MSB_FIRST,
+        );
+
+        let pixmap = connection.generate_id()?;
+        let gc = connection.generate_id()?;
+        let picture = connection.generate_id()?;
+        let cursor = connection.generate_id()?;
+
+        let _ = connection.create_pixmap(
+            32,
+            pixmap,
+            id,
+            size.width as u16,
+            size.height as u16,
+        )?;
+        let _ =
+            connection.create_gc(gc, pixmap, &xproto::CreateGCAux::new())?;
+        let _ = connection.put_image(
+            xproto::ImageFormat::Z_PIXMAP,
+            pixmap,
+            gc,
+            size.width as u16,
+            size.height as u16,
+            0,
+            0,
+            0,
+            32,
+            &image,
+        )?;
+        let _ = connection.render_create_picture(
+            picture,
+            pixmap,
+            format,
+            &render::CreatePictureAux::new(),
+        )?;
+        let _ = connection.render_create_cursor(
+            cursor,
+            picture,
+            hotspot.x as u16,
+            hotspot.y as u16,
+        )?;
+        let _ = connection.change_window_attributes(
+            id,
+            &xproto::ChangeWindowAttributesAux::new().cursor(cursor),
+        )?;
+
+        // The window keeps its own reference to the cursor
+        let _ = connection.free_cursor(cursor)?;
+        let _ = connection.render_free_picture(picture)?;
+        let _ = connection.free_gc(gc)?;
+        let _ = connection.free_pixmap(pixmap)?;
+
+        Ok(true)
+    });
+
+    match result {
+        Some(Ok(applied)) => {
+            if !applied {
+                log::warn!(
+                    "Failed to set the custom cursor: the X server has no \
+                    32-bit ARGB picture format"
+                );
+            }
+
+            applied
+        }
+        Some(Err(error)) => {
+            log::warn!("Failed to set the custom cursor: {error}");
+            false
+        }
+        None => false,
+    }
+}
+
+/// Converts the given RGBA pixels to the premultiplied 32-bit ARGB pixels
+/// of the RENDER extension, in the given byte order of the X server.
+fn premultiplied_argb(rgba: &[u8], big_endian: bool) -> Vec<u8> {
+    rgba.chunks_exact(4)
+        .flat_map(|pixel| {
+            let alpha = u32::from(pixel[3]);
+            let premultiply =
+                |channel: u8| ((u32::from(channel) * alpha + 127) / 255) as u8;
+
+            let argb = [
+                pixel[3],
+                premultiply(pixel[0]),
+                premultiply(pixel[1]),
+                premultiply(pixel[2]),
+            ];
+
+            if big_endian {
+                argb
+            } else {
+                [argb[3], argb[2], argb[1], argb[0]]
+            }
+        })
+        .collect()
+}
+
+/// Rings the bell of the X server, which plays the alert sound configured
+/// by the desktop.
+///
+/// Returns `false` if there is no X server to connect to.
+pub(crate) fn bell() -> bool {
+    let result = with_connection(|connection, _| {
+        let _ = connection.bell(0)?;
+
+        Ok(())
+    });
+
+    match result {
+        Some(Ok(())) => true,
+        Some(Err(error)) => {
+            log::warn!("Failed to ring the bell: {error}");
+            false
+        }
+        None => false,
+    }
+}
+
+/// Returns the key repeat settings of the core keyboard of the X server.
+///
+/// Returns `None` if there is no X server to connect to or it does not
+/// support the XKEYBOARD extension.
+#[cfg(feature = "system")]
+pub(crate) fn key_repeat() -> Option<crate::runtime::system::KeyRepeat> {
+    use std::time::Duration;
+    use x11rb::protocol::xkb::{self, ConnectionExt as _};
+
+    let controls = with_connection(|connection, _| {
+        let _ = connection.xkb_use_extension(1, 0)?.reply()?;
+
+        Ok(connection
+            .xkb_get_controls(xkb::ID::USE_CORE_KBD.into())?
+            .reply()?)
+    })?
+    .map_err(|error| log::warn!("Failed to read the XKB controls: {error}"))
+    .ok()?;
+
+    Some(crate::runtime::system::KeyRepeat {
+        delay: Duration::from_millis(u64::from(controls.repeat_delay)),
+        interval: Duration::from_millis(u64::from(controls.repeat_interval)),
+    })
+}
+
+/// Returns the `_NET_WM_NAME` of the window manager advertised through the
+/// `_NET_SUPPORTING_WM_CHECK` property of the root window.
+#[cfg(feature = "system")]
+pub(crate) fn window_manager_name() -> Option<String> {
+    with_connection(|connection, screen| {
+        let root = connection.setup().roots[screen].root;
+
+        let check = atom(connection, b"_NET_SUPPORTING_WM_CHECK")?;
+        let name = atom(connection, b"_NET_WM_NAME")?;
+        let utf8_string = atom(connection, b"UTF8_STRING")?;
+
+        let Some(window) = connection
+            .get_property(false, root, check, AtomEnum::WINDOW, 0, 1)?
+            .reply()?
+            .value32()
+            .and_then(|mut value| value.next())
+        else {
+            return Ok(None);
+        };
+
+        let value = connection
+            .get_property(false, window, name, utf8_string, 0, u32::MAX)?
+            .reply()?
+            .value;
+
+        Ok(String::from_utf8(value)
+            .ok()
+            .filter(|name| !name.is_empty()))
+    })?
+    .map_err(|error| log::warn!("Failed to read _NET_WM_NAME: {error}"))
+    .ok()
+    .flatten()
+}
+
+/// Returns the area of the current desktop that is not covered by panels,
+/// as reported by the `_NET_WORKAREA` property of the X11 window manager, in
+/// physical pixels.
+///
+/// Returns `None` if the `window` is not an X11 window or the window manager
+/// does not report a work area.
+pub(crate) fn work_area(window: &winit::window::Window) -> Option<Rectangle> {
+    let _ = window_id(window)?;
+
+    with_connection(|connection, screen| {
+        let root = connection.setup().roots[screen].root;
+
+        let current_desktop = atom(connection, b"_NET_CURRENT_DESKTOP")?;
+        let work_area = atom(connection, b"_NET_WORKAREA")?;
+
+        let desktop = connection
+            .get_property(
+                false,
+                root,
+                current_desktop,
+                AtomEnum::CARDINAL,
+                0,
+                1,
+            )?
+            .reply()?
+            .value32()
+            .and_then(|mut value| value.next())
+            .unwrap_or(0);
+
+        let work_areas: Vec<u32> = connection
+            .get_property(
+                false,
+                root,
+                work_area,
+                AtomEnum::CARDINAL,
+                0,
+                u32::MAX,
+            )?
+            .reply()?
+            .value32()
+            .map(Iterator::collect)
+            .unwrap_or_default();
+
+        Ok(desktop_work_area(&work_areas, desktop as usize))
+    })?
+    .map_err(|error| log::warn!("Failed to read _NET_WORKAREA: {error}"))
+    .ok()
+    .flatten()
+}
+
+/// Returns the work area of the given `desktop` out of the values of the
+/// `_NET_WORKAREA` property, which lists the `x`, `y`, `width` and `height`
+/// of the work area of every desktop.
+fn desktop_work_area(work_areas: &[u32], desktop: usize) -> Option<Rectangle> {
+    let &[x, y, width, height] =
+        work_areas.get(desktop * 4..desktop * 4 + 4)?
+    else {
+        return None;
+    };
+
+    Some(Rectangle {
+        x: x as f32,
+        y: y as f32,
+        width: width as f32,
+        height: height as f32,
+    })
+}
+
+/// Runs the given closure with the shared connection to the X server and the
+/// index of its default screen.
+///
+/// Returns `None` if there is no X server to connect to.
+fn with_connection<T>(
+    f: impl FnOnce(&RustConnection, usize) -> Result<T, ReplyOrIdError>,
+) -> Option<Result<T, ReplyOrIdError>> {
+    static CONNECTION: OnceLock<Option<(RustConnection, usize)>> =
+        OnceLock::new();
+
+    let (connection, screen) = CONNECTION
+        .get_or_init(|| {
+            x11rb::connect(None)
+                .map_err(|error| {
+                    log::warn!("Failed to connect to the X server: {error}");
+                })
+                .ok()
+        })
+        .as_ref()?;
+
+    let result = f(connection, *screen);
+    let flushed = connection.flush();
+
+    Some(result.and_then(|output| flushed.map(|()| output).map_err(Into::into)))
+}
+
+/// Returns the X11 atom with the given `name`, creating it if needed.
+fn atom(connection: &RustConnection, name: &[u8]) -> Result<u32, ReplyError> {
+    Ok(connection.intern_atom(false, name)?.reply()?.atom)
+}
+
+/// Returns the X11 id of the given `window`, if it is an X11 window.
+fn window_id(window: &winit::window::Window) -> Option<xproto::Window> {
+    let handle = window.window_handle().ok()?;
+
+    match handle.as_raw() {
+        RawWindowHandle::Xlib(handle) => u32::try_from(handle.window).ok(),
+        RawWindowHandle::Xcb(handle) => Some(handle.window.get()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn work_area_of_the_current_desktop() {
+        let work_areas = [0, 0, 1920, 1080, 0, 32, 1920, 1048];
+
+        assert_eq!(
+            desktop_work_area(&work_areas, 1),
+            Some(Rectangle {
+                x: 0.0,
+                y: 32.0,
+                width: 1920.0,
+                height: 1048.0,
+            })
+        );
+
+        assert_eq!(desktop_work_area(&work_areas, 2), None);
+        assert_eq!(desktop_work_area(&[], 0), None);
+    }
+
+    #[test]
+    fn custom_cursor_pixels_are_premultiplied() {
+        let rgba = [255, 128, 0, 128, 10, 20, 30, 255];
+
+        assert_eq!(
+            premultiplied_argb(&rgba, false),
+            [0, 64, 128, 128, 30, 20, 10, 255]
+        );
+        assert_eq!(
+            premultiplied_argb(&rgba, true),
+            [128, 128, 64, 0, 255, 10, 20, 30]
+        );
+    }
+}